@@ -250,6 +250,200 @@ typedef struct UniffiForeignFutureStructVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_GIT_CREDENTIAL_PROVIDER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_GIT_CREDENTIAL_PROVIDER_METHOD0
+typedef void (*UniffiCallbackInterfaceFfiGitCredentialProviderMethod0)(uint64_t, RustBuffer, RustBuffer, RustBuffer* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_GIT_PROGRESS_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_GIT_PROGRESS_METHOD0
+typedef void (*UniffiCallbackInterfaceFfiGitProgressMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_GIT_PROGRESS_METHOD1
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_GIT_PROGRESS_METHOD1
+typedef void (*UniffiCallbackInterfaceFfiGitProgressMethod1)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_LOG_SINK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_LOG_SINK_METHOD0
+typedef void (*UniffiCallbackInterfaceFfiLogSinkMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_SNAPSHOT_PROGRESS_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_FFI_SNAPSHOT_PROGRESS_METHOD0
+typedef void (*UniffiCallbackInterfaceFfiSnapshotProgressMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_FFI_GIT_CREDENTIAL_PROVIDER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_FFI_GIT_CREDENTIAL_PROVIDER
+typedef struct UniffiVTableCallbackInterfaceFfiGitCredentialProvider {
+    UniffiCallbackInterfaceFfiGitCredentialProviderMethod0 _Nonnull credentials;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceFfiGitCredentialProvider;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_FFI_GIT_PROGRESS
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_FFI_GIT_PROGRESS
+typedef struct UniffiVTableCallbackInterfaceFfiGitProgress {
+    UniffiCallbackInterfaceFfiGitProgressMethod0 _Nonnull onProgress;
+    UniffiCallbackInterfaceFfiGitProgressMethod1 _Nonnull onSidebandMessage;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceFfiGitProgress;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_FFI_LOG_SINK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_FFI_LOG_SINK
+typedef struct UniffiVTableCallbackInterfaceFfiLogSink {
+    UniffiCallbackInterfaceFfiLogSinkMethod0 _Nonnull log;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceFfiLogSink;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_FFI_SNAPSHOT_PROGRESS
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_FFI_SNAPSHOT_PROGRESS
+typedef struct UniffiVTableCallbackInterfaceFfiSnapshotProgress {
+    UniffiCallbackInterfaceFfiSnapshotProgressMethod0 _Nonnull onProgress;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceFfiSnapshotProgress;
+
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFICANCELLATIONTOKEN
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFICANCELLATIONTOKEN
+void*_Nonnull uniffi_jj_ffi_fn_clone_fficancellationtoken(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFICANCELLATIONTOKEN
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFICANCELLATIONTOKEN
+void uniffi_jj_ffi_fn_free_fficancellationtoken(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFICANCELLATIONTOKEN_NEW
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFICANCELLATIONTOKEN_NEW
+void*_Nonnull uniffi_jj_ffi_fn_constructor_fficancellationtoken_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICANCELLATIONTOKEN_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICANCELLATIONTOKEN_CANCEL
+void uniffi_jj_ffi_fn_method_fficancellationtoken_cancel(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICANCELLATIONTOKEN_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICANCELLATIONTOKEN_IS_CANCELLED
+int8_t uniffi_jj_ffi_fn_method_fficancellationtoken_is_cancelled(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFICOMMITBUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFICOMMITBUILDER
+void*_Nonnull uniffi_jj_ffi_fn_clone_fficommitbuilder(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFICOMMITBUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFICOMMITBUILDER
+void uniffi_jj_ffi_fn_free_fficommitbuilder(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_GENERATE_NEW_CHANGE_ID
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_GENERATE_NEW_CHANGE_ID
+void*_Nonnull uniffi_jj_ffi_fn_method_fficommitbuilder_generate_new_change_id(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_AUTHOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_AUTHOR
+void*_Nonnull uniffi_jj_ffi_fn_method_fficommitbuilder_set_author(void*_Nonnull ptr, RustBuffer author, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_COMMITTER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_COMMITTER
+void*_Nonnull uniffi_jj_ffi_fn_method_fficommitbuilder_set_committer(void*_Nonnull ptr, RustBuffer committer, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_DESCRIPTION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_DESCRIPTION
+void*_Nonnull uniffi_jj_ffi_fn_method_fficommitbuilder_set_description(void*_Nonnull ptr, RustBuffer description, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_PARENTS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_PARENTS
+void*_Nonnull uniffi_jj_ffi_fn_method_fficommitbuilder_set_parents(void*_Nonnull ptr, RustBuffer parent_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_SIGN_BEHAVIOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_SIGN_BEHAVIOR
+void*_Nonnull uniffi_jj_ffi_fn_method_fficommitbuilder_set_sign_behavior(void*_Nonnull ptr, RustBuffer behavior, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_SIGN_KEY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_SIGN_KEY
+void*_Nonnull uniffi_jj_ffi_fn_method_fficommitbuilder_set_sign_key(void*_Nonnull ptr, RustBuffer key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_TREE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_SET_TREE
+void*_Nonnull uniffi_jj_ffi_fn_method_fficommitbuilder_set_tree(void*_Nonnull ptr, RustBuffer tree_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_WRITE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFICOMMITBUILDER_WRITE
+RustBuffer uniffi_jj_ffi_fn_method_fficommitbuilder_write(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIGITCREDENTIALPROVIDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIGITCREDENTIALPROVIDER
+void*_Nonnull uniffi_jj_ffi_fn_clone_ffigitcredentialprovider(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIGITCREDENTIALPROVIDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIGITCREDENTIALPROVIDER
+void uniffi_jj_ffi_fn_free_ffigitcredentialprovider(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_INIT_CALLBACK_VTABLE_FFIGITCREDENTIALPROVIDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_INIT_CALLBACK_VTABLE_FFIGITCREDENTIALPROVIDER
+void uniffi_jj_ffi_fn_init_callback_vtable_ffigitcredentialprovider(UniffiVTableCallbackInterfaceFfiGitCredentialProvider* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITCREDENTIALPROVIDER_CREDENTIALS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITCREDENTIALPROVIDER_CREDENTIALS
+RustBuffer uniffi_jj_ffi_fn_method_ffigitcredentialprovider_credentials(void*_Nonnull ptr, RustBuffer remote_name, RustBuffer url, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIGITPROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIGITPROGRESS
+void*_Nonnull uniffi_jj_ffi_fn_clone_ffigitprogress(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIGITPROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIGITPROGRESS
+void uniffi_jj_ffi_fn_free_ffigitprogress(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_INIT_CALLBACK_VTABLE_FFIGITPROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_INIT_CALLBACK_VTABLE_FFIGITPROGRESS
+void uniffi_jj_ffi_fn_init_callback_vtable_ffigitprogress(UniffiVTableCallbackInterfaceFfiGitProgress* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITPROGRESS_ON_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITPROGRESS_ON_PROGRESS
+void uniffi_jj_ffi_fn_method_ffigitprogress_on_progress(void*_Nonnull ptr, RustBuffer update, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITPROGRESS_ON_SIDEBAND_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITPROGRESS_ON_SIDEBAND_MESSAGE
+void uniffi_jj_ffi_fn_method_ffigitprogress_on_sideband_message(void*_Nonnull ptr, RustBuffer message, RustCallStatus *_Nonnull out_status
+);
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIGITTRANSACTION
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIGITTRANSACTION
@@ -261,6 +455,11 @@ void*_Nonnull uniffi_jj_ffi_fn_clone_ffigittransaction(void*_Nonnull ptr, RustCa
 void uniffi_jj_ffi_fn_free_ffigittransaction(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_BASE_REPO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_BASE_REPO
+void*_Nonnull uniffi_jj_ffi_fn_method_ffigittransaction_base_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_COMMIT
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_COMMIT
 void*_Nonnull uniffi_jj_ffi_fn_method_ffigittransaction_commit(void*_Nonnull ptr, RustBuffer description, RustCallStatus *_Nonnull out_status
@@ -281,6 +480,16 @@ RustBuffer uniffi_jj_ffi_fn_method_ffigittransaction_export_refs(void*_Nonnull p
 RustBuffer uniffi_jj_ffi_fn_method_ffigittransaction_fetch(void*_Nonnull ptr, RustBuffer remote_name, RustBuffer branch_patterns, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_FETCH_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_FETCH_ASYNC
+uint64_t uniffi_jj_ffi_fn_method_ffigittransaction_fetch_async(void*_Nonnull ptr, RustBuffer remote_name, RustBuffer branch_patterns, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_FETCH_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_FETCH_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffigittransaction_fetch_with_options(void*_Nonnull ptr, RustBuffer remote_name, RustBuffer branch_patterns, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_IMPORT_REFS
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_IMPORT_REFS
 RustBuffer uniffi_jj_ffi_fn_method_ffigittransaction_import_refs(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -291,6 +500,66 @@ RustBuffer uniffi_jj_ffi_fn_method_ffigittransaction_import_refs(void*_Nonnull p
 RustBuffer uniffi_jj_ffi_fn_method_ffigittransaction_push_branches(void*_Nonnull ptr, RustBuffer remote_name, RustBuffer branch_names, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES_ASYNC
+uint64_t uniffi_jj_ffi_fn_method_ffigittransaction_push_branches_async(void*_Nonnull ptr, RustBuffer remote_name, RustBuffer branch_names, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffigittransaction_push_branches_with_options(void*_Nonnull ptr, RustBuffer remote_name, RustBuffer branch_names, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_PUSH_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_PUSH_CHANGES
+RustBuffer uniffi_jj_ffi_fn_method_ffigittransaction_push_changes(void*_Nonnull ptr, RustBuffer remote_name, RustBuffer change_ids, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_TRACK_REMOTE_BOOKMARK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_TRACK_REMOTE_BOOKMARK
+void uniffi_jj_ffi_fn_method_ffigittransaction_track_remote_bookmark(void*_Nonnull ptr, RustBuffer name, RustBuffer remote, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_UNTRACK_REMOTE_BOOKMARK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIGITTRANSACTION_UNTRACK_REMOTE_BOOKMARK
+void uniffi_jj_ffi_fn_method_ffigittransaction_untrack_remote_bookmark(void*_Nonnull ptr, RustBuffer name, RustBuffer remote, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFILOGITERATOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFILOGITERATOR
+void*_Nonnull uniffi_jj_ffi_fn_clone_ffilogiterator(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFILOGITERATOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFILOGITERATOR
+void uniffi_jj_ffi_fn_free_ffilogiterator(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFILOGITERATOR_NEXT_PAGE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFILOGITERATOR_NEXT_PAGE
+RustBuffer uniffi_jj_ffi_fn_method_ffilogiterator_next_page(void*_Nonnull ptr, uint32_t page_size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFILOGSINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFILOGSINK
+void*_Nonnull uniffi_jj_ffi_fn_clone_ffilogsink(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFILOGSINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFILOGSINK
+void uniffi_jj_ffi_fn_free_ffilogsink(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_INIT_CALLBACK_VTABLE_FFILOGSINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_INIT_CALLBACK_VTABLE_FFILOGSINK
+void uniffi_jj_ffi_fn_init_callback_vtable_ffilogsink(UniffiVTableCallbackInterfaceFfiLogSink* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFILOGSINK_LOG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFILOGSINK_LOG
+void uniffi_jj_ffi_fn_method_ffilogsink_log(void*_Nonnull ptr, RustBuffer record, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIREADONLYREPO
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIREADONLYREPO
 void*_Nonnull uniffi_jj_ffi_fn_clone_ffireadonlyrepo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -301,26 +570,161 @@ void*_Nonnull uniffi_jj_ffi_fn_clone_ffireadonlyrepo(void*_Nonnull ptr, RustCall
 void uniffi_jj_ffi_fn_free_ffireadonlyrepo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_ACTIVITY_HISTOGRAM
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_ACTIVITY_HISTOGRAM
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_activity_histogram(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer bucket, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_BOOKMARK_CHANGES_BETWEEN_OPS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_BOOKMARK_CHANGES_BETWEEN_OPS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_bookmark_changes_between_ops(void*_Nonnull ptr, RustBuffer old_op, RustBuffer new_op, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_BOOKMARKS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_BOOKMARKS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_bookmarks(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_BOOKMARKS_CONTAINING
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_BOOKMARKS_CONTAINING
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_bookmarks_containing(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CHILDREN
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CHILDREN
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_children(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_COMMIT_EXTRA_HEADERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_COMMIT_EXTRA_HEADERS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_commit_extra_headers(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CONFLICT_SIDES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CONFLICT_SIDES
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_conflict_sides(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CONFLICTED_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CONFLICTED_PATHS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_conflicted_paths(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CONFLICTS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CONFLICTS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_conflicts(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CONTRIBUTOR_STATS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_CONTRIBUTOR_STATS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_contributor_stats(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, int8_t include_line_stats, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_COUNT_REVSET
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_COUNT_REVSET
 uint64_t uniffi_jj_ffi_fn_method_ffireadonlyrepo_count_revset(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_COUNT_REVSET_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_COUNT_REVSET_WITH_OPTIONS
+uint64_t uniffi_jj_ffi_fn_method_ffireadonlyrepo_count_revset_with_options(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_DIFF_DIRECTORY_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_DIFF_DIRECTORY_SUMMARY
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_diff_directory_summary(void*_Nonnull ptr, RustBuffer from, RustBuffer to, RustBuffer dir, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_DIFF_FILE_HUNKS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_DIFF_FILE_HUNKS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_diff_file_hunks(void*_Nonnull ptr, RustBuffer from, RustBuffer to, RustBuffer path, uint32_t context_lines, int8_t intraline, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_DIFF_STATS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_DIFF_STATS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_diff_stats(void*_Nonnull ptr, RustBuffer from, RustBuffer to, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_DIFF_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_DIFF_SUMMARY
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_diff_summary(void*_Nonnull ptr, RustBuffer from, RustBuffer to, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_CHANGE_IDS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_CHANGE_IDS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_change_ids(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_CHANGE_IDS_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_CHANGE_IDS_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_change_ids_with_options(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_commits(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_commits_with_options(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_with_options(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EXPORT_GRAPH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EXPORT_GRAPH
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_export_graph(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EXPORT_PATCH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EXPORT_PATCH
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_export_patch(void*_Nonnull ptr, RustBuffer commit_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EXPORT_REVISION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_EXPORT_REVISION
+uint64_t uniffi_jj_ffi_fn_method_ffireadonlyrepo_export_revision(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer destination, RustBuffer format, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_FIND_OPERATION_BY_IDEMPOTENCY_KEY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_FIND_OPERATION_BY_IDEMPOTENCY_KEY
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_find_operation_by_idempotency_key(void*_Nonnull ptr, RustBuffer key, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_GET_COMMIT
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_GET_COMMIT
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_get_commit(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_GET_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_GET_COMMITS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_get_commits(void*_Nonnull ptr, RustBuffer commit_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_GIT_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_GIT_HEAD
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_git_head(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_IS_HIDDEN
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_IS_HIDDEN
+int8_t uniffi_jj_ffi_fn_method_ffireadonlyrepo_is_hidden(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_LIST_TREE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_LIST_TREE
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_list_tree(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer path_prefix, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_LOG
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_LOG
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_log(void*_Nonnull ptr, RustBuffer options, RustBuffer user_email, RustCallStatus *_Nonnull out_status
@@ -331,6 +735,66 @@ RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_log(void*_Nonnull ptr, RustBu
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_log_flat(void*_Nonnull ptr, RustBuffer options, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_LOG_ITERATOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_LOG_ITERATOR
+void*_Nonnull uniffi_jj_ffi_fn_method_ffireadonlyrepo_log_iterator(void*_Nonnull ptr, RustBuffer options, RustBuffer user_email, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_MINE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_MINE
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_mine(void*_Nonnull ptr, RustBuffer user_email, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_NEW_TREE_BUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_NEW_TREE_BUILDER
+void*_Nonnull uniffi_jj_ffi_fn_method_ffireadonlyrepo_new_tree_builder(void*_Nonnull ptr, RustBuffer base_tree_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_OPERATION
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_operation(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_OPERATION_ID
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_OPERATION_ID
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_operation_id(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_OPERATION_LOG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_OPERATION_LOG
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_operation_log(void*_Nonnull ptr, RustBuffer limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_OPERATION_MERGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_OPERATION_MERGE_REPORT
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_operation_merge_report(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_READ_FILE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_READ_FILE
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_read_file(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_READ_FILE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_READ_FILE_TEXT
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_read_file_text(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_RELOAD_AT_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_RELOAD_AT_HEAD
+void*_Nonnull uniffi_jj_ffi_fn_method_ffireadonlyrepo_reload_at_head(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_REMOTE_BOOKMARKS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_REMOTE_BOOKMARKS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_remote_bookmarks(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_RENDER_COMMIT_TEMPLATE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_RENDER_COMMIT_TEMPLATE
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_render_commit_template(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer template_text, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_RESOLVE_CHANGE_ID
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_RESOLVE_CHANGE_ID
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_resolve_change_id(void*_Nonnull ptr, RustBuffer change_id, RustCallStatus *_Nonnull out_status
@@ -341,6 +805,11 @@ RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_resolve_change_id(void*_Nonnu
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_resolve_commit_prefix(void*_Nonnull ptr, RustBuffer prefix, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_RESTORE_TO_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_RESTORE_TO_OPERATION
+void*_Nonnull uniffi_jj_ffi_fn_method_ffireadonlyrepo_restore_to_operation(void*_Nonnull ptr, RustBuffer op_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_ROOT_CHANGE_ID
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_ROOT_CHANGE_ID
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_change_id(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -356,6 +825,16 @@ RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_commit(void*_Nonnull ptr
 RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_commit_id(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_SHORTEST_CHANGE_ID_PREFIX
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_SHORTEST_CHANGE_ID_PREFIX
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_shortest_change_id_prefix(void*_Nonnull ptr, RustBuffer change_id, uint32_t min_len, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_SHORTEST_COMMIT_ID_PREFIX
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_SHORTEST_COMMIT_ID_PREFIX
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_shortest_commit_id_prefix(void*_Nonnull ptr, RustBuffer commit_id, uint32_t min_len, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_START_GIT_TRANSACTION
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_START_GIT_TRANSACTION
 void*_Nonnull uniffi_jj_ffi_fn_method_ffireadonlyrepo_start_git_transaction(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -366,6 +845,91 @@ void*_Nonnull uniffi_jj_ffi_fn_method_ffireadonlyrepo_start_git_transaction(void
 void*_Nonnull uniffi_jj_ffi_fn_method_ffireadonlyrepo_start_transaction(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_STORE_INFO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_STORE_INFO
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_store_info(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_TAGS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_TAGS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_tags(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_TRUNK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_TRUNK
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_trunk(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_UNDO_LATEST_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_UNDO_LATEST_OPERATION
+void*_Nonnull uniffi_jj_ffi_fn_method_ffireadonlyrepo_undo_latest_operation(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_UNPUSHED_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_UNPUSHED_COMMITS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_unpushed_commits(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_VISIBLE_HEAD_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_VISIBLE_HEAD_COUNT
+uint64_t uniffi_jj_ffi_fn_method_ffireadonlyrepo_visible_head_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_VISIBLE_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_VISIBLE_HEADS
+RustBuffer uniffi_jj_ffi_fn_method_ffireadonlyrepo_visible_heads(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_WRITE_MERGE_TOOL_FILES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREADONLYREPO_WRITE_MERGE_TOOL_FILES
+void uniffi_jj_ffi_fn_method_ffireadonlyrepo_write_merge_tool_files(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer path, RustBuffer files, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIREPOLOADER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIREPOLOADER
+void*_Nonnull uniffi_jj_ffi_fn_clone_ffirepoloader(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIREPOLOADER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIREPOLOADER
+void uniffi_jj_ffi_fn_free_ffirepoloader(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFIREPOLOADER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFIREPOLOADER_NEW
+void*_Nonnull uniffi_jj_ffi_fn_constructor_ffirepoloader_new(RustBuffer repo_path, RustBuffer user_name, RustBuffer user_email, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREPOLOADER_LOAD_AT_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREPOLOADER_LOAD_AT_HEAD
+void*_Nonnull uniffi_jj_ffi_fn_method_ffirepoloader_load_at_head(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREPOLOADER_LOAD_AT_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIREPOLOADER_LOAD_AT_OPERATION
+void*_Nonnull uniffi_jj_ffi_fn_method_ffirepoloader_load_at_operation(void*_Nonnull ptr, RustBuffer operation, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFISNAPSHOTPROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFISNAPSHOTPROGRESS
+void*_Nonnull uniffi_jj_ffi_fn_clone_ffisnapshotprogress(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFISNAPSHOTPROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFISNAPSHOTPROGRESS
+void uniffi_jj_ffi_fn_free_ffisnapshotprogress(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_INIT_CALLBACK_VTABLE_FFISNAPSHOTPROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_INIT_CALLBACK_VTABLE_FFISNAPSHOTPROGRESS
+void uniffi_jj_ffi_fn_init_callback_vtable_ffisnapshotprogress(UniffiVTableCallbackInterfaceFfiSnapshotProgress* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFISNAPSHOTPROGRESS_ON_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFISNAPSHOTPROGRESS_ON_PROGRESS
+void uniffi_jj_ffi_fn_method_ffisnapshotprogress_on_progress(void*_Nonnull ptr, RustBuffer update, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFITRANSACTION
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFITRANSACTION
 void*_Nonnull uniffi_jj_ffi_fn_clone_ffitransaction(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -381,9 +945,34 @@ void uniffi_jj_ffi_fn_free_ffitransaction(void*_Nonnull ptr, RustCallStatus *_No
 void uniffi_jj_ffi_fn_method_ffitransaction_abandon_commit(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_ABSORB
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_ABSORB
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_absorb(void*_Nonnull ptr, RustBuffer source_commit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_ADD_TRAILERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_ADD_TRAILERS
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_add_trailers(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer trailers, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_BASE_REPO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_BASE_REPO
+void*_Nonnull uniffi_jj_ffi_fn_method_ffitransaction_base_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_BOOKMARKS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_BOOKMARKS
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_bookmarks(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_COMMIT
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_COMMIT
-void*_Nonnull uniffi_jj_ffi_fn_method_ffitransaction_commit(void*_Nonnull ptr, RustBuffer description, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_commit(void*_Nonnull ptr, RustBuffer description, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_COMMIT_WORKING_COPY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_COMMIT_WORKING_COPY
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_commit_working_copy(void*_Nonnull ptr, RustBuffer description, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_CREATE_COMMIT_FROM_PARENT
@@ -401,44 +990,319 @@ RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_create_empty_commit(void*_Nonn
 void uniffi_jj_ffi_fn_method_ffitransaction_discard(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_HAS_CHANGES
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_HAS_CHANGES
-int8_t uniffi_jj_ffi_fn_method_ffitransaction_has_changes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REWRITE_COMMIT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REWRITE_COMMIT
-RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_rewrite_commit(void*_Nonnull ptr, RustBuffer rewrite, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_CHANGE_IDS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_CHANGE_IDS
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_to_change_ids(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_UPDATE_DESCRIPTION
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_UPDATE_DESCRIPTION
-RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_update_description(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer new_description, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_CHANGE_IDS_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_CHANGE_IDS_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_to_change_ids_with_options(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIWORKSPACE
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIWORKSPACE
-void*_Nonnull uniffi_jj_ffi_fn_clone_ffiworkspace(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_COMMITS
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_to_commits(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIWORKSPACE
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIWORKSPACE
-void uniffi_jj_ffi_fn_free_ffiworkspace(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_COMMITS_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_COMMITS_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_to_commits_with_options(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFIWORKSPACE_LOAD
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFIWORKSPACE_LOAD
-void*_Nonnull uniffi_jj_ffi_fn_constructor_ffiworkspace_load(RustBuffer workspace_path, RustBuffer user_name, RustBuffer user_email, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_EVALUATE_REVSET_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_with_options(void*_Nonnull ptr, RustBuffer revset_str, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO
-void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_GET_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_GET_COMMIT
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_get_commit(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO_PATH
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO_PATH
-RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_repo_path(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_HAS_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_HAS_CHANGES
+int8_t uniffi_jj_ffi_fn_method_ffitransaction_has_changes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_IMPORT_PATCH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_IMPORT_PATCH
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_import_patch(void*_Nonnull ptr, RustBuffer patch, RustBuffer destination, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_MERGE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_MERGE
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_merge(void*_Nonnull ptr, RustBuffer parent_ids, RustBuffer description, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_NEW_COMMIT_BUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_NEW_COMMIT_BUILDER
+void*_Nonnull uniffi_jj_ffi_fn_method_ffitransaction_new_commit_builder(void*_Nonnull ptr, RustBuffer parent_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_PARALLELIZE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_PARALLELIZE
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_parallelize(void*_Nonnull ptr, RustBuffer commit_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REBASE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REBASE
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_rebase(void*_Nonnull ptr, RustBuffer source_commit_ids, RustBuffer new_parent_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REBASE_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REBASE_COMMITS
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_rebase_commits(void*_Nonnull ptr, RustBuffer target, RustBuffer destination, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REBASE_DESCENDANTS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REBASE_DESCENDANTS
+uint32_t uniffi_jj_ffi_fn_method_ffitransaction_rebase_descendants(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_RESOLVE_CONFLICT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_RESOLVE_CONFLICT
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_resolve_conflict(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer path, RustBuffer content, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_RESOLVE_CONFLICT_FROM_MERGE_TOOL
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_RESOLVE_CONFLICT_FROM_MERGE_TOOL
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_resolve_conflict_from_merge_tool(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer path, RustBuffer output_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REWRITE_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REWRITE_COMMIT
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_rewrite_commit(void*_Nonnull ptr, RustBuffer rewrite, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REWRITE_COMMIT_BUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_REWRITE_COMMIT_BUILDER
+void*_Nonnull uniffi_jj_ffi_fn_method_ffitransaction_rewrite_commit_builder(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SET_IDEMPOTENCY_KEY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SET_IDEMPOTENCY_KEY
+void uniffi_jj_ffi_fn_method_ffitransaction_set_idempotency_key(void*_Nonnull ptr, RustBuffer key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SET_TAG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SET_TAG
+void uniffi_jj_ffi_fn_method_ffitransaction_set_tag(void*_Nonnull ptr, RustBuffer key, RustBuffer value, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SPLIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SPLIT
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_split(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer paths, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SQUASH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SQUASH
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_squash(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SQUASH_INTO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_SQUASH_INTO
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_squash_into(void*_Nonnull ptr, RustBuffer from, RustBuffer into, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_UPDATE_DESCRIPTION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITRANSACTION_UPDATE_DESCRIPTION
+RustBuffer uniffi_jj_ffi_fn_method_ffitransaction_update_description(void*_Nonnull ptr, RustBuffer commit_id, RustBuffer new_description, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFITREEBUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFITREEBUILDER
+void*_Nonnull uniffi_jj_ffi_fn_clone_ffitreebuilder(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFITREEBUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFITREEBUILDER
+void uniffi_jj_ffi_fn_free_ffitreebuilder(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITREEBUILDER_REMOVE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITREEBUILDER_REMOVE
+void uniffi_jj_ffi_fn_method_ffitreebuilder_remove(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITREEBUILDER_SET_SYMLINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITREEBUILDER_SET_SYMLINK
+void uniffi_jj_ffi_fn_method_ffitreebuilder_set_symlink(void*_Nonnull ptr, RustBuffer path, RustBuffer target, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITREEBUILDER_WRITE_FILE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITREEBUILDER_WRITE_FILE
+void uniffi_jj_ffi_fn_method_ffitreebuilder_write_file(void*_Nonnull ptr, RustBuffer path, RustBuffer content, int8_t executable, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITREEBUILDER_WRITE_TREE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFITREEBUILDER_WRITE_TREE
+RustBuffer uniffi_jj_ffi_fn_method_ffitreebuilder_write_tree(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIWORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CLONE_FFIWORKSPACE
+void*_Nonnull uniffi_jj_ffi_fn_clone_ffiworkspace(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIWORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FREE_FFIWORKSPACE
+void uniffi_jj_ffi_fn_free_ffiworkspace(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFIWORKSPACE_LOAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFIWORKSPACE_LOAD
+void*_Nonnull uniffi_jj_ffi_fn_constructor_ffiworkspace_load(RustBuffer workspace_path, RustBuffer user_name, RustBuffer user_email, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFIWORKSPACE_LOAD_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_CONSTRUCTOR_FFIWORKSPACE_LOAD_WITH_USER_CONFIG
+void*_Nonnull uniffi_jj_ffi_fn_constructor_ffiworkspace_load_with_user_config(RustBuffer workspace_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_CONFLICT_MARKER_STYLE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_CONFLICT_MARKER_STYLE
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_conflict_marker_style(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_EDIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_EDIT
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_edit(void*_Nonnull ptr, RustBuffer commit_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_FINISH_TRANSACTION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_FINISH_TRANSACTION
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_finish_transaction(void*_Nonnull ptr, void*_Nonnull tx, RustBuffer description, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_FSMONITOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_FSMONITOR
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_fsmonitor(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_GC
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_GC
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_gc(void*_Nonnull ptr, RustBuffer keep_ops_newer_than, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_GIT_HEAD_SYNC_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_GIT_HEAD_SYNC_REPORT
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_git_head_sync_report(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_IS_COLOCATED
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_IS_COLOCATED
+int8_t uniffi_jj_ffi_fn_method_ffiworkspace_is_colocated(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_NEW_CHILD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_NEW_CHILD
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_new_child(void*_Nonnull ptr, RustBuffer parent_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_OPERATION_MERGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_OPERATION_MERGE_REPORT
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_operation_merge_report(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REINDEX
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REINDEX
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_reindex(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_RELOAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_RELOAD
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_reload(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO_AT_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO_AT_OPERATION
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_repo_at_operation(void*_Nonnull ptr, RustBuffer op_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO_FORMAT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO_FORMAT
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_repo_format(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO_PATH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_REPO_PATH
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_repo_path(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_RESTORE_PATHS_FROM_PARENT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_RESTORE_PATHS_FROM_PARENT
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_restore_paths_from_parent(void*_Nonnull ptr, RustBuffer paths, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_SET_CONFLICT_MARKER_STYLE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_SET_CONFLICT_MARKER_STYLE
+void uniffi_jj_ffi_fn_method_ffiworkspace_set_conflict_marker_style(void*_Nonnull ptr, RustBuffer style, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_SET_FSMONITOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_SET_FSMONITOR
+void uniffi_jj_ffi_fn_method_ffiworkspace_set_fsmonitor(void*_Nonnull ptr, RustBuffer fsmonitor, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_SNAPSHOT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_SNAPSHOT
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_snapshot(void*_Nonnull ptr, RustBuffer cancellation, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_SNAPSHOT_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_SNAPSHOT_WITH_OPTIONS
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_snapshot_with_options(void*_Nonnull ptr, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_TRACK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_TRACK
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_track(void*_Nonnull ptr, RustBuffer paths, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_UNTRACK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_UNTRACK
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_untrack(void*_Nonnull ptr, RustBuffer paths, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_UNTRACKED_FILES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_UNTRACKED_FILES
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_untracked_files(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_UPDATE_STALE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_UPDATE_STALE
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_update_stale(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_ADD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_ADD
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_workspace_add(void*_Nonnull ptr, RustBuffer path, RustBuffer name, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_FORGET
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_FORGET
+void*_Nonnull uniffi_jj_ffi_fn_method_ffiworkspace_workspace_forget(void*_Nonnull ptr, RustBuffer name, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_LIST
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_LIST
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_workspace_list(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_NAME
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_NAME
+RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_workspace_name(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_METHOD_FFIWORKSPACE_WORKSPACE_ROOT
@@ -446,9 +1310,34 @@ RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_repo_path(void*_Nonnull ptr, Rus
 RustBuffer uniffi_jj_ffi_fn_method_ffiworkspace_workspace_root(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_ADD_DESCRIPTION_TRAILERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_ADD_DESCRIPTION_TRAILERS
+RustBuffer uniffi_jj_ffi_fn_func_add_description_trailers(RustBuffer description, RustBuffer trailers, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_CHECK_WORKSPACE_COMPATIBILITY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_CHECK_WORKSPACE_COMPATIBILITY
+RustBuffer uniffi_jj_ffi_fn_func_check_workspace_compatibility(RustBuffer workspace_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_CLONE_GIT_WORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_CLONE_GIT_WORKSPACE
+void*_Nonnull uniffi_jj_ffi_fn_func_clone_git_workspace(RustBuffer source, RustBuffer workspace_path, RustBuffer user_name, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_CLONE_GIT_WORKSPACE_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_CLONE_GIT_WORKSPACE_WITH_USER_CONFIG
+void*_Nonnull uniffi_jj_ffi_fn_func_clone_git_workspace_with_user_config(RustBuffer source, RustBuffer workspace_path, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_DESCRIPTION_TRAILERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_DESCRIPTION_TRAILERS
+RustBuffer uniffi_jj_ffi_fn_func_description_trailers(RustBuffer description, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_GET_ABANDONED_COMMITS_FROM_IMPORT
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_GET_ABANDONED_COMMITS_FROM_IMPORT
-RustBuffer uniffi_jj_ffi_fn_func_get_abandoned_commits_from_import(RustBuffer _stats, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_jj_ffi_fn_func_get_abandoned_commits_from_import(RustBuffer stats, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_COLOCATED_GIT_WORKSPACE
@@ -456,11 +1345,63 @@ RustBuffer uniffi_jj_ffi_fn_func_get_abandoned_commits_from_import(RustBuffer _s
 void*_Nonnull uniffi_jj_ffi_fn_func_init_colocated_git_workspace(RustBuffer workspace_path, RustBuffer user_name, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_COLOCATED_GIT_WORKSPACE_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_COLOCATED_GIT_WORKSPACE_WITH_OPTIONS
+void*_Nonnull uniffi_jj_ffi_fn_func_init_colocated_git_workspace_with_options(RustBuffer workspace_path, RustBuffer user_name, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_COLOCATED_GIT_WORKSPACE_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_COLOCATED_GIT_WORKSPACE_WITH_USER_CONFIG
+void*_Nonnull uniffi_jj_ffi_fn_func_init_colocated_git_workspace_with_user_config(RustBuffer workspace_path, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_EXTERNAL_GIT_WORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_EXTERNAL_GIT_WORKSPACE
+void*_Nonnull uniffi_jj_ffi_fn_func_init_external_git_workspace(RustBuffer workspace_path, RustBuffer git_repo_path, RustBuffer user_name, RustBuffer user_email, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_EXTERNAL_GIT_WORKSPACE_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_EXTERNAL_GIT_WORKSPACE_WITH_USER_CONFIG
+void*_Nonnull uniffi_jj_ffi_fn_func_init_external_git_workspace_with_user_config(RustBuffer workspace_path, RustBuffer git_repo_path, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_INTERNAL_GIT_WORKSPACE
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_INTERNAL_GIT_WORKSPACE
 void*_Nonnull uniffi_jj_ffi_fn_func_init_internal_git_workspace(RustBuffer workspace_path, RustBuffer user_name, RustBuffer user_email, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_INTERNAL_GIT_WORKSPACE_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_INTERNAL_GIT_WORKSPACE_WITH_OPTIONS
+void*_Nonnull uniffi_jj_ffi_fn_func_init_internal_git_workspace_with_options(RustBuffer workspace_path, RustBuffer user_name, RustBuffer user_email, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_INTERNAL_GIT_WORKSPACE_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INIT_INTERNAL_GIT_WORKSPACE_WITH_USER_CONFIG
+void*_Nonnull uniffi_jj_ffi_fn_func_init_internal_git_workspace_with_user_config(RustBuffer workspace_path, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INSTALL_LOG_SINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_INSTALL_LOG_SINK
+void uniffi_jj_ffi_fn_func_install_log_sink(void*_Nonnull sink, RustBuffer max_level, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_LIBRARY_INFO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_LIBRARY_INFO
+RustBuffer uniffi_jj_ffi_fn_func_library_info(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_REMOVE_LOG_SINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_REMOVE_LOG_SINK
+void uniffi_jj_ffi_fn_func_remove_log_sink(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_SET_DESCRIPTION_TRAILERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_FN_FUNC_SET_DESCRIPTION_TRAILERS
+RustBuffer uniffi_jj_ffi_fn_func_set_description_trailers(RustBuffer description, RustBuffer trailers, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUSTBUFFER_ALLOC
 #define UNIFFI_FFIDEF_FFI_JJ_FFI_RUSTBUFFER_ALLOC
 RustBuffer ffi_jj_ffi_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
@@ -586,350 +1527,1244 @@ uint32_t ffi_jj_ffi_rust_future_complete_u32(uint64_t handle, RustCallStatus *_N
 void ffi_jj_ffi_rust_future_poll_i32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_I32
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_I32
-void ffi_jj_ffi_rust_future_cancel_i32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_I32
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_I32
+void ffi_jj_ffi_rust_future_cancel_i32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_I32
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_I32
+void ffi_jj_ffi_rust_future_free_i32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_I32
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_I32
+int32_t ffi_jj_ffi_rust_future_complete_i32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_U64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_U64
+void ffi_jj_ffi_rust_future_poll_u64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_U64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_U64
+void ffi_jj_ffi_rust_future_cancel_u64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_U64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_U64
+void ffi_jj_ffi_rust_future_free_u64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_U64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_U64
+uint64_t ffi_jj_ffi_rust_future_complete_u64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_I64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_I64
+void ffi_jj_ffi_rust_future_poll_i64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_I64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_I64
+void ffi_jj_ffi_rust_future_cancel_i64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_I64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_I64
+void ffi_jj_ffi_rust_future_free_i64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_I64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_I64
+int64_t ffi_jj_ffi_rust_future_complete_i64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_F32
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_F32
+void ffi_jj_ffi_rust_future_poll_f32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_F32
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_F32
+void ffi_jj_ffi_rust_future_cancel_f32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_F32
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_F32
+void ffi_jj_ffi_rust_future_free_f32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_F32
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_F32
+float ffi_jj_ffi_rust_future_complete_f32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_F64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_F64
+void ffi_jj_ffi_rust_future_poll_f64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_F64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_F64
+void ffi_jj_ffi_rust_future_cancel_f64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_F64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_F64
+void ffi_jj_ffi_rust_future_free_f64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_F64
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_F64
+double ffi_jj_ffi_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_POINTER
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_POINTER
+void ffi_jj_ffi_rust_future_poll_pointer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_POINTER
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_POINTER
+void ffi_jj_ffi_rust_future_cancel_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_POINTER
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_POINTER
+void ffi_jj_ffi_rust_future_free_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_POINTER
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_POINTER
+void*_Nonnull ffi_jj_ffi_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_RUST_BUFFER
+void ffi_jj_ffi_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_RUST_BUFFER
+void ffi_jj_ffi_rust_future_cancel_rust_buffer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_RUST_BUFFER
+void ffi_jj_ffi_rust_future_free_rust_buffer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_RUST_BUFFER
+RustBuffer ffi_jj_ffi_rust_future_complete_rust_buffer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_VOID
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_VOID
+void ffi_jj_ffi_rust_future_poll_void(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_VOID
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_VOID
+void ffi_jj_ffi_rust_future_cancel_void(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_VOID
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_VOID
+void ffi_jj_ffi_rust_future_free_void(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_VOID
+#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_VOID
+void ffi_jj_ffi_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_ADD_DESCRIPTION_TRAILERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_ADD_DESCRIPTION_TRAILERS
+uint16_t uniffi_jj_ffi_checksum_func_add_description_trailers(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_CHECK_WORKSPACE_COMPATIBILITY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_CHECK_WORKSPACE_COMPATIBILITY
+uint16_t uniffi_jj_ffi_checksum_func_check_workspace_compatibility(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_CLONE_GIT_WORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_CLONE_GIT_WORKSPACE
+uint16_t uniffi_jj_ffi_checksum_func_clone_git_workspace(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_CLONE_GIT_WORKSPACE_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_CLONE_GIT_WORKSPACE_WITH_USER_CONFIG
+uint16_t uniffi_jj_ffi_checksum_func_clone_git_workspace_with_user_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_DESCRIPTION_TRAILERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_DESCRIPTION_TRAILERS
+uint16_t uniffi_jj_ffi_checksum_func_description_trailers(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_GET_ABANDONED_COMMITS_FROM_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_GET_ABANDONED_COMMITS_FROM_IMPORT
+uint16_t uniffi_jj_ffi_checksum_func_get_abandoned_commits_from_import(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_COLOCATED_GIT_WORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_COLOCATED_GIT_WORKSPACE
+uint16_t uniffi_jj_ffi_checksum_func_init_colocated_git_workspace(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_COLOCATED_GIT_WORKSPACE_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_COLOCATED_GIT_WORKSPACE_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_func_init_colocated_git_workspace_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_COLOCATED_GIT_WORKSPACE_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_COLOCATED_GIT_WORKSPACE_WITH_USER_CONFIG
+uint16_t uniffi_jj_ffi_checksum_func_init_colocated_git_workspace_with_user_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_EXTERNAL_GIT_WORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_EXTERNAL_GIT_WORKSPACE
+uint16_t uniffi_jj_ffi_checksum_func_init_external_git_workspace(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_EXTERNAL_GIT_WORKSPACE_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_EXTERNAL_GIT_WORKSPACE_WITH_USER_CONFIG
+uint16_t uniffi_jj_ffi_checksum_func_init_external_git_workspace_with_user_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_INTERNAL_GIT_WORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_INTERNAL_GIT_WORKSPACE
+uint16_t uniffi_jj_ffi_checksum_func_init_internal_git_workspace(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_INTERNAL_GIT_WORKSPACE_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_INTERNAL_GIT_WORKSPACE_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_func_init_internal_git_workspace_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_INTERNAL_GIT_WORKSPACE_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_INTERNAL_GIT_WORKSPACE_WITH_USER_CONFIG
+uint16_t uniffi_jj_ffi_checksum_func_init_internal_git_workspace_with_user_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INSTALL_LOG_SINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INSTALL_LOG_SINK
+uint16_t uniffi_jj_ffi_checksum_func_install_log_sink(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_LIBRARY_INFO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_LIBRARY_INFO
+uint16_t uniffi_jj_ffi_checksum_func_library_info(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_REMOVE_LOG_SINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_REMOVE_LOG_SINK
+uint16_t uniffi_jj_ffi_checksum_func_remove_log_sink(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_SET_DESCRIPTION_TRAILERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_SET_DESCRIPTION_TRAILERS
+uint16_t uniffi_jj_ffi_checksum_func_set_description_trailers(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICANCELLATIONTOKEN_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICANCELLATIONTOKEN_CANCEL
+uint16_t uniffi_jj_ffi_checksum_method_fficancellationtoken_cancel(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICANCELLATIONTOKEN_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICANCELLATIONTOKEN_IS_CANCELLED
+uint16_t uniffi_jj_ffi_checksum_method_fficancellationtoken_is_cancelled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_GENERATE_NEW_CHANGE_ID
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_GENERATE_NEW_CHANGE_ID
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_generate_new_change_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_AUTHOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_AUTHOR
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_set_author(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_COMMITTER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_COMMITTER
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_set_committer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_DESCRIPTION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_DESCRIPTION
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_set_description(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_PARENTS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_PARENTS
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_set_parents(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_SIGN_BEHAVIOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_SIGN_BEHAVIOR
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_set_sign_behavior(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_SIGN_KEY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_SIGN_KEY
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_set_sign_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_TREE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_SET_TREE
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_set_tree(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_WRITE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFICOMMITBUILDER_WRITE
+uint16_t uniffi_jj_ffi_checksum_method_fficommitbuilder_write(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITCREDENTIALPROVIDER_CREDENTIALS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITCREDENTIALPROVIDER_CREDENTIALS
+uint16_t uniffi_jj_ffi_checksum_method_ffigitcredentialprovider_credentials(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITPROGRESS_ON_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITPROGRESS_ON_PROGRESS
+uint16_t uniffi_jj_ffi_checksum_method_ffigitprogress_on_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITPROGRESS_ON_SIDEBAND_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITPROGRESS_ON_SIDEBAND_MESSAGE
+uint16_t uniffi_jj_ffi_checksum_method_ffigitprogress_on_sideband_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_BASE_REPO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_BASE_REPO
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_base_repo(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_COMMIT
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_commit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_DISCARD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_DISCARD
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_discard(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_EXPORT_REFS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_EXPORT_REFS
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_export_refs(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_FETCH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_FETCH
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_fetch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_FETCH_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_FETCH_ASYNC
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_fetch_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_FETCH_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_FETCH_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_fetch_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_IMPORT_REFS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_IMPORT_REFS
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_import_refs(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_push_branches(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES_ASYNC
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_push_branches_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_push_branches_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_CHANGES
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_push_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_TRACK_REMOTE_BOOKMARK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_TRACK_REMOTE_BOOKMARK
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_track_remote_bookmark(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_UNTRACK_REMOTE_BOOKMARK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_UNTRACK_REMOTE_BOOKMARK
+uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_untrack_remote_bookmark(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFILOGITERATOR_NEXT_PAGE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFILOGITERATOR_NEXT_PAGE
+uint16_t uniffi_jj_ffi_checksum_method_ffilogiterator_next_page(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFILOGSINK_LOG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFILOGSINK_LOG
+uint16_t uniffi_jj_ffi_checksum_method_ffilogsink_log(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ACTIVITY_HISTOGRAM
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ACTIVITY_HISTOGRAM
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_activity_histogram(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_BOOKMARK_CHANGES_BETWEEN_OPS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_BOOKMARK_CHANGES_BETWEEN_OPS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_bookmark_changes_between_ops(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_BOOKMARKS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_BOOKMARKS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_bookmarks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_BOOKMARKS_CONTAINING
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_BOOKMARKS_CONTAINING
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_bookmarks_containing(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CHILDREN
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CHILDREN
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_children(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_COMMIT_EXTRA_HEADERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_COMMIT_EXTRA_HEADERS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_commit_extra_headers(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CONFLICT_SIDES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CONFLICT_SIDES
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_conflict_sides(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CONFLICTED_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CONFLICTED_PATHS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_conflicted_paths(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CONFLICTS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CONFLICTS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_conflicts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CONTRIBUTOR_STATS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_CONTRIBUTOR_STATS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_contributor_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_COUNT_REVSET
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_COUNT_REVSET
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_count_revset(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_COUNT_REVSET_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_COUNT_REVSET_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_count_revset_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_DIFF_DIRECTORY_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_DIFF_DIRECTORY_SUMMARY
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_diff_directory_summary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_DIFF_FILE_HUNKS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_DIFF_FILE_HUNKS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_diff_file_hunks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_DIFF_STATS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_DIFF_STATS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_diff_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_DIFF_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_DIFF_SUMMARY
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_diff_summary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_CHANGE_IDS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_CHANGE_IDS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_change_ids(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_CHANGE_IDS_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_CHANGE_IDS_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_change_ids_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_commits(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_commits_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EXPORT_GRAPH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EXPORT_GRAPH
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_export_graph(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EXPORT_PATCH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EXPORT_PATCH
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_export_patch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EXPORT_REVISION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EXPORT_REVISION
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_export_revision(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_FIND_OPERATION_BY_IDEMPOTENCY_KEY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_FIND_OPERATION_BY_IDEMPOTENCY_KEY
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_find_operation_by_idempotency_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_GET_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_GET_COMMIT
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_get_commit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_GET_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_GET_COMMITS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_get_commits(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_GIT_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_GIT_HEAD
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_git_head(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_IS_HIDDEN
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_IS_HIDDEN
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_is_hidden(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LIST_TREE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LIST_TREE
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_list_tree(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_log(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG_FLAT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG_FLAT
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_log_flat(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG_ITERATOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG_ITERATOR
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_log_iterator(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_MINE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_MINE
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_mine(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_NEW_TREE_BUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_NEW_TREE_BUILDER
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_new_tree_builder(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_OPERATION
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_operation(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_OPERATION_ID
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_OPERATION_ID
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_operation_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_OPERATION_LOG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_OPERATION_LOG
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_operation_log(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_OPERATION_MERGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_OPERATION_MERGE_REPORT
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_operation_merge_report(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_READ_FILE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_READ_FILE
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_read_file(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_READ_FILE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_READ_FILE_TEXT
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_read_file_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RELOAD_AT_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RELOAD_AT_HEAD
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_reload_at_head(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_REMOTE_BOOKMARKS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_REMOTE_BOOKMARKS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_remote_bookmarks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RENDER_COMMIT_TEMPLATE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RENDER_COMMIT_TEMPLATE
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_render_commit_template(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESOLVE_CHANGE_ID
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESOLVE_CHANGE_ID
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_resolve_change_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESOLVE_COMMIT_PREFIX
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESOLVE_COMMIT_PREFIX
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_resolve_commit_prefix(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESTORE_TO_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESTORE_TO_OPERATION
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_restore_to_operation(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_CHANGE_ID
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_CHANGE_ID
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_root_change_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_COMMIT
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_root_commit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_COMMIT_ID
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_COMMIT_ID
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_root_commit_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_SHORTEST_CHANGE_ID_PREFIX
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_SHORTEST_CHANGE_ID_PREFIX
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_shortest_change_id_prefix(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_SHORTEST_COMMIT_ID_PREFIX
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_SHORTEST_COMMIT_ID_PREFIX
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_shortest_commit_id_prefix(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_START_GIT_TRANSACTION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_START_GIT_TRANSACTION
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_start_git_transaction(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_START_TRANSACTION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_START_TRANSACTION
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_start_transaction(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_STORE_INFO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_STORE_INFO
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_store_info(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_TAGS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_TAGS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_tags(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_TRUNK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_TRUNK
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_trunk(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_UNDO_LATEST_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_UNDO_LATEST_OPERATION
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_undo_latest_operation(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_UNPUSHED_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_UNPUSHED_COMMITS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_unpushed_commits(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_VISIBLE_HEAD_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_VISIBLE_HEAD_COUNT
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_visible_head_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_VISIBLE_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_VISIBLE_HEADS
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_visible_heads(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_WRITE_MERGE_TOOL_FILES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_WRITE_MERGE_TOOL_FILES
+uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_write_merge_tool_files(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREPOLOADER_LOAD_AT_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREPOLOADER_LOAD_AT_HEAD
+uint16_t uniffi_jj_ffi_checksum_method_ffirepoloader_load_at_head(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREPOLOADER_LOAD_AT_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREPOLOADER_LOAD_AT_OPERATION
+uint16_t uniffi_jj_ffi_checksum_method_ffirepoloader_load_at_operation(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFISNAPSHOTPROGRESS_ON_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFISNAPSHOTPROGRESS_ON_PROGRESS
+uint16_t uniffi_jj_ffi_checksum_method_ffisnapshotprogress_on_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_ABANDON_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_ABANDON_COMMIT
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_abandon_commit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_ABSORB
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_ABSORB
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_absorb(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_ADD_TRAILERS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_ADD_TRAILERS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_add_trailers(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_BASE_REPO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_BASE_REPO
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_base_repo(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_BOOKMARKS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_BOOKMARKS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_bookmarks(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_I32
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_I32
-void ffi_jj_ffi_rust_future_free_i32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_COMMIT
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_commit(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_I32
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_I32
-int32_t ffi_jj_ffi_rust_future_complete_i32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_COMMIT_WORKING_COPY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_COMMIT_WORKING_COPY
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_commit_working_copy(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_U64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_U64
-void ffi_jj_ffi_rust_future_poll_u64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_CREATE_COMMIT_FROM_PARENT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_CREATE_COMMIT_FROM_PARENT
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_create_commit_from_parent(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_U64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_U64
-void ffi_jj_ffi_rust_future_cancel_u64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_CREATE_EMPTY_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_CREATE_EMPTY_COMMIT
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_create_empty_commit(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_U64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_U64
-void ffi_jj_ffi_rust_future_free_u64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_DISCARD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_DISCARD
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_discard(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_U64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_U64
-uint64_t ffi_jj_ffi_rust_future_complete_u64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_I64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_I64
-void ffi_jj_ffi_rust_future_poll_i64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_CHANGE_IDS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_CHANGE_IDS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_to_change_ids(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_I64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_I64
-void ffi_jj_ffi_rust_future_cancel_i64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_CHANGE_IDS_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_CHANGE_IDS_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_to_change_ids_with_options(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_I64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_I64
-void ffi_jj_ffi_rust_future_free_i64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_COMMITS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_to_commits(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_I64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_I64
-int64_t ffi_jj_ffi_rust_future_complete_i64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_COMMITS_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_TO_COMMITS_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_to_commits_with_options(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_F32
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_F32
-void ffi_jj_ffi_rust_future_poll_f32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_EVALUATE_REVSET_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_with_options(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_F32
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_F32
-void ffi_jj_ffi_rust_future_cancel_f32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_GET_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_GET_COMMIT
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_get_commit(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_F32
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_F32
-void ffi_jj_ffi_rust_future_free_f32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_HAS_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_HAS_CHANGES
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_has_changes(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_F32
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_F32
-float ffi_jj_ffi_rust_future_complete_f32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_IMPORT_PATCH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_IMPORT_PATCH
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_import_patch(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_F64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_F64
-void ffi_jj_ffi_rust_future_poll_f64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_MERGE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_MERGE
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_merge(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_F64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_F64
-void ffi_jj_ffi_rust_future_cancel_f64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_NEW_COMMIT_BUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_NEW_COMMIT_BUILDER
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_new_commit_builder(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_F64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_F64
-void ffi_jj_ffi_rust_future_free_f64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_PARALLELIZE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_PARALLELIZE
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_parallelize(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_F64
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_F64
-double ffi_jj_ffi_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REBASE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REBASE
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_rebase(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_POINTER
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_POINTER
-void ffi_jj_ffi_rust_future_poll_pointer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REBASE_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REBASE_COMMITS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_rebase_commits(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_POINTER
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_POINTER
-void ffi_jj_ffi_rust_future_cancel_pointer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REBASE_DESCENDANTS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REBASE_DESCENDANTS
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_rebase_descendants(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_POINTER
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_POINTER
-void ffi_jj_ffi_rust_future_free_pointer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_RESOLVE_CONFLICT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_RESOLVE_CONFLICT
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_resolve_conflict(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_POINTER
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_POINTER
-void*_Nonnull ffi_jj_ffi_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_RESOLVE_CONFLICT_FROM_MERGE_TOOL
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_RESOLVE_CONFLICT_FROM_MERGE_TOOL
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_resolve_conflict_from_merge_tool(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_RUST_BUFFER
-void ffi_jj_ffi_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REWRITE_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REWRITE_COMMIT
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_rewrite_commit(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_RUST_BUFFER
-void ffi_jj_ffi_rust_future_cancel_rust_buffer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REWRITE_COMMIT_BUILDER
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REWRITE_COMMIT_BUILDER
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_rewrite_commit_builder(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_RUST_BUFFER
-void ffi_jj_ffi_rust_future_free_rust_buffer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SET_IDEMPOTENCY_KEY
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SET_IDEMPOTENCY_KEY
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_set_idempotency_key(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_RUST_BUFFER
-RustBuffer ffi_jj_ffi_rust_future_complete_rust_buffer(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SET_TAG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SET_TAG
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_set_tag(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_VOID
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_POLL_VOID
-void ffi_jj_ffi_rust_future_poll_void(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SPLIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SPLIT
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_split(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_VOID
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_CANCEL_VOID
-void ffi_jj_ffi_rust_future_cancel_void(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SQUASH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SQUASH
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_squash(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_VOID
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_FREE_VOID
-void ffi_jj_ffi_rust_future_free_void(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SQUASH_INTO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_SQUASH_INTO
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_squash_into(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_VOID
-#define UNIFFI_FFIDEF_FFI_JJ_FFI_RUST_FUTURE_COMPLETE_VOID
-void ffi_jj_ffi_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_UPDATE_DESCRIPTION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_UPDATE_DESCRIPTION
+uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_update_description(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_GET_ABANDONED_COMMITS_FROM_IMPORT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_GET_ABANDONED_COMMITS_FROM_IMPORT
-uint16_t uniffi_jj_ffi_checksum_func_get_abandoned_commits_from_import(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITREEBUILDER_REMOVE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITREEBUILDER_REMOVE
+uint16_t uniffi_jj_ffi_checksum_method_ffitreebuilder_remove(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_COLOCATED_GIT_WORKSPACE
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_COLOCATED_GIT_WORKSPACE
-uint16_t uniffi_jj_ffi_checksum_func_init_colocated_git_workspace(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITREEBUILDER_SET_SYMLINK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITREEBUILDER_SET_SYMLINK
+uint16_t uniffi_jj_ffi_checksum_method_ffitreebuilder_set_symlink(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_INTERNAL_GIT_WORKSPACE
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_FUNC_INIT_INTERNAL_GIT_WORKSPACE
-uint16_t uniffi_jj_ffi_checksum_func_init_internal_git_workspace(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITREEBUILDER_WRITE_FILE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITREEBUILDER_WRITE_FILE
+uint16_t uniffi_jj_ffi_checksum_method_ffitreebuilder_write_file(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_COMMIT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_COMMIT
-uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_commit(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITREEBUILDER_WRITE_TREE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITREEBUILDER_WRITE_TREE
+uint16_t uniffi_jj_ffi_checksum_method_ffitreebuilder_write_tree(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_DISCARD
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_DISCARD
-uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_discard(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_CONFLICT_MARKER_STYLE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_CONFLICT_MARKER_STYLE
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_conflict_marker_style(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_EXPORT_REFS
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_EXPORT_REFS
-uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_export_refs(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_EDIT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_EDIT
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_edit(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_FETCH
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_FETCH
-uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_fetch(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_FINISH_TRANSACTION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_FINISH_TRANSACTION
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_finish_transaction(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_IMPORT_REFS
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_IMPORT_REFS
-uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_import_refs(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_FSMONITOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_FSMONITOR
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_fsmonitor(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIGITTRANSACTION_PUSH_BRANCHES
-uint16_t uniffi_jj_ffi_checksum_method_ffigittransaction_push_branches(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_GC
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_GC
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_gc(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_COUNT_REVSET
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_COUNT_REVSET
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_count_revset(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_GIT_HEAD_SYNC_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_GIT_HEAD_SYNC_REPORT
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_git_head_sync_report(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_IS_COLOCATED
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_IS_COLOCATED
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_is_colocated(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_EVALUATE_REVSET_TO_COMMITS
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_commits(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_NEW_CHILD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_NEW_CHILD
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_new_child(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_GET_COMMIT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_GET_COMMIT
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_get_commit(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_OPERATION_MERGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_OPERATION_MERGE_REPORT
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_operation_merge_report(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_log(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REINDEX
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REINDEX
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_reindex(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG_FLAT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_LOG_FLAT
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_log_flat(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_RELOAD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_RELOAD
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_reload(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESOLVE_CHANGE_ID
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESOLVE_CHANGE_ID
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_resolve_change_id(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_repo(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESOLVE_COMMIT_PREFIX
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_RESOLVE_COMMIT_PREFIX
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_resolve_commit_prefix(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO_AT_OPERATION
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO_AT_OPERATION
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_repo_at_operation(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_CHANGE_ID
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_CHANGE_ID
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_root_change_id(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO_FORMAT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO_FORMAT
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_repo_format(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_COMMIT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_COMMIT
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_root_commit(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO_PATH
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO_PATH
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_repo_path(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_COMMIT_ID
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_ROOT_COMMIT_ID
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_root_commit_id(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_RESTORE_PATHS_FROM_PARENT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_RESTORE_PATHS_FROM_PARENT
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_restore_paths_from_parent(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_START_GIT_TRANSACTION
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_START_GIT_TRANSACTION
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_start_git_transaction(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_SET_CONFLICT_MARKER_STYLE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_SET_CONFLICT_MARKER_STYLE
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_set_conflict_marker_style(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_START_TRANSACTION
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIREADONLYREPO_START_TRANSACTION
-uint16_t uniffi_jj_ffi_checksum_method_ffireadonlyrepo_start_transaction(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_SET_FSMONITOR
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_SET_FSMONITOR
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_set_fsmonitor(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_ABANDON_COMMIT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_ABANDON_COMMIT
-uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_abandon_commit(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_SNAPSHOT
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_SNAPSHOT
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_snapshot(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_COMMIT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_COMMIT
-uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_commit(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_SNAPSHOT_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_SNAPSHOT_WITH_OPTIONS
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_snapshot_with_options(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_CREATE_COMMIT_FROM_PARENT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_CREATE_COMMIT_FROM_PARENT
-uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_create_commit_from_parent(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_TRACK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_TRACK
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_track(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_CREATE_EMPTY_COMMIT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_CREATE_EMPTY_COMMIT
-uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_create_empty_commit(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_UNTRACK
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_UNTRACK
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_untrack(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_DISCARD
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_DISCARD
-uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_discard(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_UNTRACKED_FILES
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_UNTRACKED_FILES
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_untracked_files(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_HAS_CHANGES
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_HAS_CHANGES
-uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_has_changes(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_UPDATE_STALE
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_UPDATE_STALE
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_update_stale(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REWRITE_COMMIT
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_REWRITE_COMMIT
-uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_rewrite_commit(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_ADD
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_ADD
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_add(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_UPDATE_DESCRIPTION
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFITRANSACTION_UPDATE_DESCRIPTION
-uint16_t uniffi_jj_ffi_checksum_method_ffitransaction_update_description(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_FORGET
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_FORGET
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_forget(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO
-uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_repo(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_LIST
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_LIST
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_list(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO_PATH
-#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_REPO_PATH
-uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_repo_path(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_NAME
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_NAME
+uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_name(void
     
 );
 #endif
@@ -937,12 +2772,30 @@ uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_repo_path(void
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_METHOD_FFIWORKSPACE_WORKSPACE_ROOT
 uint16_t uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_root(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_CONSTRUCTOR_FFICANCELLATIONTOKEN_NEW
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_CONSTRUCTOR_FFICANCELLATIONTOKEN_NEW
+uint16_t uniffi_jj_ffi_checksum_constructor_fficancellationtoken_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_CONSTRUCTOR_FFIREPOLOADER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_CONSTRUCTOR_FFIREPOLOADER_NEW
+uint16_t uniffi_jj_ffi_checksum_constructor_ffirepoloader_new(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_CONSTRUCTOR_FFIWORKSPACE_LOAD
 #define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_CONSTRUCTOR_FFIWORKSPACE_LOAD
 uint16_t uniffi_jj_ffi_checksum_constructor_ffiworkspace_load(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_CONSTRUCTOR_FFIWORKSPACE_LOAD_WITH_USER_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_JJ_FFI_CHECKSUM_CONSTRUCTOR_FFIWORKSPACE_LOAD_WITH_USER_CONFIG
+uint16_t uniffi_jj_ffi_checksum_constructor_ffiworkspace_load_with_user_config(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_JJ_FFI_UNIFFI_CONTRACT_VERSION
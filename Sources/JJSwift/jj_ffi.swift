@@ -396,6 +396,22 @@ fileprivate class UniffiHandleMap<T> {
 // Public interface members begin here.
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -444,6 +460,22 @@ fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterFloat: FfiConverterPrimitive {
+    typealias FfiType = Float
+    typealias SwiftType = Float
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Float {
+        return try lift(readFloat(&buf))
+    }
+
+    public static func write(_ value: Float, into buf: inout [UInt8]) {
+        writeFloat(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -509,63 +541,49 @@ fileprivate struct FfiConverterString: FfiConverter {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterData: FfiConverterRustBuffer {
+    typealias SwiftType = Data
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        let len: Int32 = try readInt(&buf)
+        return Data(try readBytes(&buf, count: Int(len)))
+    }
+
+    public static func write(_ value: Data, into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        writeBytes(&buf, value)
+    }
+}
+
 
 
 
 /**
- * A Git transaction for performing Git operations
- *
- * This wraps a jj Transaction and provides Git-specific operations.
+ * A token for cancelling long-running operations
  */
-public protocol FfiGitTransactionProtocol : AnyObject {
-    
-    /**
-     * Commit the git transaction and return the updated repository
-     */
-    func commit(description: String) throws  -> FfiReadonlyRepo
-    
-    /**
-     * Discard the git transaction without committing
-     */
-    func discard() throws 
-    
-    /**
-     * Export refs to the underlying Git repository
-     *
-     * This synchronizes the Git refs with changes made in jj.
-     */
-    func exportRefs() throws  -> FfiGitExportStats
-    
-    /**
-     * Fetch from a remote
-     *
-     * Fetches the specified branches (or all branches if empty) from the remote.
-     */
-    func fetch(remoteName: String, branchPatterns: [String]) throws  -> FfiGitImportStats
+public protocol FfiCancellationTokenProtocol : AnyObject {
     
     /**
-     * Import refs from the underlying Git repository
-     *
-     * This synchronizes the jj view with any changes made directly in Git.
+     * Request cancellation of the operations using this token
      */
-    func importRefs() throws  -> FfiGitImportStats
+    func cancel() 
     
     /**
-     * Push branches to a remote
-     *
-     * Pushes the specified local branches to the remote.
+     * Check whether cancellation was requested
      */
-    func pushBranches(remoteName: String, branchNames: [String]) throws  -> FfiGitPushStats
+    func isCancelled()  -> Bool
     
 }
 
 /**
- * A Git transaction for performing Git operations
- *
- * This wraps a jj Transaction and provides Git-specific operations.
+ * A token for cancelling long-running operations
  */
-open class FfiGitTransaction:
-    FfiGitTransactionProtocol {
+open class FfiCancellationToken:
+    FfiCancellationTokenProtocol {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -599,89 +617,46 @@ open class FfiGitTransaction:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_jj_ffi_fn_clone_ffigittransaction(self.pointer, $0) }
+        return try! rustCall { uniffi_jj_ffi_fn_clone_fficancellationtoken(self.pointer, $0) }
     }
-    // No primary constructor declared for this class.
+    /**
+     * Create a token that is not cancelled
+     */
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_jj_ffi_fn_constructor_fficancellationtoken_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
 
     deinit {
         guard let pointer = pointer else {
             return
         }
 
-        try! rustCall { uniffi_jj_ffi_fn_free_ffigittransaction(pointer, $0) }
+        try! rustCall { uniffi_jj_ffi_fn_free_fficancellationtoken(pointer, $0) }
     }
 
     
 
     
     /**
-     * Commit the git transaction and return the updated repository
-     */
-open func commit(description: String)throws  -> FfiReadonlyRepo {
-    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffigittransaction_commit(self.uniffiClonePointer(),
-        FfiConverterString.lower(description),$0
-    )
-})
-}
-    
-    /**
-     * Discard the git transaction without committing
-     */
-open func discard()throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffigittransaction_discard(self.uniffiClonePointer(),$0
-    )
-}
-}
-    
-    /**
-     * Export refs to the underlying Git repository
-     *
-     * This synchronizes the Git refs with changes made in jj.
-     */
-open func exportRefs()throws  -> FfiGitExportStats {
-    return try  FfiConverterTypeFfiGitExportStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffigittransaction_export_refs(self.uniffiClonePointer(),$0
-    )
-})
-}
-    
-    /**
-     * Fetch from a remote
-     *
-     * Fetches the specified branches (or all branches if empty) from the remote.
+     * Request cancellation of the operations using this token
      */
-open func fetch(remoteName: String, branchPatterns: [String])throws  -> FfiGitImportStats {
-    return try  FfiConverterTypeFfiGitImportStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffigittransaction_fetch(self.uniffiClonePointer(),
-        FfiConverterString.lower(remoteName),
-        FfiConverterSequenceString.lower(branchPatterns),$0
+open func cancel() {try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficancellationtoken_cancel(self.uniffiClonePointer(),$0
     )
-})
 }
-    
-    /**
-     * Import refs from the underlying Git repository
-     *
-     * This synchronizes the jj view with any changes made directly in Git.
-     */
-open func importRefs()throws  -> FfiGitImportStats {
-    return try  FfiConverterTypeFfiGitImportStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffigittransaction_import_refs(self.uniffiClonePointer(),$0
-    )
-})
 }
     
     /**
-     * Push branches to a remote
-     *
-     * Pushes the specified local branches to the remote.
+     * Check whether cancellation was requested
      */
-open func pushBranches(remoteName: String, branchNames: [String])throws  -> FfiGitPushStats {
-    return try  FfiConverterTypeFfiGitPushStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffigittransaction_push_branches(self.uniffiClonePointer(),
-        FfiConverterString.lower(remoteName),
-        FfiConverterSequenceString.lower(branchNames),$0
+open func isCancelled() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficancellationtoken_is_cancelled(self.uniffiClonePointer(),$0
     )
 })
 }
@@ -692,20 +667,20 @@ open func pushBranches(remoteName: String, branchNames: [String])throws  -> FfiG
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiGitTransaction: FfiConverter {
+public struct FfiConverterTypeFfiCancellationToken: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = FfiGitTransaction
+    typealias SwiftType = FfiCancellationToken
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiGitTransaction {
-        return FfiGitTransaction(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiCancellationToken {
+        return FfiCancellationToken(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: FfiGitTransaction) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: FfiCancellationToken) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitTransaction {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCancellationToken {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -716,7 +691,7 @@ public struct FfiConverterTypeFfiGitTransaction: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: FfiGitTransaction, into buf: inout [UInt8]) {
+    public static func write(_ value: FfiCancellationToken, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -729,113 +704,101 @@ public struct FfiConverterTypeFfiGitTransaction: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGitTransaction_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiGitTransaction {
-    return try FfiConverterTypeFfiGitTransaction.lift(pointer)
+public func FfiConverterTypeFfiCancellationToken_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiCancellationToken {
+    return try FfiConverterTypeFfiCancellationToken.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGitTransaction_lower(_ value: FfiGitTransaction) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeFfiGitTransaction.lower(value)
+public func FfiConverterTypeFfiCancellationToken_lower(_ value: FfiCancellationToken) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiCancellationToken.lower(value)
 }
 
 
 
 
 /**
- * A readonly repository exposed via FFI
+ * Builds a new commit, or a rewrite of an existing one, in a transaction
+ *
+ * Created by `FfiTransaction::new_commit_builder()` or
+ * `FfiTransaction::rewrite_commit_builder()`. Setters return the builder so
+ * calls can be chained. Properties that aren't set default like in
+ * `jj new` and `jj describe`: new commits get the merged tree of their
+ * parents and the user's signature, and rewritten commits keep their
+ * properties except for the committer.
  */
-public protocol FfiReadonlyRepoProtocol : AnyObject {
-    
-    /**
-     * Count commits matching a revset expression
-     */
-    func countRevset(revsetStr: String, userEmail: String) throws  -> UInt64
-    
-    /**
-     * Evaluate a revset expression and return matching commit IDs
-     */
-    func evaluateRevset(revsetStr: String, userEmail: String) throws  -> [FfiCommitId]
+public protocol FfiCommitBuilderProtocol : AnyObject {
     
     /**
-     * Evaluate a revset expression and return matching commits
+     * Give the commit a new change ID, e.g. to duplicate a commit
      */
-    func evaluateRevsetToCommits(revsetStr: String, userEmail: String) throws  -> [FfiCommit]
+    func generateNewChangeId()  -> FfiCommitBuilder
     
     /**
-     * Get a commit by its commit ID (hex string)
+     * Set the author
      */
-    func getCommit(commitId: FfiCommitId) throws  -> FfiCommit
+    func setAuthor(author: FfiSignature)  -> FfiCommitBuilder
     
     /**
-     * Get log with graph information
-     *
-     * Returns a list of log entries with commit information and graph edges.
-     * The entries are ordered topologically (children before parents) unless
-     * `reversed` is set to true in the options.
-     *
-     * # Arguments
-     * * `options` - Log options including revisions, limit, and ordering
-     * * `user_email` - User email for revset resolution
-     *
-     * # Example revisions
-     * - `["@"]` - Current working copy
-     * - `["::"]` - All commits
-     * - `["main..@"]` - Commits from main to current
+     * Set the committer, instead of the user's signature at the current time
      */
-    func log(options: FfiLogOptions, userEmail: String) throws  -> FfiLogResult
+    func setCommitter(committer: FfiSignature)  -> FfiCommitBuilder
     
     /**
-     * Get log as a flat list without graph information
+     * Set the description
      *
-     * Returns a list of commits without graph edge information.
-     * More efficient when graph visualization is not needed.
-     */
-    func logFlat(options: FfiLogOptions, userEmail: String) throws  -> [FfiCommit]
-    
-    /**
-     * Resolve a change ID to commit IDs
-     */
-    func resolveChangeId(changeId: FfiChangeId) throws  -> [FfiCommitId]
-    
-    /**
-     * Resolve a commit ID prefix (returns all matching commits)
+     * Trailers configured by `templates.commit_trailers` are added when the
+     * commit is written.
      */
-    func resolveCommitPrefix(prefix: String) throws  -> [FfiCommitId]
+    func setDescription(description: String)  -> FfiCommitBuilder
     
     /**
-     * Get the root change ID
+     * Set the parents
      */
-    func rootChangeId()  -> FfiChangeId
+    func setParents(parentIds: [FfiCommitId])  -> FfiCommitBuilder
     
     /**
-     * Get the root commit of the repository
+     * Set which commits to sign, instead of the `signing.behavior` config
      */
-    func rootCommit()  -> FfiCommit
+    func setSignBehavior(behavior: FfiSignBehavior)  -> FfiCommitBuilder
     
     /**
-     * Get the root commit ID
+     * Set the signing key, instead of the `signing.key` config
      */
-    func rootCommitId()  -> FfiCommitId
+    func setSignKey(key: String)  -> FfiCommitBuilder
     
     /**
-     * Start a new Git transaction for performing Git operations
+     * Set the tree by its hex IDs, as listed in `FfiCommit::tree_ids`
      */
-    func startGitTransaction() throws  -> FfiGitTransaction
+    func setTree(treeIds: [String])  -> FfiCommitBuilder
     
     /**
-     * Start a new transaction for making changes to the repository
+     * Write the commit to the transaction
+     *
+     * The builder can be written again, e.g. after changing some properties.
+     * Writing a rewrite again abandons the commit written before, so only
+     * the latest rewrite remains visible. A rewritten commit's descendants
+     * are rebased onto the new commit by
+     * `FfiTransaction::rebase_descendants()` or when the transaction is
+     * committed.
      */
-    func startTransaction()  -> FfiTransaction
+    func write() throws  -> FfiCommit
     
 }
 
 /**
- * A readonly repository exposed via FFI
+ * Builds a new commit, or a rewrite of an existing one, in a transaction
+ *
+ * Created by `FfiTransaction::new_commit_builder()` or
+ * `FfiTransaction::rewrite_commit_builder()`. Setters return the builder so
+ * calls can be chained. Properties that aren't set default like in
+ * `jj new` and `jj describe`: new commits get the merged tree of their
+ * parents and the user's signature, and rewritten commits keep their
+ * properties except for the committer.
  */
-open class FfiReadonlyRepo:
-    FfiReadonlyRepoProtocol {
+open class FfiCommitBuilder:
+    FfiCommitBuilderProtocol {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -869,7 +832,7 @@ open class FfiReadonlyRepo:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_jj_ffi_fn_clone_ffireadonlyrepo(self.pointer, $0) }
+        return try! rustCall { uniffi_jj_ffi_fn_clone_fficommitbuilder(self.pointer, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -878,167 +841,115 @@ open class FfiReadonlyRepo:
             return
         }
 
-        try! rustCall { uniffi_jj_ffi_fn_free_ffireadonlyrepo(pointer, $0) }
+        try! rustCall { uniffi_jj_ffi_fn_free_fficommitbuilder(pointer, $0) }
     }
 
     
 
     
     /**
-     * Count commits matching a revset expression
+     * Give the commit a new change ID, e.g. to duplicate a commit
      */
-open func countRevset(revsetStr: String, userEmail: String)throws  -> UInt64 {
-    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_count_revset(self.uniffiClonePointer(),
-        FfiConverterString.lower(revsetStr),
-        FfiConverterString.lower(userEmail),$0
+open func generateNewChangeId() -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_generate_new_change_id(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Evaluate a revset expression and return matching commit IDs
+     * Set the author
      */
-open func evaluateRevset(revsetStr: String, userEmail: String)throws  -> [FfiCommitId] {
-    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset(self.uniffiClonePointer(),
-        FfiConverterString.lower(revsetStr),
-        FfiConverterString.lower(userEmail),$0
+open func setAuthor(author: FfiSignature) -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_set_author(self.uniffiClonePointer(),
+        FfiConverterTypeFfiSignature.lower(author),$0
     )
 })
 }
     
     /**
-     * Evaluate a revset expression and return matching commits
+     * Set the committer, instead of the user's signature at the current time
      */
-open func evaluateRevsetToCommits(revsetStr: String, userEmail: String)throws  -> [FfiCommit] {
-    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_commits(self.uniffiClonePointer(),
-        FfiConverterString.lower(revsetStr),
-        FfiConverterString.lower(userEmail),$0
+open func setCommitter(committer: FfiSignature) -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_set_committer(self.uniffiClonePointer(),
+        FfiConverterTypeFfiSignature.lower(committer),$0
     )
 })
 }
     
     /**
-     * Get a commit by its commit ID (hex string)
+     * Set the description
+     *
+     * Trailers configured by `templates.commit_trailers` are added when the
+     * commit is written.
      */
-open func getCommit(commitId: FfiCommitId)throws  -> FfiCommit {
-    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_get_commit(self.uniffiClonePointer(),
-        FfiConverterTypeFfiCommitId.lower(commitId),$0
+open func setDescription(description: String) -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_set_description(self.uniffiClonePointer(),
+        FfiConverterString.lower(description),$0
     )
 })
 }
     
     /**
-     * Get log with graph information
-     *
-     * Returns a list of log entries with commit information and graph edges.
-     * The entries are ordered topologically (children before parents) unless
-     * `reversed` is set to true in the options.
-     *
-     * # Arguments
-     * * `options` - Log options including revisions, limit, and ordering
-     * * `user_email` - User email for revset resolution
-     *
-     * # Example revisions
-     * - `["@"]` - Current working copy
-     * - `["::"]` - All commits
-     * - `["main..@"]` - Commits from main to current
+     * Set the parents
      */
-open func log(options: FfiLogOptions, userEmail: String)throws  -> FfiLogResult {
-    return try  FfiConverterTypeFfiLogResult.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_log(self.uniffiClonePointer(),
-        FfiConverterTypeFfiLogOptions.lower(options),
-        FfiConverterString.lower(userEmail),$0
+open func setParents(parentIds: [FfiCommitId]) -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_set_parents(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFfiCommitId.lower(parentIds),$0
     )
 })
 }
     
     /**
-     * Get log as a flat list without graph information
-     *
-     * Returns a list of commits without graph edge information.
-     * More efficient when graph visualization is not needed.
-     */
-open func logFlat(options: FfiLogOptions, userEmail: String)throws  -> [FfiCommit] {
-    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_log_flat(self.uniffiClonePointer(),
-        FfiConverterTypeFfiLogOptions.lower(options),
-        FfiConverterString.lower(userEmail),$0
-    )
-})
-}
-    
-    /**
-     * Resolve a change ID to commit IDs
-     */
-open func resolveChangeId(changeId: FfiChangeId)throws  -> [FfiCommitId] {
-    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_resolve_change_id(self.uniffiClonePointer(),
-        FfiConverterTypeFfiChangeId.lower(changeId),$0
-    )
-})
-}
-    
-    /**
-     * Resolve a commit ID prefix (returns all matching commits)
-     */
-open func resolveCommitPrefix(prefix: String)throws  -> [FfiCommitId] {
-    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_resolve_commit_prefix(self.uniffiClonePointer(),
-        FfiConverterString.lower(prefix),$0
-    )
-})
-}
-    
-    /**
-     * Get the root change ID
-     */
-open func rootChangeId() -> FfiChangeId {
-    return try!  FfiConverterTypeFfiChangeId.lift(try! rustCall() {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_change_id(self.uniffiClonePointer(),$0
-    )
-})
-}
-    
-    /**
-     * Get the root commit of the repository
+     * Set which commits to sign, instead of the `signing.behavior` config
      */
-open func rootCommit() -> FfiCommit {
-    return try!  FfiConverterTypeFfiCommit.lift(try! rustCall() {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_commit(self.uniffiClonePointer(),$0
+open func setSignBehavior(behavior: FfiSignBehavior) -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_set_sign_behavior(self.uniffiClonePointer(),
+        FfiConverterTypeFfiSignBehavior.lower(behavior),$0
     )
 })
 }
     
     /**
-     * Get the root commit ID
+     * Set the signing key, instead of the `signing.key` config
      */
-open func rootCommitId() -> FfiCommitId {
-    return try!  FfiConverterTypeFfiCommitId.lift(try! rustCall() {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_commit_id(self.uniffiClonePointer(),$0
+open func setSignKey(key: String) -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_set_sign_key(self.uniffiClonePointer(),
+        FfiConverterString.lower(key),$0
     )
 })
 }
     
     /**
-     * Start a new Git transaction for performing Git operations
+     * Set the tree by its hex IDs, as listed in `FfiCommit::tree_ids`
      */
-open func startGitTransaction()throws  -> FfiGitTransaction {
-    return try  FfiConverterTypeFfiGitTransaction.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_start_git_transaction(self.uniffiClonePointer(),$0
+open func setTree(treeIds: [String]) -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_set_tree(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(treeIds),$0
     )
 })
 }
     
     /**
-     * Start a new transaction for making changes to the repository
+     * Write the commit to the transaction
+     *
+     * The builder can be written again, e.g. after changing some properties.
+     * Writing a rewrite again abandons the commit written before, so only
+     * the latest rewrite remains visible. A rewritten commit's descendants
+     * are rebased onto the new commit by
+     * `FfiTransaction::rebase_descendants()` or when the transaction is
+     * committed.
      */
-open func startTransaction() -> FfiTransaction {
-    return try!  FfiConverterTypeFfiTransaction.lift(try! rustCall() {
-    uniffi_jj_ffi_fn_method_ffireadonlyrepo_start_transaction(self.uniffiClonePointer(),$0
+open func write()throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_fficommitbuilder_write(self.uniffiClonePointer(),$0
     )
 })
 }
@@ -1049,20 +960,20 @@ open func startTransaction() -> FfiTransaction {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiReadonlyRepo: FfiConverter {
+public struct FfiConverterTypeFfiCommitBuilder: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = FfiReadonlyRepo
+    typealias SwiftType = FfiCommitBuilder
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiReadonlyRepo {
-        return FfiReadonlyRepo(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiCommitBuilder {
+        return FfiCommitBuilder(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: FfiReadonlyRepo) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: FfiCommitBuilder) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiReadonlyRepo {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCommitBuilder {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -1073,7 +984,7 @@ public struct FfiConverterTypeFfiReadonlyRepo: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: FfiReadonlyRepo, into buf: inout [UInt8]) {
+    public static func write(_ value: FfiCommitBuilder, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -1086,82 +997,38 @@ public struct FfiConverterTypeFfiReadonlyRepo: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiReadonlyRepo_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiReadonlyRepo {
-    return try FfiConverterTypeFfiReadonlyRepo.lift(pointer)
+public func FfiConverterTypeFfiCommitBuilder_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiCommitBuilder {
+    return try FfiConverterTypeFfiCommitBuilder.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiReadonlyRepo_lower(_ value: FfiReadonlyRepo) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeFfiReadonlyRepo.lower(value)
+public func FfiConverterTypeFfiCommitBuilder_lower(_ value: FfiCommitBuilder) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiCommitBuilder.lower(value)
 }
 
 
 
 
 /**
- * A transaction for making changes to a repository
- *
- * # Safety
- * The Transaction type from jj-lib is not Send + Sync, but we wrap it in a Mutex
- * to ensure synchronized access. All FFI operations acquire the lock before accessing
- * the transaction.
+ * Supplies credentials for Git remotes, implemented by the host app
  */
-public protocol FfiTransactionProtocol : AnyObject {
-    
-    /**
-     * Abandon a commit (its children will be rebased to its parents)
-     */
-    func abandonCommit(commitId: FfiCommitId) throws 
-    
-    /**
-     * Commit the transaction and return the updated repository
-     */
-    func commit(description: String) throws  -> FfiReadonlyRepo
-    
-    /**
-     * Create a new commit with the same tree as a parent commit
-     */
-    func createCommitFromParent(parentId: FfiCommitId, description: String) throws  -> FfiCommit
-    
-    /**
-     * Create a new commit with an empty tree (for creating empty commits)
-     */
-    func createEmptyCommit(newCommit: FfiNewCommit) throws  -> FfiCommit
-    
-    /**
-     * Discard the transaction without committing
-     */
-    func discard() throws 
-    
-    /**
-     * Check if the transaction has any uncommitted changes
-     */
-    func hasChanges() throws  -> Bool
+public protocol FfiGitCredentialProvider : AnyObject {
     
     /**
-     * Rewrite an existing commit with new properties
-     */
-    func rewriteCommit(rewrite: FfiRewriteCommit) throws  -> FfiCommit
-    
-    /**
-     * Update the description of an existing commit
+     * Get the credentials for a remote URL, or `None` to fall back to Git's
+     * own configuration (credential helpers, `~/.ssh`, ...)
      */
-    func updateDescription(commitId: FfiCommitId, newDescription: String) throws  -> FfiCommit
+    func credentials(remoteName: String, url: String)  -> FfiGitCredential?
     
 }
 
 /**
- * A transaction for making changes to a repository
- *
- * # Safety
- * The Transaction type from jj-lib is not Send + Sync, but we wrap it in a Mutex
- * to ensure synchronized access. All FFI operations acquire the lock before accessing
- * the transaction.
+ * Supplies credentials for Git remotes, implemented by the host app
  */
-open class FfiTransaction:
-    FfiTransactionProtocol {
+open class FfiGitCredentialProviderImpl:
+    FfiGitCredentialProvider {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -1195,7 +1062,7 @@ open class FfiTransaction:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_jj_ffi_fn_clone_ffitransaction(self.pointer, $0) }
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffigitcredentialprovider(self.pointer, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -1204,118 +1071,101 @@ open class FfiTransaction:
             return
         }
 
-        try! rustCall { uniffi_jj_ffi_fn_free_ffitransaction(pointer, $0) }
+        try! rustCall { uniffi_jj_ffi_fn_free_ffigitcredentialprovider(pointer, $0) }
     }
 
     
 
     
     /**
-     * Abandon a commit (its children will be rebased to its parents)
-     */
-open func abandonCommit(commitId: FfiCommitId)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffitransaction_abandon_commit(self.uniffiClonePointer(),
-        FfiConverterTypeFfiCommitId.lower(commitId),$0
-    )
-}
-}
-    
-    /**
-     * Commit the transaction and return the updated repository
-     */
-open func commit(description: String)throws  -> FfiReadonlyRepo {
-    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffitransaction_commit(self.uniffiClonePointer(),
-        FfiConverterString.lower(description),$0
-    )
-})
-}
-    
-    /**
-     * Create a new commit with the same tree as a parent commit
-     */
-open func createCommitFromParent(parentId: FfiCommitId, description: String)throws  -> FfiCommit {
-    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffitransaction_create_commit_from_parent(self.uniffiClonePointer(),
-        FfiConverterTypeFfiCommitId.lower(parentId),
-        FfiConverterString.lower(description),$0
-    )
-})
-}
-    
-    /**
-     * Create a new commit with an empty tree (for creating empty commits)
-     */
-open func createEmptyCommit(newCommit: FfiNewCommit)throws  -> FfiCommit {
-    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffitransaction_create_empty_commit(self.uniffiClonePointer(),
-        FfiConverterTypeFfiNewCommit.lower(newCommit),$0
-    )
-})
-}
-    
-    /**
-     * Discard the transaction without committing
-     */
-open func discard()throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffitransaction_discard(self.uniffiClonePointer(),$0
-    )
-}
-}
-    
-    /**
-     * Check if the transaction has any uncommitted changes
+     * Get the credentials for a remote URL, or `None` to fall back to Git's
+     * own configuration (credential helpers, `~/.ssh`, ...)
      */
-open func hasChanges()throws  -> Bool {
-    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffitransaction_has_changes(self.uniffiClonePointer(),$0
+open func credentials(remoteName: String, url: String) -> FfiGitCredential? {
+    return try!  FfiConverterOptionTypeFfiGitCredential.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffigitcredentialprovider_credentials(self.uniffiClonePointer(),
+        FfiConverterString.lower(remoteName),
+        FfiConverterString.lower(url),$0
     )
 })
 }
     
-    /**
-     * Rewrite an existing commit with new properties
-     */
-open func rewriteCommit(rewrite: FfiRewriteCommit)throws  -> FfiCommit {
-    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffitransaction_rewrite_commit(self.uniffiClonePointer(),
-        FfiConverterTypeFfiRewriteCommit.lower(rewrite),$0
-    )
-})
+
 }
-    
-    /**
-     * Update the description of an existing commit
-     */
-open func updateDescription(commitId: FfiCommitId, newDescription: String)throws  -> FfiCommit {
-    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_method_ffitransaction_update_description(self.uniffiClonePointer(),
-        FfiConverterTypeFfiCommitId.lower(commitId),
-        FfiConverterString.lower(newDescription),$0
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceFfiGitCredentialProvider {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceFfiGitCredentialProvider = UniffiVTableCallbackInterfaceFfiGitCredentialProvider(
+        credentials: { (
+            uniffiHandle: UInt64,
+            remoteName: RustBuffer,
+            url: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> FfiGitCredential? in
+                guard let uniffiObj = try? FfiConverterTypeFfiGitCredentialProvider.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.credentials(
+                     remoteName: try FfiConverterString.lift(remoteName),
+                     url: try FfiConverterString.lift(url)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterOptionTypeFfiGitCredential.lower($0) }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterTypeFfiGitCredentialProvider.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface FfiGitCredentialProvider: handle missing in uniffiFree")
+            }
+        }
     )
-})
 }
-    
 
+private func uniffiCallbackInitFfiGitCredentialProvider() {
+    uniffi_jj_ffi_fn_init_callback_vtable_ffigitcredentialprovider(&UniffiCallbackInterfaceFfiGitCredentialProvider.vtable)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiTransaction: FfiConverter {
+public struct FfiConverterTypeFfiGitCredentialProvider: FfiConverter {
+    fileprivate static var handleMap = UniffiHandleMap<FfiGitCredentialProvider>()
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = FfiTransaction
+    typealias SwiftType = FfiGitCredentialProvider
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiTransaction {
-        return FfiTransaction(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiGitCredentialProvider {
+        return FfiGitCredentialProviderImpl(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: FfiTransaction) -> UnsafeMutableRawPointer {
-        return value.uniffiClonePointer()
+    public static func lower(_ value: FfiGitCredentialProvider) -> UnsafeMutableRawPointer {
+        guard let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: handleMap.insert(obj: value))) else {
+            fatalError("Cast to UnsafeMutableRawPointer failed")
+        }
+        return ptr
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTransaction {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitCredentialProvider {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -1326,7 +1176,7 @@ public struct FfiConverterTypeFfiTransaction: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: FfiTransaction, into buf: inout [UInt8]) {
+    public static func write(_ value: FfiGitCredentialProvider, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -1339,47 +1189,47 @@ public struct FfiConverterTypeFfiTransaction: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiTransaction_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiTransaction {
-    return try FfiConverterTypeFfiTransaction.lift(pointer)
+public func FfiConverterTypeFfiGitCredentialProvider_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiGitCredentialProvider {
+    return try FfiConverterTypeFfiGitCredentialProvider.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiTransaction_lower(_ value: FfiTransaction) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeFfiTransaction.lower(value)
+public func FfiConverterTypeFfiGitCredentialProvider_lower(_ value: FfiGitCredentialProvider) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiGitCredentialProvider.lower(value)
 }
 
 
 
 
 /**
- * A workspace exposed via FFI
+ * Receives progress of Git network operations, implemented by the host app
+ *
+ * Methods are called on the thread running the operation.
  */
-public protocol FfiWorkspaceProtocol : AnyObject {
-    
-    /**
-     * Get a readonly repository handle
-     */
-    func repo()  -> FfiReadonlyRepo
+public protocol FfiGitProgress : AnyObject {
     
     /**
-     * Get the repo path
+     * Called whenever the transfer progress changes
      */
-    func repoPath()  -> String
+    func onProgress(update: FfiGitProgressUpdate) 
     
     /**
-     * Get the workspace root path
+     * Called with each line of messages sent by the remote (the `remote: `
+     * lines printed by `git`)
      */
-    func workspaceRoot()  -> String
+    func onSidebandMessage(message: String) 
     
 }
 
 /**
- * A workspace exposed via FFI
+ * Receives progress of Git network operations, implemented by the host app
+ *
+ * Methods are called on the thread running the operation.
  */
-open class FfiWorkspace:
-    FfiWorkspaceProtocol {
+open class FfiGitProgressImpl:
+    FfiGitProgress {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -1413,7 +1263,7 @@ open class FfiWorkspace:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_jj_ffi_fn_clone_ffiworkspace(self.pointer, $0) }
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffigitprogress(self.pointer, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -1422,75 +1272,125 @@ open class FfiWorkspace:
             return
         }
 
-        try! rustCall { uniffi_jj_ffi_fn_free_ffiworkspace(pointer, $0) }
+        try! rustCall { uniffi_jj_ffi_fn_free_ffigitprogress(pointer, $0) }
     }
 
     
+
+    
     /**
-     * Load an existing workspace from the given path
+     * Called whenever the transfer progress changes
      */
-public static func load(workspacePath: String, userName: String, userEmail: String)throws  -> FfiWorkspace {
-    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
-    uniffi_jj_ffi_fn_constructor_ffiworkspace_load(
-        FfiConverterString.lower(workspacePath),
-        FfiConverterString.lower(userName),
-        FfiConverterString.lower(userEmail),$0
+open func onProgress(update: FfiGitProgressUpdate) {try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffigitprogress_on_progress(self.uniffiClonePointer(),
+        FfiConverterTypeFfiGitProgressUpdate.lower(update),$0
     )
-})
 }
-    
-
+}
     
     /**
-     * Get a readonly repository handle
+     * Called with each line of messages sent by the remote (the `remote: `
+     * lines printed by `git`)
      */
-open func repo() -> FfiReadonlyRepo {
-    return try!  FfiConverterTypeFfiReadonlyRepo.lift(try! rustCall() {
-    uniffi_jj_ffi_fn_method_ffiworkspace_repo(self.uniffiClonePointer(),$0
+open func onSidebandMessage(message: String) {try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffigitprogress_on_sideband_message(self.uniffiClonePointer(),
+        FfiConverterString.lower(message),$0
     )
-})
 }
-    
-    /**
-     * Get the repo path
-     */
-open func repoPath() -> String {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_jj_ffi_fn_method_ffiworkspace_repo_path(self.uniffiClonePointer(),$0
-    )
-})
 }
     
-    /**
-     * Get the workspace root path
-     */
-open func workspaceRoot() -> String {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_jj_ffi_fn_method_ffiworkspace_workspace_root(self.uniffiClonePointer(),$0
+
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceFfiGitProgress {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceFfiGitProgress = UniffiVTableCallbackInterfaceFfiGitProgress(
+        onProgress: { (
+            uniffiHandle: UInt64,
+            update: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeFfiGitProgress.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onProgress(
+                     update: try FfiConverterTypeFfiGitProgressUpdate.lift(update)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onSidebandMessage: { (
+            uniffiHandle: UInt64,
+            message: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeFfiGitProgress.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onSidebandMessage(
+                     message: try FfiConverterString.lift(message)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterTypeFfiGitProgress.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface FfiGitProgress: handle missing in uniffiFree")
+            }
+        }
     )
-})
 }
-    
 
+private func uniffiCallbackInitFfiGitProgress() {
+    uniffi_jj_ffi_fn_init_callback_vtable_ffigitprogress(&UniffiCallbackInterfaceFfiGitProgress.vtable)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiWorkspace: FfiConverter {
+public struct FfiConverterTypeFfiGitProgress: FfiConverter {
+    fileprivate static var handleMap = UniffiHandleMap<FfiGitProgress>()
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = FfiWorkspace
+    typealias SwiftType = FfiGitProgress
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiWorkspace {
-        return FfiWorkspace(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiGitProgress {
+        return FfiGitProgressImpl(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: FfiWorkspace) -> UnsafeMutableRawPointer {
-        return value.uniffiClonePointer()
+    public static func lower(_ value: FfiGitProgress) -> UnsafeMutableRawPointer {
+        guard let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: handleMap.insert(obj: value))) else {
+            fatalError("Cast to UnsafeMutableRawPointer failed")
+        }
+        return ptr
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiWorkspace {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitProgress {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -1501,7 +1401,7 @@ public struct FfiConverterTypeFfiWorkspace: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: FfiWorkspace, into buf: inout [UInt8]) {
+    public static func write(_ value: FfiGitProgress, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -1514,188 +1414,13211 @@ public struct FfiConverterTypeFfiWorkspace: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiWorkspace_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiWorkspace {
-    return try FfiConverterTypeFfiWorkspace.lift(pointer)
+public func FfiConverterTypeFfiGitProgress_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiGitProgress {
+    return try FfiConverterTypeFfiGitProgress.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiWorkspace_lower(_ value: FfiWorkspace) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeFfiWorkspace.lower(value)
+public func FfiConverterTypeFfiGitProgress_lower(_ value: FfiGitProgress) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiGitProgress.lower(value)
 }
 
 
+
+
 /**
- * A change ID represented as a reverse-hex string for FFI
+ * A Git transaction for performing Git operations
+ *
+ * This wraps a jj Transaction and provides Git-specific operations. Like
+ * `FfiTransaction`, it is isolated from the repository it was started from
+ * until it is committed.
  */
-public struct FfiChangeId {
+public protocol FfiGitTransactionProtocol : AnyObject {
+    
     /**
-     * Reverse-hex encoded change ID (z-k digits)
+     * Get the repository at the operation the transaction was started from
+     *
+     * Remains available after the transaction is committed or discarded.
      */
-    public var hex: String
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Reverse-hex encoded change ID (z-k digits)
-         */hex: String) {
-        self.hex = hex
-    }
-}
-
-
-
-extension FfiChangeId: Equatable, Hashable {
-    public static func ==(lhs: FfiChangeId, rhs: FfiChangeId) -> Bool {
-        if lhs.hex != rhs.hex {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(hex)
-    }
+    func baseRepo()  -> FfiReadonlyRepo
+    
+    /**
+     * Commit the git transaction and return the updated repository
+     *
+     * In a colocated workspace, bookmarks and tags are exported to Git first.
+     */
+    func commit(description: String) throws  -> FfiReadonlyRepo
+    
+    /**
+     * Discard the git transaction without committing
+     */
+    func discard() throws 
+    
+    /**
+     * Export refs to the underlying Git repository
+     *
+     * This synchronizes the Git refs with changes made in jj.
+     */
+    func exportRefs() throws  -> FfiGitExportStats
+    
+    /**
+     * Fetch from a remote
+     *
+     * Fetches the specified branches (or all branches if empty) from the remote.
+     * Remote bookmarks that no longer exist on the remote are deleted and the
+     * full history is fetched, like `jj git fetch`.
+     */
+    func fetch(remoteName: String, branchPatterns: [String]) throws  -> FfiGitImportStats
+    
+    /**
+     * Fetch from a remote without blocking the calling thread
+     *
+     * Same as `fetch_with_options`, but runs on a background thread.
+     */
+    func fetchAsync(remoteName: String, branchPatterns: [String], options: FfiFetchOptions) async throws  -> FfiGitImportStats
+    
+    /**
+     * Fetch from a remote with the given options
+     *
+     * Same as `fetch`, but with options for pruning, shallow fetches,
+     * credentials, progress and cancellation.
+     */
+    func fetchWithOptions(remoteName: String, branchPatterns: [String], options: FfiFetchOptions) throws  -> FfiGitImportStats
+    
+    /**
+     * Import refs from the underlying Git repository
+     *
+     * This synchronizes the jj view with any changes made directly in Git.
+     */
+    func importRefs() throws  -> FfiGitImportStats
+    
+    /**
+     * Push branches to a remote
+     *
+     * Pushes the specified local branches to the remote. A branch that was
+     * deleted locally but is still tracked on the remote is deleted from the
+     * remote, like `jj git push --deleted`.
+     */
+    func pushBranches(remoteName: String, branchNames: [String]) throws  -> FfiGitPushStats
+    
+    /**
+     * Push branches to a remote without blocking the calling thread
+     *
+     * Same as `push_branches_with_options`, but runs on a background thread.
+     */
+    func pushBranchesAsync(remoteName: String, branchNames: [String], options: FfiPushOptions) async throws  -> FfiGitPushStats
+    
+    /**
+     * Push branches to a remote with the given options
+     *
+     * Same as `push_branches`, but with options for dry runs, credentials,
+     * progress and cancellation. With `dry_run`, nothing is pushed and the
+     * returned stats only list the updates that would be pushed.
+     */
+    func pushBranchesWithOptions(remoteName: String, branchNames: [String], options: FfiPushOptions) throws  -> FfiGitPushStats
+    
+    /**
+     * Push changes to a remote with generated bookmarks, like `jj git push -c`
+     *
+     * A "push-<change id>" bookmark is created or moved to the visible commit
+     * of each change in this transaction, then the bookmarks are pushed like
+     * in `push_branches_with_options()`. The bookmarks are kept even if the
+     * push fails or is a dry run; discard the transaction to drop them.
+     */
+    func pushChanges(remoteName: String, changeIds: [FfiChangeId], options: FfiPushOptions) throws  -> FfiGitPushStats
+    
+    /**
+     * Start tracking a remote bookmark
+     *
+     * The remote bookmark's target is merged into the local bookmark of the
+     * same name, creating it if needed. Tracking an already-tracked bookmark
+     * is a no-op.
+     */
+    func trackRemoteBookmark(name: String, remote: String) throws 
+    
+    /**
+     * Stop tracking a remote bookmark
+     *
+     * The local bookmark is left in place. Untracking a bookmark that is not
+     * tracked is a no-op.
+     */
+    func untrackRemoteBookmark(name: String, remote: String) throws 
+    
 }
 
+/**
+ * A Git transaction for performing Git operations
+ *
+ * This wraps a jj Transaction and provides Git-specific operations. Like
+ * `FfiTransaction`, it is isolated from the repository it was started from
+ * until it is committed.
+ */
+open class FfiGitTransaction:
+    FfiGitTransactionProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
 
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiChangeId: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiChangeId {
-        return
-            try FfiChangeId(
-                hex: FfiConverterString.read(from: &buf)
-        )
+    public struct NoPointer {
+        public init() {}
     }
 
-    public static func write(_ value: FfiChangeId, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.hex, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiChangeId_lift(_ buf: RustBuffer) throws -> FfiChangeId {
-    return try FfiConverterTypeFfiChangeId.lift(buf)
-}
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiChangeId_lower(_ value: FfiChangeId) -> RustBuffer {
-    return FfiConverterTypeFfiChangeId.lower(value)
-}
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffigittransaction(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
 
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
 
-/**
- * A commit exposed via FFI
- */
-public struct FfiCommit {
+        try! rustCall { uniffi_jj_ffi_fn_free_ffigittransaction(pointer, $0) }
+    }
+
+    
+
+    
     /**
-     * The commit ID (content-based hash)
+     * Get the repository at the operation the transaction was started from
+     *
+     * Remains available after the transaction is committed or discarded.
      */
-    public var id: FfiCommitId
+open func baseRepo() -> FfiReadonlyRepo {
+    return try!  FfiConverterTypeFfiReadonlyRepo.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffigittransaction_base_repo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
-     * The change ID (stable identifier across rewrites)
+     * Commit the git transaction and return the updated repository
+     *
+     * In a colocated workspace, bookmarks and tags are exported to Git first.
      */
-    public var changeId: FfiChangeId
+open func commit(description: String)throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_commit(self.uniffiClonePointer(),
+        FfiConverterString.lower(description),$0
+    )
+})
+}
+    
     /**
-     * Commit description/message
+     * Discard the git transaction without committing
      */
-    public var description: String
+open func discard()throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_discard(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
     /**
-     * Author signature
+     * Export refs to the underlying Git repository
+     *
+     * This synchronizes the Git refs with changes made in jj.
      */
-    public var author: FfiSignature
+open func exportRefs()throws  -> FfiGitExportStats {
+    return try  FfiConverterTypeFfiGitExportStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_export_refs(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Fetch from a remote
+     *
+     * Fetches the specified branches (or all branches if empty) from the remote.
+     * Remote bookmarks that no longer exist on the remote are deleted and the
+     * full history is fetched, like `jj git fetch`.
+     */
+open func fetch(remoteName: String, branchPatterns: [String])throws  -> FfiGitImportStats {
+    return try  FfiConverterTypeFfiGitImportStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_fetch(self.uniffiClonePointer(),
+        FfiConverterString.lower(remoteName),
+        FfiConverterSequenceString.lower(branchPatterns),$0
+    )
+})
+}
+    
+    /**
+     * Fetch from a remote without blocking the calling thread
+     *
+     * Same as `fetch_with_options`, but runs on a background thread.
+     */
+open func fetchAsync(remoteName: String, branchPatterns: [String], options: FfiFetchOptions)async throws  -> FfiGitImportStats {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_jj_ffi_fn_method_ffigittransaction_fetch_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(remoteName),FfiConverterSequenceString.lower(branchPatterns),FfiConverterTypeFfiFetchOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_jj_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_jj_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_jj_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeFfiGitImportStats.lift,
+            errorHandler: FfiConverterTypeJjError.lift
+        )
+}
+    
+    /**
+     * Fetch from a remote with the given options
+     *
+     * Same as `fetch`, but with options for pruning, shallow fetches,
+     * credentials, progress and cancellation.
+     */
+open func fetchWithOptions(remoteName: String, branchPatterns: [String], options: FfiFetchOptions)throws  -> FfiGitImportStats {
+    return try  FfiConverterTypeFfiGitImportStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_fetch_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(remoteName),
+        FfiConverterSequenceString.lower(branchPatterns),
+        FfiConverterTypeFfiFetchOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Import refs from the underlying Git repository
+     *
+     * This synchronizes the jj view with any changes made directly in Git.
+     */
+open func importRefs()throws  -> FfiGitImportStats {
+    return try  FfiConverterTypeFfiGitImportStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_import_refs(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Push branches to a remote
+     *
+     * Pushes the specified local branches to the remote. A branch that was
+     * deleted locally but is still tracked on the remote is deleted from the
+     * remote, like `jj git push --deleted`.
+     */
+open func pushBranches(remoteName: String, branchNames: [String])throws  -> FfiGitPushStats {
+    return try  FfiConverterTypeFfiGitPushStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_push_branches(self.uniffiClonePointer(),
+        FfiConverterString.lower(remoteName),
+        FfiConverterSequenceString.lower(branchNames),$0
+    )
+})
+}
+    
+    /**
+     * Push branches to a remote without blocking the calling thread
+     *
+     * Same as `push_branches_with_options`, but runs on a background thread.
+     */
+open func pushBranchesAsync(remoteName: String, branchNames: [String], options: FfiPushOptions)async throws  -> FfiGitPushStats {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_jj_ffi_fn_method_ffigittransaction_push_branches_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(remoteName),FfiConverterSequenceString.lower(branchNames),FfiConverterTypeFfiPushOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_jj_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_jj_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_jj_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeFfiGitPushStats.lift,
+            errorHandler: FfiConverterTypeJjError.lift
+        )
+}
+    
+    /**
+     * Push branches to a remote with the given options
+     *
+     * Same as `push_branches`, but with options for dry runs, credentials,
+     * progress and cancellation. With `dry_run`, nothing is pushed and the
+     * returned stats only list the updates that would be pushed.
+     */
+open func pushBranchesWithOptions(remoteName: String, branchNames: [String], options: FfiPushOptions)throws  -> FfiGitPushStats {
+    return try  FfiConverterTypeFfiGitPushStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_push_branches_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(remoteName),
+        FfiConverterSequenceString.lower(branchNames),
+        FfiConverterTypeFfiPushOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Push changes to a remote with generated bookmarks, like `jj git push -c`
+     *
+     * A "push-<change id>" bookmark is created or moved to the visible commit
+     * of each change in this transaction, then the bookmarks are pushed like
+     * in `push_branches_with_options()`. The bookmarks are kept even if the
+     * push fails or is a dry run; discard the transaction to drop them.
+     */
+open func pushChanges(remoteName: String, changeIds: [FfiChangeId], options: FfiPushOptions)throws  -> FfiGitPushStats {
+    return try  FfiConverterTypeFfiGitPushStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_push_changes(self.uniffiClonePointer(),
+        FfiConverterString.lower(remoteName),
+        FfiConverterSequenceTypeFfiChangeId.lower(changeIds),
+        FfiConverterTypeFfiPushOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Start tracking a remote bookmark
+     *
+     * The remote bookmark's target is merged into the local bookmark of the
+     * same name, creating it if needed. Tracking an already-tracked bookmark
+     * is a no-op.
+     */
+open func trackRemoteBookmark(name: String, remote: String)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_track_remote_bookmark(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),
+        FfiConverterString.lower(remote),$0
+    )
+}
+}
+    
+    /**
+     * Stop tracking a remote bookmark
+     *
+     * The local bookmark is left in place. Untracking a bookmark that is not
+     * tracked is a no-op.
+     */
+open func untrackRemoteBookmark(name: String, remote: String)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffigittransaction_untrack_remote_bookmark(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),
+        FfiConverterString.lower(remote),$0
+    )
+}
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGitTransaction: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiGitTransaction
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiGitTransaction {
+        return FfiGitTransaction(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiGitTransaction) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitTransaction {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiGitTransaction, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitTransaction_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiGitTransaction {
+    return try FfiConverterTypeFfiGitTransaction.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitTransaction_lower(_ value: FfiGitTransaction) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiGitTransaction.lower(value)
+}
+
+
+
+
+/**
+ * Iterator over the log, loading commits one page at a time
+ *
+ * Unlike `evaluate_log`, only the commits of the requested page are read,
+ * so large histories can be loaded as the user scrolls. The graph itself
+ * (commit IDs and edges only) is computed on creation.
+ */
+public protocol FfiLogIteratorProtocol : AnyObject {
+    
+    /**
+     * Get the next page of at most `page_size` log entries
+     *
+     * Returns an empty list once the log is exhausted.
+     */
+    func nextPage(pageSize: UInt32) throws  -> [FfiLogEntry]
+    
+}
+
+/**
+ * Iterator over the log, loading commits one page at a time
+ *
+ * Unlike `evaluate_log`, only the commits of the requested page are read,
+ * so large histories can be loaded as the user scrolls. The graph itself
+ * (commit IDs and edges only) is computed on creation.
+ */
+open class FfiLogIterator:
+    FfiLogIteratorProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffilogiterator(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_jj_ffi_fn_free_ffilogiterator(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Get the next page of at most `page_size` log entries
+     *
+     * Returns an empty list once the log is exhausted.
+     */
+open func nextPage(pageSize: UInt32)throws  -> [FfiLogEntry] {
+    return try  FfiConverterSequenceTypeFfiLogEntry.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffilogiterator_next_page(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(pageSize),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiLogIterator: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiLogIterator
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiLogIterator {
+        return FfiLogIterator(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiLogIterator) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogIterator {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiLogIterator, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogIterator_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiLogIterator {
+    return try FfiConverterTypeFfiLogIterator.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogIterator_lower(_ value: FfiLogIterator) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiLogIterator.lower(value)
+}
+
+
+
+
+/**
+ * Receives log records, implemented by the host app
+ *
+ * Methods are called on the thread that emitted the event. Events emitted
+ * while the sink is running are dropped.
+ */
+public protocol FfiLogSink : AnyObject {
+    
+    /**
+     * Called with each event at or below the sink's level
+     */
+    func log(record: FfiLogRecord) 
+    
+}
+
+/**
+ * Receives log records, implemented by the host app
+ *
+ * Methods are called on the thread that emitted the event. Events emitted
+ * while the sink is running are dropped.
+ */
+open class FfiLogSinkImpl:
+    FfiLogSink {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffilogsink(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_jj_ffi_fn_free_ffilogsink(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Called with each event at or below the sink's level
+     */
+open func log(record: FfiLogRecord) {try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffilogsink_log(self.uniffiClonePointer(),
+        FfiConverterTypeFfiLogRecord.lower(record),$0
+    )
+}
+}
+    
+
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceFfiLogSink {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceFfiLogSink = UniffiVTableCallbackInterfaceFfiLogSink(
+        log: { (
+            uniffiHandle: UInt64,
+            record: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeFfiLogSink.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.log(
+                     record: try FfiConverterTypeFfiLogRecord.lift(record)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterTypeFfiLogSink.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface FfiLogSink: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitFfiLogSink() {
+    uniffi_jj_ffi_fn_init_callback_vtable_ffilogsink(&UniffiCallbackInterfaceFfiLogSink.vtable)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiLogSink: FfiConverter {
+    fileprivate static var handleMap = UniffiHandleMap<FfiLogSink>()
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiLogSink
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiLogSink {
+        return FfiLogSinkImpl(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiLogSink) -> UnsafeMutableRawPointer {
+        guard let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: handleMap.insert(obj: value))) else {
+            fatalError("Cast to UnsafeMutableRawPointer failed")
+        }
+        return ptr
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogSink {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiLogSink, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogSink_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiLogSink {
+    return try FfiConverterTypeFfiLogSink.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogSink_lower(_ value: FfiLogSink) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiLogSink.lower(value)
+}
+
+
+
+
+/**
+ * A readonly repository exposed via FFI
+ *
+ * A repository handle is pinned to a single operation and never changes, so
+ * it can be queried from any thread, including while a transaction started
+ * from it is in flight. Use `reload_at_head()` to observe newer operations.
+ *
+ * Handles obtained from an `FfiWorkspace` resolve `@` in revsets to the
+ * workspace's working-copy commit; handles from `FfiRepoLoader` don't.
+ *
+ * The revset aliases are prepared on the first revset query and reused by
+ * later queries on the same handle and its transactions.
+ */
+public protocol FfiReadonlyRepoProtocol : AnyObject {
+    
+    /**
+     * Count commits in a revset per day or week of their author date
+     */
+    func activityHistogram(revsetStr: String, userEmail: String, bucket: FfiActivityBucket) throws  -> [FfiActivityCount]
+    
+    /**
+     * List the bookmarks that moved, appeared or disappeared between two operations
+     */
+    func bookmarkChangesBetweenOps(oldOp: String, newOp: String) throws  -> [FfiBookmarkChange]
+    
+    /**
+     * List the local bookmarks in the repository
+     */
+    func bookmarks()  -> [FfiBookmark]
+    
+    /**
+     * List the local and remote bookmarks that contain a commit
+     */
+    func bookmarksContaining(commitId: FfiCommitId) throws  -> [FfiBookmarkRef]
+    
+    /**
+     * Get the visible children of a commit, newest first
+     */
+    func children(commitId: FfiCommitId) throws  -> [FfiCommitId]
+    
+    /**
+     * Read the extra headers stored in the Git object of a commit
+     *
+     * Kept separate from `FfiCommit` so log queries don't have to re-read
+     * every Git object.
+     */
+    func commitExtraHeaders(commitId: FfiCommitId) throws  -> [FfiCommitHeader]
+    
+    /**
+     * Get the sides and bases of a conflicted file in a commit
+     *
+     * Each term carries its own content, allowing clients to render
+     * multi-pane conflict views instead of marker-materialized text.
+     */
+    func conflictSides(commitId: FfiCommitId, path: String) throws  -> FfiConflictSides
+    
+    /**
+     * List the paths with unresolved conflicts in a commit
+     */
+    func conflictedPaths(commitId: FfiCommitId) throws  -> [String]
+    
+    /**
+     * Get the sides and bases of every conflicted file in a commit
+     */
+    func conflicts(commitId: FfiCommitId) throws  -> [FfiConflictSides]
+    
+    /**
+     * Compute per-author commit statistics for commits in a revset
+     *
+     * Line stats are only computed if `include_line_stats` is set, as they
+     * require diffing every commit.
+     */
+    func contributorStats(revsetStr: String, userEmail: String, includeLineStats: Bool) throws  -> [FfiContributorStats]
+    
+    /**
+     * Count commits matching a revset expression
+     */
+    func countRevset(revsetStr: String, userEmail: String) throws  -> UInt64
+    
+    /**
+     * Same as `count_revset`, with options like a cancellation token
+     */
+    func countRevsetWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions) throws  -> UInt64
+    
+    /**
+     * Summarize changes between two commits per subdirectory
+     *
+     * Returns one entry for each immediate subdirectory of `dir` (use `""`
+     * for the repository root) containing changed files, with file and line
+     * counts aggregated recursively, and one entry for each changed file
+     * directly inside `dir`.
+     */
+    func diffDirectorySummary(from: FfiCommitId, to: FfiCommitId, dir: String) throws  -> [FfiDirectoryDiffSummary]
+    
+    /**
+     * Compute line-based diff hunks for a file between two commits
+     *
+     * `context_lines` controls how many unchanged lines surround each hunk.
+     * If `intraline` is set, added and removed lines also carry the ranges of
+     * the words that changed within them, for word-level highlighting.
+     */
+    func diffFileHunks(from: FfiCommitId, to: FfiCommitId, path: String, contextLines: UInt32, intraline: Bool) throws  -> [FfiDiffHunk]
+    
+    /**
+     * Count inserted and deleted lines per file between two commits
+     *
+     * Like `jj diff --stat`, this is cheaper than computing hunks for each
+     * file. Binary files are flagged and count no lines.
+     */
+    func diffStats(from: FfiCommitId, to: FfiCommitId) throws  -> FfiDiffStats
+    
+    /**
+     * List the files changed between two commits
+     *
+     * Renames and copies are detected when the backend records them.
+     */
+    func diffSummary(from: FfiCommitId, to: FfiCommitId) throws  -> [FfiDiffEntry]
+    
+    /**
+     * Evaluate a revset expression and return matching commit IDs
+     */
+    func evaluateRevset(revsetStr: String, userEmail: String) throws  -> [FfiCommitId]
+    
+    /**
+     * Evaluate a revset expression and return the change IDs of matching
+     * commits, without duplicates, in revset order
+     */
+    func evaluateRevsetToChangeIds(revsetStr: String, userEmail: String) throws  -> [FfiChangeId]
+    
+    /**
+     * Same as `evaluate_revset_to_change_ids`, with options like a cancellation token
+     */
+    func evaluateRevsetToChangeIdsWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions) throws  -> [FfiChangeId]
+    
+    /**
+     * Evaluate a revset expression and return matching commits
+     */
+    func evaluateRevsetToCommits(revsetStr: String, userEmail: String) throws  -> [FfiCommit]
+    
+    /**
+     * Same as `evaluate_revset_to_commits`, with options like a cancellation token
+     */
+    func evaluateRevsetToCommitsWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions) throws  -> [FfiCommit]
+    
+    /**
+     * Same as `evaluate_revset`, with options like a cancellation token
+     */
+    func evaluateRevsetWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions) throws  -> [FfiCommitId]
+    
+    /**
+     * Export the commits selected by a revset as DOT or Mermaid text
+     */
+    func exportGraph(revsetStr: String, userEmail: String, options: FfiGraphExportOptions) throws  -> String
+    
+    /**
+     * Export commits as `git format-patch` style text
+     *
+     * Each commit is diffed against its parents and carries its change ID in
+     * a `Change-Id` trailer. File contents are included byte for byte, so the
+     * patch is returned as bytes rather than a string. Exporting a commit that
+     * changes a binary file fails, as the patch couldn't be imported again.
+     */
+    func exportPatch(commitIds: [FfiCommitId]) throws  -> Data
+    
+    /**
+     * Export a commit's tree to a directory or zip archive
+     *
+     * The working copy is not touched. The destination must not exist (or be
+     * an empty directory). Returns the number of exported entries.
+     */
+    func exportRevision(commitId: FfiCommitId, destination: String, format: FfiExportFormat) throws  -> UInt64
+    
+    /**
+     * Find the latest operation committed with an idempotency key
+     *
+     * Returns the operation ID, or `None` if no such operation exists.
+     */
+    func findOperationByIdempotencyKey(key: String) throws  -> String?
+    
+    /**
+     * Get a commit by its commit ID (hex string)
+     *
+     * Hidden commits, such as rewritten or abandoned ones, can be fetched
+     * too. Use `is_hidden()` to tell them apart.
+     */
+    func getCommit(commitId: FfiCommitId) throws  -> FfiCommit
+    
+    /**
+     * Get several commits by their commit IDs in one call
+     *
+     * Commits are returned in the order of `commit_ids`. Fails if any of the
+     * commits cannot be found.
+     */
+    func getCommits(commitIds: [FfiCommitId]) throws  -> [FfiCommit]
+    
+    /**
+     * Get HEAD of the backing Git repository
+     *
+     * In a colocated workspace, this is what `git` currently points at,
+     * which is normally the parent of the working-copy commit.
+     */
+    func gitHead() throws  -> FfiGitHead
+    
+    /**
+     * Check whether a commit is hidden, e.g. because it was rewritten or abandoned
+     */
+    func isHidden(commitId: FfiCommitId) throws  -> Bool
+    
+    /**
+     * List the entries directly inside a directory of a commit's tree
+     *
+     * Use `""` as `path_prefix` to list the repository root.
+     */
+    func listTree(commitId: FfiCommitId, pathPrefix: String) throws  -> [FfiTreeEntry]
+    
+    /**
+     * Get log with graph information
+     *
+     * Returns a list of log entries with commit information and graph edges.
+     * The entries are ordered topologically (children before parents) unless
+     * `reversed` is set to true in the options.
+     *
+     * # Arguments
+     * * `options` - Log options including revisions, limit, and ordering
+     * * `user_email` - User email for revset resolution
+     *
+     * # Example revisions
+     * - `["@"]` - Current working copy
+     * - `["::"]` - All commits
+     * - `["main..@"]` - Commits from main to current
+     */
+    func log(options: FfiLogOptions, userEmail: String) throws  -> FfiLogResult
+    
+    /**
+     * Get log as a flat list without graph information
+     *
+     * Returns a list of commits without graph edge information.
+     * More efficient when graph visualization is not needed.
+     */
+    func logFlat(options: FfiLogOptions, userEmail: String) throws  -> [FfiCommit]
+    
+    /**
+     * Get log with graph information one page at a time
+     *
+     * Returns an iterator whose `next_page()` loads only the commits of the
+     * requested page, for lazily loading large histories.
+     */
+    func logIterator(options: FfiLogOptions, userEmail: String) throws  -> FfiLogIterator
+    
+    /**
+     * Get visible commits authored by the given email
+     */
+    func mine(userEmail: String) throws  -> [FfiCommitId]
+    
+    /**
+     * Start building a tree from the tree with the given hex IDs, or from an
+     * empty tree
+     *
+     * The IDs are in the form of `FfiCommit::tree_ids`, e.g. to change the
+     * content of an existing commit.
+     */
+    func newTreeBuilder(baseTreeIds: [String]?) throws  -> FfiTreeBuilder
+    
+    /**
+     * Get the operation this repository was loaded at, with its metadata
+     */
+    func operation()  -> FfiOperation
+    
+    /**
+     * Get the ID of the operation this repository was loaded at
+     */
+    func operationId()  -> String
+    
+    /**
+     * List the operations leading to this repository's operation, newest first
+     *
+     * Like `jj op log`, merged operations are followed through all parents.
+     * At most `limit` operations are returned if given.
+     */
+    func operationLog(limit: UInt32?) throws  -> [FfiOperation]
+    
+    /**
+     * Get the divergent operations merged when this repository was loaded
+     * by `reload_at_head()` or `FfiRepoLoader::load_at_head()`, or before
+     * the operation of `undo_latest_operation()` or `restore_to_operation()`
+     *
+     * Returns `None` if the repository had a single latest operation or was
+     * loaded otherwise.
+     */
+    func operationMergeReport()  -> FfiOperationMergeReport?
+    
+    /**
+     * Read the content of a file in a commit
+     *
+     * Conflicted files are returned with conflict markers.
+     */
+    func readFile(commitId: FfiCommitId, path: String) throws  -> Data
+    
+    /**
+     * Read the content of a file in a commit as UTF-8 text
+     *
+     * Fails if the content is not valid UTF-8.
+     */
+    func readFileText(commitId: FfiCommitId, path: String) throws  -> String
+    
+    /**
+     * Load the repository at the latest operation
+     *
+     * Returns a new handle that sees operations made since this one was
+     * loaded, e.g. by the `jj` CLI. Divergent operations are merged like the
+     * CLI does; see `operation_merge_report()` of the new handle.
+     */
+    func reloadAtHead() throws  -> FfiReadonlyRepo
+    
+    /**
+     * List the remote bookmarks with their tracking status and how far
+     * they are ahead of or behind the local bookmarks
+     */
+    func remoteBookmarks() throws  -> [FfiRemoteBookmark]
+    
+    /**
+     * Render a commit with a template in jj's template language
+     *
+     * For example, `change_id.short() ++ " " ++ description.first_line()`,
+     * or an alias like `builtin_log_oneline`.
+     */
+    func renderCommitTemplate(commitId: FfiCommitId, templateText: String) throws  -> String
+    
+    /**
+     * Resolve a change ID to commit IDs
+     */
+    func resolveChangeId(changeId: FfiChangeId) throws  -> [FfiCommitId]
+    
+    /**
+     * Resolve a commit ID prefix (returns all matching commits)
+     */
+    func resolveCommitPrefix(prefix: String) throws  -> [FfiCommitId]
+    
+    /**
+     * Restore the repository to its state at an operation, like `jj op restore`
+     *
+     * `op_id` is an operation ID (or unique prefix) or an expression like
+     * "@-". Returns the repository at the new operation. Files on disk are
+     * not updated, so if the restore changes a workspace's working-copy
+     * commit, the workspace is stale until `FfiWorkspace::update_stale()` is
+     * called.
+     */
+    func restoreToOperation(opId: String) throws  -> FfiReadonlyRepo
+    
+    /**
+     * Get the root change ID
+     */
+    func rootChangeId()  -> FfiChangeId
+    
+    /**
+     * Get the root commit of the repository
+     */
+    func rootCommit()  -> FfiCommit
+    
+    /**
+     * Get the root commit ID
+     */
+    func rootCommitId()  -> FfiCommitId
+    
+    /**
+     * Get the shortest prefix that uniquely identifies a change ID
+     *
+     * The prefix is padded to at least `min_len` digits.
+     */
+    func shortestChangeIdPrefix(changeId: FfiChangeId, minLen: UInt32) throws  -> String
+    
+    /**
+     * Get the shortest prefix that uniquely identifies a commit ID
+     *
+     * The prefix is padded to at least `min_len` digits.
+     */
+    func shortestCommitIdPrefix(commitId: FfiCommitId, minLen: UInt32) throws  -> String
+    
+    /**
+     * Start a new Git transaction for performing Git operations
+     */
+    func startGitTransaction() throws  -> FfiGitTransaction
+    
+    /**
+     * Start a new transaction for making changes to the repository
+     */
+    func startTransaction()  -> FfiTransaction
+    
+    /**
+     * Describe the stores of the repository, e.g. to hide Git features for
+     * repositories without a Git backend
+     */
+    func storeInfo()  -> FfiStoreInfo
+    
+    /**
+     * List the local tags in the repository
+     */
+    func tags()  -> [FfiTag]
+    
+    /**
+     * Get the trunk commit (latest of main/master/trunk on origin/upstream)
+     */
+    func trunk() throws  -> FfiCommit
+    
+    /**
+     * Undo the latest operation in the repository, like `jj undo`
+     *
+     * Returns the repository at the new operation. Files on disk are not
+     * updated, so if the undo changes a workspace's working-copy commit, the
+     * workspace is stale until `FfiWorkspace::update_stale()` is called.
+     */
+    func undoLatestOperation() throws  -> FfiReadonlyRepo
+    
+    /**
+     * Get commits of tracked bookmarks that have not been pushed yet
+     */
+    func unpushedCommits() throws  -> [FfiCommitId]
+    
+    /**
+     * Count the visible head commits, without listing them
+     */
+    func visibleHeadCount()  -> UInt64
+    
+    /**
+     * Get the visible head commits, newest first
+     */
+    func visibleHeads() throws  -> [FfiCommitId]
+    
+    /**
+     * Write the versions of a conflicted file for an external merge tool
+     *
+     * Only conflicts with two sides are supported. After the tool has
+     * written its result to `files.output`, pass it to
+     * `FfiTransaction::resolve_conflict_from_merge_tool()`.
+     */
+    func writeMergeToolFiles(commitId: FfiCommitId, path: String, files: FfiMergeToolFiles) throws 
+    
+}
+
+/**
+ * A readonly repository exposed via FFI
+ *
+ * A repository handle is pinned to a single operation and never changes, so
+ * it can be queried from any thread, including while a transaction started
+ * from it is in flight. Use `reload_at_head()` to observe newer operations.
+ *
+ * Handles obtained from an `FfiWorkspace` resolve `@` in revsets to the
+ * workspace's working-copy commit; handles from `FfiRepoLoader` don't.
+ *
+ * The revset aliases are prepared on the first revset query and reused by
+ * later queries on the same handle and its transactions.
+ */
+open class FfiReadonlyRepo:
+    FfiReadonlyRepoProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffireadonlyrepo(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_jj_ffi_fn_free_ffireadonlyrepo(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Count commits in a revset per day or week of their author date
+     */
+open func activityHistogram(revsetStr: String, userEmail: String, bucket: FfiActivityBucket)throws  -> [FfiActivityCount] {
+    return try  FfiConverterSequenceTypeFfiActivityCount.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_activity_histogram(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiActivityBucket.lower(bucket),$0
+    )
+})
+}
+    
+    /**
+     * List the bookmarks that moved, appeared or disappeared between two operations
+     */
+open func bookmarkChangesBetweenOps(oldOp: String, newOp: String)throws  -> [FfiBookmarkChange] {
+    return try  FfiConverterSequenceTypeFfiBookmarkChange.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_bookmark_changes_between_ops(self.uniffiClonePointer(),
+        FfiConverterString.lower(oldOp),
+        FfiConverterString.lower(newOp),$0
+    )
+})
+}
+    
+    /**
+     * List the local bookmarks in the repository
+     */
+open func bookmarks() -> [FfiBookmark] {
+    return try!  FfiConverterSequenceTypeFfiBookmark.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_bookmarks(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * List the local and remote bookmarks that contain a commit
+     */
+open func bookmarksContaining(commitId: FfiCommitId)throws  -> [FfiBookmarkRef] {
+    return try  FfiConverterSequenceTypeFfiBookmarkRef.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_bookmarks_containing(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Get the visible children of a commit, newest first
+     */
+open func children(commitId: FfiCommitId)throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_children(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Read the extra headers stored in the Git object of a commit
+     *
+     * Kept separate from `FfiCommit` so log queries don't have to re-read
+     * every Git object.
+     */
+open func commitExtraHeaders(commitId: FfiCommitId)throws  -> [FfiCommitHeader] {
+    return try  FfiConverterSequenceTypeFfiCommitHeader.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_commit_extra_headers(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Get the sides and bases of a conflicted file in a commit
+     *
+     * Each term carries its own content, allowing clients to render
+     * multi-pane conflict views instead of marker-materialized text.
+     */
+open func conflictSides(commitId: FfiCommitId, path: String)throws  -> FfiConflictSides {
+    return try  FfiConverterTypeFfiConflictSides.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_conflict_sides(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * List the paths with unresolved conflicts in a commit
+     */
+open func conflictedPaths(commitId: FfiCommitId)throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_conflicted_paths(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Get the sides and bases of every conflicted file in a commit
+     */
+open func conflicts(commitId: FfiCommitId)throws  -> [FfiConflictSides] {
+    return try  FfiConverterSequenceTypeFfiConflictSides.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_conflicts(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Compute per-author commit statistics for commits in a revset
+     *
+     * Line stats are only computed if `include_line_stats` is set, as they
+     * require diffing every commit.
+     */
+open func contributorStats(revsetStr: String, userEmail: String, includeLineStats: Bool)throws  -> [FfiContributorStats] {
+    return try  FfiConverterSequenceTypeFfiContributorStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_contributor_stats(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterBool.lower(includeLineStats),$0
+    )
+})
+}
+    
+    /**
+     * Count commits matching a revset expression
+     */
+open func countRevset(revsetStr: String, userEmail: String)throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_count_revset(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Same as `count_revset`, with options like a cancellation token
+     */
+open func countRevsetWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions)throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_count_revset_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiRevsetOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Summarize changes between two commits per subdirectory
+     *
+     * Returns one entry for each immediate subdirectory of `dir` (use `""`
+     * for the repository root) containing changed files, with file and line
+     * counts aggregated recursively, and one entry for each changed file
+     * directly inside `dir`.
+     */
+open func diffDirectorySummary(from: FfiCommitId, to: FfiCommitId, dir: String)throws  -> [FfiDirectoryDiffSummary] {
+    return try  FfiConverterSequenceTypeFfiDirectoryDiffSummary.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_diff_directory_summary(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(from),
+        FfiConverterTypeFfiCommitId.lower(to),
+        FfiConverterString.lower(dir),$0
+    )
+})
+}
+    
+    /**
+     * Compute line-based diff hunks for a file between two commits
+     *
+     * `context_lines` controls how many unchanged lines surround each hunk.
+     * If `intraline` is set, added and removed lines also carry the ranges of
+     * the words that changed within them, for word-level highlighting.
+     */
+open func diffFileHunks(from: FfiCommitId, to: FfiCommitId, path: String, contextLines: UInt32, intraline: Bool)throws  -> [FfiDiffHunk] {
+    return try  FfiConverterSequenceTypeFfiDiffHunk.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_diff_file_hunks(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(from),
+        FfiConverterTypeFfiCommitId.lower(to),
+        FfiConverterString.lower(path),
+        FfiConverterUInt32.lower(contextLines),
+        FfiConverterBool.lower(intraline),$0
+    )
+})
+}
+    
+    /**
+     * Count inserted and deleted lines per file between two commits
+     *
+     * Like `jj diff --stat`, this is cheaper than computing hunks for each
+     * file. Binary files are flagged and count no lines.
+     */
+open func diffStats(from: FfiCommitId, to: FfiCommitId)throws  -> FfiDiffStats {
+    return try  FfiConverterTypeFfiDiffStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_diff_stats(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(from),
+        FfiConverterTypeFfiCommitId.lower(to),$0
+    )
+})
+}
+    
+    /**
+     * List the files changed between two commits
+     *
+     * Renames and copies are detected when the backend records them.
+     */
+open func diffSummary(from: FfiCommitId, to: FfiCommitId)throws  -> [FfiDiffEntry] {
+    return try  FfiConverterSequenceTypeFfiDiffEntry.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_diff_summary(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(from),
+        FfiConverterTypeFfiCommitId.lower(to),$0
+    )
+})
+}
+    
+    /**
+     * Evaluate a revset expression and return matching commit IDs
+     */
+open func evaluateRevset(revsetStr: String, userEmail: String)throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Evaluate a revset expression and return the change IDs of matching
+     * commits, without duplicates, in revset order
+     */
+open func evaluateRevsetToChangeIds(revsetStr: String, userEmail: String)throws  -> [FfiChangeId] {
+    return try  FfiConverterSequenceTypeFfiChangeId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_change_ids(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Same as `evaluate_revset_to_change_ids`, with options like a cancellation token
+     */
+open func evaluateRevsetToChangeIdsWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions)throws  -> [FfiChangeId] {
+    return try  FfiConverterSequenceTypeFfiChangeId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_change_ids_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiRevsetOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Evaluate a revset expression and return matching commits
+     */
+open func evaluateRevsetToCommits(revsetStr: String, userEmail: String)throws  -> [FfiCommit] {
+    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_commits(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Same as `evaluate_revset_to_commits`, with options like a cancellation token
+     */
+open func evaluateRevsetToCommitsWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions)throws  -> [FfiCommit] {
+    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_to_commits_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiRevsetOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Same as `evaluate_revset`, with options like a cancellation token
+     */
+open func evaluateRevsetWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions)throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_evaluate_revset_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiRevsetOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Export the commits selected by a revset as DOT or Mermaid text
+     */
+open func exportGraph(revsetStr: String, userEmail: String, options: FfiGraphExportOptions)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_export_graph(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiGraphExportOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Export commits as `git format-patch` style text
+     *
+     * Each commit is diffed against its parents and carries its change ID in
+     * a `Change-Id` trailer. File contents are included byte for byte, so the
+     * patch is returned as bytes rather than a string. Exporting a commit that
+     * changes a binary file fails, as the patch couldn't be imported again.
+     */
+open func exportPatch(commitIds: [FfiCommitId])throws  -> Data {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_export_patch(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFfiCommitId.lower(commitIds),$0
+    )
+})
+}
+    
+    /**
+     * Export a commit's tree to a directory or zip archive
+     *
+     * The working copy is not touched. The destination must not exist (or be
+     * an empty directory). Returns the number of exported entries.
+     */
+open func exportRevision(commitId: FfiCommitId, destination: String, format: FfiExportFormat)throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_export_revision(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(destination),
+        FfiConverterTypeFfiExportFormat.lower(format),$0
+    )
+})
+}
+    
+    /**
+     * Find the latest operation committed with an idempotency key
+     *
+     * Returns the operation ID, or `None` if no such operation exists.
+     */
+open func findOperationByIdempotencyKey(key: String)throws  -> String? {
+    return try  FfiConverterOptionString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_find_operation_by_idempotency_key(self.uniffiClonePointer(),
+        FfiConverterString.lower(key),$0
+    )
+})
+}
+    
+    /**
+     * Get a commit by its commit ID (hex string)
+     *
+     * Hidden commits, such as rewritten or abandoned ones, can be fetched
+     * too. Use `is_hidden()` to tell them apart.
+     */
+open func getCommit(commitId: FfiCommitId)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_get_commit(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Get several commits by their commit IDs in one call
+     *
+     * Commits are returned in the order of `commit_ids`. Fails if any of the
+     * commits cannot be found.
+     */
+open func getCommits(commitIds: [FfiCommitId])throws  -> [FfiCommit] {
+    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_get_commits(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFfiCommitId.lower(commitIds),$0
+    )
+})
+}
+    
+    /**
+     * Get HEAD of the backing Git repository
+     *
+     * In a colocated workspace, this is what `git` currently points at,
+     * which is normally the parent of the working-copy commit.
+     */
+open func gitHead()throws  -> FfiGitHead {
+    return try  FfiConverterTypeFfiGitHead.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_git_head(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Check whether a commit is hidden, e.g. because it was rewritten or abandoned
+     */
+open func isHidden(commitId: FfiCommitId)throws  -> Bool {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_is_hidden(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * List the entries directly inside a directory of a commit's tree
+     *
+     * Use `""` as `path_prefix` to list the repository root.
+     */
+open func listTree(commitId: FfiCommitId, pathPrefix: String)throws  -> [FfiTreeEntry] {
+    return try  FfiConverterSequenceTypeFfiTreeEntry.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_list_tree(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(pathPrefix),$0
+    )
+})
+}
+    
+    /**
+     * Get log with graph information
+     *
+     * Returns a list of log entries with commit information and graph edges.
+     * The entries are ordered topologically (children before parents) unless
+     * `reversed` is set to true in the options.
+     *
+     * # Arguments
+     * * `options` - Log options including revisions, limit, and ordering
+     * * `user_email` - User email for revset resolution
+     *
+     * # Example revisions
+     * - `["@"]` - Current working copy
+     * - `["::"]` - All commits
+     * - `["main..@"]` - Commits from main to current
+     */
+open func log(options: FfiLogOptions, userEmail: String)throws  -> FfiLogResult {
+    return try  FfiConverterTypeFfiLogResult.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_log(self.uniffiClonePointer(),
+        FfiConverterTypeFfiLogOptions.lower(options),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Get log as a flat list without graph information
+     *
+     * Returns a list of commits without graph edge information.
+     * More efficient when graph visualization is not needed.
+     */
+open func logFlat(options: FfiLogOptions, userEmail: String)throws  -> [FfiCommit] {
+    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_log_flat(self.uniffiClonePointer(),
+        FfiConverterTypeFfiLogOptions.lower(options),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Get log with graph information one page at a time
+     *
+     * Returns an iterator whose `next_page()` loads only the commits of the
+     * requested page, for lazily loading large histories.
+     */
+open func logIterator(options: FfiLogOptions, userEmail: String)throws  -> FfiLogIterator {
+    return try  FfiConverterTypeFfiLogIterator.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_log_iterator(self.uniffiClonePointer(),
+        FfiConverterTypeFfiLogOptions.lower(options),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Get visible commits authored by the given email
+     */
+open func mine(userEmail: String)throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_mine(self.uniffiClonePointer(),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Start building a tree from the tree with the given hex IDs, or from an
+     * empty tree
+     *
+     * The IDs are in the form of `FfiCommit::tree_ids`, e.g. to change the
+     * content of an existing commit.
+     */
+open func newTreeBuilder(baseTreeIds: [String]?)throws  -> FfiTreeBuilder {
+    return try  FfiConverterTypeFfiTreeBuilder.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_new_tree_builder(self.uniffiClonePointer(),
+        FfiConverterOptionSequenceString.lower(baseTreeIds),$0
+    )
+})
+}
+    
+    /**
+     * Get the operation this repository was loaded at, with its metadata
+     */
+open func operation() -> FfiOperation {
+    return try!  FfiConverterTypeFfiOperation.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_operation(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the ID of the operation this repository was loaded at
+     */
+open func operationId() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_operation_id(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * List the operations leading to this repository's operation, newest first
+     *
+     * Like `jj op log`, merged operations are followed through all parents.
+     * At most `limit` operations are returned if given.
+     */
+open func operationLog(limit: UInt32?)throws  -> [FfiOperation] {
+    return try  FfiConverterSequenceTypeFfiOperation.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_operation_log(self.uniffiClonePointer(),
+        FfiConverterOptionUInt32.lower(limit),$0
+    )
+})
+}
+    
+    /**
+     * Get the divergent operations merged when this repository was loaded
+     * by `reload_at_head()` or `FfiRepoLoader::load_at_head()`, or before
+     * the operation of `undo_latest_operation()` or `restore_to_operation()`
+     *
+     * Returns `None` if the repository had a single latest operation or was
+     * loaded otherwise.
+     */
+open func operationMergeReport() -> FfiOperationMergeReport? {
+    return try!  FfiConverterOptionTypeFfiOperationMergeReport.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_operation_merge_report(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Read the content of a file in a commit
+     *
+     * Conflicted files are returned with conflict markers.
+     */
+open func readFile(commitId: FfiCommitId, path: String)throws  -> Data {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_read_file(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Read the content of a file in a commit as UTF-8 text
+     *
+     * Fails if the content is not valid UTF-8.
+     */
+open func readFileText(commitId: FfiCommitId, path: String)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_read_file_text(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Load the repository at the latest operation
+     *
+     * Returns a new handle that sees operations made since this one was
+     * loaded, e.g. by the `jj` CLI. Divergent operations are merged like the
+     * CLI does; see `operation_merge_report()` of the new handle.
+     */
+open func reloadAtHead()throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_reload_at_head(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * List the remote bookmarks with their tracking status and how far
+     * they are ahead of or behind the local bookmarks
+     */
+open func remoteBookmarks()throws  -> [FfiRemoteBookmark] {
+    return try  FfiConverterSequenceTypeFfiRemoteBookmark.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_remote_bookmarks(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Render a commit with a template in jj's template language
+     *
+     * For example, `change_id.short() ++ " " ++ description.first_line()`,
+     * or an alias like `builtin_log_oneline`.
+     */
+open func renderCommitTemplate(commitId: FfiCommitId, templateText: String)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_render_commit_template(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(templateText),$0
+    )
+})
+}
+    
+    /**
+     * Resolve a change ID to commit IDs
+     */
+open func resolveChangeId(changeId: FfiChangeId)throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_resolve_change_id(self.uniffiClonePointer(),
+        FfiConverterTypeFfiChangeId.lower(changeId),$0
+    )
+})
+}
+    
+    /**
+     * Resolve a commit ID prefix (returns all matching commits)
+     */
+open func resolveCommitPrefix(prefix: String)throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_resolve_commit_prefix(self.uniffiClonePointer(),
+        FfiConverterString.lower(prefix),$0
+    )
+})
+}
+    
+    /**
+     * Restore the repository to its state at an operation, like `jj op restore`
+     *
+     * `op_id` is an operation ID (or unique prefix) or an expression like
+     * "@-". Returns the repository at the new operation. Files on disk are
+     * not updated, so if the restore changes a workspace's working-copy
+     * commit, the workspace is stale until `FfiWorkspace::update_stale()` is
+     * called.
+     */
+open func restoreToOperation(opId: String)throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_restore_to_operation(self.uniffiClonePointer(),
+        FfiConverterString.lower(opId),$0
+    )
+})
+}
+    
+    /**
+     * Get the root change ID
+     */
+open func rootChangeId() -> FfiChangeId {
+    return try!  FfiConverterTypeFfiChangeId.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_change_id(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the root commit of the repository
+     */
+open func rootCommit() -> FfiCommit {
+    return try!  FfiConverterTypeFfiCommit.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_commit(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the root commit ID
+     */
+open func rootCommitId() -> FfiCommitId {
+    return try!  FfiConverterTypeFfiCommitId.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_root_commit_id(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the shortest prefix that uniquely identifies a change ID
+     *
+     * The prefix is padded to at least `min_len` digits.
+     */
+open func shortestChangeIdPrefix(changeId: FfiChangeId, minLen: UInt32)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_shortest_change_id_prefix(self.uniffiClonePointer(),
+        FfiConverterTypeFfiChangeId.lower(changeId),
+        FfiConverterUInt32.lower(minLen),$0
+    )
+})
+}
+    
+    /**
+     * Get the shortest prefix that uniquely identifies a commit ID
+     *
+     * The prefix is padded to at least `min_len` digits.
+     */
+open func shortestCommitIdPrefix(commitId: FfiCommitId, minLen: UInt32)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_shortest_commit_id_prefix(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterUInt32.lower(minLen),$0
+    )
+})
+}
+    
+    /**
+     * Start a new Git transaction for performing Git operations
+     */
+open func startGitTransaction()throws  -> FfiGitTransaction {
+    return try  FfiConverterTypeFfiGitTransaction.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_start_git_transaction(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Start a new transaction for making changes to the repository
+     */
+open func startTransaction() -> FfiTransaction {
+    return try!  FfiConverterTypeFfiTransaction.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_start_transaction(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Describe the stores of the repository, e.g. to hide Git features for
+     * repositories without a Git backend
+     */
+open func storeInfo() -> FfiStoreInfo {
+    return try!  FfiConverterTypeFfiStoreInfo.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_store_info(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * List the local tags in the repository
+     */
+open func tags() -> [FfiTag] {
+    return try!  FfiConverterSequenceTypeFfiTag.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_tags(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the trunk commit (latest of main/master/trunk on origin/upstream)
+     */
+open func trunk()throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_trunk(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Undo the latest operation in the repository, like `jj undo`
+     *
+     * Returns the repository at the new operation. Files on disk are not
+     * updated, so if the undo changes a workspace's working-copy commit, the
+     * workspace is stale until `FfiWorkspace::update_stale()` is called.
+     */
+open func undoLatestOperation()throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_undo_latest_operation(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get commits of tracked bookmarks that have not been pushed yet
+     */
+open func unpushedCommits()throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_unpushed_commits(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Count the visible head commits, without listing them
+     */
+open func visibleHeadCount() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_visible_head_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the visible head commits, newest first
+     */
+open func visibleHeads()throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_visible_heads(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Write the versions of a conflicted file for an external merge tool
+     *
+     * Only conflicts with two sides are supported. After the tool has
+     * written its result to `files.output`, pass it to
+     * `FfiTransaction::resolve_conflict_from_merge_tool()`.
+     */
+open func writeMergeToolFiles(commitId: FfiCommitId, path: String, files: FfiMergeToolFiles)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffireadonlyrepo_write_merge_tool_files(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(path),
+        FfiConverterTypeFfiMergeToolFiles.lower(files),$0
+    )
+}
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiReadonlyRepo: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiReadonlyRepo
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiReadonlyRepo {
+        return FfiReadonlyRepo(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiReadonlyRepo) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiReadonlyRepo {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiReadonlyRepo, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiReadonlyRepo_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiReadonlyRepo {
+    return try FfiConverterTypeFfiReadonlyRepo.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiReadonlyRepo_lower(_ value: FfiReadonlyRepo) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiReadonlyRepo.lower(value)
+}
+
+
+
+
+/**
+ * A repository loader exposed via FFI
+ *
+ * Loads repositories directly from the repo directory (`.jj/repo`),
+ * skipping workspace and working-copy setup.
+ */
+public protocol FfiRepoLoaderProtocol : AnyObject {
+    
+    /**
+     * Load the repository at the current head operation
+     *
+     * Divergent operations are merged like the `jj` CLI does; see
+     * `FfiReadonlyRepo::operation_merge_report()`.
+     */
+    func loadAtHead() throws  -> FfiReadonlyRepo
+    
+    /**
+     * Load the repository at a given operation
+     *
+     * Accepts an operation ID (or unique prefix) or an operation expression
+     * such as "@" or "@-".
+     */
+    func loadAtOperation(operation: String) throws  -> FfiReadonlyRepo
+    
+}
+
+/**
+ * A repository loader exposed via FFI
+ *
+ * Loads repositories directly from the repo directory (`.jj/repo`),
+ * skipping workspace and working-copy setup.
+ */
+open class FfiRepoLoader:
+    FfiRepoLoaderProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffirepoloader(self.pointer, $0) }
+    }
+    /**
+     * Create a loader for the repo directory at the given path
+     */
+public convenience init(repoPath: String, userName: String, userEmail: String)throws  {
+    let pointer =
+        try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_constructor_ffirepoloader_new(
+        FfiConverterString.lower(repoPath),
+        FfiConverterString.lower(userName),
+        FfiConverterString.lower(userEmail),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_jj_ffi_fn_free_ffirepoloader(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Load the repository at the current head operation
+     *
+     * Divergent operations are merged like the `jj` CLI does; see
+     * `FfiReadonlyRepo::operation_merge_report()`.
+     */
+open func loadAtHead()throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffirepoloader_load_at_head(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Load the repository at a given operation
+     *
+     * Accepts an operation ID (or unique prefix) or an operation expression
+     * such as "@" or "@-".
+     */
+open func loadAtOperation(operation: String)throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffirepoloader_load_at_operation(self.uniffiClonePointer(),
+        FfiConverterString.lower(operation),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRepoLoader: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiRepoLoader
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiRepoLoader {
+        return FfiRepoLoader(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiRepoLoader) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRepoLoader {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiRepoLoader, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRepoLoader_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiRepoLoader {
+    return try FfiConverterTypeFfiRepoLoader.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRepoLoader_lower(_ value: FfiRepoLoader) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiRepoLoader.lower(value)
+}
+
+
+
+
+/**
+ * Receives progress of snapshots, implemented by the host app
+ *
+ * Methods are called on the threads scanning the working copy.
+ */
+public protocol FfiSnapshotProgress : AnyObject {
+    
+    /**
+     * Called at most every 50 ms while files are scanned, and once when
+     * scanning finished
+     */
+    func onProgress(update: FfiSnapshotProgressUpdate) 
+    
+}
+
+/**
+ * Receives progress of snapshots, implemented by the host app
+ *
+ * Methods are called on the threads scanning the working copy.
+ */
+open class FfiSnapshotProgressImpl:
+    FfiSnapshotProgress {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffisnapshotprogress(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_jj_ffi_fn_free_ffisnapshotprogress(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Called at most every 50 ms while files are scanned, and once when
+     * scanning finished
+     */
+open func onProgress(update: FfiSnapshotProgressUpdate) {try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffisnapshotprogress_on_progress(self.uniffiClonePointer(),
+        FfiConverterTypeFfiSnapshotProgressUpdate.lower(update),$0
+    )
+}
+}
+    
+
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceFfiSnapshotProgress {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceFfiSnapshotProgress = UniffiVTableCallbackInterfaceFfiSnapshotProgress(
+        onProgress: { (
+            uniffiHandle: UInt64,
+            update: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeFfiSnapshotProgress.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onProgress(
+                     update: try FfiConverterTypeFfiSnapshotProgressUpdate.lift(update)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterTypeFfiSnapshotProgress.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface FfiSnapshotProgress: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitFfiSnapshotProgress() {
+    uniffi_jj_ffi_fn_init_callback_vtable_ffisnapshotprogress(&UniffiCallbackInterfaceFfiSnapshotProgress.vtable)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiSnapshotProgress: FfiConverter {
+    fileprivate static var handleMap = UniffiHandleMap<FfiSnapshotProgress>()
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiSnapshotProgress
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiSnapshotProgress {
+        return FfiSnapshotProgressImpl(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiSnapshotProgress) -> UnsafeMutableRawPointer {
+        guard let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: handleMap.insert(obj: value))) else {
+            fatalError("Cast to UnsafeMutableRawPointer failed")
+        }
+        return ptr
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSnapshotProgress {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiSnapshotProgress, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSnapshotProgress_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiSnapshotProgress {
+    return try FfiConverterTypeFfiSnapshotProgress.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSnapshotProgress_lower(_ value: FfiSnapshotProgress) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiSnapshotProgress.lower(value)
+}
+
+
+
+
+/**
+ * A transaction for making changes to a repository
+ *
+ * # Snapshot isolation
+ * Changes made in a transaction are invisible outside of it until it is
+ * committed. The `FfiReadonlyRepo` the transaction was started from, and any
+ * other handle to the same operation, keeps answering queries from that
+ * operation while the transaction is in flight and after it is committed.
+ * Committing returns a new `FfiReadonlyRepo` for the new operation.
+ *
+ * # Safety
+ * The Transaction type from jj-lib is not Send + Sync, but we wrap it in a Mutex
+ * to ensure synchronized access. All FFI operations acquire the lock before accessing
+ * the transaction.
+ */
+public protocol FfiTransactionProtocol : AnyObject {
+    
+    /**
+     * Abandon a commit (its children will be rebased to its parents)
+     *
+     * Descendants are rebased by `rebase_descendants()` or when the
+     * transaction is committed.
+     */
+    func abandonCommit(commitId: FfiCommitId) throws 
+    
+    /**
+     * Absorb the changes of a commit into its mutable ancestors, like
+     * `jj absorb`
+     *
+     * Each hunk moves into the mutable ancestor that last modified the
+     * surrounding lines. Without `source_commit`, the working-copy commit is
+     * used, which requires a transaction started from a workspace's
+     * repository.
+     */
+    func absorb(sourceCommit: FfiCommitId?) throws  -> FfiAbsorbResult
+    
+    /**
+     * Add trailers to the description of an existing commit
+     *
+     * Trailers already present in the description are skipped. See
+     * `add_description_trailers()`.
+     */
+    func addTrailers(commitId: FfiCommitId, trailers: [FfiTrailer]) throws  -> FfiCommit
+    
+    /**
+     * Get the repository at the operation the transaction was started from
+     *
+     * Remains available after the transaction is committed or discarded.
+     */
+    func baseRepo()  -> FfiReadonlyRepo
+    
+    /**
+     * List the local bookmarks in the in-progress state of the transaction
+     */
+    func bookmarks() throws  -> [FfiBookmark]
+    
+    /**
+     * Commit the transaction and return the updated repository along with
+     * the commits rewritten in it
+     *
+     * Descendants of rewritten or abandoned commits are rebased first. In a
+     * colocated workspace, Git HEAD and refs are then updated like the `jj`
+     * CLI does. Files on disk are left unchanged; use
+     * `FfiWorkspace::finish_transaction()` to also update the working copy.
+     */
+    func commit(description: String) throws  -> FfiTransactionResult
+    
+    /**
+     * Commit the snapshotted working-copy changes, like `jj commit`
+     *
+     * The working-copy commit, with the tree recorded by the latest
+     * `FfiWorkspace::snapshot()`, gets the description, and a new empty
+     * working-copy commit is created on top of it. Returns the committed
+     * commit. The files on disk are unchanged, so snapshot the workspace
+     * before starting the transaction to include the latest edits.
+     *
+     * Only available in transactions started from a workspace's repository.
+     */
+    func commitWorkingCopy(description: String) throws  -> FfiCommit
+    
+    /**
+     * Create a new commit with the same tree as a parent commit
+     */
+    func createCommitFromParent(parentId: FfiCommitId, description: String) throws  -> FfiCommit
+    
+    /**
+     * Create a new commit with an empty tree (for creating empty commits)
+     */
+    func createEmptyCommit(newCommit: FfiNewCommit) throws  -> FfiCommit
+    
+    /**
+     * Discard the transaction without committing
+     */
+    func discard() throws 
+    
+    /**
+     * Evaluate a revset expression against the in-progress state of the transaction
+     */
+    func evaluateRevset(revsetStr: String, userEmail: String) throws  -> [FfiCommitId]
+    
+    /**
+     * Evaluate a revset expression against the in-progress state of the
+     * transaction and return the change IDs of matching commits, without
+     * duplicates, in revset order
+     */
+    func evaluateRevsetToChangeIds(revsetStr: String, userEmail: String) throws  -> [FfiChangeId]
+    
+    /**
+     * Same as `evaluate_revset_to_change_ids`, with options like a cancellation token
+     */
+    func evaluateRevsetToChangeIdsWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions) throws  -> [FfiChangeId]
+    
+    /**
+     * Evaluate a revset expression against the in-progress state of the
+     * transaction and return matching commits
+     */
+    func evaluateRevsetToCommits(revsetStr: String, userEmail: String) throws  -> [FfiCommit]
+    
+    /**
+     * Same as `evaluate_revset_to_commits`, with options like a cancellation token
+     */
+    func evaluateRevsetToCommitsWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions) throws  -> [FfiCommit]
+    
+    /**
+     * Same as `evaluate_revset`, with options like a cancellation token
+     */
+    func evaluateRevsetWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions) throws  -> [FfiCommitId]
+    
+    /**
+     * Get a commit by its commit ID, including commits created in this transaction
+     */
+    func getCommit(commitId: FfiCommitId) throws  -> FfiCommit
+    
+    /**
+     * Check if the transaction has any uncommitted changes
+     */
+    func hasChanges() throws  -> Bool
+    
+    /**
+     * Create commits from `git format-patch` style text
+     *
+     * Each patch in the text becomes a new commit, stacked in order on top of
+     * `destination`. Renames and copies are applied, and commits keep the
+     * change ID of the patch's `Change-Id` trailer, so importing a patch into
+     * a repository that still has the change makes the change divergent.
+     * Returns the created commits.
+     */
+    func importPatch(patch: Data, destination: FfiCommitId) throws  -> [FfiCommit]
+    
+    /**
+     * Create a merge commit whose tree merges the parents' trees, like `jj new a b`
+     *
+     * Conflicting changes in the parents result in a conflicted tree.
+     */
+    func merge(parentIds: [FfiCommitId], description: String) throws  -> FfiCommit
+    
+    /**
+     * Start building a new commit on the given parents
+     *
+     * Unless a tree is set, the commit gets the merged tree of its parents,
+     * like `jj new`.
+     */
+    func newCommitBuilder(parentIds: [FfiCommitId])  -> FfiCommitBuilder
+    
+    /**
+     * Make commits siblings of each other, like `jj parallelize`
+     *
+     * Typically the commits are a linear run, which becomes a set of
+     * siblings on the run's parent. Children of the run are rebased onto all
+     * of the siblings. Returns the rewritten commits, children first.
+     */
+    func parallelize(commitIds: [FfiCommitId]) throws  -> [FfiCommit]
+    
+    /**
+     * Rebase commits onto new parents
+     *
+     * Each source commit is moved together with its descendants, like
+     * `jj rebase -s`. Descendants of rewritten commits elsewhere in the graph
+     * are rebased as well. See `rebase_commits()` for the other modes.
+     */
+    func rebase(sourceCommitIds: [FfiCommitId], newParentIds: [FfiCommitId]) throws  -> FfiRebaseStats
+    
+    /**
+     * Rebase commits, a subtree or a branch onto new parents or between
+     * existing commits, like `jj rebase` with `-r`, `-s` or `-b` and `-d`,
+     * `--insert-after` or `--insert-before`
+     *
+     * Descendants of rewritten commits elsewhere in the graph are rebased as
+     * well.
+     */
+    func rebaseCommits(target: FfiRebaseTarget, destination: FfiRebaseDestination) throws  -> FfiRebaseStats
+    
+    /**
+     * Rebase the descendants of commits rewritten or abandoned in this transaction
+     *
+     * Returns the number of rebased commits, e.g. to report "3 descendant
+     * commits rebased". Committing the transaction rebases any remaining
+     * descendants, but calling this first also makes the rebased commits
+     * visible to queries within the transaction.
+     */
+    func rebaseDescendants() throws  -> UInt32
+    
+    /**
+     * Resolve a conflicted file in a commit with the given content
+     *
+     * Rewrites the commit and rebases its descendants. Returns the new commit.
+     */
+    func resolveConflict(commitId: FfiCommitId, path: String, content: Data) throws  -> FfiCommit
+    
+    /**
+     * Resolve a conflicted file with the result of an external merge tool
+     *
+     * `output_path` is the result file, usually the `output` path passed to
+     * `FfiReadonlyRepo::write_merge_tool_files()`. Conflict markers left in
+     * the result keep those hunks conflicted. Returns the rewritten commit.
+     */
+    func resolveConflictFromMergeTool(commitId: FfiCommitId, path: String, outputPath: String) throws  -> FfiCommit
+    
+    /**
+     * Rewrite an existing commit with new properties
+     *
+     * Descendants are rebased onto the new commit by `rebase_descendants()`
+     * or when the transaction is committed.
+     */
+    func rewriteCommit(rewrite: FfiRewriteCommit) throws  -> FfiCommit
+    
+    /**
+     * Start building a rewrite of an existing commit
+     *
+     * The rewritten commit keeps the properties that aren't set on the
+     * builder, except for the committer. The root commit can't be rewritten.
+     */
+    func rewriteCommitBuilder(commitId: FfiCommitId) throws  -> FfiCommitBuilder
+    
+    /**
+     * Record a client-supplied idempotency key in the operation metadata
+     *
+     * Before retrying a transaction whose outcome is unknown, check
+     * `FfiReadonlyRepo::find_operation_by_idempotency_key()` to avoid
+     * applying the same changes twice.
+     */
+    func setIdempotencyKey(key: String) throws 
+    
+    /**
+     * Set a tag in the operation metadata
+     *
+     * Tags are returned in `FfiOperation::tags` and can be used to tell
+     * operations made by the app from those made by the `jj` CLI.
+     */
+    func setTag(key: String, value: String) throws 
+    
+    /**
+     * Split a commit by paths, like `jj split <paths>`
+     *
+     * The first commit contains the changes to the given paths (files or
+     * directories) and the second one, on top of it, the remaining changes.
+     * At least one path is required.
+     */
+    func split(commitId: FfiCommitId, paths: [String]) throws  -> FfiSplitResult
+    
+    /**
+     * Squash a commit into its parent, like `jj squash -r`
+     *
+     * Returns the rewritten parent.
+     */
+    func squash(commitId: FfiCommitId, options: FfiSquashOptions) throws  -> FfiCommit
+    
+    /**
+     * Squash a commit into another commit, like `jj squash --from --into`
+     *
+     * Returns the rewritten destination.
+     */
+    func squashInto(from: FfiCommitId, into: FfiCommitId, options: FfiSquashOptions) throws  -> FfiCommit
+    
+    /**
+     * Update the description of an existing commit
+     */
+    func updateDescription(commitId: FfiCommitId, newDescription: String) throws  -> FfiCommit
+    
+}
+
+/**
+ * A transaction for making changes to a repository
+ *
+ * # Snapshot isolation
+ * Changes made in a transaction are invisible outside of it until it is
+ * committed. The `FfiReadonlyRepo` the transaction was started from, and any
+ * other handle to the same operation, keeps answering queries from that
+ * operation while the transaction is in flight and after it is committed.
+ * Committing returns a new `FfiReadonlyRepo` for the new operation.
+ *
+ * # Safety
+ * The Transaction type from jj-lib is not Send + Sync, but we wrap it in a Mutex
+ * to ensure synchronized access. All FFI operations acquire the lock before accessing
+ * the transaction.
+ */
+open class FfiTransaction:
+    FfiTransactionProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffitransaction(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_jj_ffi_fn_free_ffitransaction(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Abandon a commit (its children will be rebased to its parents)
+     *
+     * Descendants are rebased by `rebase_descendants()` or when the
+     * transaction is committed.
+     */
+open func abandonCommit(commitId: FfiCommitId)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_abandon_commit(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+}
+}
+    
+    /**
+     * Absorb the changes of a commit into its mutable ancestors, like
+     * `jj absorb`
+     *
+     * Each hunk moves into the mutable ancestor that last modified the
+     * surrounding lines. Without `source_commit`, the working-copy commit is
+     * used, which requires a transaction started from a workspace's
+     * repository.
+     */
+open func absorb(sourceCommit: FfiCommitId?)throws  -> FfiAbsorbResult {
+    return try  FfiConverterTypeFfiAbsorbResult.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_absorb(self.uniffiClonePointer(),
+        FfiConverterOptionTypeFfiCommitId.lower(sourceCommit),$0
+    )
+})
+}
+    
+    /**
+     * Add trailers to the description of an existing commit
+     *
+     * Trailers already present in the description are skipped. See
+     * `add_description_trailers()`.
+     */
+open func addTrailers(commitId: FfiCommitId, trailers: [FfiTrailer])throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_add_trailers(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterSequenceTypeFfiTrailer.lower(trailers),$0
+    )
+})
+}
+    
+    /**
+     * Get the repository at the operation the transaction was started from
+     *
+     * Remains available after the transaction is committed or discarded.
+     */
+open func baseRepo() -> FfiReadonlyRepo {
+    return try!  FfiConverterTypeFfiReadonlyRepo.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffitransaction_base_repo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * List the local bookmarks in the in-progress state of the transaction
+     */
+open func bookmarks()throws  -> [FfiBookmark] {
+    return try  FfiConverterSequenceTypeFfiBookmark.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_bookmarks(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Commit the transaction and return the updated repository along with
+     * the commits rewritten in it
+     *
+     * Descendants of rewritten or abandoned commits are rebased first. In a
+     * colocated workspace, Git HEAD and refs are then updated like the `jj`
+     * CLI does. Files on disk are left unchanged; use
+     * `FfiWorkspace::finish_transaction()` to also update the working copy.
+     */
+open func commit(description: String)throws  -> FfiTransactionResult {
+    return try  FfiConverterTypeFfiTransactionResult.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_commit(self.uniffiClonePointer(),
+        FfiConverterString.lower(description),$0
+    )
+})
+}
+    
+    /**
+     * Commit the snapshotted working-copy changes, like `jj commit`
+     *
+     * The working-copy commit, with the tree recorded by the latest
+     * `FfiWorkspace::snapshot()`, gets the description, and a new empty
+     * working-copy commit is created on top of it. Returns the committed
+     * commit. The files on disk are unchanged, so snapshot the workspace
+     * before starting the transaction to include the latest edits.
+     *
+     * Only available in transactions started from a workspace's repository.
+     */
+open func commitWorkingCopy(description: String)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_commit_working_copy(self.uniffiClonePointer(),
+        FfiConverterString.lower(description),$0
+    )
+})
+}
+    
+    /**
+     * Create a new commit with the same tree as a parent commit
+     */
+open func createCommitFromParent(parentId: FfiCommitId, description: String)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_create_commit_from_parent(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(parentId),
+        FfiConverterString.lower(description),$0
+    )
+})
+}
+    
+    /**
+     * Create a new commit with an empty tree (for creating empty commits)
+     */
+open func createEmptyCommit(newCommit: FfiNewCommit)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_create_empty_commit(self.uniffiClonePointer(),
+        FfiConverterTypeFfiNewCommit.lower(newCommit),$0
+    )
+})
+}
+    
+    /**
+     * Discard the transaction without committing
+     */
+open func discard()throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_discard(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Evaluate a revset expression against the in-progress state of the transaction
+     */
+open func evaluateRevset(revsetStr: String, userEmail: String)throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Evaluate a revset expression against the in-progress state of the
+     * transaction and return the change IDs of matching commits, without
+     * duplicates, in revset order
+     */
+open func evaluateRevsetToChangeIds(revsetStr: String, userEmail: String)throws  -> [FfiChangeId] {
+    return try  FfiConverterSequenceTypeFfiChangeId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_to_change_ids(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Same as `evaluate_revset_to_change_ids`, with options like a cancellation token
+     */
+open func evaluateRevsetToChangeIdsWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions)throws  -> [FfiChangeId] {
+    return try  FfiConverterSequenceTypeFfiChangeId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_to_change_ids_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiRevsetOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Evaluate a revset expression against the in-progress state of the
+     * transaction and return matching commits
+     */
+open func evaluateRevsetToCommits(revsetStr: String, userEmail: String)throws  -> [FfiCommit] {
+    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_to_commits(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Same as `evaluate_revset_to_commits`, with options like a cancellation token
+     */
+open func evaluateRevsetToCommitsWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions)throws  -> [FfiCommit] {
+    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_to_commits_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiRevsetOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Same as `evaluate_revset`, with options like a cancellation token
+     */
+open func evaluateRevsetWithOptions(revsetStr: String, userEmail: String, options: FfiRevsetOptions)throws  -> [FfiCommitId] {
+    return try  FfiConverterSequenceTypeFfiCommitId.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_evaluate_revset_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(revsetStr),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiRevsetOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Get a commit by its commit ID, including commits created in this transaction
+     */
+open func getCommit(commitId: FfiCommitId)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_get_commit(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Check if the transaction has any uncommitted changes
+     */
+open func hasChanges()throws  -> Bool {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_has_changes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Create commits from `git format-patch` style text
+     *
+     * Each patch in the text becomes a new commit, stacked in order on top of
+     * `destination`. Renames and copies are applied, and commits keep the
+     * change ID of the patch's `Change-Id` trailer, so importing a patch into
+     * a repository that still has the change makes the change divergent.
+     * Returns the created commits.
+     */
+open func importPatch(patch: Data, destination: FfiCommitId)throws  -> [FfiCommit] {
+    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_import_patch(self.uniffiClonePointer(),
+        FfiConverterData.lower(patch),
+        FfiConverterTypeFfiCommitId.lower(destination),$0
+    )
+})
+}
+    
+    /**
+     * Create a merge commit whose tree merges the parents' trees, like `jj new a b`
+     *
+     * Conflicting changes in the parents result in a conflicted tree.
+     */
+open func merge(parentIds: [FfiCommitId], description: String)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_merge(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFfiCommitId.lower(parentIds),
+        FfiConverterString.lower(description),$0
+    )
+})
+}
+    
+    /**
+     * Start building a new commit on the given parents
+     *
+     * Unless a tree is set, the commit gets the merged tree of its parents,
+     * like `jj new`.
+     */
+open func newCommitBuilder(parentIds: [FfiCommitId]) -> FfiCommitBuilder {
+    return try!  FfiConverterTypeFfiCommitBuilder.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffitransaction_new_commit_builder(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFfiCommitId.lower(parentIds),$0
+    )
+})
+}
+    
+    /**
+     * Make commits siblings of each other, like `jj parallelize`
+     *
+     * Typically the commits are a linear run, which becomes a set of
+     * siblings on the run's parent. Children of the run are rebased onto all
+     * of the siblings. Returns the rewritten commits, children first.
+     */
+open func parallelize(commitIds: [FfiCommitId])throws  -> [FfiCommit] {
+    return try  FfiConverterSequenceTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_parallelize(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFfiCommitId.lower(commitIds),$0
+    )
+})
+}
+    
+    /**
+     * Rebase commits onto new parents
+     *
+     * Each source commit is moved together with its descendants, like
+     * `jj rebase -s`. Descendants of rewritten commits elsewhere in the graph
+     * are rebased as well. See `rebase_commits()` for the other modes.
+     */
+open func rebase(sourceCommitIds: [FfiCommitId], newParentIds: [FfiCommitId])throws  -> FfiRebaseStats {
+    return try  FfiConverterTypeFfiRebaseStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_rebase(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFfiCommitId.lower(sourceCommitIds),
+        FfiConverterSequenceTypeFfiCommitId.lower(newParentIds),$0
+    )
+})
+}
+    
+    /**
+     * Rebase commits, a subtree or a branch onto new parents or between
+     * existing commits, like `jj rebase` with `-r`, `-s` or `-b` and `-d`,
+     * `--insert-after` or `--insert-before`
+     *
+     * Descendants of rewritten commits elsewhere in the graph are rebased as
+     * well.
+     */
+open func rebaseCommits(target: FfiRebaseTarget, destination: FfiRebaseDestination)throws  -> FfiRebaseStats {
+    return try  FfiConverterTypeFfiRebaseStats.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_rebase_commits(self.uniffiClonePointer(),
+        FfiConverterTypeFfiRebaseTarget.lower(target),
+        FfiConverterTypeFfiRebaseDestination.lower(destination),$0
+    )
+})
+}
+    
+    /**
+     * Rebase the descendants of commits rewritten or abandoned in this transaction
+     *
+     * Returns the number of rebased commits, e.g. to report "3 descendant
+     * commits rebased". Committing the transaction rebases any remaining
+     * descendants, but calling this first also makes the rebased commits
+     * visible to queries within the transaction.
+     */
+open func rebaseDescendants()throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_rebase_descendants(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Resolve a conflicted file in a commit with the given content
+     *
+     * Rewrites the commit and rebases its descendants. Returns the new commit.
+     */
+open func resolveConflict(commitId: FfiCommitId, path: String, content: Data)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_resolve_conflict(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(path),
+        FfiConverterData.lower(content),$0
+    )
+})
+}
+    
+    /**
+     * Resolve a conflicted file with the result of an external merge tool
+     *
+     * `output_path` is the result file, usually the `output` path passed to
+     * `FfiReadonlyRepo::write_merge_tool_files()`. Conflict markers left in
+     * the result keep those hunks conflicted. Returns the rewritten commit.
+     */
+open func resolveConflictFromMergeTool(commitId: FfiCommitId, path: String, outputPath: String)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_resolve_conflict_from_merge_tool(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(outputPath),$0
+    )
+})
+}
+    
+    /**
+     * Rewrite an existing commit with new properties
+     *
+     * Descendants are rebased onto the new commit by `rebase_descendants()`
+     * or when the transaction is committed.
+     */
+open func rewriteCommit(rewrite: FfiRewriteCommit)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_rewrite_commit(self.uniffiClonePointer(),
+        FfiConverterTypeFfiRewriteCommit.lower(rewrite),$0
+    )
+})
+}
+    
+    /**
+     * Start building a rewrite of an existing commit
+     *
+     * The rewritten commit keeps the properties that aren't set on the
+     * builder, except for the committer. The root commit can't be rewritten.
+     */
+open func rewriteCommitBuilder(commitId: FfiCommitId)throws  -> FfiCommitBuilder {
+    return try  FfiConverterTypeFfiCommitBuilder.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_rewrite_commit_builder(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Record a client-supplied idempotency key in the operation metadata
+     *
+     * Before retrying a transaction whose outcome is unknown, check
+     * `FfiReadonlyRepo::find_operation_by_idempotency_key()` to avoid
+     * applying the same changes twice.
+     */
+open func setIdempotencyKey(key: String)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_set_idempotency_key(self.uniffiClonePointer(),
+        FfiConverterString.lower(key),$0
+    )
+}
+}
+    
+    /**
+     * Set a tag in the operation metadata
+     *
+     * Tags are returned in `FfiOperation::tags` and can be used to tell
+     * operations made by the app from those made by the `jj` CLI.
+     */
+open func setTag(key: String, value: String)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_set_tag(self.uniffiClonePointer(),
+        FfiConverterString.lower(key),
+        FfiConverterString.lower(value),$0
+    )
+}
+}
+    
+    /**
+     * Split a commit by paths, like `jj split <paths>`
+     *
+     * The first commit contains the changes to the given paths (files or
+     * directories) and the second one, on top of it, the remaining changes.
+     * At least one path is required.
+     */
+open func split(commitId: FfiCommitId, paths: [String])throws  -> FfiSplitResult {
+    return try  FfiConverterTypeFfiSplitResult.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_split(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterSequenceString.lower(paths),$0
+    )
+})
+}
+    
+    /**
+     * Squash a commit into its parent, like `jj squash -r`
+     *
+     * Returns the rewritten parent.
+     */
+open func squash(commitId: FfiCommitId, options: FfiSquashOptions)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_squash(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterTypeFfiSquashOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Squash a commit into another commit, like `jj squash --from --into`
+     *
+     * Returns the rewritten destination.
+     */
+open func squashInto(from: FfiCommitId, into: FfiCommitId, options: FfiSquashOptions)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_squash_into(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(from),
+        FfiConverterTypeFfiCommitId.lower(into),
+        FfiConverterTypeFfiSquashOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Update the description of an existing commit
+     */
+open func updateDescription(commitId: FfiCommitId, newDescription: String)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitransaction_update_description(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),
+        FfiConverterString.lower(newDescription),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTransaction: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiTransaction
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiTransaction {
+        return FfiTransaction(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiTransaction) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTransaction {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiTransaction, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTransaction_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiTransaction {
+    return try FfiConverterTypeFfiTransaction.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTransaction_lower(_ value: FfiTransaction) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiTransaction.lower(value)
+}
+
+
+
+
+/**
+ * Builds a tree from a base tree and changes to individual paths
+ *
+ * Files are written to the store as they are added. The resulting tree can
+ * be used with `FfiCommitBuilder::set_tree()` to create commits without a
+ * working copy.
+ */
+public protocol FfiTreeBuilderProtocol : AnyObject {
+    
+    /**
+     * Remove the file, symlink or conflict at a path
+     *
+     * Directories are removed with their contents.
+     */
+    func remove(path: String) throws 
+    
+    /**
+     * Set a symlink, replacing any existing entry at the path
+     */
+    func setSymlink(path: String, target: String) throws 
+    
+    /**
+     * Set the content of a file, replacing any existing entry at the path
+     */
+    func writeFile(path: String, content: Data, executable: Bool) throws 
+    
+    /**
+     * Write the tree to the store and return its hex IDs
+     *
+     * The IDs are in the form of `FfiCommit::tree_ids`. The builder can keep
+     * being used to write further trees.
+     */
+    func writeTree() throws  -> [String]
+    
+}
+
+/**
+ * Builds a tree from a base tree and changes to individual paths
+ *
+ * Files are written to the store as they are added. The resulting tree can
+ * be used with `FfiCommitBuilder::set_tree()` to create commits without a
+ * working copy.
+ */
+open class FfiTreeBuilder:
+    FfiTreeBuilderProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffitreebuilder(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_jj_ffi_fn_free_ffitreebuilder(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Remove the file, symlink or conflict at a path
+     *
+     * Directories are removed with their contents.
+     */
+open func remove(path: String)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitreebuilder_remove(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+}
+}
+    
+    /**
+     * Set a symlink, replacing any existing entry at the path
+     */
+open func setSymlink(path: String, target: String)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitreebuilder_set_symlink(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(target),$0
+    )
+}
+}
+    
+    /**
+     * Set the content of a file, replacing any existing entry at the path
+     */
+open func writeFile(path: String, content: Data, executable: Bool)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitreebuilder_write_file(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),
+        FfiConverterData.lower(content),
+        FfiConverterBool.lower(executable),$0
+    )
+}
+}
+    
+    /**
+     * Write the tree to the store and return its hex IDs
+     *
+     * The IDs are in the form of `FfiCommit::tree_ids`. The builder can keep
+     * being used to write further trees.
+     */
+open func writeTree()throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffitreebuilder_write_tree(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTreeBuilder: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiTreeBuilder
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiTreeBuilder {
+        return FfiTreeBuilder(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiTreeBuilder) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTreeBuilder {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiTreeBuilder, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTreeBuilder_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiTreeBuilder {
+    return try FfiConverterTypeFfiTreeBuilder.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTreeBuilder_lower(_ value: FfiTreeBuilder) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiTreeBuilder.lower(value)
+}
+
+
+
+
+/**
+ * A workspace exposed via FFI
+ */
+public protocol FfiWorkspaceProtocol : AnyObject {
+    
+    /**
+     * Get the conflict marker style used for materialization and working-copy writes
+     */
+    func conflictMarkerStyle()  -> FfiConflictMarkerStyle
+    
+    /**
+     * Make an existing commit the working-copy commit, like `jj edit`
+     *
+     * Pending changes on disk are snapshotted into the current working-copy
+     * commit first. Files on disk are updated to the edited commit's tree.
+     */
+    func edit(commitId: FfiCommitId) throws  -> FfiCommit
+    
+    /**
+     * Commit a transaction and update the working copy to its result
+     *
+     * Like `FfiTransaction::commit()`, then files on disk are updated to the
+     * workspace's new working-copy commit, and `repo()` returns the new
+     * repository. The transaction must be started from a repository of this
+     * workspace. Fails without committing if the working copy was updated
+     * since the transaction was started, e.g. by a snapshot, or if files on
+     * disk have changes that were not snapshotted, since checking out would
+     * overwrite them.
+     */
+    func finishTransaction(tx: FfiTransaction, description: String) throws  -> FfiTransactionResult
+    
+    /**
+     * Get the filesystem monitor used to find changed files when snapshotting
+     */
+    func fsmonitor()  -> FfiFsmonitor
+    
+    /**
+     * Abandon old operations and delete unreachable data, like `jj op abandon`
+     * followed by `jj util gc`
+     *
+     * Operations that ended before `keep_ops_newer_than` are abandoned, and
+     * the newer operations get new IDs. The latest operation is always kept.
+     * Commits and operations that are no longer reachable and older than
+     * `keep_ops_newer_than` are then deleted from the store. Returns the
+     * repository at the new latest operation.
+     */
+    func gc(keepOpsNewerThan: FfiTimestamp) throws  -> FfiReadonlyRepo
+    
+    /**
+     * Get what was reconciled with Git by the latest load, reload or
+     * snapshot
+     *
+     * Returns `None` unless the workspace is colocated and Git HEAD or refs
+     * were changed outside of jj.
+     */
+    func gitHeadSyncReport()  -> FfiGitHeadSyncReport?
+    
+    /**
+     * Check whether the workspace shares its working directory with Git
+     *
+     * In a colocated workspace, Git HEAD and refs are imported on load and
+     * exported when snapshots and transactions are committed, like the `jj`
+     * CLI does, so jj and Git stay in sync.
+     */
+    func isColocated()  -> Bool
+    
+    /**
+     * Create a new empty working-copy commit on top of the given parents, like `jj new`
+     *
+     * Pending changes on disk are snapshotted into the current working-copy
+     * commit first. Files on disk are updated to the new commit's tree.
+     */
+    func newChild(parentIds: [FfiCommitId]) throws  -> FfiCommit
+    
+    /**
+     * Get the divergent operations merged by the latest `load()`,
+     * `reload()`, `update_stale()`, `gc()` or `reindex()`
+     *
+     * Returns `None` if the repository had a single latest operation.
+     */
+    func operationMergeReport()  -> FfiOperationMergeReport?
+    
+    /**
+     * Rebuild the commit index from scratch, like `jj debug reindex`
+     *
+     * Commits imported from a shallow Git repository keep their truncated
+     * history after the repository is deepened by a fetch with a larger
+     * depth. Reindexing picks up the fetched history. Returns the repository
+     * at the latest operation.
+     */
+    func reindex() throws  -> FfiReadonlyRepo
+    
+    /**
+     * Reload the repository at the latest operation
+     *
+     * Picks up operations made since the workspace was loaded, e.g. by the
+     * `jj` CLI. Subsequent calls to `repo()` return the reloaded repository.
+     * Divergent operations are merged and, in a colocated workspace, Git
+     * changes are imported like on load.
+     */
+    func reload() throws  -> FfiReadonlyRepo
+    
+    /**
+     * Get a readonly repository handle
+     *
+     * Revsets evaluated with the handle resolve `@` to this workspace's
+     * working-copy commit.
+     */
+    func repo()  -> FfiReadonlyRepo
+    
+    /**
+     * Get a readonly repository handle at a past operation
+     *
+     * Accepts an operation ID (or unique prefix) or an operation expression
+     * such as "@-".
+     */
+    func repoAtOperation(opId: String) throws  -> FfiReadonlyRepo
+    
+    /**
+     * Get the store and working copy types used by the workspace
+     */
+    func repoFormat() throws  -> FfiRepoFormat
+    
+    /**
+     * Get the repo path
+     */
+    func repoPath()  -> String
+    
+    /**
+     * Discard the changes to paths in the working copy, like `jj restore <paths>`
+     *
+     * Paths are repository-relative files or directories in internal
+     * ("/"-separated) form. Pending changes on disk are snapshotted first,
+     * then the paths are restored to their state in the parent commit(s) in
+     * the working-copy commit and on disk. Returns the rewritten
+     * working-copy commit.
+     */
+    func restorePathsFromParent(paths: [String]) throws  -> FfiCommit
+    
+    /**
+     * Set the conflict marker style used for materialization and working-copy writes
+     *
+     * This should match the user's `ui.conflict-marker-style` so conflicted
+     * files can be parsed back. Repositories obtained from `repo()` afterwards
+     * use the new style.
+     */
+    func setConflictMarkerStyle(style: FfiConflictMarkerStyle) throws 
+    
+    /**
+     * Set the filesystem monitor used to find changed files when snapshotting
+     *
+     * Overrides the `fsmonitor.backend` setting. With Watchman, snapshots of
+     * large working copies only check the files changed since the previous
+     * snapshot. Fails if the library was built without the `watchman`
+     * feature.
+     */
+    func setFsmonitor(fsmonitor: FfiFsmonitor) throws 
+    
+    /**
+     * Snapshot the files on disk into the working-copy commit
+     *
+     * Records a new operation if files changed and returns the resulting
+     * repository. Fails with `Cancelled` if the cancellation token is
+     * cancelled before the snapshot is recorded.
+     */
+    func snapshot(cancellation: FfiCancellationToken?) throws  -> FfiReadonlyRepo
+    
+    /**
+     * Snapshot the working copy with the given options
+     *
+     * Unlike `snapshot()`, new files left untracked because they are too
+     * large or not matched by the auto-track fileset are reported in the
+     * result.
+     */
+    func snapshotWithOptions(options: FfiSnapshotOptions) throws  -> FfiSnapshotResult
+    
+    /**
+     * Start tracking paths, like `jj file track`
+     *
+     * Paths are repository-relative files or directories in internal
+     * ("/"-separated) form. Files under them are tracked even if they are
+     * ignored, too large or not matched by the auto-track fileset.
+     */
+    func track(paths: [String]) throws  -> FfiReadonlyRepo
+    
+    /**
+     * Stop tracking paths, like `jj file untrack`
+     *
+     * Paths are repository-relative files or directories in internal
+     * ("/"-separated) form. The files stay on disk and are removed from the
+     * working-copy commit. Fails if a file would be tracked again by the next
+     * snapshot, i.e. it is matched by the auto-track fileset and not ignored.
+     */
+    func untrack(paths: [String]) throws  -> FfiReadonlyRepo
+    
+    /**
+     * List new files in the working copy that a snapshot leaves untracked
+     *
+     * These are files that are too large or don't match the auto-track
+     * fileset. Ignored files are not listed. Nothing is recorded; the
+     * working copy is scanned without finishing a snapshot.
+     */
+    func untrackedFiles() throws  -> [FfiUntrackedPath]
+    
+    /**
+     * Update a stale working copy to the latest operation, like
+     * `jj workspace update-stale`
+     *
+     * The working copy becomes stale when the working-copy commit is
+     * rewritten without updating the files on disk, e.g. by
+     * `FfiReadonlyRepo::undo_latest_operation()`,
+     * `FfiReadonlyRepo::restore_to_operation()` or the `jj` CLI in another
+     * workspace. Snapshots fail until the working copy is updated.
+     *
+     * Pending changes on disk are first snapshotted at the operation the
+     * working copy was last updated at, and merged with the latest
+     * operation like the CLI does, so they are not lost. Files on disk are
+     * then updated to the latest working-copy commit. Does nothing but
+     * reload if the working copy is not stale.
+     */
+    func updateStale() throws  -> FfiReadonlyRepo
+    
+    /**
+     * Add a secondary workspace of the repository at the given path, like
+     * `jj workspace add`
+     *
+     * The new workspace's working-copy commit is created on top of the
+     * parents of this workspace's working-copy commit, and its files are
+     * written to the path. The directory is created if it doesn't exist.
+     */
+    func workspaceAdd(path: String, name: String) throws  -> FfiWorkspace
+    
+    /**
+     * Stop tracking a workspace's working-copy commit, like `jj workspace forget`
+     *
+     * The workspace's files on disk are left untouched. This workspace can't
+     * forget itself; forget it from another workspace instead. Returns the
+     * updated repository.
+     */
+    func workspaceForget(name: String) throws  -> FfiReadonlyRepo
+    
+    /**
+     * List the workspaces of the repository, like `jj workspace list`
+     */
+    func workspaceList()  -> [FfiWorkspaceInfo]
+    
+    /**
+     * Get the name of the workspace
+     */
+    func workspaceName()  -> String
+    
+    /**
+     * Get the workspace root path
+     */
+    func workspaceRoot()  -> String
+    
+}
+
+/**
+ * A workspace exposed via FFI
+ */
+open class FfiWorkspace:
+    FfiWorkspaceProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_jj_ffi_fn_clone_ffiworkspace(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_jj_ffi_fn_free_ffiworkspace(pointer, $0) }
+    }
+
+    
+    /**
+     * Load an existing workspace from the given path
+     *
+     * Fails with `IncompatibleRepository` if the repository uses a format
+     * this library doesn't support.
+     *
+     * Divergent operations, e.g. from the app and the `jj` CLI running
+     * concurrently, are merged like the CLI does. See
+     * `operation_merge_report()`.
+     *
+     * In a colocated workspace, Git HEAD moves made by external tools are
+     * imported on load, reload and snapshot. See `git_head_sync_report()`.
+     */
+public static func load(workspacePath: String, userName: String, userEmail: String)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_constructor_ffiworkspace_load(
+        FfiConverterString.lower(workspacePath),
+        FfiConverterString.lower(userName),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+    
+    /**
+     * Load an existing workspace with settings from the user's jj config
+     *
+     * The config files are found like the `jj` CLI does: `$JJ_CONFIG`, or
+     * the user config file and `conf.d` directory, followed by the repo's
+     * config. Unlike `load()`, this applies every setting, such as the
+     * user identity, signing and Git settings.
+     */
+public static func loadWithUserConfig(workspacePath: String)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_constructor_ffiworkspace_load_with_user_config(
+        FfiConverterString.lower(workspacePath),$0
+    )
+})
+}
+    
+
+    
+    /**
+     * Get the conflict marker style used for materialization and working-copy writes
+     */
+open func conflictMarkerStyle() -> FfiConflictMarkerStyle {
+    return try!  FfiConverterTypeFfiConflictMarkerStyle.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_conflict_marker_style(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Make an existing commit the working-copy commit, like `jj edit`
+     *
+     * Pending changes on disk are snapshotted into the current working-copy
+     * commit first. Files on disk are updated to the edited commit's tree.
+     */
+open func edit(commitId: FfiCommitId)throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_edit(self.uniffiClonePointer(),
+        FfiConverterTypeFfiCommitId.lower(commitId),$0
+    )
+})
+}
+    
+    /**
+     * Commit a transaction and update the working copy to its result
+     *
+     * Like `FfiTransaction::commit()`, then files on disk are updated to the
+     * workspace's new working-copy commit, and `repo()` returns the new
+     * repository. The transaction must be started from a repository of this
+     * workspace. Fails without committing if the working copy was updated
+     * since the transaction was started, e.g. by a snapshot, or if files on
+     * disk have changes that were not snapshotted, since checking out would
+     * overwrite them.
+     */
+open func finishTransaction(tx: FfiTransaction, description: String)throws  -> FfiTransactionResult {
+    return try  FfiConverterTypeFfiTransactionResult.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_finish_transaction(self.uniffiClonePointer(),
+        FfiConverterTypeFfiTransaction.lower(tx),
+        FfiConverterString.lower(description),$0
+    )
+})
+}
+    
+    /**
+     * Get the filesystem monitor used to find changed files when snapshotting
+     */
+open func fsmonitor() -> FfiFsmonitor {
+    return try!  FfiConverterTypeFfiFsmonitor.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_fsmonitor(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Abandon old operations and delete unreachable data, like `jj op abandon`
+     * followed by `jj util gc`
+     *
+     * Operations that ended before `keep_ops_newer_than` are abandoned, and
+     * the newer operations get new IDs. The latest operation is always kept.
+     * Commits and operations that are no longer reachable and older than
+     * `keep_ops_newer_than` are then deleted from the store. Returns the
+     * repository at the new latest operation.
+     */
+open func gc(keepOpsNewerThan: FfiTimestamp)throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_gc(self.uniffiClonePointer(),
+        FfiConverterTypeFfiTimestamp.lower(keepOpsNewerThan),$0
+    )
+})
+}
+    
+    /**
+     * Get what was reconciled with Git by the latest load, reload or
+     * snapshot
+     *
+     * Returns `None` unless the workspace is colocated and Git HEAD or refs
+     * were changed outside of jj.
+     */
+open func gitHeadSyncReport() -> FfiGitHeadSyncReport? {
+    return try!  FfiConverterOptionTypeFfiGitHeadSyncReport.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_git_head_sync_report(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Check whether the workspace shares its working directory with Git
+     *
+     * In a colocated workspace, Git HEAD and refs are imported on load and
+     * exported when snapshots and transactions are committed, like the `jj`
+     * CLI does, so jj and Git stay in sync.
+     */
+open func isColocated() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_is_colocated(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Create a new empty working-copy commit on top of the given parents, like `jj new`
+     *
+     * Pending changes on disk are snapshotted into the current working-copy
+     * commit first. Files on disk are updated to the new commit's tree.
+     */
+open func newChild(parentIds: [FfiCommitId])throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_new_child(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFfiCommitId.lower(parentIds),$0
+    )
+})
+}
+    
+    /**
+     * Get the divergent operations merged by the latest `load()`,
+     * `reload()`, `update_stale()`, `gc()` or `reindex()`
+     *
+     * Returns `None` if the repository had a single latest operation.
+     */
+open func operationMergeReport() -> FfiOperationMergeReport? {
+    return try!  FfiConverterOptionTypeFfiOperationMergeReport.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_operation_merge_report(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Rebuild the commit index from scratch, like `jj debug reindex`
+     *
+     * Commits imported from a shallow Git repository keep their truncated
+     * history after the repository is deepened by a fetch with a larger
+     * depth. Reindexing picks up the fetched history. Returns the repository
+     * at the latest operation.
+     */
+open func reindex()throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_reindex(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Reload the repository at the latest operation
+     *
+     * Picks up operations made since the workspace was loaded, e.g. by the
+     * `jj` CLI. Subsequent calls to `repo()` return the reloaded repository.
+     * Divergent operations are merged and, in a colocated workspace, Git
+     * changes are imported like on load.
+     */
+open func reload()throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_reload(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get a readonly repository handle
+     *
+     * Revsets evaluated with the handle resolve `@` to this workspace's
+     * working-copy commit.
+     */
+open func repo() -> FfiReadonlyRepo {
+    return try!  FfiConverterTypeFfiReadonlyRepo.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_repo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get a readonly repository handle at a past operation
+     *
+     * Accepts an operation ID (or unique prefix) or an operation expression
+     * such as "@-".
+     */
+open func repoAtOperation(opId: String)throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_repo_at_operation(self.uniffiClonePointer(),
+        FfiConverterString.lower(opId),$0
+    )
+})
+}
+    
+    /**
+     * Get the store and working copy types used by the workspace
+     */
+open func repoFormat()throws  -> FfiRepoFormat {
+    return try  FfiConverterTypeFfiRepoFormat.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_repo_format(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the repo path
+     */
+open func repoPath() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_repo_path(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Discard the changes to paths in the working copy, like `jj restore <paths>`
+     *
+     * Paths are repository-relative files or directories in internal
+     * ("/"-separated) form. Pending changes on disk are snapshotted first,
+     * then the paths are restored to their state in the parent commit(s) in
+     * the working-copy commit and on disk. Returns the rewritten
+     * working-copy commit.
+     */
+open func restorePathsFromParent(paths: [String])throws  -> FfiCommit {
+    return try  FfiConverterTypeFfiCommit.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_restore_paths_from_parent(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(paths),$0
+    )
+})
+}
+    
+    /**
+     * Set the conflict marker style used for materialization and working-copy writes
+     *
+     * This should match the user's `ui.conflict-marker-style` so conflicted
+     * files can be parsed back. Repositories obtained from `repo()` afterwards
+     * use the new style.
+     */
+open func setConflictMarkerStyle(style: FfiConflictMarkerStyle)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_set_conflict_marker_style(self.uniffiClonePointer(),
+        FfiConverterTypeFfiConflictMarkerStyle.lower(style),$0
+    )
+}
+}
+    
+    /**
+     * Set the filesystem monitor used to find changed files when snapshotting
+     *
+     * Overrides the `fsmonitor.backend` setting. With Watchman, snapshots of
+     * large working copies only check the files changed since the previous
+     * snapshot. Fails if the library was built without the `watchman`
+     * feature.
+     */
+open func setFsmonitor(fsmonitor: FfiFsmonitor)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_set_fsmonitor(self.uniffiClonePointer(),
+        FfiConverterTypeFfiFsmonitor.lower(fsmonitor),$0
+    )
+}
+}
+    
+    /**
+     * Snapshot the files on disk into the working-copy commit
+     *
+     * Records a new operation if files changed and returns the resulting
+     * repository. Fails with `Cancelled` if the cancellation token is
+     * cancelled before the snapshot is recorded.
+     */
+open func snapshot(cancellation: FfiCancellationToken?)throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_snapshot(self.uniffiClonePointer(),
+        FfiConverterOptionTypeFfiCancellationToken.lower(cancellation),$0
+    )
+})
+}
+    
+    /**
+     * Snapshot the working copy with the given options
+     *
+     * Unlike `snapshot()`, new files left untracked because they are too
+     * large or not matched by the auto-track fileset are reported in the
+     * result.
+     */
+open func snapshotWithOptions(options: FfiSnapshotOptions)throws  -> FfiSnapshotResult {
+    return try  FfiConverterTypeFfiSnapshotResult.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_snapshot_with_options(self.uniffiClonePointer(),
+        FfiConverterTypeFfiSnapshotOptions.lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Start tracking paths, like `jj file track`
+     *
+     * Paths are repository-relative files or directories in internal
+     * ("/"-separated) form. Files under them are tracked even if they are
+     * ignored, too large or not matched by the auto-track fileset.
+     */
+open func track(paths: [String])throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_track(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(paths),$0
+    )
+})
+}
+    
+    /**
+     * Stop tracking paths, like `jj file untrack`
+     *
+     * Paths are repository-relative files or directories in internal
+     * ("/"-separated) form. The files stay on disk and are removed from the
+     * working-copy commit. Fails if a file would be tracked again by the next
+     * snapshot, i.e. it is matched by the auto-track fileset and not ignored.
+     */
+open func untrack(paths: [String])throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_untrack(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(paths),$0
+    )
+})
+}
+    
+    /**
+     * List new files in the working copy that a snapshot leaves untracked
+     *
+     * These are files that are too large or don't match the auto-track
+     * fileset. Ignored files are not listed. Nothing is recorded; the
+     * working copy is scanned without finishing a snapshot.
+     */
+open func untrackedFiles()throws  -> [FfiUntrackedPath] {
+    return try  FfiConverterSequenceTypeFfiUntrackedPath.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_untracked_files(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Update a stale working copy to the latest operation, like
+     * `jj workspace update-stale`
+     *
+     * The working copy becomes stale when the working-copy commit is
+     * rewritten without updating the files on disk, e.g. by
+     * `FfiReadonlyRepo::undo_latest_operation()`,
+     * `FfiReadonlyRepo::restore_to_operation()` or the `jj` CLI in another
+     * workspace. Snapshots fail until the working copy is updated.
+     *
+     * Pending changes on disk are first snapshotted at the operation the
+     * working copy was last updated at, and merged with the latest
+     * operation like the CLI does, so they are not lost. Files on disk are
+     * then updated to the latest working-copy commit. Does nothing but
+     * reload if the working copy is not stale.
+     */
+open func updateStale()throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_update_stale(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Add a secondary workspace of the repository at the given path, like
+     * `jj workspace add`
+     *
+     * The new workspace's working-copy commit is created on top of the
+     * parents of this workspace's working-copy commit, and its files are
+     * written to the path. The directory is created if it doesn't exist.
+     */
+open func workspaceAdd(path: String, name: String)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_workspace_add(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(name),$0
+    )
+})
+}
+    
+    /**
+     * Stop tracking a workspace's working-copy commit, like `jj workspace forget`
+     *
+     * The workspace's files on disk are left untouched. This workspace can't
+     * forget itself; forget it from another workspace instead. Returns the
+     * updated repository.
+     */
+open func workspaceForget(name: String)throws  -> FfiReadonlyRepo {
+    return try  FfiConverterTypeFfiReadonlyRepo.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_method_ffiworkspace_workspace_forget(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),$0
+    )
+})
+}
+    
+    /**
+     * List the workspaces of the repository, like `jj workspace list`
+     */
+open func workspaceList() -> [FfiWorkspaceInfo] {
+    return try!  FfiConverterSequenceTypeFfiWorkspaceInfo.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_workspace_list(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the name of the workspace
+     */
+open func workspaceName() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_workspace_name(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the workspace root path
+     */
+open func workspaceRoot() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_method_ffiworkspace_workspace_root(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiWorkspace: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FfiWorkspace
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiWorkspace {
+        return FfiWorkspace(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FfiWorkspace) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiWorkspace {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FfiWorkspace, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiWorkspace_lift(_ pointer: UnsafeMutableRawPointer) throws -> FfiWorkspace {
+    return try FfiConverterTypeFfiWorkspace.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiWorkspace_lower(_ value: FfiWorkspace) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFfiWorkspace.lower(value)
+}
+
+
+/**
+ * The commits rewritten by an absorb
+ */
+public struct FfiAbsorbResult {
+    /**
+     * The source commit with the absorbed changes removed, or `None` if it
+     * was abandoned or nothing was absorbed
+     */
+    public var rewrittenSource: FfiCommit?
+    /**
+     * Commits the changes were absorbed into, parents first
+     */
+    public var rewrittenDestinations: [FfiCommit]
+    /**
+     * Number of other descendants that were rebased
+     */
+    public var numRebased: UInt32
+    /**
+     * Paths whose changes were left in the source commit
+     */
+    public var skippedPaths: [FfiAbsorbSkippedPath]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The source commit with the absorbed changes removed, or `None` if it
+         * was abandoned or nothing was absorbed
+         */rewrittenSource: FfiCommit?, 
+        /**
+         * Commits the changes were absorbed into, parents first
+         */rewrittenDestinations: [FfiCommit], 
+        /**
+         * Number of other descendants that were rebased
+         */numRebased: UInt32, 
+        /**
+         * Paths whose changes were left in the source commit
+         */skippedPaths: [FfiAbsorbSkippedPath]) {
+        self.rewrittenSource = rewrittenSource
+        self.rewrittenDestinations = rewrittenDestinations
+        self.numRebased = numRebased
+        self.skippedPaths = skippedPaths
+    }
+}
+
+
+
+extension FfiAbsorbResult: Equatable, Hashable {
+    public static func ==(lhs: FfiAbsorbResult, rhs: FfiAbsorbResult) -> Bool {
+        if lhs.rewrittenSource != rhs.rewrittenSource {
+            return false
+        }
+        if lhs.rewrittenDestinations != rhs.rewrittenDestinations {
+            return false
+        }
+        if lhs.numRebased != rhs.numRebased {
+            return false
+        }
+        if lhs.skippedPaths != rhs.skippedPaths {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(rewrittenSource)
+        hasher.combine(rewrittenDestinations)
+        hasher.combine(numRebased)
+        hasher.combine(skippedPaths)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiAbsorbResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiAbsorbResult {
+        return
+            try FfiAbsorbResult(
+                rewrittenSource: FfiConverterOptionTypeFfiCommit.read(from: &buf), 
+                rewrittenDestinations: FfiConverterSequenceTypeFfiCommit.read(from: &buf), 
+                numRebased: FfiConverterUInt32.read(from: &buf), 
+                skippedPaths: FfiConverterSequenceTypeFfiAbsorbSkippedPath.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiAbsorbResult, into buf: inout [UInt8]) {
+        FfiConverterOptionTypeFfiCommit.write(value.rewrittenSource, into: &buf)
+        FfiConverterSequenceTypeFfiCommit.write(value.rewrittenDestinations, into: &buf)
+        FfiConverterUInt32.write(value.numRebased, into: &buf)
+        FfiConverterSequenceTypeFfiAbsorbSkippedPath.write(value.skippedPaths, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiAbsorbResult_lift(_ buf: RustBuffer) throws -> FfiAbsorbResult {
+    return try FfiConverterTypeFfiAbsorbResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiAbsorbResult_lower(_ value: FfiAbsorbResult) -> RustBuffer {
+    return FfiConverterTypeFfiAbsorbResult.lower(value)
+}
+
+
+/**
+ * A path whose changes could not be absorbed
+ */
+public struct FfiAbsorbSkippedPath {
+    /**
+     * Repository-relative path in internal ("/"-separated) form
+     */
+    public var path: String
+    /**
+     * Why the changes were skipped
+     */
+    public var reason: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Repository-relative path in internal ("/"-separated) form
+         */path: String, 
+        /**
+         * Why the changes were skipped
+         */reason: String) {
+        self.path = path
+        self.reason = reason
+    }
+}
+
+
+
+extension FfiAbsorbSkippedPath: Equatable, Hashable {
+    public static func ==(lhs: FfiAbsorbSkippedPath, rhs: FfiAbsorbSkippedPath) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.reason != rhs.reason {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(reason)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiAbsorbSkippedPath: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiAbsorbSkippedPath {
+        return
+            try FfiAbsorbSkippedPath(
+                path: FfiConverterString.read(from: &buf), 
+                reason: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiAbsorbSkippedPath, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterString.write(value.reason, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiAbsorbSkippedPath_lift(_ buf: RustBuffer) throws -> FfiAbsorbSkippedPath {
+    return try FfiConverterTypeFfiAbsorbSkippedPath.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiAbsorbSkippedPath_lower(_ value: FfiAbsorbSkippedPath) -> RustBuffer {
+    return FfiConverterTypeFfiAbsorbSkippedPath.lower(value)
+}
+
+
+/**
+ * Number of commits in an activity histogram bucket
+ */
+public struct FfiActivityCount {
+    /**
+     * First day of the bucket, as "YYYY-MM-DD"
+     */
+    public var startDate: String
+    /**
+     * Number of commits authored within the bucket
+     */
+    public var count: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * First day of the bucket, as "YYYY-MM-DD"
+         */startDate: String, 
+        /**
+         * Number of commits authored within the bucket
+         */count: UInt64) {
+        self.startDate = startDate
+        self.count = count
+    }
+}
+
+
+
+extension FfiActivityCount: Equatable, Hashable {
+    public static func ==(lhs: FfiActivityCount, rhs: FfiActivityCount) -> Bool {
+        if lhs.startDate != rhs.startDate {
+            return false
+        }
+        if lhs.count != rhs.count {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(startDate)
+        hasher.combine(count)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiActivityCount: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiActivityCount {
+        return
+            try FfiActivityCount(
+                startDate: FfiConverterString.read(from: &buf), 
+                count: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiActivityCount, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.startDate, into: &buf)
+        FfiConverterUInt64.write(value.count, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiActivityCount_lift(_ buf: RustBuffer) throws -> FfiActivityCount {
+    return try FfiConverterTypeFfiActivityCount.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiActivityCount_lower(_ value: FfiActivityCount) -> RustBuffer {
+    return FfiConverterTypeFfiActivityCount.lower(value)
+}
+
+
+/**
+ * A local bookmark in the repository view
+ */
+public struct FfiBookmark {
+    /**
+     * Bookmark name
+     */
+    public var name: String
+    /**
+     * Target commit (`None` if the bookmark is conflicted)
+     */
+    public var target: FfiCommitId?
+    /**
+     * Whether the bookmark points to more than one commit
+     */
+    public var isConflicted: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Bookmark name
+         */name: String, 
+        /**
+         * Target commit (`None` if the bookmark is conflicted)
+         */target: FfiCommitId?, 
+        /**
+         * Whether the bookmark points to more than one commit
+         */isConflicted: Bool) {
+        self.name = name
+        self.target = target
+        self.isConflicted = isConflicted
+    }
+}
+
+
+
+extension FfiBookmark: Equatable, Hashable {
+    public static func ==(lhs: FfiBookmark, rhs: FfiBookmark) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.target != rhs.target {
+            return false
+        }
+        if lhs.isConflicted != rhs.isConflicted {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(target)
+        hasher.combine(isConflicted)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiBookmark: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiBookmark {
+        return
+            try FfiBookmark(
+                name: FfiConverterString.read(from: &buf), 
+                target: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                isConflicted: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiBookmark, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.target, into: &buf)
+        FfiConverterBool.write(value.isConflicted, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiBookmark_lift(_ buf: RustBuffer) throws -> FfiBookmark {
+    return try FfiConverterTypeFfiBookmark.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiBookmark_lower(_ value: FfiBookmark) -> RustBuffer {
+    return FfiConverterTypeFfiBookmark.lower(value)
+}
+
+
+/**
+ * A change to a local or remote bookmark between two operations
+ */
+public struct FfiBookmarkChange {
+    /**
+     * The changed bookmark
+     */
+    public var bookmark: FfiBookmarkRef
+    /**
+     * Kind of change
+     */
+    public var kind: FfiBookmarkChangeKind
+    /**
+     * Target before the change (`None` if absent or conflicted)
+     */
+    public var oldTarget: FfiCommitId?
+    /**
+     * Target after the change (`None` if absent or conflicted)
+     */
+    public var newTarget: FfiCommitId?
+    /**
+     * Number of commits reachable from the new target but not the old one
+     * (zero unless the bookmark was moved)
+     */
+    public var commitsAdded: UInt64
+    /**
+     * Number of commits reachable from the old target but not the new one
+     * (zero unless the bookmark was moved)
+     */
+    public var commitsRemoved: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The changed bookmark
+         */bookmark: FfiBookmarkRef, 
+        /**
+         * Kind of change
+         */kind: FfiBookmarkChangeKind, 
+        /**
+         * Target before the change (`None` if absent or conflicted)
+         */oldTarget: FfiCommitId?, 
+        /**
+         * Target after the change (`None` if absent or conflicted)
+         */newTarget: FfiCommitId?, 
+        /**
+         * Number of commits reachable from the new target but not the old one
+         * (zero unless the bookmark was moved)
+         */commitsAdded: UInt64, 
+        /**
+         * Number of commits reachable from the old target but not the new one
+         * (zero unless the bookmark was moved)
+         */commitsRemoved: UInt64) {
+        self.bookmark = bookmark
+        self.kind = kind
+        self.oldTarget = oldTarget
+        self.newTarget = newTarget
+        self.commitsAdded = commitsAdded
+        self.commitsRemoved = commitsRemoved
+    }
+}
+
+
+
+extension FfiBookmarkChange: Equatable, Hashable {
+    public static func ==(lhs: FfiBookmarkChange, rhs: FfiBookmarkChange) -> Bool {
+        if lhs.bookmark != rhs.bookmark {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.oldTarget != rhs.oldTarget {
+            return false
+        }
+        if lhs.newTarget != rhs.newTarget {
+            return false
+        }
+        if lhs.commitsAdded != rhs.commitsAdded {
+            return false
+        }
+        if lhs.commitsRemoved != rhs.commitsRemoved {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(bookmark)
+        hasher.combine(kind)
+        hasher.combine(oldTarget)
+        hasher.combine(newTarget)
+        hasher.combine(commitsAdded)
+        hasher.combine(commitsRemoved)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiBookmarkChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiBookmarkChange {
+        return
+            try FfiBookmarkChange(
+                bookmark: FfiConverterTypeFfiBookmarkRef.read(from: &buf), 
+                kind: FfiConverterTypeFfiBookmarkChangeKind.read(from: &buf), 
+                oldTarget: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                newTarget: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                commitsAdded: FfiConverterUInt64.read(from: &buf), 
+                commitsRemoved: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiBookmarkChange, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiBookmarkRef.write(value.bookmark, into: &buf)
+        FfiConverterTypeFfiBookmarkChangeKind.write(value.kind, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.oldTarget, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.newTarget, into: &buf)
+        FfiConverterUInt64.write(value.commitsAdded, into: &buf)
+        FfiConverterUInt64.write(value.commitsRemoved, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiBookmarkChange_lift(_ buf: RustBuffer) throws -> FfiBookmarkChange {
+    return try FfiConverterTypeFfiBookmarkChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiBookmarkChange_lower(_ value: FfiBookmarkChange) -> RustBuffer {
+    return FfiConverterTypeFfiBookmarkChange.lower(value)
+}
+
+
+/**
+ * A reference to a local or remote bookmark
+ */
+public struct FfiBookmarkRef {
+    /**
+     * Bookmark name
+     */
+    public var name: String
+    /**
+     * Remote name (`None` for a local bookmark)
+     */
+    public var remote: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Bookmark name
+         */name: String, 
+        /**
+         * Remote name (`None` for a local bookmark)
+         */remote: String?) {
+        self.name = name
+        self.remote = remote
+    }
+}
+
+
+
+extension FfiBookmarkRef: Equatable, Hashable {
+    public static func ==(lhs: FfiBookmarkRef, rhs: FfiBookmarkRef) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.remote != rhs.remote {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(remote)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiBookmarkRef: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiBookmarkRef {
+        return
+            try FfiBookmarkRef(
+                name: FfiConverterString.read(from: &buf), 
+                remote: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiBookmarkRef, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.remote, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiBookmarkRef_lift(_ buf: RustBuffer) throws -> FfiBookmarkRef {
+    return try FfiConverterTypeFfiBookmarkRef.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiBookmarkRef_lower(_ value: FfiBookmarkRef) -> RustBuffer {
+    return FfiConverterTypeFfiBookmarkRef.lower(value)
+}
+
+
+/**
+ * A change ID represented as a reverse-hex string for FFI
+ */
+public struct FfiChangeId {
+    /**
+     * Reverse-hex encoded change ID (z-k digits)
+     */
+    public var hex: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Reverse-hex encoded change ID (z-k digits)
+         */hex: String) {
+        self.hex = hex
+    }
+}
+
+
+
+extension FfiChangeId: Equatable, Hashable {
+    public static func ==(lhs: FfiChangeId, rhs: FfiChangeId) -> Bool {
+        if lhs.hex != rhs.hex {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(hex)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiChangeId: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiChangeId {
+        return
+            try FfiChangeId(
+                hex: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiChangeId, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.hex, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiChangeId_lift(_ buf: RustBuffer) throws -> FfiChangeId {
+    return try FfiConverterTypeFfiChangeId.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiChangeId_lower(_ value: FfiChangeId) -> RustBuffer {
+    return FfiConverterTypeFfiChangeId.lower(value)
+}
+
+
+/**
+ * Options for cloning a Git repository
+ */
+public struct FfiCloneOptions {
+    /**
+     * Name of the remote created for the source, e.g. "origin"
+     */
+    public var remoteName: String
+    /**
+     * Whether to colocate the Git repository with the workspace
+     */
+    public var colocated: Bool
+    /**
+     * Number of commits to fetch from the tips, like `jj git clone --depth`
+     * (the full history is fetched if `None`)
+     *
+     * Fetch with a larger depth and call `FfiWorkspace::reindex()` to
+     * deepen the clone later.
+     */
+    public var depth: UInt32?
+    /**
+     * Asked for the credentials of the source URL
+     */
+    public var credentials: FfiGitCredentialProvider?
+    /**
+     * Receives the transfer progress
+     */
+    public var progress: FfiGitProgress?
+    /**
+     * Cancels the clone, checked during the transfer
+     *
+     * The partially created workspace is removed when a clone is cancelled.
+     */
+    public var cancellation: FfiCancellationToken?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Name of the remote created for the source, e.g. "origin"
+         */remoteName: String, 
+        /**
+         * Whether to colocate the Git repository with the workspace
+         */colocated: Bool, 
+        /**
+         * Number of commits to fetch from the tips, like `jj git clone --depth`
+         * (the full history is fetched if `None`)
+         *
+         * Fetch with a larger depth and call `FfiWorkspace::reindex()` to
+         * deepen the clone later.
+         */depth: UInt32?, 
+        /**
+         * Asked for the credentials of the source URL
+         */credentials: FfiGitCredentialProvider?, 
+        /**
+         * Receives the transfer progress
+         */progress: FfiGitProgress?, 
+        /**
+         * Cancels the clone, checked during the transfer
+         *
+         * The partially created workspace is removed when a clone is cancelled.
+         */cancellation: FfiCancellationToken?) {
+        self.remoteName = remoteName
+        self.colocated = colocated
+        self.depth = depth
+        self.credentials = credentials
+        self.progress = progress
+        self.cancellation = cancellation
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiCloneOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCloneOptions {
+        return
+            try FfiCloneOptions(
+                remoteName: FfiConverterString.read(from: &buf), 
+                colocated: FfiConverterBool.read(from: &buf), 
+                depth: FfiConverterOptionUInt32.read(from: &buf), 
+                credentials: FfiConverterOptionTypeFfiGitCredentialProvider.read(from: &buf), 
+                progress: FfiConverterOptionTypeFfiGitProgress.read(from: &buf), 
+                cancellation: FfiConverterOptionTypeFfiCancellationToken.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiCloneOptions, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.remoteName, into: &buf)
+        FfiConverterBool.write(value.colocated, into: &buf)
+        FfiConverterOptionUInt32.write(value.depth, into: &buf)
+        FfiConverterOptionTypeFfiGitCredentialProvider.write(value.credentials, into: &buf)
+        FfiConverterOptionTypeFfiGitProgress.write(value.progress, into: &buf)
+        FfiConverterOptionTypeFfiCancellationToken.write(value.cancellation, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCloneOptions_lift(_ buf: RustBuffer) throws -> FfiCloneOptions {
+    return try FfiConverterTypeFfiCloneOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCloneOptions_lower(_ value: FfiCloneOptions) -> RustBuffer {
+    return FfiConverterTypeFfiCloneOptions.lower(value)
+}
+
+
+/**
+ * A commit exposed via FFI
+ */
+public struct FfiCommit {
+    /**
+     * The commit ID (content-based hash)
+     */
+    public var id: FfiCommitId
+    /**
+     * The change ID (stable identifier across rewrites)
+     */
+    public var changeId: FfiChangeId
+    /**
+     * Commit description/message
+     */
+    public var description: String
+    /**
+     * Author signature
+     */
+    public var author: FfiSignature
     /**
      * Committer signature
      */
-    public var committer: FfiSignature
+    public var committer: FfiSignature
+    /**
+     * Parent commit IDs
+     */
+    public var parentIds: [FfiCommitId]
+    /**
+     * Whether this commit is signed
+     */
+    public var isSigned: Bool
+    /**
+     * Whether this commit's tree has unresolved conflicts
+     */
+    public var hasConflict: Bool
+    /**
+     * Hex IDs of the commit's tree
+     *
+     * This is a single ID unless the tree has conflicts, in which case the
+     * IDs of the conflict's terms are listed in order (alternating added and
+     * removed trees). Commits with equal tree IDs have the same content.
+     */
+    public var treeIds: [String]
+    /**
+     * Length of the shortest unique commit ID prefix, if requested
+     */
+    public var commitIdPrefixLen: UInt32?
+    /**
+     * Length of the shortest unique change ID prefix, if requested
+     */
+    public var changeIdPrefixLen: UInt32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The commit ID (content-based hash)
+         */id: FfiCommitId, 
+        /**
+         * The change ID (stable identifier across rewrites)
+         */changeId: FfiChangeId, 
+        /**
+         * Commit description/message
+         */description: String, 
+        /**
+         * Author signature
+         */author: FfiSignature, 
+        /**
+         * Committer signature
+         */committer: FfiSignature, 
+        /**
+         * Parent commit IDs
+         */parentIds: [FfiCommitId], 
+        /**
+         * Whether this commit is signed
+         */isSigned: Bool, 
+        /**
+         * Whether this commit's tree has unresolved conflicts
+         */hasConflict: Bool, 
+        /**
+         * Hex IDs of the commit's tree
+         *
+         * This is a single ID unless the tree has conflicts, in which case the
+         * IDs of the conflict's terms are listed in order (alternating added and
+         * removed trees). Commits with equal tree IDs have the same content.
+         */treeIds: [String], 
+        /**
+         * Length of the shortest unique commit ID prefix, if requested
+         */commitIdPrefixLen: UInt32?, 
+        /**
+         * Length of the shortest unique change ID prefix, if requested
+         */changeIdPrefixLen: UInt32?) {
+        self.id = id
+        self.changeId = changeId
+        self.description = description
+        self.author = author
+        self.committer = committer
+        self.parentIds = parentIds
+        self.isSigned = isSigned
+        self.hasConflict = hasConflict
+        self.treeIds = treeIds
+        self.commitIdPrefixLen = commitIdPrefixLen
+        self.changeIdPrefixLen = changeIdPrefixLen
+    }
+}
+
+
+
+extension FfiCommit: Equatable, Hashable {
+    public static func ==(lhs: FfiCommit, rhs: FfiCommit) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.changeId != rhs.changeId {
+            return false
+        }
+        if lhs.description != rhs.description {
+            return false
+        }
+        if lhs.author != rhs.author {
+            return false
+        }
+        if lhs.committer != rhs.committer {
+            return false
+        }
+        if lhs.parentIds != rhs.parentIds {
+            return false
+        }
+        if lhs.isSigned != rhs.isSigned {
+            return false
+        }
+        if lhs.hasConflict != rhs.hasConflict {
+            return false
+        }
+        if lhs.treeIds != rhs.treeIds {
+            return false
+        }
+        if lhs.commitIdPrefixLen != rhs.commitIdPrefixLen {
+            return false
+        }
+        if lhs.changeIdPrefixLen != rhs.changeIdPrefixLen {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(changeId)
+        hasher.combine(description)
+        hasher.combine(author)
+        hasher.combine(committer)
+        hasher.combine(parentIds)
+        hasher.combine(isSigned)
+        hasher.combine(hasConflict)
+        hasher.combine(treeIds)
+        hasher.combine(commitIdPrefixLen)
+        hasher.combine(changeIdPrefixLen)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiCommit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCommit {
+        return
+            try FfiCommit(
+                id: FfiConverterTypeFfiCommitId.read(from: &buf), 
+                changeId: FfiConverterTypeFfiChangeId.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                author: FfiConverterTypeFfiSignature.read(from: &buf), 
+                committer: FfiConverterTypeFfiSignature.read(from: &buf), 
+                parentIds: FfiConverterSequenceTypeFfiCommitId.read(from: &buf), 
+                isSigned: FfiConverterBool.read(from: &buf), 
+                hasConflict: FfiConverterBool.read(from: &buf), 
+                treeIds: FfiConverterSequenceString.read(from: &buf), 
+                commitIdPrefixLen: FfiConverterOptionUInt32.read(from: &buf), 
+                changeIdPrefixLen: FfiConverterOptionUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiCommit, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiCommitId.write(value.id, into: &buf)
+        FfiConverterTypeFfiChangeId.write(value.changeId, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterTypeFfiSignature.write(value.author, into: &buf)
+        FfiConverterTypeFfiSignature.write(value.committer, into: &buf)
+        FfiConverterSequenceTypeFfiCommitId.write(value.parentIds, into: &buf)
+        FfiConverterBool.write(value.isSigned, into: &buf)
+        FfiConverterBool.write(value.hasConflict, into: &buf)
+        FfiConverterSequenceString.write(value.treeIds, into: &buf)
+        FfiConverterOptionUInt32.write(value.commitIdPrefixLen, into: &buf)
+        FfiConverterOptionUInt32.write(value.changeIdPrefixLen, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCommit_lift(_ buf: RustBuffer) throws -> FfiCommit {
+    return try FfiConverterTypeFfiCommit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCommit_lower(_ value: FfiCommit) -> RustBuffer {
+    return FfiConverterTypeFfiCommit.lower(value)
+}
+
+
+/**
+ * An extra header of a Git commit object
+ */
+public struct FfiCommitHeader {
+    /**
+     * Header name, e.g. "change-id" or "gpgsig"
+     */
+    public var key: String
+    /**
+     * Header value (lossily decoded as UTF-8)
+     */
+    public var value: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Header name, e.g. "change-id" or "gpgsig"
+         */key: String, 
+        /**
+         * Header value (lossily decoded as UTF-8)
+         */value: String) {
+        self.key = key
+        self.value = value
+    }
+}
+
+
+
+extension FfiCommitHeader: Equatable, Hashable {
+    public static func ==(lhs: FfiCommitHeader, rhs: FfiCommitHeader) -> Bool {
+        if lhs.key != rhs.key {
+            return false
+        }
+        if lhs.value != rhs.value {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(key)
+        hasher.combine(value)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiCommitHeader: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCommitHeader {
+        return
+            try FfiCommitHeader(
+                key: FfiConverterString.read(from: &buf), 
+                value: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiCommitHeader, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterString.write(value.value, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCommitHeader_lift(_ buf: RustBuffer) throws -> FfiCommitHeader {
+    return try FfiConverterTypeFfiCommitHeader.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCommitHeader_lower(_ value: FfiCommitHeader) -> RustBuffer {
+    return FfiConverterTypeFfiCommitHeader.lower(value)
+}
+
+
+/**
+ * A commit ID represented as a hex string for FFI
+ */
+public struct FfiCommitId {
+    /**
+     * Hex-encoded commit ID
+     */
+    public var hex: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Hex-encoded commit ID
+         */hex: String) {
+        self.hex = hex
+    }
+}
+
+
+
+extension FfiCommitId: Equatable, Hashable {
+    public static func ==(lhs: FfiCommitId, rhs: FfiCommitId) -> Bool {
+        if lhs.hex != rhs.hex {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(hex)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiCommitId: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCommitId {
+        return
+            try FfiCommitId(
+                hex: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiCommitId, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.hex, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCommitId_lift(_ buf: RustBuffer) throws -> FfiCommitId {
+    return try FfiConverterTypeFfiCommitId.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCommitId_lower(_ value: FfiCommitId) -> RustBuffer {
+    return FfiConverterTypeFfiCommitId.lower(value)
+}
+
+
+/**
+ * Refs pointing at a commit, as shown next to it by `jj log`
+ *
+ * Conflicted refs are listed on each of the commits they point to.
+ */
+public struct FfiCommitRefs {
+    /**
+     * Local bookmarks, sorted by name
+     */
+    public var localBookmarks: [String]
+    /**
+     * Remote bookmarks (excluding the backing Git repository), sorted by
+     * name and remote
+     */
+    public var remoteBookmarks: [FfiBookmarkRef]
+    /**
+     * Tags, sorted by name
+     */
+    public var tags: [String]
+    /**
+     * Names of the workspaces that have this commit as working-copy commit
+     */
+    public var workingCopies: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Local bookmarks, sorted by name
+         */localBookmarks: [String], 
+        /**
+         * Remote bookmarks (excluding the backing Git repository), sorted by
+         * name and remote
+         */remoteBookmarks: [FfiBookmarkRef], 
+        /**
+         * Tags, sorted by name
+         */tags: [String], 
+        /**
+         * Names of the workspaces that have this commit as working-copy commit
+         */workingCopies: [String]) {
+        self.localBookmarks = localBookmarks
+        self.remoteBookmarks = remoteBookmarks
+        self.tags = tags
+        self.workingCopies = workingCopies
+    }
+}
+
+
+
+extension FfiCommitRefs: Equatable, Hashable {
+    public static func ==(lhs: FfiCommitRefs, rhs: FfiCommitRefs) -> Bool {
+        if lhs.localBookmarks != rhs.localBookmarks {
+            return false
+        }
+        if lhs.remoteBookmarks != rhs.remoteBookmarks {
+            return false
+        }
+        if lhs.tags != rhs.tags {
+            return false
+        }
+        if lhs.workingCopies != rhs.workingCopies {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(localBookmarks)
+        hasher.combine(remoteBookmarks)
+        hasher.combine(tags)
+        hasher.combine(workingCopies)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiCommitRefs: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCommitRefs {
+        return
+            try FfiCommitRefs(
+                localBookmarks: FfiConverterSequenceString.read(from: &buf), 
+                remoteBookmarks: FfiConverterSequenceTypeFfiBookmarkRef.read(from: &buf), 
+                tags: FfiConverterSequenceString.read(from: &buf), 
+                workingCopies: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiCommitRefs, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.localBookmarks, into: &buf)
+        FfiConverterSequenceTypeFfiBookmarkRef.write(value.remoteBookmarks, into: &buf)
+        FfiConverterSequenceString.write(value.tags, into: &buf)
+        FfiConverterSequenceString.write(value.workingCopies, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCommitRefs_lift(_ buf: RustBuffer) throws -> FfiCommitRefs {
+    return try FfiConverterTypeFfiCommitRefs.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiCommitRefs_lower(_ value: FfiCommitRefs) -> RustBuffer {
+    return FfiConverterTypeFfiCommitRefs.lower(value)
+}
+
+
+/**
+ * The sides and bases of a conflicted file
+ */
+public struct FfiConflictSides {
+    /**
+     * Repository-relative path of the conflicted file
+     */
+    public var path: String
+    /**
+     * Sides of the conflict (one more than the number of bases)
+     */
+    public var sides: [FfiConflictTerm]
+    /**
+     * Bases of the conflict
+     */
+    public var bases: [FfiConflictTerm]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Repository-relative path of the conflicted file
+         */path: String, 
+        /**
+         * Sides of the conflict (one more than the number of bases)
+         */sides: [FfiConflictTerm], 
+        /**
+         * Bases of the conflict
+         */bases: [FfiConflictTerm]) {
+        self.path = path
+        self.sides = sides
+        self.bases = bases
+    }
+}
+
+
+
+extension FfiConflictSides: Equatable, Hashable {
+    public static func ==(lhs: FfiConflictSides, rhs: FfiConflictSides) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.sides != rhs.sides {
+            return false
+        }
+        if lhs.bases != rhs.bases {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(sides)
+        hasher.combine(bases)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiConflictSides: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiConflictSides {
+        return
+            try FfiConflictSides(
+                path: FfiConverterString.read(from: &buf), 
+                sides: FfiConverterSequenceTypeFfiConflictTerm.read(from: &buf), 
+                bases: FfiConverterSequenceTypeFfiConflictTerm.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiConflictSides, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterSequenceTypeFfiConflictTerm.write(value.sides, into: &buf)
+        FfiConverterSequenceTypeFfiConflictTerm.write(value.bases, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiConflictSides_lift(_ buf: RustBuffer) throws -> FfiConflictSides {
+    return try FfiConverterTypeFfiConflictSides.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiConflictSides_lower(_ value: FfiConflictSides) -> RustBuffer {
+    return FfiConverterTypeFfiConflictSides.lower(value)
+}
+
+
+/**
+ * One term (a side or a base) of a conflicted file
+ */
+public struct FfiConflictTerm {
+    /**
+     * Label used by jj's conflict markers, e.g. "side #1", or "base" if the
+     * conflict has a single base and "base #1" otherwise
+     */
+    public var label: String
+    /**
+     * File content of this term (`None` if the file is absent on this term)
+     */
+    public var content: Data?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Label used by jj's conflict markers, e.g. "side #1", or "base" if the
+         * conflict has a single base and "base #1" otherwise
+         */label: String, 
+        /**
+         * File content of this term (`None` if the file is absent on this term)
+         */content: Data?) {
+        self.label = label
+        self.content = content
+    }
+}
+
+
+
+extension FfiConflictTerm: Equatable, Hashable {
+    public static func ==(lhs: FfiConflictTerm, rhs: FfiConflictTerm) -> Bool {
+        if lhs.label != rhs.label {
+            return false
+        }
+        if lhs.content != rhs.content {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(label)
+        hasher.combine(content)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiConflictTerm: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiConflictTerm {
+        return
+            try FfiConflictTerm(
+                label: FfiConverterString.read(from: &buf), 
+                content: FfiConverterOptionData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiConflictTerm, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.label, into: &buf)
+        FfiConverterOptionData.write(value.content, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiConflictTerm_lift(_ buf: RustBuffer) throws -> FfiConflictTerm {
+    return try FfiConverterTypeFfiConflictTerm.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiConflictTerm_lower(_ value: FfiConflictTerm) -> RustBuffer {
+    return FfiConverterTypeFfiConflictTerm.lower(value)
+}
+
+
+/**
+ * Commit statistics for a single author
+ */
+public struct FfiContributorStats {
+    /**
+     * Author name (as of their most recent commit)
+     */
+    public var name: String
+    /**
+     * Author email
+     */
+    public var email: String
+    /**
+     * Number of commits authored
+     */
+    public var commitCount: UInt64
+    /**
+     * Number of inserted lines (`None` unless line stats were requested)
+     */
+    public var insertions: UInt64?
+    /**
+     * Number of deleted lines (`None` unless line stats were requested)
+     */
+    public var deletions: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Author name (as of their most recent commit)
+         */name: String, 
+        /**
+         * Author email
+         */email: String, 
+        /**
+         * Number of commits authored
+         */commitCount: UInt64, 
+        /**
+         * Number of inserted lines (`None` unless line stats were requested)
+         */insertions: UInt64?, 
+        /**
+         * Number of deleted lines (`None` unless line stats were requested)
+         */deletions: UInt64?) {
+        self.name = name
+        self.email = email
+        self.commitCount = commitCount
+        self.insertions = insertions
+        self.deletions = deletions
+    }
+}
+
+
+
+extension FfiContributorStats: Equatable, Hashable {
+    public static func ==(lhs: FfiContributorStats, rhs: FfiContributorStats) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.email != rhs.email {
+            return false
+        }
+        if lhs.commitCount != rhs.commitCount {
+            return false
+        }
+        if lhs.insertions != rhs.insertions {
+            return false
+        }
+        if lhs.deletions != rhs.deletions {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(email)
+        hasher.combine(commitCount)
+        hasher.combine(insertions)
+        hasher.combine(deletions)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiContributorStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiContributorStats {
+        return
+            try FfiContributorStats(
+                name: FfiConverterString.read(from: &buf), 
+                email: FfiConverterString.read(from: &buf), 
+                commitCount: FfiConverterUInt64.read(from: &buf), 
+                insertions: FfiConverterOptionUInt64.read(from: &buf), 
+                deletions: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiContributorStats, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.email, into: &buf)
+        FfiConverterUInt64.write(value.commitCount, into: &buf)
+        FfiConverterOptionUInt64.write(value.insertions, into: &buf)
+        FfiConverterOptionUInt64.write(value.deletions, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiContributorStats_lift(_ buf: RustBuffer) throws -> FfiContributorStats {
+    return try FfiConverterTypeFfiContributorStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiContributorStats_lower(_ value: FfiContributorStats) -> RustBuffer {
+    return FfiConverterTypeFfiContributorStats.lower(value)
+}
+
+
+/**
+ * A changed file in a diff
+ */
+public struct FfiDiffEntry {
+    /**
+     * Repository-relative path of the file after the change
+     */
+    public var path: String
+    /**
+     * Path the file was renamed or copied from, if any
+     */
+    public var sourcePath: String?
+    /**
+     * The kind of change
+     */
+    public var changeType: FfiChangeType
+    /**
+     * Type of the entry before the change (absent for added files)
+     */
+    public var oldEntryType: FfiTreeEntryType?
+    /**
+     * Whether the entry was an executable file before the change
+     */
+    public var oldExecutable: Bool
+    /**
+     * Type of the entry after the change (absent for deleted files)
+     */
+    public var newEntryType: FfiTreeEntryType?
+    /**
+     * Whether the entry is an executable file after the change
+     */
+    public var newExecutable: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Repository-relative path of the file after the change
+         */path: String, 
+        /**
+         * Path the file was renamed or copied from, if any
+         */sourcePath: String?, 
+        /**
+         * The kind of change
+         */changeType: FfiChangeType, 
+        /**
+         * Type of the entry before the change (absent for added files)
+         */oldEntryType: FfiTreeEntryType?, 
+        /**
+         * Whether the entry was an executable file before the change
+         */oldExecutable: Bool, 
+        /**
+         * Type of the entry after the change (absent for deleted files)
+         */newEntryType: FfiTreeEntryType?, 
+        /**
+         * Whether the entry is an executable file after the change
+         */newExecutable: Bool) {
+        self.path = path
+        self.sourcePath = sourcePath
+        self.changeType = changeType
+        self.oldEntryType = oldEntryType
+        self.oldExecutable = oldExecutable
+        self.newEntryType = newEntryType
+        self.newExecutable = newExecutable
+    }
+}
+
+
+
+extension FfiDiffEntry: Equatable, Hashable {
+    public static func ==(lhs: FfiDiffEntry, rhs: FfiDiffEntry) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.sourcePath != rhs.sourcePath {
+            return false
+        }
+        if lhs.changeType != rhs.changeType {
+            return false
+        }
+        if lhs.oldEntryType != rhs.oldEntryType {
+            return false
+        }
+        if lhs.oldExecutable != rhs.oldExecutable {
+            return false
+        }
+        if lhs.newEntryType != rhs.newEntryType {
+            return false
+        }
+        if lhs.newExecutable != rhs.newExecutable {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(sourcePath)
+        hasher.combine(changeType)
+        hasher.combine(oldEntryType)
+        hasher.combine(oldExecutable)
+        hasher.combine(newEntryType)
+        hasher.combine(newExecutable)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiDiffEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiDiffEntry {
+        return
+            try FfiDiffEntry(
+                path: FfiConverterString.read(from: &buf), 
+                sourcePath: FfiConverterOptionString.read(from: &buf), 
+                changeType: FfiConverterTypeFfiChangeType.read(from: &buf), 
+                oldEntryType: FfiConverterOptionTypeFfiTreeEntryType.read(from: &buf), 
+                oldExecutable: FfiConverterBool.read(from: &buf), 
+                newEntryType: FfiConverterOptionTypeFfiTreeEntryType.read(from: &buf), 
+                newExecutable: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiDiffEntry, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterOptionString.write(value.sourcePath, into: &buf)
+        FfiConverterTypeFfiChangeType.write(value.changeType, into: &buf)
+        FfiConverterOptionTypeFfiTreeEntryType.write(value.oldEntryType, into: &buf)
+        FfiConverterBool.write(value.oldExecutable, into: &buf)
+        FfiConverterOptionTypeFfiTreeEntryType.write(value.newEntryType, into: &buf)
+        FfiConverterBool.write(value.newExecutable, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffEntry_lift(_ buf: RustBuffer) throws -> FfiDiffEntry {
+    return try FfiConverterTypeFfiDiffEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffEntry_lower(_ value: FfiDiffEntry) -> RustBuffer {
+    return FfiConverterTypeFfiDiffEntry.lower(value)
+}
+
+
+/**
+ * A hunk of a line-based diff
+ */
+public struct FfiDiffHunk {
+    /**
+     * 1-based start line in the old content
+     */
+    public var oldStart: UInt32
+    /**
+     * Number of old lines covered by this hunk
+     */
+    public var oldCount: UInt32
+    /**
+     * 1-based start line in the new content
+     */
+    public var newStart: UInt32
+    /**
+     * Number of new lines covered by this hunk
+     */
+    public var newCount: UInt32
+    /**
+     * Lines of the hunk
+     */
+    public var lines: [FfiDiffLine]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * 1-based start line in the old content
+         */oldStart: UInt32, 
+        /**
+         * Number of old lines covered by this hunk
+         */oldCount: UInt32, 
+        /**
+         * 1-based start line in the new content
+         */newStart: UInt32, 
+        /**
+         * Number of new lines covered by this hunk
+         */newCount: UInt32, 
+        /**
+         * Lines of the hunk
+         */lines: [FfiDiffLine]) {
+        self.oldStart = oldStart
+        self.oldCount = oldCount
+        self.newStart = newStart
+        self.newCount = newCount
+        self.lines = lines
+    }
+}
+
+
+
+extension FfiDiffHunk: Equatable, Hashable {
+    public static func ==(lhs: FfiDiffHunk, rhs: FfiDiffHunk) -> Bool {
+        if lhs.oldStart != rhs.oldStart {
+            return false
+        }
+        if lhs.oldCount != rhs.oldCount {
+            return false
+        }
+        if lhs.newStart != rhs.newStart {
+            return false
+        }
+        if lhs.newCount != rhs.newCount {
+            return false
+        }
+        if lhs.lines != rhs.lines {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(oldStart)
+        hasher.combine(oldCount)
+        hasher.combine(newStart)
+        hasher.combine(newCount)
+        hasher.combine(lines)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiDiffHunk: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiDiffHunk {
+        return
+            try FfiDiffHunk(
+                oldStart: FfiConverterUInt32.read(from: &buf), 
+                oldCount: FfiConverterUInt32.read(from: &buf), 
+                newStart: FfiConverterUInt32.read(from: &buf), 
+                newCount: FfiConverterUInt32.read(from: &buf), 
+                lines: FfiConverterSequenceTypeFfiDiffLine.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiDiffHunk, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.oldStart, into: &buf)
+        FfiConverterUInt32.write(value.oldCount, into: &buf)
+        FfiConverterUInt32.write(value.newStart, into: &buf)
+        FfiConverterUInt32.write(value.newCount, into: &buf)
+        FfiConverterSequenceTypeFfiDiffLine.write(value.lines, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffHunk_lift(_ buf: RustBuffer) throws -> FfiDiffHunk {
+    return try FfiConverterTypeFfiDiffHunk.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffHunk_lower(_ value: FfiDiffHunk) -> RustBuffer {
+    return FfiConverterTypeFfiDiffHunk.lower(value)
+}
+
+
+/**
+ * A single line in a diff hunk
+ */
+public struct FfiDiffLine {
+    /**
+     * Type of the line
+     */
+    public var lineType: FfiDiffLineType
+    /**
+     * Line content without the trailing newline (lossily decoded as UTF-8)
+     */
+    public var content: String
+    /**
+     * 1-based line number in the old content (absent for added lines)
+     */
+    public var oldLineNumber: UInt32?
+    /**
+     * 1-based line number in the new content (absent for removed lines)
+     */
+    public var newLineNumber: UInt32?
+    /**
+     * Ranges of changed words within `content` (empty for context lines or
+     * if intraline ranges were not requested)
+     */
+    public var changedRanges: [FfiTextRange]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Type of the line
+         */lineType: FfiDiffLineType, 
+        /**
+         * Line content without the trailing newline (lossily decoded as UTF-8)
+         */content: String, 
+        /**
+         * 1-based line number in the old content (absent for added lines)
+         */oldLineNumber: UInt32?, 
+        /**
+         * 1-based line number in the new content (absent for removed lines)
+         */newLineNumber: UInt32?, 
+        /**
+         * Ranges of changed words within `content` (empty for context lines or
+         * if intraline ranges were not requested)
+         */changedRanges: [FfiTextRange]) {
+        self.lineType = lineType
+        self.content = content
+        self.oldLineNumber = oldLineNumber
+        self.newLineNumber = newLineNumber
+        self.changedRanges = changedRanges
+    }
+}
+
+
+
+extension FfiDiffLine: Equatable, Hashable {
+    public static func ==(lhs: FfiDiffLine, rhs: FfiDiffLine) -> Bool {
+        if lhs.lineType != rhs.lineType {
+            return false
+        }
+        if lhs.content != rhs.content {
+            return false
+        }
+        if lhs.oldLineNumber != rhs.oldLineNumber {
+            return false
+        }
+        if lhs.newLineNumber != rhs.newLineNumber {
+            return false
+        }
+        if lhs.changedRanges != rhs.changedRanges {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(lineType)
+        hasher.combine(content)
+        hasher.combine(oldLineNumber)
+        hasher.combine(newLineNumber)
+        hasher.combine(changedRanges)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiDiffLine: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiDiffLine {
+        return
+            try FfiDiffLine(
+                lineType: FfiConverterTypeFfiDiffLineType.read(from: &buf), 
+                content: FfiConverterString.read(from: &buf), 
+                oldLineNumber: FfiConverterOptionUInt32.read(from: &buf), 
+                newLineNumber: FfiConverterOptionUInt32.read(from: &buf), 
+                changedRanges: FfiConverterSequenceTypeFfiTextRange.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiDiffLine, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiDiffLineType.write(value.lineType, into: &buf)
+        FfiConverterString.write(value.content, into: &buf)
+        FfiConverterOptionUInt32.write(value.oldLineNumber, into: &buf)
+        FfiConverterOptionUInt32.write(value.newLineNumber, into: &buf)
+        FfiConverterSequenceTypeFfiTextRange.write(value.changedRanges, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffLine_lift(_ buf: RustBuffer) throws -> FfiDiffLine {
+    return try FfiConverterTypeFfiDiffLine.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffLine_lower(_ value: FfiDiffLine) -> RustBuffer {
+    return FfiConverterTypeFfiDiffLine.lower(value)
+}
+
+
+/**
+ * Line counts of all files changed between two commits, like `jj diff --stat`
+ */
+public struct FfiDiffStats {
+    /**
+     * Per-file line counts, ordered by path
+     */
+    public var files: [FfiFileDiffStat]
+    /**
+     * Total number of inserted lines
+     */
+    public var totalInsertions: UInt64
+    /**
+     * Total number of deleted lines
+     */
+    public var totalDeletions: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Per-file line counts, ordered by path
+         */files: [FfiFileDiffStat], 
+        /**
+         * Total number of inserted lines
+         */totalInsertions: UInt64, 
+        /**
+         * Total number of deleted lines
+         */totalDeletions: UInt64) {
+        self.files = files
+        self.totalInsertions = totalInsertions
+        self.totalDeletions = totalDeletions
+    }
+}
+
+
+
+extension FfiDiffStats: Equatable, Hashable {
+    public static func ==(lhs: FfiDiffStats, rhs: FfiDiffStats) -> Bool {
+        if lhs.files != rhs.files {
+            return false
+        }
+        if lhs.totalInsertions != rhs.totalInsertions {
+            return false
+        }
+        if lhs.totalDeletions != rhs.totalDeletions {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(files)
+        hasher.combine(totalInsertions)
+        hasher.combine(totalDeletions)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiDiffStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiDiffStats {
+        return
+            try FfiDiffStats(
+                files: FfiConverterSequenceTypeFfiFileDiffStat.read(from: &buf), 
+                totalInsertions: FfiConverterUInt64.read(from: &buf), 
+                totalDeletions: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiDiffStats, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeFfiFileDiffStat.write(value.files, into: &buf)
+        FfiConverterUInt64.write(value.totalInsertions, into: &buf)
+        FfiConverterUInt64.write(value.totalDeletions, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffStats_lift(_ buf: RustBuffer) throws -> FfiDiffStats {
+    return try FfiConverterTypeFfiDiffStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffStats_lower(_ value: FfiDiffStats) -> RustBuffer {
+    return FfiConverterTypeFfiDiffStats.lower(value)
+}
+
+
+/**
+ * Aggregated diff statistics for a directory, or for a single file
+ * directly inside the summarized directory
+ */
+public struct FfiDirectoryDiffSummary {
+    /**
+     * Repository-relative path of the directory or file
+     */
+    public var path: String
+    /**
+     * Whether the entry is a subdirectory rather than a file
+     */
+    public var isDirectory: Bool
+    /**
+     * Number of changed files under this directory (recursively), or 1 for
+     * a file
+     */
+    public var filesChanged: UInt64
+    /**
+     * Number of inserted lines under this directory
+     */
+    public var insertions: UInt64
+    /**
+     * Number of deleted lines under this directory
+     */
+    public var deletions: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Repository-relative path of the directory or file
+         */path: String, 
+        /**
+         * Whether the entry is a subdirectory rather than a file
+         */isDirectory: Bool, 
+        /**
+         * Number of changed files under this directory (recursively), or 1 for
+         * a file
+         */filesChanged: UInt64, 
+        /**
+         * Number of inserted lines under this directory
+         */insertions: UInt64, 
+        /**
+         * Number of deleted lines under this directory
+         */deletions: UInt64) {
+        self.path = path
+        self.isDirectory = isDirectory
+        self.filesChanged = filesChanged
+        self.insertions = insertions
+        self.deletions = deletions
+    }
+}
+
+
+
+extension FfiDirectoryDiffSummary: Equatable, Hashable {
+    public static func ==(lhs: FfiDirectoryDiffSummary, rhs: FfiDirectoryDiffSummary) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.isDirectory != rhs.isDirectory {
+            return false
+        }
+        if lhs.filesChanged != rhs.filesChanged {
+            return false
+        }
+        if lhs.insertions != rhs.insertions {
+            return false
+        }
+        if lhs.deletions != rhs.deletions {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(isDirectory)
+        hasher.combine(filesChanged)
+        hasher.combine(insertions)
+        hasher.combine(deletions)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiDirectoryDiffSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiDirectoryDiffSummary {
+        return
+            try FfiDirectoryDiffSummary(
+                path: FfiConverterString.read(from: &buf), 
+                isDirectory: FfiConverterBool.read(from: &buf), 
+                filesChanged: FfiConverterUInt64.read(from: &buf), 
+                insertions: FfiConverterUInt64.read(from: &buf), 
+                deletions: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiDirectoryDiffSummary, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterBool.write(value.isDirectory, into: &buf)
+        FfiConverterUInt64.write(value.filesChanged, into: &buf)
+        FfiConverterUInt64.write(value.insertions, into: &buf)
+        FfiConverterUInt64.write(value.deletions, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDirectoryDiffSummary_lift(_ buf: RustBuffer) throws -> FfiDirectoryDiffSummary {
+    return try FfiConverterTypeFfiDirectoryDiffSummary.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDirectoryDiffSummary_lower(_ value: FfiDirectoryDiffSummary) -> RustBuffer {
+    return FfiConverterTypeFfiDirectoryDiffSummary.lower(value)
+}
+
+
+/**
+ * A bookmark or tag that could not be exported to Git
+ */
+public struct FfiFailedRefExport {
+    /**
+     * Bookmark or tag name
+     */
+    public var name: String
+    /**
+     * Remote name ("git" for local bookmarks and tags)
+     */
+    public var remote: String
+    /**
+     * Why the ref could not be exported
+     */
+    public var reason: FfiRefExportFailureReason
+    /**
+     * Human-readable description of the reason
+     */
+    public var message: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Bookmark or tag name
+         */name: String, 
+        /**
+         * Remote name ("git" for local bookmarks and tags)
+         */remote: String, 
+        /**
+         * Why the ref could not be exported
+         */reason: FfiRefExportFailureReason, 
+        /**
+         * Human-readable description of the reason
+         */message: String) {
+        self.name = name
+        self.remote = remote
+        self.reason = reason
+        self.message = message
+    }
+}
+
+
+
+extension FfiFailedRefExport: Equatable, Hashable {
+    public static func ==(lhs: FfiFailedRefExport, rhs: FfiFailedRefExport) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.remote != rhs.remote {
+            return false
+        }
+        if lhs.reason != rhs.reason {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(remote)
+        hasher.combine(reason)
+        hasher.combine(message)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiFailedRefExport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiFailedRefExport {
+        return
+            try FfiFailedRefExport(
+                name: FfiConverterString.read(from: &buf), 
+                remote: FfiConverterString.read(from: &buf), 
+                reason: FfiConverterTypeFfiRefExportFailureReason.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiFailedRefExport, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.remote, into: &buf)
+        FfiConverterTypeFfiRefExportFailureReason.write(value.reason, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiFailedRefExport_lift(_ buf: RustBuffer) throws -> FfiFailedRefExport {
+    return try FfiConverterTypeFfiFailedRefExport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiFailedRefExport_lower(_ value: FfiFailedRefExport) -> RustBuffer {
+    return FfiConverterTypeFfiFailedRefExport.lower(value)
+}
+
+
+/**
+ * Options for fetching from a remote
+ *
+ * Use `clone_git_workspace()` to create a shallow clone of a remote.
+ */
+public struct FfiFetchOptions {
+    /**
+     * Whether to delete remote bookmarks that no longer exist on the remote,
+     * like `jj git fetch` does (they are kept otherwise)
+     */
+    public var prune: Bool
+    /**
+     * Number of commits to fetch from the tips, like `git fetch --depth`
+     * (the full history is fetched if `None`)
+     *
+     * Fetching an already shallow repository with a larger depth deepens
+     * it. Call `FfiWorkspace::reindex()` afterwards so commits imported
+     * before see their newly fetched history.
+     */
+    public var depth: UInt32?
+    /**
+     * Asked for the credentials of the remote's fetch URL
+     */
+    public var credentials: FfiGitCredentialProvider?
+    /**
+     * Receives the transfer progress
+     */
+    public var progress: FfiGitProgress?
+    /**
+     * Cancels the fetch, checked during the transfer
+     *
+     * A cancelled fetch leaves the transaction unchanged.
+     */
+    public var cancellation: FfiCancellationToken?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Whether to delete remote bookmarks that no longer exist on the remote,
+         * like `jj git fetch` does (they are kept otherwise)
+         */prune: Bool, 
+        /**
+         * Number of commits to fetch from the tips, like `git fetch --depth`
+         * (the full history is fetched if `None`)
+         *
+         * Fetching an already shallow repository with a larger depth deepens
+         * it. Call `FfiWorkspace::reindex()` afterwards so commits imported
+         * before see their newly fetched history.
+         */depth: UInt32?, 
+        /**
+         * Asked for the credentials of the remote's fetch URL
+         */credentials: FfiGitCredentialProvider?, 
+        /**
+         * Receives the transfer progress
+         */progress: FfiGitProgress?, 
+        /**
+         * Cancels the fetch, checked during the transfer
+         *
+         * A cancelled fetch leaves the transaction unchanged.
+         */cancellation: FfiCancellationToken?) {
+        self.prune = prune
+        self.depth = depth
+        self.credentials = credentials
+        self.progress = progress
+        self.cancellation = cancellation
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiFetchOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiFetchOptions {
+        return
+            try FfiFetchOptions(
+                prune: FfiConverterBool.read(from: &buf), 
+                depth: FfiConverterOptionUInt32.read(from: &buf), 
+                credentials: FfiConverterOptionTypeFfiGitCredentialProvider.read(from: &buf), 
+                progress: FfiConverterOptionTypeFfiGitProgress.read(from: &buf), 
+                cancellation: FfiConverterOptionTypeFfiCancellationToken.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiFetchOptions, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.prune, into: &buf)
+        FfiConverterOptionUInt32.write(value.depth, into: &buf)
+        FfiConverterOptionTypeFfiGitCredentialProvider.write(value.credentials, into: &buf)
+        FfiConverterOptionTypeFfiGitProgress.write(value.progress, into: &buf)
+        FfiConverterOptionTypeFfiCancellationToken.write(value.cancellation, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiFetchOptions_lift(_ buf: RustBuffer) throws -> FfiFetchOptions {
+    return try FfiConverterTypeFfiFetchOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiFetchOptions_lower(_ value: FfiFetchOptions) -> RustBuffer {
+    return FfiConverterTypeFfiFetchOptions.lower(value)
+}
+
+
+/**
+ * Line counts of a changed file, like a line of `jj diff --stat`
+ */
+public struct FfiFileDiffStat {
+    /**
+     * Repository-relative path of the file
+     */
+    public var path: String
+    /**
+     * Number of inserted lines
+     */
+    public var insertions: UInt64
+    /**
+     * Number of deleted lines
+     */
+    public var deletions: UInt64
+    /**
+     * Whether either side of the file is binary (no lines are counted)
+     */
+    public var binary: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Repository-relative path of the file
+         */path: String, 
+        /**
+         * Number of inserted lines
+         */insertions: UInt64, 
+        /**
+         * Number of deleted lines
+         */deletions: UInt64, 
+        /**
+         * Whether either side of the file is binary (no lines are counted)
+         */binary: Bool) {
+        self.path = path
+        self.insertions = insertions
+        self.deletions = deletions
+        self.binary = binary
+    }
+}
+
+
+
+extension FfiFileDiffStat: Equatable, Hashable {
+    public static func ==(lhs: FfiFileDiffStat, rhs: FfiFileDiffStat) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.insertions != rhs.insertions {
+            return false
+        }
+        if lhs.deletions != rhs.deletions {
+            return false
+        }
+        if lhs.binary != rhs.binary {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(insertions)
+        hasher.combine(deletions)
+        hasher.combine(binary)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiFileDiffStat: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiFileDiffStat {
+        return
+            try FfiFileDiffStat(
+                path: FfiConverterString.read(from: &buf), 
+                insertions: FfiConverterUInt64.read(from: &buf), 
+                deletions: FfiConverterUInt64.read(from: &buf), 
+                binary: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiFileDiffStat, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterUInt64.write(value.insertions, into: &buf)
+        FfiConverterUInt64.write(value.deletions, into: &buf)
+        FfiConverterBool.write(value.binary, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiFileDiffStat_lift(_ buf: RustBuffer) throws -> FfiFileDiffStat {
+    return try FfiConverterTypeFfiFileDiffStat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiFileDiffStat_lower(_ value: FfiFileDiffStat) -> RustBuffer {
+    return FfiConverterTypeFfiFileDiffStat.lower(value)
+}
+
+
+/**
+ * Statistics from a git export operation
+ */
+public struct FfiGitExportStats {
+    /**
+     * Number of bookmarks that failed to export
+     */
+    public var failedBookmarksCount: UInt64
+    /**
+     * Number of tags that failed to export
+     */
+    public var failedTagsCount: UInt64
+    /**
+     * Bookmarks that failed to export, sorted by name and remote
+     */
+    public var failedBookmarks: [FfiFailedRefExport]
+    /**
+     * Tags that failed to export, sorted by name
+     */
+    public var failedTags: [FfiFailedRefExport]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Number of bookmarks that failed to export
+         */failedBookmarksCount: UInt64, 
+        /**
+         * Number of tags that failed to export
+         */failedTagsCount: UInt64, 
+        /**
+         * Bookmarks that failed to export, sorted by name and remote
+         */failedBookmarks: [FfiFailedRefExport], 
+        /**
+         * Tags that failed to export, sorted by name
+         */failedTags: [FfiFailedRefExport]) {
+        self.failedBookmarksCount = failedBookmarksCount
+        self.failedTagsCount = failedTagsCount
+        self.failedBookmarks = failedBookmarks
+        self.failedTags = failedTags
+    }
+}
+
+
+
+extension FfiGitExportStats: Equatable, Hashable {
+    public static func ==(lhs: FfiGitExportStats, rhs: FfiGitExportStats) -> Bool {
+        if lhs.failedBookmarksCount != rhs.failedBookmarksCount {
+            return false
+        }
+        if lhs.failedTagsCount != rhs.failedTagsCount {
+            return false
+        }
+        if lhs.failedBookmarks != rhs.failedBookmarks {
+            return false
+        }
+        if lhs.failedTags != rhs.failedTags {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(failedBookmarksCount)
+        hasher.combine(failedTagsCount)
+        hasher.combine(failedBookmarks)
+        hasher.combine(failedTags)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGitExportStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitExportStats {
+        return
+            try FfiGitExportStats(
+                failedBookmarksCount: FfiConverterUInt64.read(from: &buf), 
+                failedTagsCount: FfiConverterUInt64.read(from: &buf), 
+                failedBookmarks: FfiConverterSequenceTypeFfiFailedRefExport.read(from: &buf), 
+                failedTags: FfiConverterSequenceTypeFfiFailedRefExport.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGitExportStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.failedBookmarksCount, into: &buf)
+        FfiConverterUInt64.write(value.failedTagsCount, into: &buf)
+        FfiConverterSequenceTypeFfiFailedRefExport.write(value.failedBookmarks, into: &buf)
+        FfiConverterSequenceTypeFfiFailedRefExport.write(value.failedTags, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitExportStats_lift(_ buf: RustBuffer) throws -> FfiGitExportStats {
+    return try FfiConverterTypeFfiGitExportStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitExportStats_lower(_ value: FfiGitExportStats) -> RustBuffer {
+    return FfiConverterTypeFfiGitExportStats.lower(value)
+}
+
+
+/**
+ * HEAD of the backing Git repository
+ */
+public struct FfiGitHead {
+    /**
+     * Full name of the ref HEAD points to, e.g. "refs/heads/main" (`None`
+     * if HEAD is detached)
+     */
+    public var refName: String?
+    /**
+     * Commit HEAD resolves to (`None` if the ref HEAD points to doesn't
+     * exist yet)
+     */
+    public var commitId: FfiCommitId?
+    /**
+     * Whether HEAD points directly to a commit rather than to a ref
+     */
+    public var isDetached: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Full name of the ref HEAD points to, e.g. "refs/heads/main" (`None`
+         * if HEAD is detached)
+         */refName: String?, 
+        /**
+         * Commit HEAD resolves to (`None` if the ref HEAD points to doesn't
+         * exist yet)
+         */commitId: FfiCommitId?, 
+        /**
+         * Whether HEAD points directly to a commit rather than to a ref
+         */isDetached: Bool) {
+        self.refName = refName
+        self.commitId = commitId
+        self.isDetached = isDetached
+    }
+}
+
+
+
+extension FfiGitHead: Equatable, Hashable {
+    public static func ==(lhs: FfiGitHead, rhs: FfiGitHead) -> Bool {
+        if lhs.refName != rhs.refName {
+            return false
+        }
+        if lhs.commitId != rhs.commitId {
+            return false
+        }
+        if lhs.isDetached != rhs.isDetached {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(refName)
+        hasher.combine(commitId)
+        hasher.combine(isDetached)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGitHead: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitHead {
+        return
+            try FfiGitHead(
+                refName: FfiConverterOptionString.read(from: &buf), 
+                commitId: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                isDetached: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGitHead, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.refName, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.commitId, into: &buf)
+        FfiConverterBool.write(value.isDetached, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitHead_lift(_ buf: RustBuffer) throws -> FfiGitHead {
+    return try FfiConverterTypeFfiGitHead.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitHead_lower(_ value: FfiGitHead) -> RustBuffer {
+    return FfiConverterTypeFfiGitHead.lower(value)
+}
+
+
+/**
+ * What was reconciled after Git HEAD was moved by an external tool
+ */
+public struct FfiGitHeadSyncReport {
+    /**
+     * Git HEAD as last recorded by jj
+     */
+    public var oldGitHead: FfiCommitId?
+    /**
+     * Git HEAD as found in the Git repository
+     */
+    public var newGitHead: FfiCommitId?
+    /**
+     * Working-copy commit created on top of the new Git HEAD
+     */
+    public var newWorkingCopyCommit: FfiCommitId?
+    /**
+     * Statistics from importing Git refs
+     */
+    public var importStats: FfiGitImportStats
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Git HEAD as last recorded by jj
+         */oldGitHead: FfiCommitId?, 
+        /**
+         * Git HEAD as found in the Git repository
+         */newGitHead: FfiCommitId?, 
+        /**
+         * Working-copy commit created on top of the new Git HEAD
+         */newWorkingCopyCommit: FfiCommitId?, 
+        /**
+         * Statistics from importing Git refs
+         */importStats: FfiGitImportStats) {
+        self.oldGitHead = oldGitHead
+        self.newGitHead = newGitHead
+        self.newWorkingCopyCommit = newWorkingCopyCommit
+        self.importStats = importStats
+    }
+}
+
+
+
+extension FfiGitHeadSyncReport: Equatable, Hashable {
+    public static func ==(lhs: FfiGitHeadSyncReport, rhs: FfiGitHeadSyncReport) -> Bool {
+        if lhs.oldGitHead != rhs.oldGitHead {
+            return false
+        }
+        if lhs.newGitHead != rhs.newGitHead {
+            return false
+        }
+        if lhs.newWorkingCopyCommit != rhs.newWorkingCopyCommit {
+            return false
+        }
+        if lhs.importStats != rhs.importStats {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(oldGitHead)
+        hasher.combine(newGitHead)
+        hasher.combine(newWorkingCopyCommit)
+        hasher.combine(importStats)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGitHeadSyncReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitHeadSyncReport {
+        return
+            try FfiGitHeadSyncReport(
+                oldGitHead: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                newGitHead: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                newWorkingCopyCommit: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                importStats: FfiConverterTypeFfiGitImportStats.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGitHeadSyncReport, into buf: inout [UInt8]) {
+        FfiConverterOptionTypeFfiCommitId.write(value.oldGitHead, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.newGitHead, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.newWorkingCopyCommit, into: &buf)
+        FfiConverterTypeFfiGitImportStats.write(value.importStats, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitHeadSyncReport_lift(_ buf: RustBuffer) throws -> FfiGitHeadSyncReport {
+    return try FfiConverterTypeFfiGitHeadSyncReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitHeadSyncReport_lower(_ value: FfiGitHeadSyncReport) -> RustBuffer {
+    return FfiConverterTypeFfiGitHeadSyncReport.lower(value)
+}
+
+
+/**
+ * Statistics from a git import operation
+ */
+public struct FfiGitImportStats {
+    /**
+     * Number of commits that were abandoned
+     */
+    public var abandonedCommitsCount: UInt64
+    /**
+     * Number of remote bookmarks that changed
+     */
+    public var changedRemoteBookmarksCount: UInt64
+    /**
+     * Number of remote tags that changed
+     */
+    public var changedRemoteTagsCount: UInt64
+    /**
+     * Number of refs that failed to import
+     */
+    public var failedRefsCount: UInt64
+    /**
+     * Commits that were abandoned because they were superseded by imported commits
+     */
+    public var abandonedCommits: [FfiCommitId]
+    /**
+     * Remote bookmarks that changed, sorted by name and remote
+     */
+    public var changedRemoteBookmarks: [FfiImportedRefChange]
+    /**
+     * Remote tags that changed, sorted by name and remote
+     */
+    public var changedRemoteTags: [FfiImportedRefChange]
+    /**
+     * Full names of the Git refs that failed to import, sorted by name
+     */
+    public var failedRefs: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Number of commits that were abandoned
+         */abandonedCommitsCount: UInt64, 
+        /**
+         * Number of remote bookmarks that changed
+         */changedRemoteBookmarksCount: UInt64, 
+        /**
+         * Number of remote tags that changed
+         */changedRemoteTagsCount: UInt64, 
+        /**
+         * Number of refs that failed to import
+         */failedRefsCount: UInt64, 
+        /**
+         * Commits that were abandoned because they were superseded by imported commits
+         */abandonedCommits: [FfiCommitId], 
+        /**
+         * Remote bookmarks that changed, sorted by name and remote
+         */changedRemoteBookmarks: [FfiImportedRefChange], 
+        /**
+         * Remote tags that changed, sorted by name and remote
+         */changedRemoteTags: [FfiImportedRefChange], 
+        /**
+         * Full names of the Git refs that failed to import, sorted by name
+         */failedRefs: [String]) {
+        self.abandonedCommitsCount = abandonedCommitsCount
+        self.changedRemoteBookmarksCount = changedRemoteBookmarksCount
+        self.changedRemoteTagsCount = changedRemoteTagsCount
+        self.failedRefsCount = failedRefsCount
+        self.abandonedCommits = abandonedCommits
+        self.changedRemoteBookmarks = changedRemoteBookmarks
+        self.changedRemoteTags = changedRemoteTags
+        self.failedRefs = failedRefs
+    }
+}
+
+
+
+extension FfiGitImportStats: Equatable, Hashable {
+    public static func ==(lhs: FfiGitImportStats, rhs: FfiGitImportStats) -> Bool {
+        if lhs.abandonedCommitsCount != rhs.abandonedCommitsCount {
+            return false
+        }
+        if lhs.changedRemoteBookmarksCount != rhs.changedRemoteBookmarksCount {
+            return false
+        }
+        if lhs.changedRemoteTagsCount != rhs.changedRemoteTagsCount {
+            return false
+        }
+        if lhs.failedRefsCount != rhs.failedRefsCount {
+            return false
+        }
+        if lhs.abandonedCommits != rhs.abandonedCommits {
+            return false
+        }
+        if lhs.changedRemoteBookmarks != rhs.changedRemoteBookmarks {
+            return false
+        }
+        if lhs.changedRemoteTags != rhs.changedRemoteTags {
+            return false
+        }
+        if lhs.failedRefs != rhs.failedRefs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(abandonedCommitsCount)
+        hasher.combine(changedRemoteBookmarksCount)
+        hasher.combine(changedRemoteTagsCount)
+        hasher.combine(failedRefsCount)
+        hasher.combine(abandonedCommits)
+        hasher.combine(changedRemoteBookmarks)
+        hasher.combine(changedRemoteTags)
+        hasher.combine(failedRefs)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGitImportStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitImportStats {
+        return
+            try FfiGitImportStats(
+                abandonedCommitsCount: FfiConverterUInt64.read(from: &buf), 
+                changedRemoteBookmarksCount: FfiConverterUInt64.read(from: &buf), 
+                changedRemoteTagsCount: FfiConverterUInt64.read(from: &buf), 
+                failedRefsCount: FfiConverterUInt64.read(from: &buf), 
+                abandonedCommits: FfiConverterSequenceTypeFfiCommitId.read(from: &buf), 
+                changedRemoteBookmarks: FfiConverterSequenceTypeFfiImportedRefChange.read(from: &buf), 
+                changedRemoteTags: FfiConverterSequenceTypeFfiImportedRefChange.read(from: &buf), 
+                failedRefs: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGitImportStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.abandonedCommitsCount, into: &buf)
+        FfiConverterUInt64.write(value.changedRemoteBookmarksCount, into: &buf)
+        FfiConverterUInt64.write(value.changedRemoteTagsCount, into: &buf)
+        FfiConverterUInt64.write(value.failedRefsCount, into: &buf)
+        FfiConverterSequenceTypeFfiCommitId.write(value.abandonedCommits, into: &buf)
+        FfiConverterSequenceTypeFfiImportedRefChange.write(value.changedRemoteBookmarks, into: &buf)
+        FfiConverterSequenceTypeFfiImportedRefChange.write(value.changedRemoteTags, into: &buf)
+        FfiConverterSequenceString.write(value.failedRefs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitImportStats_lift(_ buf: RustBuffer) throws -> FfiGitImportStats {
+    return try FfiConverterTypeFfiGitImportStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitImportStats_lower(_ value: FfiGitImportStats) -> RustBuffer {
+    return FfiConverterTypeFfiGitImportStats.lower(value)
+}
+
+
+/**
+ * A progress update of a Git network operation
+ */
+public struct FfiGitProgressUpdate {
+    /**
+     * Overall progress from 0.0 to 1.0, combining counting, compressing,
+     * receiving and resolving objects
+     */
+    public var overall: Float
+    /**
+     * Number of bytes transferred so far, if reported by the transport
+     */
+    public var bytesDownloaded: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Overall progress from 0.0 to 1.0, combining counting, compressing,
+         * receiving and resolving objects
+         */overall: Float, 
+        /**
+         * Number of bytes transferred so far, if reported by the transport
+         */bytesDownloaded: UInt64?) {
+        self.overall = overall
+        self.bytesDownloaded = bytesDownloaded
+    }
+}
+
+
+
+extension FfiGitProgressUpdate: Equatable, Hashable {
+    public static func ==(lhs: FfiGitProgressUpdate, rhs: FfiGitProgressUpdate) -> Bool {
+        if lhs.overall != rhs.overall {
+            return false
+        }
+        if lhs.bytesDownloaded != rhs.bytesDownloaded {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(overall)
+        hasher.combine(bytesDownloaded)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGitProgressUpdate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitProgressUpdate {
+        return
+            try FfiGitProgressUpdate(
+                overall: FfiConverterFloat.read(from: &buf), 
+                bytesDownloaded: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGitProgressUpdate, into buf: inout [UInt8]) {
+        FfiConverterFloat.write(value.overall, into: &buf)
+        FfiConverterOptionUInt64.write(value.bytesDownloaded, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitProgressUpdate_lift(_ buf: RustBuffer) throws -> FfiGitProgressUpdate {
+    return try FfiConverterTypeFfiGitProgressUpdate.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitProgressUpdate_lower(_ value: FfiGitProgressUpdate) -> RustBuffer {
+    return FfiConverterTypeFfiGitProgressUpdate.lower(value)
+}
+
+
+/**
+ * Statistics from a git push operation
+ */
+public struct FfiGitPushStats {
+    /**
+     * Number of refs that were successfully pushed
+     */
+    public var pushedCount: UInt64
+    /**
+     * Number of refs that were rejected (lease failure)
+     */
+    public var rejectedCount: UInt64
+    /**
+     * Number of refs that were rejected by the remote
+     */
+    public var remoteRejectedCount: UInt64
+    /**
+     * Whether all refs were pushed successfully
+     */
+    public var allOk: Bool
+    /**
+     * Full names of the refs that were pushed, e.g. "refs/heads/main"
+     * (including deleted refs)
+     */
+    public var pushedRefs: [String]
+    /**
+     * Full names of the pushed refs that were deleted on the remote, or
+     * would be deleted in a dry run
+     */
+    public var deletedRefs: [String]
+    /**
+     * Refs that were rejected (lease failure)
+     */
+    public var rejectedRefs: [FfiRejectedRef]
+    /**
+     * Refs that were rejected by the remote
+     */
+    public var remoteRejectedRefs: [FfiRejectedRef]
+    /**
+     * Bookmark updates that were pushed, or would be pushed in a dry run
+     */
+    public var updates: [FfiPushUpdate]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Number of refs that were successfully pushed
+         */pushedCount: UInt64, 
+        /**
+         * Number of refs that were rejected (lease failure)
+         */rejectedCount: UInt64, 
+        /**
+         * Number of refs that were rejected by the remote
+         */remoteRejectedCount: UInt64, 
+        /**
+         * Whether all refs were pushed successfully
+         */allOk: Bool, 
+        /**
+         * Full names of the refs that were pushed, e.g. "refs/heads/main"
+         * (including deleted refs)
+         */pushedRefs: [String], 
+        /**
+         * Full names of the pushed refs that were deleted on the remote, or
+         * would be deleted in a dry run
+         */deletedRefs: [String], 
+        /**
+         * Refs that were rejected (lease failure)
+         */rejectedRefs: [FfiRejectedRef], 
+        /**
+         * Refs that were rejected by the remote
+         */remoteRejectedRefs: [FfiRejectedRef], 
+        /**
+         * Bookmark updates that were pushed, or would be pushed in a dry run
+         */updates: [FfiPushUpdate]) {
+        self.pushedCount = pushedCount
+        self.rejectedCount = rejectedCount
+        self.remoteRejectedCount = remoteRejectedCount
+        self.allOk = allOk
+        self.pushedRefs = pushedRefs
+        self.deletedRefs = deletedRefs
+        self.rejectedRefs = rejectedRefs
+        self.remoteRejectedRefs = remoteRejectedRefs
+        self.updates = updates
+    }
+}
+
+
+
+extension FfiGitPushStats: Equatable, Hashable {
+    public static func ==(lhs: FfiGitPushStats, rhs: FfiGitPushStats) -> Bool {
+        if lhs.pushedCount != rhs.pushedCount {
+            return false
+        }
+        if lhs.rejectedCount != rhs.rejectedCount {
+            return false
+        }
+        if lhs.remoteRejectedCount != rhs.remoteRejectedCount {
+            return false
+        }
+        if lhs.allOk != rhs.allOk {
+            return false
+        }
+        if lhs.pushedRefs != rhs.pushedRefs {
+            return false
+        }
+        if lhs.deletedRefs != rhs.deletedRefs {
+            return false
+        }
+        if lhs.rejectedRefs != rhs.rejectedRefs {
+            return false
+        }
+        if lhs.remoteRejectedRefs != rhs.remoteRejectedRefs {
+            return false
+        }
+        if lhs.updates != rhs.updates {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(pushedCount)
+        hasher.combine(rejectedCount)
+        hasher.combine(remoteRejectedCount)
+        hasher.combine(allOk)
+        hasher.combine(pushedRefs)
+        hasher.combine(deletedRefs)
+        hasher.combine(rejectedRefs)
+        hasher.combine(remoteRejectedRefs)
+        hasher.combine(updates)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGitPushStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitPushStats {
+        return
+            try FfiGitPushStats(
+                pushedCount: FfiConverterUInt64.read(from: &buf), 
+                rejectedCount: FfiConverterUInt64.read(from: &buf), 
+                remoteRejectedCount: FfiConverterUInt64.read(from: &buf), 
+                allOk: FfiConverterBool.read(from: &buf), 
+                pushedRefs: FfiConverterSequenceString.read(from: &buf), 
+                deletedRefs: FfiConverterSequenceString.read(from: &buf), 
+                rejectedRefs: FfiConverterSequenceTypeFfiRejectedRef.read(from: &buf), 
+                remoteRejectedRefs: FfiConverterSequenceTypeFfiRejectedRef.read(from: &buf), 
+                updates: FfiConverterSequenceTypeFfiPushUpdate.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGitPushStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.pushedCount, into: &buf)
+        FfiConverterUInt64.write(value.rejectedCount, into: &buf)
+        FfiConverterUInt64.write(value.remoteRejectedCount, into: &buf)
+        FfiConverterBool.write(value.allOk, into: &buf)
+        FfiConverterSequenceString.write(value.pushedRefs, into: &buf)
+        FfiConverterSequenceString.write(value.deletedRefs, into: &buf)
+        FfiConverterSequenceTypeFfiRejectedRef.write(value.rejectedRefs, into: &buf)
+        FfiConverterSequenceTypeFfiRejectedRef.write(value.remoteRejectedRefs, into: &buf)
+        FfiConverterSequenceTypeFfiPushUpdate.write(value.updates, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitPushStats_lift(_ buf: RustBuffer) throws -> FfiGitPushStats {
+    return try FfiConverterTypeFfiGitPushStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitPushStats_lower(_ value: FfiGitPushStats) -> RustBuffer {
+    return FfiConverterTypeFfiGitPushStats.lower(value)
+}
+
+
+/**
+ * A graph edge connecting to a parent commit
+ */
+public struct FfiGraphEdge {
+    /**
+     * The target commit ID (parent)
+     */
+    public var target: FfiCommitId
+    /**
+     * The type of edge
+     */
+    public var edgeType: FfiGraphEdgeType
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The target commit ID (parent)
+         */target: FfiCommitId, 
+        /**
+         * The type of edge
+         */edgeType: FfiGraphEdgeType) {
+        self.target = target
+        self.edgeType = edgeType
+    }
+}
+
+
+
+extension FfiGraphEdge: Equatable, Hashable {
+    public static func ==(lhs: FfiGraphEdge, rhs: FfiGraphEdge) -> Bool {
+        if lhs.target != rhs.target {
+            return false
+        }
+        if lhs.edgeType != rhs.edgeType {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(target)
+        hasher.combine(edgeType)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGraphEdge: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphEdge {
+        return
+            try FfiGraphEdge(
+                target: FfiConverterTypeFfiCommitId.read(from: &buf), 
+                edgeType: FfiConverterTypeFfiGraphEdgeType.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGraphEdge, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiCommitId.write(value.target, into: &buf)
+        FfiConverterTypeFfiGraphEdgeType.write(value.edgeType, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphEdge_lift(_ buf: RustBuffer) throws -> FfiGraphEdge {
+    return try FfiConverterTypeFfiGraphEdge.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphEdge_lower(_ value: FfiGraphEdge) -> RustBuffer {
+    return FfiConverterTypeFfiGraphEdge.lower(value)
+}
+
+
+/**
+ * Options for exporting the commit graph
+ */
+public struct FfiGraphExportOptions {
+    /**
+     * Output format
+     */
+    public var format: FfiGraphFormat
+    /**
+     * Label lines of each node, in order (change ID and description if empty)
+     */
+    public var labels: [FfiGraphLabel]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Output format
+         */format: FfiGraphFormat, 
+        /**
+         * Label lines of each node, in order (change ID and description if empty)
+         */labels: [FfiGraphLabel]) {
+        self.format = format
+        self.labels = labels
+    }
+}
+
+
+
+extension FfiGraphExportOptions: Equatable, Hashable {
+    public static func ==(lhs: FfiGraphExportOptions, rhs: FfiGraphExportOptions) -> Bool {
+        if lhs.format != rhs.format {
+            return false
+        }
+        if lhs.labels != rhs.labels {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(format)
+        hasher.combine(labels)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGraphExportOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphExportOptions {
+        return
+            try FfiGraphExportOptions(
+                format: FfiConverterTypeFfiGraphFormat.read(from: &buf), 
+                labels: FfiConverterSequenceTypeFfiGraphLabel.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGraphExportOptions, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiGraphFormat.write(value.format, into: &buf)
+        FfiConverterSequenceTypeFfiGraphLabel.write(value.labels, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphExportOptions_lift(_ buf: RustBuffer) throws -> FfiGraphExportOptions {
+    return try FfiConverterTypeFfiGraphExportOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphExportOptions_lower(_ value: FfiGraphExportOptions) -> RustBuffer {
+    return FfiConverterTypeFfiGraphExportOptions.lower(value)
+}
+
+
+/**
+ * A lane leaving a node towards one of its graph parents
+ */
+public struct FfiGraphLane {
+    /**
+     * Column of the lane in the following rows
+     */
+    public var column: UInt32
+    /**
+     * The target commit ID (parent)
+     */
+    public var target: FfiCommitId
+    /**
+     * The type of edge
+     */
+    public var edgeType: FfiGraphEdgeType
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Column of the lane in the following rows
+         */column: UInt32, 
+        /**
+         * The target commit ID (parent)
+         */target: FfiCommitId, 
+        /**
+         * The type of edge
+         */edgeType: FfiGraphEdgeType) {
+        self.column = column
+        self.target = target
+        self.edgeType = edgeType
+    }
+}
+
+
+
+extension FfiGraphLane: Equatable, Hashable {
+    public static func ==(lhs: FfiGraphLane, rhs: FfiGraphLane) -> Bool {
+        if lhs.column != rhs.column {
+            return false
+        }
+        if lhs.target != rhs.target {
+            return false
+        }
+        if lhs.edgeType != rhs.edgeType {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(column)
+        hasher.combine(target)
+        hasher.combine(edgeType)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGraphLane: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphLane {
+        return
+            try FfiGraphLane(
+                column: FfiConverterUInt32.read(from: &buf), 
+                target: FfiConverterTypeFfiCommitId.read(from: &buf), 
+                edgeType: FfiConverterTypeFfiGraphEdgeType.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGraphLane, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.column, into: &buf)
+        FfiConverterTypeFfiCommitId.write(value.target, into: &buf)
+        FfiConverterTypeFfiGraphEdgeType.write(value.edgeType, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphLane_lift(_ buf: RustBuffer) throws -> FfiGraphLane {
+    return try FfiConverterTypeFfiGraphLane.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphLane_lower(_ value: FfiGraphLane) -> RustBuffer {
+    return FfiConverterTypeFfiGraphLane.lower(value)
+}
+
+
+/**
+ * Position of a log entry's node and lanes in the graph
+ */
+public struct FfiGraphLayout {
+    /**
+     * Column of the node
+     */
+    public var column: UInt32
+    /**
+     * Columns of lanes from the rows above that end at the node, including
+     * the node's own column if a lane ends there
+     */
+    public var incomingColumns: [UInt32]
+    /**
+     * Columns of lanes that pass the node unchanged, from the rows above
+     * to the rows below
+     */
+    public var passingColumns: [UInt32]
+    /**
+     * Lanes towards the node's parents, in the order of the entry's edges
+     *
+     * A lane moves diagonally when its column differs from the node's.
+     * Lanes of `Missing` edges end right below the node.
+     */
+    public var outgoing: [FfiGraphLane]
+    /**
+     * Number of columns used by the row, including the lanes below it
+     */
+    public var width: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Column of the node
+         */column: UInt32, 
+        /**
+         * Columns of lanes from the rows above that end at the node, including
+         * the node's own column if a lane ends there
+         */incomingColumns: [UInt32], 
+        /**
+         * Columns of lanes that pass the node unchanged, from the rows above
+         * to the rows below
+         */passingColumns: [UInt32], 
+        /**
+         * Lanes towards the node's parents, in the order of the entry's edges
+         *
+         * A lane moves diagonally when its column differs from the node's.
+         * Lanes of `Missing` edges end right below the node.
+         */outgoing: [FfiGraphLane], 
+        /**
+         * Number of columns used by the row, including the lanes below it
+         */width: UInt32) {
+        self.column = column
+        self.incomingColumns = incomingColumns
+        self.passingColumns = passingColumns
+        self.outgoing = outgoing
+        self.width = width
+    }
+}
+
+
+
+extension FfiGraphLayout: Equatable, Hashable {
+    public static func ==(lhs: FfiGraphLayout, rhs: FfiGraphLayout) -> Bool {
+        if lhs.column != rhs.column {
+            return false
+        }
+        if lhs.incomingColumns != rhs.incomingColumns {
+            return false
+        }
+        if lhs.passingColumns != rhs.passingColumns {
+            return false
+        }
+        if lhs.outgoing != rhs.outgoing {
+            return false
+        }
+        if lhs.width != rhs.width {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(column)
+        hasher.combine(incomingColumns)
+        hasher.combine(passingColumns)
+        hasher.combine(outgoing)
+        hasher.combine(width)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGraphLayout: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphLayout {
+        return
+            try FfiGraphLayout(
+                column: FfiConverterUInt32.read(from: &buf), 
+                incomingColumns: FfiConverterSequenceUInt32.read(from: &buf), 
+                passingColumns: FfiConverterSequenceUInt32.read(from: &buf), 
+                outgoing: FfiConverterSequenceTypeFfiGraphLane.read(from: &buf), 
+                width: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiGraphLayout, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.column, into: &buf)
+        FfiConverterSequenceUInt32.write(value.incomingColumns, into: &buf)
+        FfiConverterSequenceUInt32.write(value.passingColumns, into: &buf)
+        FfiConverterSequenceTypeFfiGraphLane.write(value.outgoing, into: &buf)
+        FfiConverterUInt32.write(value.width, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphLayout_lift(_ buf: RustBuffer) throws -> FfiGraphLayout {
+    return try FfiConverterTypeFfiGraphLayout.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphLayout_lower(_ value: FfiGraphLayout) -> RustBuffer {
+    return FfiConverterTypeFfiGraphLayout.lower(value)
+}
+
+
+/**
+ * A remote bookmark or tag that changed during a git import
+ */
+public struct FfiImportedRefChange {
+    /**
+     * Bookmark or tag name
+     */
+    public var name: String
+    /**
+     * Remote name ("git" for refs of the backing Git repository)
+     */
+    public var remote: String
+    /**
+     * Target before the import (`None` if absent or conflicted)
+     */
+    public var oldTarget: FfiCommitId?
+    /**
+     * Target after the import (`None` if absent or conflicted)
+     */
+    public var newTarget: FfiCommitId?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Bookmark or tag name
+         */name: String, 
+        /**
+         * Remote name ("git" for refs of the backing Git repository)
+         */remote: String, 
+        /**
+         * Target before the import (`None` if absent or conflicted)
+         */oldTarget: FfiCommitId?, 
+        /**
+         * Target after the import (`None` if absent or conflicted)
+         */newTarget: FfiCommitId?) {
+        self.name = name
+        self.remote = remote
+        self.oldTarget = oldTarget
+        self.newTarget = newTarget
+    }
+}
+
+
+
+extension FfiImportedRefChange: Equatable, Hashable {
+    public static func ==(lhs: FfiImportedRefChange, rhs: FfiImportedRefChange) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.remote != rhs.remote {
+            return false
+        }
+        if lhs.oldTarget != rhs.oldTarget {
+            return false
+        }
+        if lhs.newTarget != rhs.newTarget {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(remote)
+        hasher.combine(oldTarget)
+        hasher.combine(newTarget)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiImportedRefChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiImportedRefChange {
+        return
+            try FfiImportedRefChange(
+                name: FfiConverterString.read(from: &buf), 
+                remote: FfiConverterString.read(from: &buf), 
+                oldTarget: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                newTarget: FfiConverterOptionTypeFfiCommitId.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiImportedRefChange, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.remote, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.oldTarget, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.newTarget, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiImportedRefChange_lift(_ buf: RustBuffer) throws -> FfiImportedRefChange {
+    return try FfiConverterTypeFfiImportedRefChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiImportedRefChange_lower(_ value: FfiImportedRefChange) -> RustBuffer {
+    return FfiConverterTypeFfiImportedRefChange.lower(value)
+}
+
+
+/**
+ * Options for creating an initial commit when initializing a workspace
+ */
+public struct FfiInitOptions {
+    /**
+     * Description of the initial commit (no initial commit is created if `None`)
+     */
+    public var initialCommitDescription: String?
+    /**
+     * Whether to include the files already present in the workspace directory
+     */
+    public var includeExistingFiles: Bool
+    /**
+     * Name of a local bookmark to point at the initial commit
+     */
+    public var bookmarkName: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Description of the initial commit (no initial commit is created if `None`)
+         */initialCommitDescription: String?, 
+        /**
+         * Whether to include the files already present in the workspace directory
+         */includeExistingFiles: Bool, 
+        /**
+         * Name of a local bookmark to point at the initial commit
+         */bookmarkName: String?) {
+        self.initialCommitDescription = initialCommitDescription
+        self.includeExistingFiles = includeExistingFiles
+        self.bookmarkName = bookmarkName
+    }
+}
+
+
+
+extension FfiInitOptions: Equatable, Hashable {
+    public static func ==(lhs: FfiInitOptions, rhs: FfiInitOptions) -> Bool {
+        if lhs.initialCommitDescription != rhs.initialCommitDescription {
+            return false
+        }
+        if lhs.includeExistingFiles != rhs.includeExistingFiles {
+            return false
+        }
+        if lhs.bookmarkName != rhs.bookmarkName {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(initialCommitDescription)
+        hasher.combine(includeExistingFiles)
+        hasher.combine(bookmarkName)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiInitOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiInitOptions {
+        return
+            try FfiInitOptions(
+                initialCommitDescription: FfiConverterOptionString.read(from: &buf), 
+                includeExistingFiles: FfiConverterBool.read(from: &buf), 
+                bookmarkName: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiInitOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.initialCommitDescription, into: &buf)
+        FfiConverterBool.write(value.includeExistingFiles, into: &buf)
+        FfiConverterOptionString.write(value.bookmarkName, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiInitOptions_lift(_ buf: RustBuffer) throws -> FfiInitOptions {
+    return try FfiConverterTypeFfiInitOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiInitOptions_lower(_ value: FfiInitOptions) -> RustBuffer {
+    return FfiConverterTypeFfiInitOptions.lower(value)
+}
+
+
+/**
+ * Information about this build of the library
+ */
+public struct FfiLibraryInfo {
+    /**
+     * Version of the jj-ffi crate
+     */
+    public var version: String
+    /**
+     * Version of jj-lib
+     */
+    public var jjLibVersion: String
+    /**
+     * Supported commit backend types
+     */
+    public var commitBackends: [String]
+    /**
+     * Supported operation store types
+     */
+    public var opStores: [String]
+    /**
+     * Supported operation heads store types
+     */
+    public var opHeadsStores: [String]
+    /**
+     * Supported index store types
+     */
+    public var indexStores: [String]
+    /**
+     * Supported working copy types
+     */
+    public var workingCopies: [String]
+    /**
+     * Enabled optional features, e.g. "git"
+     */
+    public var features: [String]
+    /**
+     * Available commit signing backends
+     */
+    public var signingBackends: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Version of the jj-ffi crate
+         */version: String, 
+        /**
+         * Version of jj-lib
+         */jjLibVersion: String, 
+        /**
+         * Supported commit backend types
+         */commitBackends: [String], 
+        /**
+         * Supported operation store types
+         */opStores: [String], 
+        /**
+         * Supported operation heads store types
+         */opHeadsStores: [String], 
+        /**
+         * Supported index store types
+         */indexStores: [String], 
+        /**
+         * Supported working copy types
+         */workingCopies: [String], 
+        /**
+         * Enabled optional features, e.g. "git"
+         */features: [String], 
+        /**
+         * Available commit signing backends
+         */signingBackends: [String]) {
+        self.version = version
+        self.jjLibVersion = jjLibVersion
+        self.commitBackends = commitBackends
+        self.opStores = opStores
+        self.opHeadsStores = opHeadsStores
+        self.indexStores = indexStores
+        self.workingCopies = workingCopies
+        self.features = features
+        self.signingBackends = signingBackends
+    }
+}
+
+
+
+extension FfiLibraryInfo: Equatable, Hashable {
+    public static func ==(lhs: FfiLibraryInfo, rhs: FfiLibraryInfo) -> Bool {
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.jjLibVersion != rhs.jjLibVersion {
+            return false
+        }
+        if lhs.commitBackends != rhs.commitBackends {
+            return false
+        }
+        if lhs.opStores != rhs.opStores {
+            return false
+        }
+        if lhs.opHeadsStores != rhs.opHeadsStores {
+            return false
+        }
+        if lhs.indexStores != rhs.indexStores {
+            return false
+        }
+        if lhs.workingCopies != rhs.workingCopies {
+            return false
+        }
+        if lhs.features != rhs.features {
+            return false
+        }
+        if lhs.signingBackends != rhs.signingBackends {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(version)
+        hasher.combine(jjLibVersion)
+        hasher.combine(commitBackends)
+        hasher.combine(opStores)
+        hasher.combine(opHeadsStores)
+        hasher.combine(indexStores)
+        hasher.combine(workingCopies)
+        hasher.combine(features)
+        hasher.combine(signingBackends)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiLibraryInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLibraryInfo {
+        return
+            try FfiLibraryInfo(
+                version: FfiConverterString.read(from: &buf), 
+                jjLibVersion: FfiConverterString.read(from: &buf), 
+                commitBackends: FfiConverterSequenceString.read(from: &buf), 
+                opStores: FfiConverterSequenceString.read(from: &buf), 
+                opHeadsStores: FfiConverterSequenceString.read(from: &buf), 
+                indexStores: FfiConverterSequenceString.read(from: &buf), 
+                workingCopies: FfiConverterSequenceString.read(from: &buf), 
+                features: FfiConverterSequenceString.read(from: &buf), 
+                signingBackends: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiLibraryInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.version, into: &buf)
+        FfiConverterString.write(value.jjLibVersion, into: &buf)
+        FfiConverterSequenceString.write(value.commitBackends, into: &buf)
+        FfiConverterSequenceString.write(value.opStores, into: &buf)
+        FfiConverterSequenceString.write(value.opHeadsStores, into: &buf)
+        FfiConverterSequenceString.write(value.indexStores, into: &buf)
+        FfiConverterSequenceString.write(value.workingCopies, into: &buf)
+        FfiConverterSequenceString.write(value.features, into: &buf)
+        FfiConverterSequenceString.write(value.signingBackends, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLibraryInfo_lift(_ buf: RustBuffer) throws -> FfiLibraryInfo {
+    return try FfiConverterTypeFfiLibraryInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLibraryInfo_lower(_ value: FfiLibraryInfo) -> RustBuffer {
+    return FfiConverterTypeFfiLibraryInfo.lower(value)
+}
+
+
+/**
+ * A log entry containing commit information and graph edges
+ */
+public struct FfiLogEntry {
+    /**
+     * The commit information
+     */
+    public var commit: FfiCommit
+    /**
+     * Edges to parent commits in the graph
+     */
+    public var edges: [FfiGraphEdge]
+    /**
+     * Bookmarks, tags and working copies pointing at the commit
+     */
+    public var refs: FfiCommitRefs
+    /**
+     * Whether the commit's tree equals the auto-merged trees of its parents,
+     * i.e. the commit makes no changes (`None` unless requested with
+     * `include_is_empty`)
+     */
+    public var isEmpty: Bool?
+    /**
+     * Columns of the node and its lanes for drawing the graph
+     */
+    public var layout: FfiGraphLayout
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The commit information
+         */commit: FfiCommit, 
+        /**
+         * Edges to parent commits in the graph
+         */edges: [FfiGraphEdge], 
+        /**
+         * Bookmarks, tags and working copies pointing at the commit
+         */refs: FfiCommitRefs, 
+        /**
+         * Whether the commit's tree equals the auto-merged trees of its parents,
+         * i.e. the commit makes no changes (`None` unless requested with
+         * `include_is_empty`)
+         */isEmpty: Bool?, 
+        /**
+         * Columns of the node and its lanes for drawing the graph
+         */layout: FfiGraphLayout) {
+        self.commit = commit
+        self.edges = edges
+        self.refs = refs
+        self.isEmpty = isEmpty
+        self.layout = layout
+    }
+}
+
+
+
+extension FfiLogEntry: Equatable, Hashable {
+    public static func ==(lhs: FfiLogEntry, rhs: FfiLogEntry) -> Bool {
+        if lhs.commit != rhs.commit {
+            return false
+        }
+        if lhs.edges != rhs.edges {
+            return false
+        }
+        if lhs.refs != rhs.refs {
+            return false
+        }
+        if lhs.isEmpty != rhs.isEmpty {
+            return false
+        }
+        if lhs.layout != rhs.layout {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(commit)
+        hasher.combine(edges)
+        hasher.combine(refs)
+        hasher.combine(isEmpty)
+        hasher.combine(layout)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiLogEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogEntry {
+        return
+            try FfiLogEntry(
+                commit: FfiConverterTypeFfiCommit.read(from: &buf), 
+                edges: FfiConverterSequenceTypeFfiGraphEdge.read(from: &buf), 
+                refs: FfiConverterTypeFfiCommitRefs.read(from: &buf), 
+                isEmpty: FfiConverterOptionBool.read(from: &buf), 
+                layout: FfiConverterTypeFfiGraphLayout.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiLogEntry, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiCommit.write(value.commit, into: &buf)
+        FfiConverterSequenceTypeFfiGraphEdge.write(value.edges, into: &buf)
+        FfiConverterTypeFfiCommitRefs.write(value.refs, into: &buf)
+        FfiConverterOptionBool.write(value.isEmpty, into: &buf)
+        FfiConverterTypeFfiGraphLayout.write(value.layout, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogEntry_lift(_ buf: RustBuffer) throws -> FfiLogEntry {
+    return try FfiConverterTypeFfiLogEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogEntry_lower(_ value: FfiLogEntry) -> RustBuffer {
+    return FfiConverterTypeFfiLogEntry.lower(value)
+}
+
+
+/**
+ * Options for log retrieval
+ */
+public struct FfiLogOptions {
+    /**
+     * Revset expressions to evaluate (if empty, uses default)
+     */
+    public var revisions: [String]
+    /**
+     * Maximum number of commits to return (-1 for no limit)
+     */
+    public var limit: Int64
+    /**
+     * Whether to return commits in reverse order (oldest first)
+     */
+    public var reversed: Bool
+    /**
+     * Approximate memory budget in bytes for materialized commits (`None` for no budget)
+     */
+    public var maxBytes: UInt64?
+    /**
+     * Whether to fill in the shortest unique ID prefix lengths of commits
+     */
+    public var includeIdPrefixes: Bool
+    /**
+     * Whether to fill in `is_empty` of entries, which compares the tree of
+     * each commit with those of its parents
+     */
+    public var includeIsEmpty: Bool
+    /**
+     * Fileset expressions restricting the log to commits that modify
+     * matching files, like `jj log <paths>` (all commits if empty)
+     *
+     * Paths are relative to the workspace root, or to the repository root
+     * for repositories not loaded from a workspace.
+     */
+    public var paths: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Revset expressions to evaluate (if empty, uses default)
+         */revisions: [String], 
+        /**
+         * Maximum number of commits to return (-1 for no limit)
+         */limit: Int64, 
+        /**
+         * Whether to return commits in reverse order (oldest first)
+         */reversed: Bool, 
+        /**
+         * Approximate memory budget in bytes for materialized commits (`None` for no budget)
+         */maxBytes: UInt64?, 
+        /**
+         * Whether to fill in the shortest unique ID prefix lengths of commits
+         */includeIdPrefixes: Bool, 
+        /**
+         * Whether to fill in `is_empty` of entries, which compares the tree of
+         * each commit with those of its parents
+         */includeIsEmpty: Bool, 
+        /**
+         * Fileset expressions restricting the log to commits that modify
+         * matching files, like `jj log <paths>` (all commits if empty)
+         *
+         * Paths are relative to the workspace root, or to the repository root
+         * for repositories not loaded from a workspace.
+         */paths: [String]) {
+        self.revisions = revisions
+        self.limit = limit
+        self.reversed = reversed
+        self.maxBytes = maxBytes
+        self.includeIdPrefixes = includeIdPrefixes
+        self.includeIsEmpty = includeIsEmpty
+        self.paths = paths
+    }
+}
+
+
+
+extension FfiLogOptions: Equatable, Hashable {
+    public static func ==(lhs: FfiLogOptions, rhs: FfiLogOptions) -> Bool {
+        if lhs.revisions != rhs.revisions {
+            return false
+        }
+        if lhs.limit != rhs.limit {
+            return false
+        }
+        if lhs.reversed != rhs.reversed {
+            return false
+        }
+        if lhs.maxBytes != rhs.maxBytes {
+            return false
+        }
+        if lhs.includeIdPrefixes != rhs.includeIdPrefixes {
+            return false
+        }
+        if lhs.includeIsEmpty != rhs.includeIsEmpty {
+            return false
+        }
+        if lhs.paths != rhs.paths {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(revisions)
+        hasher.combine(limit)
+        hasher.combine(reversed)
+        hasher.combine(maxBytes)
+        hasher.combine(includeIdPrefixes)
+        hasher.combine(includeIsEmpty)
+        hasher.combine(paths)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiLogOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogOptions {
+        return
+            try FfiLogOptions(
+                revisions: FfiConverterSequenceString.read(from: &buf), 
+                limit: FfiConverterInt64.read(from: &buf), 
+                reversed: FfiConverterBool.read(from: &buf), 
+                maxBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                includeIdPrefixes: FfiConverterBool.read(from: &buf), 
+                includeIsEmpty: FfiConverterBool.read(from: &buf), 
+                paths: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiLogOptions, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.revisions, into: &buf)
+        FfiConverterInt64.write(value.limit, into: &buf)
+        FfiConverterBool.write(value.reversed, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxBytes, into: &buf)
+        FfiConverterBool.write(value.includeIdPrefixes, into: &buf)
+        FfiConverterBool.write(value.includeIsEmpty, into: &buf)
+        FfiConverterSequenceString.write(value.paths, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogOptions_lift(_ buf: RustBuffer) throws -> FfiLogOptions {
+    return try FfiConverterTypeFfiLogOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogOptions_lower(_ value: FfiLogOptions) -> RustBuffer {
+    return FfiConverterTypeFfiLogOptions.lower(value)
+}
+
+
+/**
+ * A log event emitted by jj-lib or this library
+ */
+public struct FfiLogRecord {
+    /**
+     * Severity of the event
+     */
+    public var level: FfiLogLevel
+    /**
+     * Module path of the code that emitted the event, e.g. `jj_lib::git`
+     */
+    public var target: String
+    /**
+     * The event's message, followed by its other fields as `key=value`
+     */
+    public var message: String
+    /**
+     * Spans the event was emitted in, outermost first, each formatted as
+     * its name followed by its fields
+     */
+    public var spans: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Severity of the event
+         */level: FfiLogLevel, 
+        /**
+         * Module path of the code that emitted the event, e.g. `jj_lib::git`
+         */target: String, 
+        /**
+         * The event's message, followed by its other fields as `key=value`
+         */message: String, 
+        /**
+         * Spans the event was emitted in, outermost first, each formatted as
+         * its name followed by its fields
+         */spans: [String]) {
+        self.level = level
+        self.target = target
+        self.message = message
+        self.spans = spans
+    }
+}
+
+
+
+extension FfiLogRecord: Equatable, Hashable {
+    public static func ==(lhs: FfiLogRecord, rhs: FfiLogRecord) -> Bool {
+        if lhs.level != rhs.level {
+            return false
+        }
+        if lhs.target != rhs.target {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.spans != rhs.spans {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(level)
+        hasher.combine(target)
+        hasher.combine(message)
+        hasher.combine(spans)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiLogRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogRecord {
+        return
+            try FfiLogRecord(
+                level: FfiConverterTypeFfiLogLevel.read(from: &buf), 
+                target: FfiConverterString.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf), 
+                spans: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiLogRecord, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiLogLevel.write(value.level, into: &buf)
+        FfiConverterString.write(value.target, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+        FfiConverterSequenceString.write(value.spans, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogRecord_lift(_ buf: RustBuffer) throws -> FfiLogRecord {
+    return try FfiConverterTypeFfiLogRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogRecord_lower(_ value: FfiLogRecord) -> RustBuffer {
+    return FfiConverterTypeFfiLogRecord.lower(value)
+}
+
+
+/**
+ * Result of a log operation
+ */
+public struct FfiLogResult {
+    /**
+     * Log entries with graph information
+     */
+    public var entries: [FfiLogEntry]
+    /**
+     * Whether entries were cut short by `limit` or `max_bytes`, i.e. more
+     * entries exist
+     */
+    public var truncated: Bool
+    /**
+     * Number of entries left out when truncated, as estimated by the revset
+     * engine (`None` if not truncated)
+     *
+     * Counting only walks the revset, without loading the remaining commits.
+     */
+    public var remainingCount: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Log entries with graph information
+         */entries: [FfiLogEntry], 
+        /**
+         * Whether entries were cut short by `limit` or `max_bytes`, i.e. more
+         * entries exist
+         */truncated: Bool, 
+        /**
+         * Number of entries left out when truncated, as estimated by the revset
+         * engine (`None` if not truncated)
+         *
+         * Counting only walks the revset, without loading the remaining commits.
+         */remainingCount: UInt64?) {
+        self.entries = entries
+        self.truncated = truncated
+        self.remainingCount = remainingCount
+    }
+}
+
+
+
+extension FfiLogResult: Equatable, Hashable {
+    public static func ==(lhs: FfiLogResult, rhs: FfiLogResult) -> Bool {
+        if lhs.entries != rhs.entries {
+            return false
+        }
+        if lhs.truncated != rhs.truncated {
+            return false
+        }
+        if lhs.remainingCount != rhs.remainingCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(entries)
+        hasher.combine(truncated)
+        hasher.combine(remainingCount)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiLogResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogResult {
+        return
+            try FfiLogResult(
+                entries: FfiConverterSequenceTypeFfiLogEntry.read(from: &buf), 
+                truncated: FfiConverterBool.read(from: &buf), 
+                remainingCount: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiLogResult, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeFfiLogEntry.write(value.entries, into: &buf)
+        FfiConverterBool.write(value.truncated, into: &buf)
+        FfiConverterOptionUInt64.write(value.remainingCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogResult_lift(_ buf: RustBuffer) throws -> FfiLogResult {
+    return try FfiConverterTypeFfiLogResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogResult_lower(_ value: FfiLogResult) -> RustBuffer {
+    return FfiConverterTypeFfiLogResult.lower(value)
+}
+
+
+/**
+ * Files written for an external merge tool, like Kaleidoscope or FileMerge
+ */
+public struct FfiMergeToolFiles {
+    /**
+     * Path for the base version, the common ancestor of the sides
+     */
+    public var base: String
+    /**
+     * Path for the left side
+     */
+    public var left: String
+    /**
+     * Path for the right side
+     */
+    public var right: String
+    /**
+     * Path for the merge result, which starts as the conflict materialized
+     * with conflict markers
+     */
+    public var output: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Path for the base version, the common ancestor of the sides
+         */base: String, 
+        /**
+         * Path for the left side
+         */left: String, 
+        /**
+         * Path for the right side
+         */right: String, 
+        /**
+         * Path for the merge result, which starts as the conflict materialized
+         * with conflict markers
+         */output: String) {
+        self.base = base
+        self.left = left
+        self.right = right
+        self.output = output
+    }
+}
+
+
+
+extension FfiMergeToolFiles: Equatable, Hashable {
+    public static func ==(lhs: FfiMergeToolFiles, rhs: FfiMergeToolFiles) -> Bool {
+        if lhs.base != rhs.base {
+            return false
+        }
+        if lhs.left != rhs.left {
+            return false
+        }
+        if lhs.right != rhs.right {
+            return false
+        }
+        if lhs.output != rhs.output {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(base)
+        hasher.combine(left)
+        hasher.combine(right)
+        hasher.combine(output)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiMergeToolFiles: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiMergeToolFiles {
+        return
+            try FfiMergeToolFiles(
+                base: FfiConverterString.read(from: &buf), 
+                left: FfiConverterString.read(from: &buf), 
+                right: FfiConverterString.read(from: &buf), 
+                output: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiMergeToolFiles, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.base, into: &buf)
+        FfiConverterString.write(value.left, into: &buf)
+        FfiConverterString.write(value.right, into: &buf)
+        FfiConverterString.write(value.output, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiMergeToolFiles_lift(_ buf: RustBuffer) throws -> FfiMergeToolFiles {
+    return try FfiConverterTypeFfiMergeToolFiles.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiMergeToolFiles_lower(_ value: FfiMergeToolFiles) -> RustBuffer {
+    return FfiConverterTypeFfiMergeToolFiles.lower(value)
+}
+
+
+/**
+ * Input data for creating a new commit via FFI
+ */
+public struct FfiNewCommit {
+    /**
+     * Parent commit IDs
+     */
+    public var parentIds: [FfiCommitId]
+    /**
+     * Commit description/message
+     */
+    public var description: String
+    /**
+     * Author name (optional, uses settings default if not provided)
+     */
+    public var authorName: String?
+    /**
+     * Author email (optional, uses settings default if not provided)
+     */
+    public var authorEmail: String?
+    /**
+     * Author timestamp (optional, uses current time if not provided)
+     */
+    public var authorTimestamp: FfiTimestamp?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Parent commit IDs
+         */parentIds: [FfiCommitId], 
+        /**
+         * Commit description/message
+         */description: String, 
+        /**
+         * Author name (optional, uses settings default if not provided)
+         */authorName: String?, 
+        /**
+         * Author email (optional, uses settings default if not provided)
+         */authorEmail: String?, 
+        /**
+         * Author timestamp (optional, uses current time if not provided)
+         */authorTimestamp: FfiTimestamp?) {
+        self.parentIds = parentIds
+        self.description = description
+        self.authorName = authorName
+        self.authorEmail = authorEmail
+        self.authorTimestamp = authorTimestamp
+    }
+}
+
+
+
+extension FfiNewCommit: Equatable, Hashable {
+    public static func ==(lhs: FfiNewCommit, rhs: FfiNewCommit) -> Bool {
+        if lhs.parentIds != rhs.parentIds {
+            return false
+        }
+        if lhs.description != rhs.description {
+            return false
+        }
+        if lhs.authorName != rhs.authorName {
+            return false
+        }
+        if lhs.authorEmail != rhs.authorEmail {
+            return false
+        }
+        if lhs.authorTimestamp != rhs.authorTimestamp {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(parentIds)
+        hasher.combine(description)
+        hasher.combine(authorName)
+        hasher.combine(authorEmail)
+        hasher.combine(authorTimestamp)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiNewCommit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiNewCommit {
+        return
+            try FfiNewCommit(
+                parentIds: FfiConverterSequenceTypeFfiCommitId.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                authorName: FfiConverterOptionString.read(from: &buf), 
+                authorEmail: FfiConverterOptionString.read(from: &buf), 
+                authorTimestamp: FfiConverterOptionTypeFfiTimestamp.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiNewCommit, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeFfiCommitId.write(value.parentIds, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterOptionString.write(value.authorName, into: &buf)
+        FfiConverterOptionString.write(value.authorEmail, into: &buf)
+        FfiConverterOptionTypeFfiTimestamp.write(value.authorTimestamp, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiNewCommit_lift(_ buf: RustBuffer) throws -> FfiNewCommit {
+    return try FfiConverterTypeFfiNewCommit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiNewCommit_lower(_ value: FfiNewCommit) -> RustBuffer {
+    return FfiConverterTypeFfiNewCommit.lower(value)
+}
+
+
+/**
+ * An operation in the operation log
+ */
+public struct FfiOperation {
+    /**
+     * Operation ID (hex string)
+     */
+    public var id: String
+    /**
+     * IDs of the parent operations (more than one for merged operations)
+     */
+    public var parentIds: [String]
+    /**
+     * Description of the operation
+     */
+    public var description: String
+    /**
+     * Time the operation's transaction was started
+     */
+    public var startTime: FfiTimestamp
+    /**
+     * Time the operation was committed
+     */
+    public var endTime: FfiTimestamp
+    /**
+     * Name of the user who made the operation
+     */
+    public var username: String
+    /**
+     * Name of the host the operation was made on
+     */
+    public var hostname: String
+    /**
+     * Whether the operation only snapshots the working copy
+     */
+    public var isSnapshot: Bool
+    /**
+     * Tags set on the operation, e.g. by `FfiTransaction::set_tag()`
+     */
+    public var tags: [String: String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Operation ID (hex string)
+         */id: String, 
+        /**
+         * IDs of the parent operations (more than one for merged operations)
+         */parentIds: [String], 
+        /**
+         * Description of the operation
+         */description: String, 
+        /**
+         * Time the operation's transaction was started
+         */startTime: FfiTimestamp, 
+        /**
+         * Time the operation was committed
+         */endTime: FfiTimestamp, 
+        /**
+         * Name of the user who made the operation
+         */username: String, 
+        /**
+         * Name of the host the operation was made on
+         */hostname: String, 
+        /**
+         * Whether the operation only snapshots the working copy
+         */isSnapshot: Bool, 
+        /**
+         * Tags set on the operation, e.g. by `FfiTransaction::set_tag()`
+         */tags: [String: String]) {
+        self.id = id
+        self.parentIds = parentIds
+        self.description = description
+        self.startTime = startTime
+        self.endTime = endTime
+        self.username = username
+        self.hostname = hostname
+        self.isSnapshot = isSnapshot
+        self.tags = tags
+    }
+}
+
+
+
+extension FfiOperation: Equatable, Hashable {
+    public static func ==(lhs: FfiOperation, rhs: FfiOperation) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.parentIds != rhs.parentIds {
+            return false
+        }
+        if lhs.description != rhs.description {
+            return false
+        }
+        if lhs.startTime != rhs.startTime {
+            return false
+        }
+        if lhs.endTime != rhs.endTime {
+            return false
+        }
+        if lhs.username != rhs.username {
+            return false
+        }
+        if lhs.hostname != rhs.hostname {
+            return false
+        }
+        if lhs.isSnapshot != rhs.isSnapshot {
+            return false
+        }
+        if lhs.tags != rhs.tags {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(parentIds)
+        hasher.combine(description)
+        hasher.combine(startTime)
+        hasher.combine(endTime)
+        hasher.combine(username)
+        hasher.combine(hostname)
+        hasher.combine(isSnapshot)
+        hasher.combine(tags)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiOperation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiOperation {
+        return
+            try FfiOperation(
+                id: FfiConverterString.read(from: &buf), 
+                parentIds: FfiConverterSequenceString.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                startTime: FfiConverterTypeFfiTimestamp.read(from: &buf), 
+                endTime: FfiConverterTypeFfiTimestamp.read(from: &buf), 
+                username: FfiConverterString.read(from: &buf), 
+                hostname: FfiConverterString.read(from: &buf), 
+                isSnapshot: FfiConverterBool.read(from: &buf), 
+                tags: FfiConverterDictionaryStringString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiOperation, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterSequenceString.write(value.parentIds, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterTypeFfiTimestamp.write(value.startTime, into: &buf)
+        FfiConverterTypeFfiTimestamp.write(value.endTime, into: &buf)
+        FfiConverterString.write(value.username, into: &buf)
+        FfiConverterString.write(value.hostname, into: &buf)
+        FfiConverterBool.write(value.isSnapshot, into: &buf)
+        FfiConverterDictionaryStringString.write(value.tags, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiOperation_lift(_ buf: RustBuffer) throws -> FfiOperation {
+    return try FfiConverterTypeFfiOperation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiOperation_lower(_ value: FfiOperation) -> RustBuffer {
+    return FfiConverterTypeFfiOperation.lower(value)
+}
+
+
+/**
+ * Divergent operations merged when a repository was loaded
+ */
+public struct FfiOperationMergeReport {
+    /**
+     * IDs of the merged operation heads, oldest first
+     */
+    public var mergedOperationIds: [String]
+    /**
+     * ID of the operation merging them
+     */
+    public var mergeOperationId: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * IDs of the merged operation heads, oldest first
+         */mergedOperationIds: [String], 
+        /**
+         * ID of the operation merging them
+         */mergeOperationId: String) {
+        self.mergedOperationIds = mergedOperationIds
+        self.mergeOperationId = mergeOperationId
+    }
+}
+
+
+
+extension FfiOperationMergeReport: Equatable, Hashable {
+    public static func ==(lhs: FfiOperationMergeReport, rhs: FfiOperationMergeReport) -> Bool {
+        if lhs.mergedOperationIds != rhs.mergedOperationIds {
+            return false
+        }
+        if lhs.mergeOperationId != rhs.mergeOperationId {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(mergedOperationIds)
+        hasher.combine(mergeOperationId)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiOperationMergeReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiOperationMergeReport {
+        return
+            try FfiOperationMergeReport(
+                mergedOperationIds: FfiConverterSequenceString.read(from: &buf), 
+                mergeOperationId: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiOperationMergeReport, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.mergedOperationIds, into: &buf)
+        FfiConverterString.write(value.mergeOperationId, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiOperationMergeReport_lift(_ buf: RustBuffer) throws -> FfiOperationMergeReport {
+    return try FfiConverterTypeFfiOperationMergeReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiOperationMergeReport_lower(_ value: FfiOperationMergeReport) -> RustBuffer {
+    return FfiConverterTypeFfiOperationMergeReport.lower(value)
+}
+
+
+/**
+ * Options for pushing to a remote
+ */
+public struct FfiPushOptions {
+    /**
+     * Whether to only report the updates that would be pushed, without
+     * pushing anything
+     */
+    public var dryRun: Bool
+    /**
+     * Asked for the credentials of the remote's push URL
+     */
+    public var credentials: FfiGitCredentialProvider?
+    /**
+     * Receives the transfer progress
+     */
+    public var progress: FfiGitProgress?
+    /**
+     * Cancels the push, checked during the transfer
+     *
+     * Refs may already be updated on the remote when a push is cancelled,
+     * but the transaction is left unchanged.
+     */
+    public var cancellation: FfiCancellationToken?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Whether to only report the updates that would be pushed, without
+         * pushing anything
+         */dryRun: Bool, 
+        /**
+         * Asked for the credentials of the remote's push URL
+         */credentials: FfiGitCredentialProvider?, 
+        /**
+         * Receives the transfer progress
+         */progress: FfiGitProgress?, 
+        /**
+         * Cancels the push, checked during the transfer
+         *
+         * Refs may already be updated on the remote when a push is cancelled,
+         * but the transaction is left unchanged.
+         */cancellation: FfiCancellationToken?) {
+        self.dryRun = dryRun
+        self.credentials = credentials
+        self.progress = progress
+        self.cancellation = cancellation
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiPushOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiPushOptions {
+        return
+            try FfiPushOptions(
+                dryRun: FfiConverterBool.read(from: &buf), 
+                credentials: FfiConverterOptionTypeFfiGitCredentialProvider.read(from: &buf), 
+                progress: FfiConverterOptionTypeFfiGitProgress.read(from: &buf), 
+                cancellation: FfiConverterOptionTypeFfiCancellationToken.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiPushOptions, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.dryRun, into: &buf)
+        FfiConverterOptionTypeFfiGitCredentialProvider.write(value.credentials, into: &buf)
+        FfiConverterOptionTypeFfiGitProgress.write(value.progress, into: &buf)
+        FfiConverterOptionTypeFfiCancellationToken.write(value.cancellation, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiPushOptions_lift(_ buf: RustBuffer) throws -> FfiPushOptions {
+    return try FfiConverterTypeFfiPushOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiPushOptions_lower(_ value: FfiPushOptions) -> RustBuffer {
+    return FfiConverterTypeFfiPushOptions.lower(value)
+}
+
+
+/**
+ * A bookmark update sent to a remote by a push
+ */
+public struct FfiPushUpdate {
+    /**
+     * Bookmark name
+     */
+    public var bookmark: String
+    /**
+     * Full name of the remote ref, e.g. "refs/heads/main"
+     */
+    public var refName: String
+    /**
+     * Target on the remote before the push (`None` if the ref is new)
+     */
+    public var oldTarget: FfiCommitId?
+    /**
+     * Target on the remote after the push (`None` if the ref is deleted)
+     */
+    public var newTarget: FfiCommitId?
+    /**
+     * Whether the update moves the ref backwards or sideways, so commits on
+     * the remote would be lost
+     */
+    public var isForce: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Bookmark name
+         */bookmark: String, 
+        /**
+         * Full name of the remote ref, e.g. "refs/heads/main"
+         */refName: String, 
+        /**
+         * Target on the remote before the push (`None` if the ref is new)
+         */oldTarget: FfiCommitId?, 
+        /**
+         * Target on the remote after the push (`None` if the ref is deleted)
+         */newTarget: FfiCommitId?, 
+        /**
+         * Whether the update moves the ref backwards or sideways, so commits on
+         * the remote would be lost
+         */isForce: Bool) {
+        self.bookmark = bookmark
+        self.refName = refName
+        self.oldTarget = oldTarget
+        self.newTarget = newTarget
+        self.isForce = isForce
+    }
+}
+
+
+
+extension FfiPushUpdate: Equatable, Hashable {
+    public static func ==(lhs: FfiPushUpdate, rhs: FfiPushUpdate) -> Bool {
+        if lhs.bookmark != rhs.bookmark {
+            return false
+        }
+        if lhs.refName != rhs.refName {
+            return false
+        }
+        if lhs.oldTarget != rhs.oldTarget {
+            return false
+        }
+        if lhs.newTarget != rhs.newTarget {
+            return false
+        }
+        if lhs.isForce != rhs.isForce {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(bookmark)
+        hasher.combine(refName)
+        hasher.combine(oldTarget)
+        hasher.combine(newTarget)
+        hasher.combine(isForce)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiPushUpdate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiPushUpdate {
+        return
+            try FfiPushUpdate(
+                bookmark: FfiConverterString.read(from: &buf), 
+                refName: FfiConverterString.read(from: &buf), 
+                oldTarget: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                newTarget: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                isForce: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiPushUpdate, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.bookmark, into: &buf)
+        FfiConverterString.write(value.refName, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.oldTarget, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.newTarget, into: &buf)
+        FfiConverterBool.write(value.isForce, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiPushUpdate_lift(_ buf: RustBuffer) throws -> FfiPushUpdate {
+    return try FfiConverterTypeFfiPushUpdate.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiPushUpdate_lower(_ value: FfiPushUpdate) -> RustBuffer {
+    return FfiConverterTypeFfiPushUpdate.lower(value)
+}
+
+
+/**
+ * Statistics about a rebase
+ */
+public struct FfiRebaseStats {
+    /**
+     * Number of source commits (and their descendants) that were rebased
+     */
+    public var numRebasedTargets: UInt32
+    /**
+     * Number of other descendants that were rebased
+     */
+    public var numRebasedDescendants: UInt32
+    /**
+     * Number of commits that were already in place and left unchanged
+     */
+    public var numSkippedRebases: UInt32
+    /**
+     * Number of commits that were abandoned because they became empty
+     */
+    public var numAbandonedEmpty: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Number of source commits (and their descendants) that were rebased
+         */numRebasedTargets: UInt32, 
+        /**
+         * Number of other descendants that were rebased
+         */numRebasedDescendants: UInt32, 
+        /**
+         * Number of commits that were already in place and left unchanged
+         */numSkippedRebases: UInt32, 
+        /**
+         * Number of commits that were abandoned because they became empty
+         */numAbandonedEmpty: UInt32) {
+        self.numRebasedTargets = numRebasedTargets
+        self.numRebasedDescendants = numRebasedDescendants
+        self.numSkippedRebases = numSkippedRebases
+        self.numAbandonedEmpty = numAbandonedEmpty
+    }
+}
+
+
+
+extension FfiRebaseStats: Equatable, Hashable {
+    public static func ==(lhs: FfiRebaseStats, rhs: FfiRebaseStats) -> Bool {
+        if lhs.numRebasedTargets != rhs.numRebasedTargets {
+            return false
+        }
+        if lhs.numRebasedDescendants != rhs.numRebasedDescendants {
+            return false
+        }
+        if lhs.numSkippedRebases != rhs.numSkippedRebases {
+            return false
+        }
+        if lhs.numAbandonedEmpty != rhs.numAbandonedEmpty {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(numRebasedTargets)
+        hasher.combine(numRebasedDescendants)
+        hasher.combine(numSkippedRebases)
+        hasher.combine(numAbandonedEmpty)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRebaseStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRebaseStats {
+        return
+            try FfiRebaseStats(
+                numRebasedTargets: FfiConverterUInt32.read(from: &buf), 
+                numRebasedDescendants: FfiConverterUInt32.read(from: &buf), 
+                numSkippedRebases: FfiConverterUInt32.read(from: &buf), 
+                numAbandonedEmpty: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiRebaseStats, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.numRebasedTargets, into: &buf)
+        FfiConverterUInt32.write(value.numRebasedDescendants, into: &buf)
+        FfiConverterUInt32.write(value.numSkippedRebases, into: &buf)
+        FfiConverterUInt32.write(value.numAbandonedEmpty, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRebaseStats_lift(_ buf: RustBuffer) throws -> FfiRebaseStats {
+    return try FfiConverterTypeFfiRebaseStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRebaseStats_lower(_ value: FfiRebaseStats) -> RustBuffer {
+    return FfiConverterTypeFfiRebaseStats.lower(value)
+}
+
+
+/**
+ * A ref that could not be pushed
+ */
+public struct FfiRejectedRef {
+    /**
+     * Full ref name, e.g. "refs/heads/main"
+     */
+    public var name: String
+    /**
+     * Why the ref was rejected, if reported
+     */
+    public var reason: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Full ref name, e.g. "refs/heads/main"
+         */name: String, 
+        /**
+         * Why the ref was rejected, if reported
+         */reason: String?) {
+        self.name = name
+        self.reason = reason
+    }
+}
+
+
+
+extension FfiRejectedRef: Equatable, Hashable {
+    public static func ==(lhs: FfiRejectedRef, rhs: FfiRejectedRef) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.reason != rhs.reason {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(reason)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRejectedRef: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRejectedRef {
+        return
+            try FfiRejectedRef(
+                name: FfiConverterString.read(from: &buf), 
+                reason: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiRejectedRef, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.reason, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRejectedRef_lift(_ buf: RustBuffer) throws -> FfiRejectedRef {
+    return try FfiConverterTypeFfiRejectedRef.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRejectedRef_lower(_ value: FfiRejectedRef) -> RustBuffer {
+    return FfiConverterTypeFfiRejectedRef.lower(value)
+}
+
+
+/**
+ * A bookmark on a remote, with its relation to the local bookmark
+ */
+public struct FfiRemoteBookmark {
+    /**
+     * Bookmark name
+     */
+    public var name: String
+    /**
+     * Remote name
+     */
+    public var remote: String
+    /**
+     * Target commit (`None` if the bookmark is conflicted)
+     */
+    public var target: FfiCommitId?
+    /**
+     * Whether the bookmark points to more than one commit
+     */
+    public var isConflicted: Bool
+    /**
+     * Whether the local bookmark tracks the remote bookmark
+     */
+    public var isTracked: Bool
+    /**
+     * Number of commits reachable from the local bookmark but not the
+     * remote one (`None` if there is no local bookmark)
+     */
+    public var ahead: UInt64?
+    /**
+     * Number of commits reachable from the remote bookmark but not the
+     * local one (`None` if there is no local bookmark)
+     */
+    public var behind: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Bookmark name
+         */name: String, 
+        /**
+         * Remote name
+         */remote: String, 
+        /**
+         * Target commit (`None` if the bookmark is conflicted)
+         */target: FfiCommitId?, 
+        /**
+         * Whether the bookmark points to more than one commit
+         */isConflicted: Bool, 
+        /**
+         * Whether the local bookmark tracks the remote bookmark
+         */isTracked: Bool, 
+        /**
+         * Number of commits reachable from the local bookmark but not the
+         * remote one (`None` if there is no local bookmark)
+         */ahead: UInt64?, 
+        /**
+         * Number of commits reachable from the remote bookmark but not the
+         * local one (`None` if there is no local bookmark)
+         */behind: UInt64?) {
+        self.name = name
+        self.remote = remote
+        self.target = target
+        self.isConflicted = isConflicted
+        self.isTracked = isTracked
+        self.ahead = ahead
+        self.behind = behind
+    }
+}
+
+
+
+extension FfiRemoteBookmark: Equatable, Hashable {
+    public static func ==(lhs: FfiRemoteBookmark, rhs: FfiRemoteBookmark) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.remote != rhs.remote {
+            return false
+        }
+        if lhs.target != rhs.target {
+            return false
+        }
+        if lhs.isConflicted != rhs.isConflicted {
+            return false
+        }
+        if lhs.isTracked != rhs.isTracked {
+            return false
+        }
+        if lhs.ahead != rhs.ahead {
+            return false
+        }
+        if lhs.behind != rhs.behind {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(remote)
+        hasher.combine(target)
+        hasher.combine(isConflicted)
+        hasher.combine(isTracked)
+        hasher.combine(ahead)
+        hasher.combine(behind)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRemoteBookmark: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRemoteBookmark {
+        return
+            try FfiRemoteBookmark(
+                name: FfiConverterString.read(from: &buf), 
+                remote: FfiConverterString.read(from: &buf), 
+                target: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                isConflicted: FfiConverterBool.read(from: &buf), 
+                isTracked: FfiConverterBool.read(from: &buf), 
+                ahead: FfiConverterOptionUInt64.read(from: &buf), 
+                behind: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiRemoteBookmark, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.remote, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.target, into: &buf)
+        FfiConverterBool.write(value.isConflicted, into: &buf)
+        FfiConverterBool.write(value.isTracked, into: &buf)
+        FfiConverterOptionUInt64.write(value.ahead, into: &buf)
+        FfiConverterOptionUInt64.write(value.behind, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRemoteBookmark_lift(_ buf: RustBuffer) throws -> FfiRemoteBookmark {
+    return try FfiConverterTypeFfiRemoteBookmark.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRemoteBookmark_lower(_ value: FfiRemoteBookmark) -> RustBuffer {
+    return FfiConverterTypeFfiRemoteBookmark.lower(value)
+}
+
+
+/**
+ * Store types used by a repository
+ */
+public struct FfiRepoFormat {
+    /**
+     * Commit backend type, e.g. "git"
+     */
+    public var commitBackend: String
+    /**
+     * Operation store type
+     */
+    public var opStore: String
+    /**
+     * Operation heads store type
+     */
+    public var opHeadsStore: String
+    /**
+     * Index store type
+     */
+    public var indexStore: String
+    /**
+     * Working copy type (`None` when inspecting a repo without a workspace)
+     */
+    public var workingCopy: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Commit backend type, e.g. "git"
+         */commitBackend: String, 
+        /**
+         * Operation store type
+         */opStore: String, 
+        /**
+         * Operation heads store type
+         */opHeadsStore: String, 
+        /**
+         * Index store type
+         */indexStore: String, 
+        /**
+         * Working copy type (`None` when inspecting a repo without a workspace)
+         */workingCopy: String?) {
+        self.commitBackend = commitBackend
+        self.opStore = opStore
+        self.opHeadsStore = opHeadsStore
+        self.indexStore = indexStore
+        self.workingCopy = workingCopy
+    }
+}
+
+
+
+extension FfiRepoFormat: Equatable, Hashable {
+    public static func ==(lhs: FfiRepoFormat, rhs: FfiRepoFormat) -> Bool {
+        if lhs.commitBackend != rhs.commitBackend {
+            return false
+        }
+        if lhs.opStore != rhs.opStore {
+            return false
+        }
+        if lhs.opHeadsStore != rhs.opHeadsStore {
+            return false
+        }
+        if lhs.indexStore != rhs.indexStore {
+            return false
+        }
+        if lhs.workingCopy != rhs.workingCopy {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(commitBackend)
+        hasher.combine(opStore)
+        hasher.combine(opHeadsStore)
+        hasher.combine(indexStore)
+        hasher.combine(workingCopy)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRepoFormat: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRepoFormat {
+        return
+            try FfiRepoFormat(
+                commitBackend: FfiConverterString.read(from: &buf), 
+                opStore: FfiConverterString.read(from: &buf), 
+                opHeadsStore: FfiConverterString.read(from: &buf), 
+                indexStore: FfiConverterString.read(from: &buf), 
+                workingCopy: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiRepoFormat, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.commitBackend, into: &buf)
+        FfiConverterString.write(value.opStore, into: &buf)
+        FfiConverterString.write(value.opHeadsStore, into: &buf)
+        FfiConverterString.write(value.indexStore, into: &buf)
+        FfiConverterOptionString.write(value.workingCopy, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRepoFormat_lift(_ buf: RustBuffer) throws -> FfiRepoFormat {
+    return try FfiConverterTypeFfiRepoFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRepoFormat_lower(_ value: FfiRepoFormat) -> RustBuffer {
+    return FfiConverterTypeFfiRepoFormat.lower(value)
+}
+
+
+/**
+ * Options for evaluating a revset
+ */
+public struct FfiRevsetOptions {
+    /**
+     * Cancels the evaluation, checked before each matching commit
+     */
+    public var cancellation: FfiCancellationToken?
+    /**
+     * Approximate memory budget in bytes for the returned IDs or commits
+     * (`None` for no budget)
+     *
+     * The evaluation fails once the result exceeds the budget.
+     */
+    public var maxBytes: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Cancels the evaluation, checked before each matching commit
+         */cancellation: FfiCancellationToken?, 
+        /**
+         * Approximate memory budget in bytes for the returned IDs or commits
+         * (`None` for no budget)
+         *
+         * The evaluation fails once the result exceeds the budget.
+         */maxBytes: UInt64?) {
+        self.cancellation = cancellation
+        self.maxBytes = maxBytes
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRevsetOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRevsetOptions {
+        return
+            try FfiRevsetOptions(
+                cancellation: FfiConverterOptionTypeFfiCancellationToken.read(from: &buf), 
+                maxBytes: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiRevsetOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionTypeFfiCancellationToken.write(value.cancellation, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxBytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRevsetOptions_lift(_ buf: RustBuffer) throws -> FfiRevsetOptions {
+    return try FfiConverterTypeFfiRevsetOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRevsetOptions_lower(_ value: FfiRevsetOptions) -> RustBuffer {
+    return FfiConverterTypeFfiRevsetOptions.lower(value)
+}
+
+
+/**
+ * Input data for rewriting an existing commit via FFI
+ */
+public struct FfiRewriteCommit {
+    /**
+     * The commit ID to rewrite
+     */
+    public var commitId: FfiCommitId
+    /**
+     * New description (optional, keeps original if not provided)
+     */
+    public var newDescription: String?
+    /**
+     * New parent IDs (optional, keeps original if not provided)
+     */
+    public var newParentIds: [FfiCommitId]?
+    /**
+     * New author (optional, keeps original if not provided)
+     */
+    public var newAuthor: FfiSignature?
+    /**
+     * New committer (optional, uses settings and current time if not provided)
+     */
+    public var newCommitter: FfiSignature?
+    /**
+     * Reset the author to the committer, like `jj describe --reset-author`
+     */
+    public var resetAuthor: Bool
+    /**
+     * New author timestamp, keeping the author's name and email (optional)
+     */
+    public var newAuthorTimestamp: FfiTimestamp?
+    /**
+     * Set the author timestamp to the current time, like
+     * `jj metaedit --update-author-timestamp`
+     */
+    public var updateAuthorTimestamp: Bool
+    /**
+     * New committer timestamp, instead of the current time (optional)
+     */
+    public var newCommitterTimestamp: FfiTimestamp?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The commit ID to rewrite
+         */commitId: FfiCommitId, 
+        /**
+         * New description (optional, keeps original if not provided)
+         */newDescription: String?, 
+        /**
+         * New parent IDs (optional, keeps original if not provided)
+         */newParentIds: [FfiCommitId]?, 
+        /**
+         * New author (optional, keeps original if not provided)
+         */newAuthor: FfiSignature?, 
+        /**
+         * New committer (optional, uses settings and current time if not provided)
+         */newCommitter: FfiSignature?, 
+        /**
+         * Reset the author to the committer, like `jj describe --reset-author`
+         */resetAuthor: Bool, 
+        /**
+         * New author timestamp, keeping the author's name and email (optional)
+         */newAuthorTimestamp: FfiTimestamp?, 
+        /**
+         * Set the author timestamp to the current time, like
+         * `jj metaedit --update-author-timestamp`
+         */updateAuthorTimestamp: Bool, 
+        /**
+         * New committer timestamp, instead of the current time (optional)
+         */newCommitterTimestamp: FfiTimestamp?) {
+        self.commitId = commitId
+        self.newDescription = newDescription
+        self.newParentIds = newParentIds
+        self.newAuthor = newAuthor
+        self.newCommitter = newCommitter
+        self.resetAuthor = resetAuthor
+        self.newAuthorTimestamp = newAuthorTimestamp
+        self.updateAuthorTimestamp = updateAuthorTimestamp
+        self.newCommitterTimestamp = newCommitterTimestamp
+    }
+}
+
+
+
+extension FfiRewriteCommit: Equatable, Hashable {
+    public static func ==(lhs: FfiRewriteCommit, rhs: FfiRewriteCommit) -> Bool {
+        if lhs.commitId != rhs.commitId {
+            return false
+        }
+        if lhs.newDescription != rhs.newDescription {
+            return false
+        }
+        if lhs.newParentIds != rhs.newParentIds {
+            return false
+        }
+        if lhs.newAuthor != rhs.newAuthor {
+            return false
+        }
+        if lhs.newCommitter != rhs.newCommitter {
+            return false
+        }
+        if lhs.resetAuthor != rhs.resetAuthor {
+            return false
+        }
+        if lhs.newAuthorTimestamp != rhs.newAuthorTimestamp {
+            return false
+        }
+        if lhs.updateAuthorTimestamp != rhs.updateAuthorTimestamp {
+            return false
+        }
+        if lhs.newCommitterTimestamp != rhs.newCommitterTimestamp {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(commitId)
+        hasher.combine(newDescription)
+        hasher.combine(newParentIds)
+        hasher.combine(newAuthor)
+        hasher.combine(newCommitter)
+        hasher.combine(resetAuthor)
+        hasher.combine(newAuthorTimestamp)
+        hasher.combine(updateAuthorTimestamp)
+        hasher.combine(newCommitterTimestamp)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRewriteCommit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRewriteCommit {
+        return
+            try FfiRewriteCommit(
+                commitId: FfiConverterTypeFfiCommitId.read(from: &buf), 
+                newDescription: FfiConverterOptionString.read(from: &buf), 
+                newParentIds: FfiConverterOptionSequenceTypeFfiCommitId.read(from: &buf), 
+                newAuthor: FfiConverterOptionTypeFfiSignature.read(from: &buf), 
+                newCommitter: FfiConverterOptionTypeFfiSignature.read(from: &buf), 
+                resetAuthor: FfiConverterBool.read(from: &buf), 
+                newAuthorTimestamp: FfiConverterOptionTypeFfiTimestamp.read(from: &buf), 
+                updateAuthorTimestamp: FfiConverterBool.read(from: &buf), 
+                newCommitterTimestamp: FfiConverterOptionTypeFfiTimestamp.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiRewriteCommit, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiCommitId.write(value.commitId, into: &buf)
+        FfiConverterOptionString.write(value.newDescription, into: &buf)
+        FfiConverterOptionSequenceTypeFfiCommitId.write(value.newParentIds, into: &buf)
+        FfiConverterOptionTypeFfiSignature.write(value.newAuthor, into: &buf)
+        FfiConverterOptionTypeFfiSignature.write(value.newCommitter, into: &buf)
+        FfiConverterBool.write(value.resetAuthor, into: &buf)
+        FfiConverterOptionTypeFfiTimestamp.write(value.newAuthorTimestamp, into: &buf)
+        FfiConverterBool.write(value.updateAuthorTimestamp, into: &buf)
+        FfiConverterOptionTypeFfiTimestamp.write(value.newCommitterTimestamp, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRewriteCommit_lift(_ buf: RustBuffer) throws -> FfiRewriteCommit {
+    return try FfiConverterTypeFfiRewriteCommit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRewriteCommit_lower(_ value: FfiRewriteCommit) -> RustBuffer {
+    return FfiConverterTypeFfiRewriteCommit.lower(value)
+}
+
+
+/**
+ * A commit signature (author or committer) for FFI
+ */
+public struct FfiSignature {
+    /**
+     * Name of the person
+     */
+    public var name: String
+    /**
+     * Email address
+     */
+    public var email: String
+    /**
+     * Timestamp of the signature
+     */
+    public var timestamp: FfiTimestamp
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Name of the person
+         */name: String, 
+        /**
+         * Email address
+         */email: String, 
+        /**
+         * Timestamp of the signature
+         */timestamp: FfiTimestamp) {
+        self.name = name
+        self.email = email
+        self.timestamp = timestamp
+    }
+}
+
+
+
+extension FfiSignature: Equatable, Hashable {
+    public static func ==(lhs: FfiSignature, rhs: FfiSignature) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.email != rhs.email {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(email)
+        hasher.combine(timestamp)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiSignature: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSignature {
+        return
+            try FfiSignature(
+                name: FfiConverterString.read(from: &buf), 
+                email: FfiConverterString.read(from: &buf), 
+                timestamp: FfiConverterTypeFfiTimestamp.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiSignature, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.email, into: &buf)
+        FfiConverterTypeFfiTimestamp.write(value.timestamp, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSignature_lift(_ buf: RustBuffer) throws -> FfiSignature {
+    return try FfiConverterTypeFfiSignature.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSignature_lower(_ value: FfiSignature) -> RustBuffer {
+    return FfiConverterTypeFfiSignature.lower(value)
+}
+
+
+/**
+ * Options for snapshotting the working copy
+ */
+public struct FfiSnapshotOptions {
+    /**
+     * Largest untracked file that starts being tracked, in bytes (uses
+     * `snapshot.max-new-file-size` or 1 MiB if not provided)
+     */
+    public var maxNewFileSize: UInt64?
+    /**
+     * Fileset of untracked files that start being tracked (uses
+     * `snapshot.auto-track` or `all()` if not provided)
+     */
+    public var autoTrack: String?
+    /**
+     * Conflict marker style used to parse conflicts in files (keeps the
+     * workspace's style if not provided)
+     *
+     * The style also becomes the workspace's style for later working-copy
+     * updates, like `FfiWorkspace::set_conflict_marker_style()`.
+     */
+    public var conflictMarkerStyle: FfiConflictMarkerStyle?
+    /**
+     * Receives the progress of scanning large working copies
+     */
+    public var progress: FfiSnapshotProgress?
+    /**
+     * Cancels the snapshot, checked while scanning the files
+     *
+     * A cancelled snapshot records nothing.
+     */
+    public var cancellation: FfiCancellationToken?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Largest untracked file that starts being tracked, in bytes (uses
+         * `snapshot.max-new-file-size` or 1 MiB if not provided)
+         */maxNewFileSize: UInt64?, 
+        /**
+         * Fileset of untracked files that start being tracked (uses
+         * `snapshot.auto-track` or `all()` if not provided)
+         */autoTrack: String?, 
+        /**
+         * Conflict marker style used to parse conflicts in files (keeps the
+         * workspace's style if not provided)
+         *
+         * The style also becomes the workspace's style for later working-copy
+         * updates, like `FfiWorkspace::set_conflict_marker_style()`.
+         */conflictMarkerStyle: FfiConflictMarkerStyle?, 
+        /**
+         * Receives the progress of scanning large working copies
+         */progress: FfiSnapshotProgress?, 
+        /**
+         * Cancels the snapshot, checked while scanning the files
+         *
+         * A cancelled snapshot records nothing.
+         */cancellation: FfiCancellationToken?) {
+        self.maxNewFileSize = maxNewFileSize
+        self.autoTrack = autoTrack
+        self.conflictMarkerStyle = conflictMarkerStyle
+        self.progress = progress
+        self.cancellation = cancellation
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiSnapshotOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSnapshotOptions {
+        return
+            try FfiSnapshotOptions(
+                maxNewFileSize: FfiConverterOptionUInt64.read(from: &buf), 
+                autoTrack: FfiConverterOptionString.read(from: &buf), 
+                conflictMarkerStyle: FfiConverterOptionTypeFfiConflictMarkerStyle.read(from: &buf), 
+                progress: FfiConverterOptionTypeFfiSnapshotProgress.read(from: &buf), 
+                cancellation: FfiConverterOptionTypeFfiCancellationToken.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiSnapshotOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt64.write(value.maxNewFileSize, into: &buf)
+        FfiConverterOptionString.write(value.autoTrack, into: &buf)
+        FfiConverterOptionTypeFfiConflictMarkerStyle.write(value.conflictMarkerStyle, into: &buf)
+        FfiConverterOptionTypeFfiSnapshotProgress.write(value.progress, into: &buf)
+        FfiConverterOptionTypeFfiCancellationToken.write(value.cancellation, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSnapshotOptions_lift(_ buf: RustBuffer) throws -> FfiSnapshotOptions {
+    return try FfiConverterTypeFfiSnapshotOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSnapshotOptions_lower(_ value: FfiSnapshotOptions) -> RustBuffer {
+    return FfiConverterTypeFfiSnapshotOptions.lower(value)
+}
+
+
+/**
+ * Progress of a snapshot
+ */
+public struct FfiSnapshotProgressUpdate {
+    /**
+     * Number of files scanned so far
+     */
+    public var filesScanned: UInt64
+    /**
+     * Number of new files that started being tracked (zero until finished)
+     */
+    public var filesAdded: UInt64
+    /**
+     * Repository-relative path of the file scanned last, in internal
+     * ("/"-separated) form (empty once finished)
+     */
+    public var currentPath: String
+    /**
+     * Whether scanning finished, which is the last update
+     */
+    public var finished: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Number of files scanned so far
+         */filesScanned: UInt64, 
+        /**
+         * Number of new files that started being tracked (zero until finished)
+         */filesAdded: UInt64, 
+        /**
+         * Repository-relative path of the file scanned last, in internal
+         * ("/"-separated) form (empty once finished)
+         */currentPath: String, 
+        /**
+         * Whether scanning finished, which is the last update
+         */finished: Bool) {
+        self.filesScanned = filesScanned
+        self.filesAdded = filesAdded
+        self.currentPath = currentPath
+        self.finished = finished
+    }
+}
+
+
+
+extension FfiSnapshotProgressUpdate: Equatable, Hashable {
+    public static func ==(lhs: FfiSnapshotProgressUpdate, rhs: FfiSnapshotProgressUpdate) -> Bool {
+        if lhs.filesScanned != rhs.filesScanned {
+            return false
+        }
+        if lhs.filesAdded != rhs.filesAdded {
+            return false
+        }
+        if lhs.currentPath != rhs.currentPath {
+            return false
+        }
+        if lhs.finished != rhs.finished {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(filesScanned)
+        hasher.combine(filesAdded)
+        hasher.combine(currentPath)
+        hasher.combine(finished)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiSnapshotProgressUpdate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSnapshotProgressUpdate {
+        return
+            try FfiSnapshotProgressUpdate(
+                filesScanned: FfiConverterUInt64.read(from: &buf), 
+                filesAdded: FfiConverterUInt64.read(from: &buf), 
+                currentPath: FfiConverterString.read(from: &buf), 
+                finished: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiSnapshotProgressUpdate, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.filesScanned, into: &buf)
+        FfiConverterUInt64.write(value.filesAdded, into: &buf)
+        FfiConverterString.write(value.currentPath, into: &buf)
+        FfiConverterBool.write(value.finished, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSnapshotProgressUpdate_lift(_ buf: RustBuffer) throws -> FfiSnapshotProgressUpdate {
+    return try FfiConverterTypeFfiSnapshotProgressUpdate.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSnapshotProgressUpdate_lower(_ value: FfiSnapshotProgressUpdate) -> RustBuffer {
+    return FfiConverterTypeFfiSnapshotProgressUpdate.lower(value)
+}
+
+
+/**
+ * Result of snapshotting the working copy
+ */
+public struct FfiSnapshotResult {
+    /**
+     * The repository after the snapshot
+     */
+    public var repo: FfiReadonlyRepo
+    /**
+     * New files that were left untracked
+     */
+    public var untrackedPaths: [FfiUntrackedPath]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The repository after the snapshot
+         */repo: FfiReadonlyRepo, 
+        /**
+         * New files that were left untracked
+         */untrackedPaths: [FfiUntrackedPath]) {
+        self.repo = repo
+        self.untrackedPaths = untrackedPaths
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiSnapshotResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSnapshotResult {
+        return
+            try FfiSnapshotResult(
+                repo: FfiConverterTypeFfiReadonlyRepo.read(from: &buf), 
+                untrackedPaths: FfiConverterSequenceTypeFfiUntrackedPath.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiSnapshotResult, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiReadonlyRepo.write(value.repo, into: &buf)
+        FfiConverterSequenceTypeFfiUntrackedPath.write(value.untrackedPaths, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSnapshotResult_lift(_ buf: RustBuffer) throws -> FfiSnapshotResult {
+    return try FfiConverterTypeFfiSnapshotResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSnapshotResult_lower(_ value: FfiSnapshotResult) -> RustBuffer {
+    return FfiConverterTypeFfiSnapshotResult.lower(value)
+}
+
+
+/**
+ * The two commits resulting from a split
+ */
+public struct FfiSplitResult {
+    /**
+     * Commit with the selected paths (keeps the original change ID)
+     */
+    public var first: FfiCommit
+    /**
+     * Commit with the remaining changes, on top of `first`
+     */
+    public var second: FfiCommit
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Commit with the selected paths (keeps the original change ID)
+         */first: FfiCommit, 
+        /**
+         * Commit with the remaining changes, on top of `first`
+         */second: FfiCommit) {
+        self.first = first
+        self.second = second
+    }
+}
+
+
+
+extension FfiSplitResult: Equatable, Hashable {
+    public static func ==(lhs: FfiSplitResult, rhs: FfiSplitResult) -> Bool {
+        if lhs.first != rhs.first {
+            return false
+        }
+        if lhs.second != rhs.second {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(first)
+        hasher.combine(second)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiSplitResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSplitResult {
+        return
+            try FfiSplitResult(
+                first: FfiConverterTypeFfiCommit.read(from: &buf), 
+                second: FfiConverterTypeFfiCommit.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiSplitResult, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiCommit.write(value.first, into: &buf)
+        FfiConverterTypeFfiCommit.write(value.second, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSplitResult_lift(_ buf: RustBuffer) throws -> FfiSplitResult {
+    return try FfiConverterTypeFfiSplitResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSplitResult_lower(_ value: FfiSplitResult) -> RustBuffer {
+    return FfiConverterTypeFfiSplitResult.lower(value)
+}
+
+
+/**
+ * Options for squashing a commit into another, mirroring `jj squash`
+ */
+public struct FfiSquashOptions {
+    /**
+     * Description of the resulting commit (overrides the other description options)
+     */
+    public var description: String?
+    /**
+     * Keep the destination's description instead of combining descriptions
+     */
+    public var useDestinationMessage: Bool
+    /**
+     * Keep the source commit even if it becomes empty
+     */
+    public var keepEmptied: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Description of the resulting commit (overrides the other description options)
+         */description: String?, 
+        /**
+         * Keep the destination's description instead of combining descriptions
+         */useDestinationMessage: Bool, 
+        /**
+         * Keep the source commit even if it becomes empty
+         */keepEmptied: Bool) {
+        self.description = description
+        self.useDestinationMessage = useDestinationMessage
+        self.keepEmptied = keepEmptied
+    }
+}
+
+
+
+extension FfiSquashOptions: Equatable, Hashable {
+    public static func ==(lhs: FfiSquashOptions, rhs: FfiSquashOptions) -> Bool {
+        if lhs.description != rhs.description {
+            return false
+        }
+        if lhs.useDestinationMessage != rhs.useDestinationMessage {
+            return false
+        }
+        if lhs.keepEmptied != rhs.keepEmptied {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(description)
+        hasher.combine(useDestinationMessage)
+        hasher.combine(keepEmptied)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiSquashOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSquashOptions {
+        return
+            try FfiSquashOptions(
+                description: FfiConverterOptionString.read(from: &buf), 
+                useDestinationMessage: FfiConverterBool.read(from: &buf), 
+                keepEmptied: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiSquashOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.description, into: &buf)
+        FfiConverterBool.write(value.useDestinationMessage, into: &buf)
+        FfiConverterBool.write(value.keepEmptied, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSquashOptions_lift(_ buf: RustBuffer) throws -> FfiSquashOptions {
+    return try FfiConverterTypeFfiSquashOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSquashOptions_lower(_ value: FfiSquashOptions) -> RustBuffer {
+    return FfiConverterTypeFfiSquashOptions.lower(value)
+}
+
+
+/**
+ * Stores of a loaded repository
+ */
+public struct FfiStoreInfo {
+    /**
+     * Commit backend type, e.g. "git" or "simple"
+     */
+    public var commitBackend: String
+    /**
+     * Path of the backing Git repository (`None` for non-Git backends)
+     */
+    public var gitRepoPath: String?
+    /**
+     * Length of commit IDs in bytes
+     */
+    public var commitIdLength: UInt32
+    /**
+     * Length of change IDs in bytes
+     */
+    public var changeIdLength: UInt32
+    /**
+     * Operation store type
+     */
+    public var opStore: String
+    /**
+     * Operation heads store type
+     */
+    public var opHeadsStore: String
+    /**
+     * Index store type
+     */
+    public var indexStore: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Commit backend type, e.g. "git" or "simple"
+         */commitBackend: String, 
+        /**
+         * Path of the backing Git repository (`None` for non-Git backends)
+         */gitRepoPath: String?, 
+        /**
+         * Length of commit IDs in bytes
+         */commitIdLength: UInt32, 
+        /**
+         * Length of change IDs in bytes
+         */changeIdLength: UInt32, 
+        /**
+         * Operation store type
+         */opStore: String, 
+        /**
+         * Operation heads store type
+         */opHeadsStore: String, 
+        /**
+         * Index store type
+         */indexStore: String) {
+        self.commitBackend = commitBackend
+        self.gitRepoPath = gitRepoPath
+        self.commitIdLength = commitIdLength
+        self.changeIdLength = changeIdLength
+        self.opStore = opStore
+        self.opHeadsStore = opHeadsStore
+        self.indexStore = indexStore
+    }
+}
+
+
+
+extension FfiStoreInfo: Equatable, Hashable {
+    public static func ==(lhs: FfiStoreInfo, rhs: FfiStoreInfo) -> Bool {
+        if lhs.commitBackend != rhs.commitBackend {
+            return false
+        }
+        if lhs.gitRepoPath != rhs.gitRepoPath {
+            return false
+        }
+        if lhs.commitIdLength != rhs.commitIdLength {
+            return false
+        }
+        if lhs.changeIdLength != rhs.changeIdLength {
+            return false
+        }
+        if lhs.opStore != rhs.opStore {
+            return false
+        }
+        if lhs.opHeadsStore != rhs.opHeadsStore {
+            return false
+        }
+        if lhs.indexStore != rhs.indexStore {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(commitBackend)
+        hasher.combine(gitRepoPath)
+        hasher.combine(commitIdLength)
+        hasher.combine(changeIdLength)
+        hasher.combine(opStore)
+        hasher.combine(opHeadsStore)
+        hasher.combine(indexStore)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiStoreInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiStoreInfo {
+        return
+            try FfiStoreInfo(
+                commitBackend: FfiConverterString.read(from: &buf), 
+                gitRepoPath: FfiConverterOptionString.read(from: &buf), 
+                commitIdLength: FfiConverterUInt32.read(from: &buf), 
+                changeIdLength: FfiConverterUInt32.read(from: &buf), 
+                opStore: FfiConverterString.read(from: &buf), 
+                opHeadsStore: FfiConverterString.read(from: &buf), 
+                indexStore: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiStoreInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.commitBackend, into: &buf)
+        FfiConverterOptionString.write(value.gitRepoPath, into: &buf)
+        FfiConverterUInt32.write(value.commitIdLength, into: &buf)
+        FfiConverterUInt32.write(value.changeIdLength, into: &buf)
+        FfiConverterString.write(value.opStore, into: &buf)
+        FfiConverterString.write(value.opHeadsStore, into: &buf)
+        FfiConverterString.write(value.indexStore, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiStoreInfo_lift(_ buf: RustBuffer) throws -> FfiStoreInfo {
+    return try FfiConverterTypeFfiStoreInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiStoreInfo_lower(_ value: FfiStoreInfo) -> RustBuffer {
+    return FfiConverterTypeFfiStoreInfo.lower(value)
+}
+
+
+/**
+ * A local tag in the repository view
+ */
+public struct FfiTag {
+    /**
+     * Tag name
+     */
+    public var name: String
+    /**
+     * Target commit (`None` if the tag is conflicted)
+     */
+    public var target: FfiCommitId?
+    /**
+     * Whether the tag points to more than one commit
+     */
+    public var isConflicted: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Tag name
+         */name: String, 
+        /**
+         * Target commit (`None` if the tag is conflicted)
+         */target: FfiCommitId?, 
+        /**
+         * Whether the tag points to more than one commit
+         */isConflicted: Bool) {
+        self.name = name
+        self.target = target
+        self.isConflicted = isConflicted
+    }
+}
+
+
+
+extension FfiTag: Equatable, Hashable {
+    public static func ==(lhs: FfiTag, rhs: FfiTag) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.target != rhs.target {
+            return false
+        }
+        if lhs.isConflicted != rhs.isConflicted {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(target)
+        hasher.combine(isConflicted)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTag: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTag {
+        return
+            try FfiTag(
+                name: FfiConverterString.read(from: &buf), 
+                target: FfiConverterOptionTypeFfiCommitId.read(from: &buf), 
+                isConflicted: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiTag, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionTypeFfiCommitId.write(value.target, into: &buf)
+        FfiConverterBool.write(value.isConflicted, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTag_lift(_ buf: RustBuffer) throws -> FfiTag {
+    return try FfiConverterTypeFfiTag.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTag_lower(_ value: FfiTag) -> RustBuffer {
+    return FfiConverterTypeFfiTag.lower(value)
+}
+
+
+/**
+ * A range of UTF-8 byte offsets within a line's content
+ */
+public struct FfiTextRange {
+    /**
+     * Offset of the first byte
+     */
+    public var start: UInt32
+    /**
+     * Offset past the last byte
+     */
+    public var end: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Offset of the first byte
+         */start: UInt32, 
+        /**
+         * Offset past the last byte
+         */end: UInt32) {
+        self.start = start
+        self.end = end
+    }
+}
+
+
+
+extension FfiTextRange: Equatable, Hashable {
+    public static func ==(lhs: FfiTextRange, rhs: FfiTextRange) -> Bool {
+        if lhs.start != rhs.start {
+            return false
+        }
+        if lhs.end != rhs.end {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(start)
+        hasher.combine(end)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTextRange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTextRange {
+        return
+            try FfiTextRange(
+                start: FfiConverterUInt32.read(from: &buf), 
+                end: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiTextRange, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.start, into: &buf)
+        FfiConverterUInt32.write(value.end, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTextRange_lift(_ buf: RustBuffer) throws -> FfiTextRange {
+    return try FfiConverterTypeFfiTextRange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTextRange_lower(_ value: FfiTextRange) -> RustBuffer {
+    return FfiConverterTypeFfiTextRange.lower(value)
+}
+
+
+/**
+ * A timestamp for FFI
+ */
+public struct FfiTimestamp {
+    /**
+     * Milliseconds since Unix epoch
+     */
+    public var millisSinceEpoch: Int64
+    /**
+     * Timezone offset in minutes from UTC
+     */
+    public var tzOffsetMinutes: Int32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Milliseconds since Unix epoch
+         */millisSinceEpoch: Int64, 
+        /**
+         * Timezone offset in minutes from UTC
+         */tzOffsetMinutes: Int32) {
+        self.millisSinceEpoch = millisSinceEpoch
+        self.tzOffsetMinutes = tzOffsetMinutes
+    }
+}
+
+
+
+extension FfiTimestamp: Equatable, Hashable {
+    public static func ==(lhs: FfiTimestamp, rhs: FfiTimestamp) -> Bool {
+        if lhs.millisSinceEpoch != rhs.millisSinceEpoch {
+            return false
+        }
+        if lhs.tzOffsetMinutes != rhs.tzOffsetMinutes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(millisSinceEpoch)
+        hasher.combine(tzOffsetMinutes)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTimestamp: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTimestamp {
+        return
+            try FfiTimestamp(
+                millisSinceEpoch: FfiConverterInt64.read(from: &buf), 
+                tzOffsetMinutes: FfiConverterInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiTimestamp, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.millisSinceEpoch, into: &buf)
+        FfiConverterInt32.write(value.tzOffsetMinutes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTimestamp_lift(_ buf: RustBuffer) throws -> FfiTimestamp {
+    return try FfiConverterTypeFfiTimestamp.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTimestamp_lower(_ value: FfiTimestamp) -> RustBuffer {
+    return FfiConverterTypeFfiTimestamp.lower(value)
+}
+
+
+/**
+ * A `Key: Value` trailer of a commit description
+ */
+public struct FfiTrailer {
+    /**
+     * Key, e.g. `Signed-off-by`
+     */
+    public var key: String
+    /**
+     * Value, which may span multiple lines
+     */
+    public var value: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Key, e.g. `Signed-off-by`
+         */key: String, 
+        /**
+         * Value, which may span multiple lines
+         */value: String) {
+        self.key = key
+        self.value = value
+    }
+}
+
+
+
+extension FfiTrailer: Equatable, Hashable {
+    public static func ==(lhs: FfiTrailer, rhs: FfiTrailer) -> Bool {
+        if lhs.key != rhs.key {
+            return false
+        }
+        if lhs.value != rhs.value {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(key)
+        hasher.combine(value)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTrailer: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTrailer {
+        return
+            try FfiTrailer(
+                key: FfiConverterString.read(from: &buf), 
+                value: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiTrailer, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterString.write(value.value, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTrailer_lift(_ buf: RustBuffer) throws -> FfiTrailer {
+    return try FfiConverterTypeFfiTrailer.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTrailer_lower(_ value: FfiTrailer) -> RustBuffer {
+    return FfiConverterTypeFfiTrailer.lower(value)
+}
+
+
+/**
+ * Result of committing a transaction
+ */
+public struct FfiTransactionResult {
+    /**
+     * The repository after the transaction
+     */
+    public var repo: FfiReadonlyRepo
+    /**
+     * Commits rewritten in the transaction, mapped to their new commits
+     *
+     * A commit usually maps to a single new commit, or to several if it was
+     * rewritten divergently (e.g. split). Abandoned commits are not included.
+     */
+    public var rewrittenCommits: [FfiCommitId: [FfiCommitId]]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The repository after the transaction
+         */repo: FfiReadonlyRepo, 
+        /**
+         * Commits rewritten in the transaction, mapped to their new commits
+         *
+         * A commit usually maps to a single new commit, or to several if it was
+         * rewritten divergently (e.g. split). Abandoned commits are not included.
+         */rewrittenCommits: [FfiCommitId: [FfiCommitId]]) {
+        self.repo = repo
+        self.rewrittenCommits = rewrittenCommits
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTransactionResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTransactionResult {
+        return
+            try FfiTransactionResult(
+                repo: FfiConverterTypeFfiReadonlyRepo.read(from: &buf), 
+                rewrittenCommits: FfiConverterDictionaryTypeFfiCommitIdSequenceTypeFfiCommitId.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiTransactionResult, into buf: inout [UInt8]) {
+        FfiConverterTypeFfiReadonlyRepo.write(value.repo, into: &buf)
+        FfiConverterDictionaryTypeFfiCommitIdSequenceTypeFfiCommitId.write(value.rewrittenCommits, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTransactionResult_lift(_ buf: RustBuffer) throws -> FfiTransactionResult {
+    return try FfiConverterTypeFfiTransactionResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTransactionResult_lower(_ value: FfiTransactionResult) -> RustBuffer {
+    return FfiConverterTypeFfiTransactionResult.lower(value)
+}
+
+
+/**
+ * An entry in a tree listing
+ */
+public struct FfiTreeEntry {
+    /**
+     * Name of the entry (last path component)
+     */
+    public var name: String
+    /**
+     * Repository-relative path of the entry
+     */
+    public var path: String
+    /**
+     * Type of the entry
+     */
+    public var entryType: FfiTreeEntryType
+    /**
+     * Whether the entry is an executable file
+     */
+    public var executable: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Name of the entry (last path component)
+         */name: String, 
+        /**
+         * Repository-relative path of the entry
+         */path: String, 
+        /**
+         * Type of the entry
+         */entryType: FfiTreeEntryType, 
+        /**
+         * Whether the entry is an executable file
+         */executable: Bool) {
+        self.name = name
+        self.path = path
+        self.entryType = entryType
+        self.executable = executable
+    }
+}
+
+
+
+extension FfiTreeEntry: Equatable, Hashable {
+    public static func ==(lhs: FfiTreeEntry, rhs: FfiTreeEntry) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.entryType != rhs.entryType {
+            return false
+        }
+        if lhs.executable != rhs.executable {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(path)
+        hasher.combine(entryType)
+        hasher.combine(executable)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTreeEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTreeEntry {
+        return
+            try FfiTreeEntry(
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                entryType: FfiConverterTypeFfiTreeEntryType.read(from: &buf), 
+                executable: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiTreeEntry, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterTypeFfiTreeEntryType.write(value.entryType, into: &buf)
+        FfiConverterBool.write(value.executable, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTreeEntry_lift(_ buf: RustBuffer) throws -> FfiTreeEntry {
+    return try FfiConverterTypeFfiTreeEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiTreeEntry_lower(_ value: FfiTreeEntry) -> RustBuffer {
+    return FfiConverterTypeFfiTreeEntry.lower(value)
+}
+
+
+/**
+ * A new file in the working copy that was left untracked
+ */
+public struct FfiUntrackedPath {
+    /**
+     * Repository-relative path in internal ("/"-separated) form
+     */
+    public var path: String
+    /**
+     * Why the file was not tracked
+     */
+    public var reason: FfiUntrackedReason
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Repository-relative path in internal ("/"-separated) form
+         */path: String, 
+        /**
+         * Why the file was not tracked
+         */reason: FfiUntrackedReason) {
+        self.path = path
+        self.reason = reason
+    }
+}
+
+
+
+extension FfiUntrackedPath: Equatable, Hashable {
+    public static func ==(lhs: FfiUntrackedPath, rhs: FfiUntrackedPath) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.reason != rhs.reason {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(reason)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiUntrackedPath: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiUntrackedPath {
+        return
+            try FfiUntrackedPath(
+                path: FfiConverterString.read(from: &buf), 
+                reason: FfiConverterTypeFfiUntrackedReason.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiUntrackedPath, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterTypeFfiUntrackedReason.write(value.reason, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiUntrackedPath_lift(_ buf: RustBuffer) throws -> FfiUntrackedPath {
+    return try FfiConverterTypeFfiUntrackedPath.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiUntrackedPath_lower(_ value: FfiUntrackedPath) -> RustBuffer {
+    return FfiConverterTypeFfiUntrackedPath.lower(value)
+}
+
+
+/**
+ * A workspace of a repository and its working-copy commit
+ */
+public struct FfiWorkspaceInfo {
+    /**
+     * Name of the workspace
+     */
+    public var name: String
+    /**
+     * The workspace's working-copy commit
+     */
+    public var workingCopyCommitId: FfiCommitId
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Name of the workspace
+         */name: String, 
+        /**
+         * The workspace's working-copy commit
+         */workingCopyCommitId: FfiCommitId) {
+        self.name = name
+        self.workingCopyCommitId = workingCopyCommitId
+    }
+}
+
+
+
+extension FfiWorkspaceInfo: Equatable, Hashable {
+    public static func ==(lhs: FfiWorkspaceInfo, rhs: FfiWorkspaceInfo) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.workingCopyCommitId != rhs.workingCopyCommitId {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(workingCopyCommitId)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiWorkspaceInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiWorkspaceInfo {
+        return
+            try FfiWorkspaceInfo(
+                name: FfiConverterString.read(from: &buf), 
+                workingCopyCommitId: FfiConverterTypeFfiCommitId.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FfiWorkspaceInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeFfiCommitId.write(value.workingCopyCommitId, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiWorkspaceInfo_lift(_ buf: RustBuffer) throws -> FfiWorkspaceInfo {
+    return try FfiConverterTypeFfiWorkspaceInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiWorkspaceInfo_lower(_ value: FfiWorkspaceInfo) -> RustBuffer {
+    return FfiConverterTypeFfiWorkspaceInfo.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Time span covered by an activity histogram bucket
+ */
+
+public enum FfiActivityBucket {
+    
+    /**
+     * One calendar day
+     */
+    case day
+    /**
+     * One week starting on Monday
+     */
+    case week
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiActivityBucket: FfiConverterRustBuffer {
+    typealias SwiftType = FfiActivityBucket
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiActivityBucket {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .day
+        
+        case 2: return .week
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiActivityBucket, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .day:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .week:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiActivityBucket_lift(_ buf: RustBuffer) throws -> FfiActivityBucket {
+    return try FfiConverterTypeFfiActivityBucket.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiActivityBucket_lower(_ value: FfiActivityBucket) -> RustBuffer {
+    return FfiConverterTypeFfiActivityBucket.lower(value)
+}
+
+
+
+extension FfiActivityBucket: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Kind of change to a bookmark between two operations
+ */
+
+public enum FfiBookmarkChangeKind {
+    
+    /**
+     * The bookmark was created
+     */
+    case added
+    /**
+     * The bookmark was deleted
+     */
+    case removed
+    /**
+     * The bookmark was moved to another target
+     */
+    case moved
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiBookmarkChangeKind: FfiConverterRustBuffer {
+    typealias SwiftType = FfiBookmarkChangeKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiBookmarkChangeKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .added
+        
+        case 2: return .removed
+        
+        case 3: return .moved
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiBookmarkChangeKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .added:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .removed:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .moved:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiBookmarkChangeKind_lift(_ buf: RustBuffer) throws -> FfiBookmarkChangeKind {
+    return try FfiConverterTypeFfiBookmarkChangeKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiBookmarkChangeKind_lower(_ value: FfiBookmarkChangeKind) -> RustBuffer {
+    return FfiConverterTypeFfiBookmarkChangeKind.lower(value)
+}
+
+
+
+extension FfiBookmarkChangeKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Kind of change made to a file
+ */
+
+public enum FfiChangeType {
+    
+    /**
+     * The file was added
+     */
+    case added
+    /**
+     * The file was modified in place
+     */
+    case modified
+    /**
+     * The file was deleted
+     */
+    case deleted
+    /**
+     * The file was renamed (and possibly modified)
+     */
+    case renamed
+    /**
+     * The file was copied from another file (and possibly modified)
+     */
+    case copied
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiChangeType: FfiConverterRustBuffer {
+    typealias SwiftType = FfiChangeType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiChangeType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .added
+        
+        case 2: return .modified
+        
+        case 3: return .deleted
+        
+        case 4: return .renamed
+        
+        case 5: return .copied
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiChangeType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .added:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .modified:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .deleted:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .renamed:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .copied:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiChangeType_lift(_ buf: RustBuffer) throws -> FfiChangeType {
+    return try FfiConverterTypeFfiChangeType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiChangeType_lower(_ value: FfiChangeType) -> RustBuffer {
+    return FfiConverterTypeFfiChangeType.lower(value)
+}
+
+
+
+extension FfiChangeType: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Style of conflict markers used when materializing conflicts
+ */
+
+public enum FfiConflictMarkerStyle {
+    
+    /**
+     * A snapshot of one side and a series of diffs to apply
+     */
+    case diff
+    /**
+     * A snapshot of each side and base
+     */
+    case snapshot
+    /**
+     * Git's "diff3" style, for compatibility with external tools
+     */
+    case git
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiConflictMarkerStyle: FfiConverterRustBuffer {
+    typealias SwiftType = FfiConflictMarkerStyle
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiConflictMarkerStyle {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .diff
+        
+        case 2: return .snapshot
+        
+        case 3: return .git
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiConflictMarkerStyle, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .diff:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .snapshot:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .git:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiConflictMarkerStyle_lift(_ buf: RustBuffer) throws -> FfiConflictMarkerStyle {
+    return try FfiConverterTypeFfiConflictMarkerStyle.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiConflictMarkerStyle_lower(_ value: FfiConflictMarkerStyle) -> RustBuffer {
+    return FfiConverterTypeFfiConflictMarkerStyle.lower(value)
+}
+
+
+
+extension FfiConflictMarkerStyle: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Type of a line in a diff hunk
+ */
+
+public enum FfiDiffLineType {
+    
+    /**
+     * Unchanged line shown for context
+     */
+    case context
+    /**
+     * Line removed from the old content
+     */
+    case removed
+    /**
+     * Line added in the new content
+     */
+    case added
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiDiffLineType: FfiConverterRustBuffer {
+    typealias SwiftType = FfiDiffLineType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiDiffLineType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .context
+        
+        case 2: return .removed
+        
+        case 3: return .added
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiDiffLineType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .context:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .removed:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .added:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffLineType_lift(_ buf: RustBuffer) throws -> FfiDiffLineType {
+    return try FfiConverterTypeFfiDiffLineType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiDiffLineType_lower(_ value: FfiDiffLineType) -> RustBuffer {
+    return FfiConverterTypeFfiDiffLineType.lower(value)
+}
+
+
+
+extension FfiDiffLineType: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Destination format for exporting a revision
+ */
+
+public enum FfiExportFormat {
+    
+    /**
+     * Write files into a directory
+     */
+    case directory
+    /**
+     * Write files into a zip archive
+     */
+    case zip
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiExportFormat: FfiConverterRustBuffer {
+    typealias SwiftType = FfiExportFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiExportFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .directory
+        
+        case 2: return .zip
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiExportFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .directory:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .zip:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiExportFormat_lift(_ buf: RustBuffer) throws -> FfiExportFormat {
+    return try FfiConverterTypeFfiExportFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiExportFormat_lower(_ value: FfiExportFormat) -> RustBuffer {
+    return FfiConverterTypeFfiExportFormat.lower(value)
+}
+
+
+
+extension FfiExportFormat: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Filesystem monitor used to find changed files when snapshotting
+ */
+
+public enum FfiFsmonitor {
+    
+    /**
+     * No monitor; every snapshot walks the whole working copy
+     */
+    case none
+    /**
+     * Watchman, which must be installed on the system
+     *
+     * Snapshots fall back to walking the working copy if Watchman can't be
+     * queried.
+     */
+    case watchman(
+        /**
+         * Whether to register a Watchman trigger that runs
+         * `jj debug snapshot` when files change, which requires the `jj`
+         * CLI
+         */registerSnapshotTrigger: Bool
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiFsmonitor: FfiConverterRustBuffer {
+    typealias SwiftType = FfiFsmonitor
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiFsmonitor {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .none
+        
+        case 2: return .watchman(registerSnapshotTrigger: try FfiConverterBool.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiFsmonitor, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .none:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .watchman(registerSnapshotTrigger):
+            writeInt(&buf, Int32(2))
+            FfiConverterBool.write(registerSnapshotTrigger, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiFsmonitor_lift(_ buf: RustBuffer) throws -> FfiFsmonitor {
+    return try FfiConverterTypeFfiFsmonitor.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiFsmonitor_lower(_ value: FfiFsmonitor) -> RustBuffer {
+    return FfiConverterTypeFfiFsmonitor.lower(value)
+}
+
+
+
+extension FfiFsmonitor: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Credentials for a Git remote
+ */
+
+public enum FfiGitCredential {
+    
+    /**
+     * Authenticate over SSH with a private key file (without passphrase)
+     */
+    case sshKey(privateKeyPath: String
+    )
+    /**
+     * Authenticate over SSH with the agent listening on a socket
+     */
+    case sshAgent(socketPath: String
+    )
+    /**
+     * Authenticate over HTTPS with a username and password or access token
+     */
+    case userPassword(username: String, password: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGitCredential: FfiConverterRustBuffer {
+    typealias SwiftType = FfiGitCredential
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitCredential {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .sshKey(privateKeyPath: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .sshAgent(socketPath: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .userPassword(username: try FfiConverterString.read(from: &buf), password: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiGitCredential, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .sshKey(privateKeyPath):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(privateKeyPath, into: &buf)
+            
+        
+        case let .sshAgent(socketPath):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(socketPath, into: &buf)
+            
+        
+        case let .userPassword(username,password):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(username, into: &buf)
+            FfiConverterString.write(password, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitCredential_lift(_ buf: RustBuffer) throws -> FfiGitCredential {
+    return try FfiConverterTypeFfiGitCredential.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGitCredential_lower(_ value: FfiGitCredential) -> RustBuffer {
+    return FfiConverterTypeFfiGitCredential.lower(value)
+}
+
+
+
+extension FfiGitCredential: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Graph edge type exposed via FFI
+ */
+
+public enum FfiGraphEdgeType {
+    
+    /**
+     * Direct parent-child relationship
+     */
+    case direct
+    /**
+     * Indirect relationship (some commits in between are elided)
+     */
+    case indirect
+    /**
+     * Missing parent (incomplete history)
+     */
+    case missing
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGraphEdgeType: FfiConverterRustBuffer {
+    typealias SwiftType = FfiGraphEdgeType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphEdgeType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .direct
+        
+        case 2: return .indirect
+        
+        case 3: return .missing
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiGraphEdgeType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .direct:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .indirect:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .missing:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphEdgeType_lift(_ buf: RustBuffer) throws -> FfiGraphEdgeType {
+    return try FfiConverterTypeFfiGraphEdgeType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphEdgeType_lower(_ value: FfiGraphEdgeType) -> RustBuffer {
+    return FfiConverterTypeFfiGraphEdgeType.lower(value)
+}
+
+
+
+extension FfiGraphEdgeType: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Output format of a graph export
+ */
+
+public enum FfiGraphFormat {
+    
+    /**
+     * Graphviz DOT
+     */
+    case dot
+    /**
+     * Mermaid flowchart
+     */
+    case mermaid
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGraphFormat: FfiConverterRustBuffer {
+    typealias SwiftType = FfiGraphFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .dot
+        
+        case 2: return .mermaid
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiGraphFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .dot:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .mermaid:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphFormat_lift(_ buf: RustBuffer) throws -> FfiGraphFormat {
+    return try FfiConverterTypeFfiGraphFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphFormat_lower(_ value: FfiGraphFormat) -> RustBuffer {
+    return FfiConverterTypeFfiGraphFormat.lower(value)
+}
+
+
+
+extension FfiGraphFormat: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A piece of information shown in the label of each commit node
+ */
+
+public enum FfiGraphLabel {
+    
+    /**
+     * Short change ID
+     */
+    case changeId
+    /**
+     * Short commit ID
+     */
+    case commitId
+    /**
+     * First line of the description
+     */
+    case description
+    /**
+     * Author name
+     */
+    case author
+    /**
+     * Local bookmarks pointing at the commit
+     */
+    case bookmarks
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiGraphLabel: FfiConverterRustBuffer {
+    typealias SwiftType = FfiGraphLabel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphLabel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .changeId
+        
+        case 2: return .commitId
+        
+        case 3: return .description
+        
+        case 4: return .author
+        
+        case 5: return .bookmarks
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiGraphLabel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .changeId:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .commitId:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .description:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .author:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .bookmarks:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphLabel_lift(_ buf: RustBuffer) throws -> FfiGraphLabel {
+    return try FfiConverterTypeFfiGraphLabel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiGraphLabel_lower(_ value: FfiGraphLabel) -> RustBuffer {
+    return FfiConverterTypeFfiGraphLabel.lower(value)
+}
+
+
+
+extension FfiGraphLabel: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Severity of a log record
+ */
+
+public enum FfiLogLevel {
+    
+    /**
+     * Failures
+     */
+    case error
+    /**
+     * Unexpected conditions that don't cause failures
+     */
+    case warn
+    /**
+     * Progress of operations
+     */
+    case info
+    /**
+     * Details useful for debugging
+     */
+    case debug
+    /**
+     * Very verbose details, e.g. of each object access
+     */
+    case trace
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiLogLevel: FfiConverterRustBuffer {
+    typealias SwiftType = FfiLogLevel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogLevel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .error
+        
+        case 2: return .warn
+        
+        case 3: return .info
+        
+        case 4: return .debug
+        
+        case 5: return .trace
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiLogLevel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .error:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .warn:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .info:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .debug:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .trace:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogLevel_lift(_ buf: RustBuffer) throws -> FfiLogLevel {
+    return try FfiConverterTypeFfiLogLevel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiLogLevel_lower(_ value: FfiLogLevel) -> RustBuffer {
+    return FfiConverterTypeFfiLogLevel.lower(value)
+}
+
+
+
+extension FfiLogLevel: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Where to move rebased commits, mirroring the `-d`, `-A` and `-B` options
+ * of `jj rebase`
+ */
+
+public enum FfiRebaseDestination {
+    
+    /**
+     * Onto the given parents (`-d`)
+     */
+    case onto(parentIds: [FfiCommitId]
+    )
+    /**
+     * Between the given commits and their children (`--insert-after`)
+     */
+    case insertAfter(commitIds: [FfiCommitId]
+    )
+    /**
+     * Between the given commits and their parents (`--insert-before`)
+     */
+    case insertBefore(commitIds: [FfiCommitId]
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRebaseDestination: FfiConverterRustBuffer {
+    typealias SwiftType = FfiRebaseDestination
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRebaseDestination {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .onto(parentIds: try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
+        )
+        
+        case 2: return .insertAfter(commitIds: try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
+        )
+        
+        case 3: return .insertBefore(commitIds: try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiRebaseDestination, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .onto(parentIds):
+            writeInt(&buf, Int32(1))
+            FfiConverterSequenceTypeFfiCommitId.write(parentIds, into: &buf)
+            
+        
+        case let .insertAfter(commitIds):
+            writeInt(&buf, Int32(2))
+            FfiConverterSequenceTypeFfiCommitId.write(commitIds, into: &buf)
+            
+        
+        case let .insertBefore(commitIds):
+            writeInt(&buf, Int32(3))
+            FfiConverterSequenceTypeFfiCommitId.write(commitIds, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRebaseDestination_lift(_ buf: RustBuffer) throws -> FfiRebaseDestination {
+    return try FfiConverterTypeFfiRebaseDestination.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRebaseDestination_lower(_ value: FfiRebaseDestination) -> RustBuffer {
+    return FfiConverterTypeFfiRebaseDestination.lower(value)
+}
+
+
+
+extension FfiRebaseDestination: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Commits to rebase, mirroring the `-r`, `-s` and `-b` options of `jj rebase`
+ */
+
+public enum FfiRebaseTarget {
+    
+    /**
+     * Only the given commits; their descendants are rebased onto their
+     * parents (`-r`)
+     */
+    case revisions(commitIds: [FfiCommitId]
+    )
+    /**
+     * The given commits and their descendants (`-s`)
+     */
+    case source(commitIds: [FfiCommitId]
+    )
+    /**
+     * The branches containing the given commits: the commits not already
+     * on the destination, with their descendants (`-b`)
+     */
+    case branch(commitIds: [FfiCommitId]
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRebaseTarget: FfiConverterRustBuffer {
+    typealias SwiftType = FfiRebaseTarget
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRebaseTarget {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .revisions(commitIds: try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
+        )
+        
+        case 2: return .source(commitIds: try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
+        )
+        
+        case 3: return .branch(commitIds: try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiRebaseTarget, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .revisions(commitIds):
+            writeInt(&buf, Int32(1))
+            FfiConverterSequenceTypeFfiCommitId.write(commitIds, into: &buf)
+            
+        
+        case let .source(commitIds):
+            writeInt(&buf, Int32(2))
+            FfiConverterSequenceTypeFfiCommitId.write(commitIds, into: &buf)
+            
+        
+        case let .branch(commitIds):
+            writeInt(&buf, Int32(3))
+            FfiConverterSequenceTypeFfiCommitId.write(commitIds, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRebaseTarget_lift(_ buf: RustBuffer) throws -> FfiRebaseTarget {
+    return try FfiConverterTypeFfiRebaseTarget.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRebaseTarget_lower(_ value: FfiRebaseTarget) -> RustBuffer {
+    return FfiConverterTypeFfiRebaseTarget.lower(value)
+}
+
+
+
+extension FfiRebaseTarget: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Why a bookmark or tag could not be exported to Git
+ */
+
+public enum FfiRefExportFailureReason {
+    
+    /**
+     * The name is not allowed in Git
+     */
+    case invalidGitName
     /**
-     * Parent commit IDs
+     * The ref was conflicted after the last import (a re-import should fix it)
      */
-    public var parentIds: [FfiCommitId]
+    case conflictedOldState
     /**
-     * Whether this commit is signed
+     * The ref points to the root commit, which Git doesn't have
      */
-    public var isSigned: Bool
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The commit ID (content-based hash)
-         */id: FfiCommitId, 
-        /**
-         * The change ID (stable identifier across rewrites)
-         */changeId: FfiChangeId, 
-        /**
-         * Commit description/message
-         */description: String, 
-        /**
-         * Author signature
-         */author: FfiSignature, 
-        /**
-         * Committer signature
-         */committer: FfiSignature, 
-        /**
-         * Parent commit IDs
-         */parentIds: [FfiCommitId], 
-        /**
-         * Whether this commit is signed
-         */isSigned: Bool) {
-        self.id = id
-        self.changeId = changeId
-        self.description = description
-        self.author = author
-        self.committer = committer
-        self.parentIds = parentIds
-        self.isSigned = isSigned
-    }
+    case onRootCommit
+    /**
+     * The ref was deleted in jj but modified in Git
+     */
+    case deletedInJjModifiedInGit
+    /**
+     * The ref was added in jj but added with a different target in Git
+     */
+    case addedInJjAddedInGit
+    /**
+     * The ref was modified in jj but deleted in Git
+     */
+    case modifiedInJjDeletedInGit
+    /**
+     * The Git ref could not be deleted
+     */
+    case failedToDelete(message: String
+    )
+    /**
+     * The Git ref could not be updated
+     */
+    case failedToSet(message: String
+    )
 }
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiRefExportFailureReason: FfiConverterRustBuffer {
+    typealias SwiftType = FfiRefExportFailureReason
 
-extension FfiCommit: Equatable, Hashable {
-    public static func ==(lhs: FfiCommit, rhs: FfiCommit) -> Bool {
-        if lhs.id != rhs.id {
-            return false
-        }
-        if lhs.changeId != rhs.changeId {
-            return false
-        }
-        if lhs.description != rhs.description {
-            return false
-        }
-        if lhs.author != rhs.author {
-            return false
-        }
-        if lhs.committer != rhs.committer {
-            return false
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRefExportFailureReason {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .invalidGitName
+        
+        case 2: return .conflictedOldState
+        
+        case 3: return .onRootCommit
+        
+        case 4: return .deletedInJjModifiedInGit
+        
+        case 5: return .addedInJjAddedInGit
+        
+        case 6: return .modifiedInJjDeletedInGit
+        
+        case 7: return .failedToDelete(message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 8: return .failedToSet(message: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
-        if lhs.parentIds != rhs.parentIds {
-            return false
+    }
+
+    public static func write(_ value: FfiRefExportFailureReason, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .invalidGitName:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .conflictedOldState:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .onRootCommit:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .deletedInJjModifiedInGit:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .addedInJjAddedInGit:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .modifiedInJjDeletedInGit:
+            writeInt(&buf, Int32(6))
+        
+        
+        case let .failedToDelete(message):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .failedToSet(message):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(message, into: &buf)
+            
         }
-        if lhs.isSigned != rhs.isSigned {
-            return false
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRefExportFailureReason_lift(_ buf: RustBuffer) throws -> FfiRefExportFailureReason {
+    return try FfiConverterTypeFfiRefExportFailureReason.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiRefExportFailureReason_lower(_ value: FfiRefExportFailureReason) -> RustBuffer {
+    return FfiConverterTypeFfiRefExportFailureReason.lower(value)
+}
+
+
+
+extension FfiRefExportFailureReason: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Which commits to sign when writing, mirroring `signing.behavior`
+ */
+
+public enum FfiSignBehavior {
+    
+    /**
+     * Drop existing signatures
+     */
+    case drop
+    /**
+     * Re-sign commits that were authored by the user and already signed
+     */
+    case keep
+    /**
+     * Sign commits authored by the user, dropping others' signatures
+     */
+    case own
+    /**
+     * Sign all commits
+     */
+    case force
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiSignBehavior: FfiConverterRustBuffer {
+    typealias SwiftType = FfiSignBehavior
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSignBehavior {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .drop
+        
+        case 2: return .keep
+        
+        case 3: return .own
+        
+        case 4: return .force
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
-        return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
-        hasher.combine(changeId)
-        hasher.combine(description)
-        hasher.combine(author)
-        hasher.combine(committer)
-        hasher.combine(parentIds)
-        hasher.combine(isSigned)
+    public static func write(_ value: FfiSignBehavior, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .drop:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .keep:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .own:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .force:
+            writeInt(&buf, Int32(4))
+        
+        }
     }
 }
 
@@ -1703,28 +14626,102 @@ extension FfiCommit: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiCommit: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCommit {
-        return
-            try FfiCommit(
-                id: FfiConverterTypeFfiCommitId.read(from: &buf), 
-                changeId: FfiConverterTypeFfiChangeId.read(from: &buf), 
-                description: FfiConverterString.read(from: &buf), 
-                author: FfiConverterTypeFfiSignature.read(from: &buf), 
-                committer: FfiConverterTypeFfiSignature.read(from: &buf), 
-                parentIds: FfiConverterSequenceTypeFfiCommitId.read(from: &buf), 
-                isSigned: FfiConverterBool.read(from: &buf)
-        )
+public func FfiConverterTypeFfiSignBehavior_lift(_ buf: RustBuffer) throws -> FfiSignBehavior {
+    return try FfiConverterTypeFfiSignBehavior.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiSignBehavior_lower(_ value: FfiSignBehavior) -> RustBuffer {
+    return FfiConverterTypeFfiSignBehavior.lower(value)
+}
+
+
+
+extension FfiSignBehavior: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Type of an entry in a tree
+ */
+
+public enum FfiTreeEntryType {
+    
+    /**
+     * A regular file
+     */
+    case file
+    /**
+     * A directory
+     */
+    case directory
+    /**
+     * A symbolic link
+     */
+    case symlink
+    /**
+     * A Git submodule
+     */
+    case submodule
+    /**
+     * An unresolved conflict
+     */
+    case conflict
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiTreeEntryType: FfiConverterRustBuffer {
+    typealias SwiftType = FfiTreeEntryType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTreeEntryType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .file
+        
+        case 2: return .directory
+        
+        case 3: return .symlink
+        
+        case 4: return .submodule
+        
+        case 5: return .conflict
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: FfiCommit, into buf: inout [UInt8]) {
-        FfiConverterTypeFfiCommitId.write(value.id, into: &buf)
-        FfiConverterTypeFfiChangeId.write(value.changeId, into: &buf)
-        FfiConverterString.write(value.description, into: &buf)
-        FfiConverterTypeFfiSignature.write(value.author, into: &buf)
-        FfiConverterTypeFfiSignature.write(value.committer, into: &buf)
-        FfiConverterSequenceTypeFfiCommitId.write(value.parentIds, into: &buf)
-        FfiConverterBool.write(value.isSigned, into: &buf)
+    public static func write(_ value: FfiTreeEntryType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .file:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .directory:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .symlink:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .submodule:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .conflict:
+            writeInt(&buf, Int32(5))
+        
+        }
     }
 }
 
@@ -1732,66 +14729,76 @@ public struct FfiConverterTypeFfiCommit: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiCommit_lift(_ buf: RustBuffer) throws -> FfiCommit {
-    return try FfiConverterTypeFfiCommit.lift(buf)
+public func FfiConverterTypeFfiTreeEntryType_lift(_ buf: RustBuffer) throws -> FfiTreeEntryType {
+    return try FfiConverterTypeFfiTreeEntryType.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiCommit_lower(_ value: FfiCommit) -> RustBuffer {
-    return FfiConverterTypeFfiCommit.lower(value)
+public func FfiConverterTypeFfiTreeEntryType_lower(_ value: FfiTreeEntryType) -> RustBuffer {
+    return FfiConverterTypeFfiTreeEntryType.lower(value)
 }
 
 
-/**
- * A commit ID represented as a hex string for FFI
- */
-public struct FfiCommitId {
-    /**
-     * Hex-encoded commit ID
-     */
-    public var hex: String
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Hex-encoded commit ID
-         */hex: String) {
-        self.hex = hex
-    }
-}
+extension FfiTreeEntryType: Equatable, Hashable {}
 
 
 
-extension FfiCommitId: Equatable, Hashable {
-    public static func ==(lhs: FfiCommitId, rhs: FfiCommitId) -> Bool {
-        if lhs.hex != rhs.hex {
-            return false
-        }
-        return true
-    }
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Why a new file in the working copy was not tracked
+ */
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(hex)
-    }
+public enum FfiUntrackedReason {
+    
+    /**
+     * The file is larger than the maximum size of new files
+     */
+    case fileTooLarge(size: UInt64, maxSize: UInt64
+    )
+    /**
+     * The file doesn't match the auto-track fileset
+     */
+    case fileNotAutoTracked
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiCommitId: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiCommitId {
-        return
-            try FfiCommitId(
-                hex: FfiConverterString.read(from: &buf)
+public struct FfiConverterTypeFfiUntrackedReason: FfiConverterRustBuffer {
+    typealias SwiftType = FfiUntrackedReason
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiUntrackedReason {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .fileTooLarge(size: try FfiConverterUInt64.read(from: &buf), maxSize: try FfiConverterUInt64.read(from: &buf)
         )
+        
+        case 2: return .fileNotAutoTracked
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: FfiCommitId, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.hex, into: &buf)
+    public static func write(_ value: FfiUntrackedReason, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .fileTooLarge(size,maxSize):
+            writeInt(&buf, Int32(1))
+            FfiConverterUInt64.write(size, into: &buf)
+            FfiConverterUInt64.write(maxSize, into: &buf)
+            
+        
+        case .fileNotAutoTracked:
+            writeInt(&buf, Int32(2))
+        
+        }
     }
 }
 
@@ -1799,1347 +14806,1515 @@ public struct FfiConverterTypeFfiCommitId: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiCommitId_lift(_ buf: RustBuffer) throws -> FfiCommitId {
-    return try FfiConverterTypeFfiCommitId.lift(buf)
+public func FfiConverterTypeFfiUntrackedReason_lift(_ buf: RustBuffer) throws -> FfiUntrackedReason {
+    return try FfiConverterTypeFfiUntrackedReason.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiCommitId_lower(_ value: FfiCommitId) -> RustBuffer {
-    return FfiConverterTypeFfiCommitId.lower(value)
+public func FfiConverterTypeFfiUntrackedReason_lower(_ value: FfiUntrackedReason) -> RustBuffer {
+    return FfiConverterTypeFfiUntrackedReason.lower(value)
 }
 
 
+
+extension FfiUntrackedReason: Equatable, Hashable {}
+
+
+
+
 /**
- * Statistics from a git export operation
+ * Unified error type exposed via FFI
  */
-public struct FfiGitExportStats {
-    /**
-     * Number of bookmarks that failed to export
-     */
-    public var failedBookmarksCount: UInt64
-    /**
-     * Number of tags that failed to export
-     */
-    public var failedTagsCount: UInt64
+public enum JjError {
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Number of bookmarks that failed to export
-         */failedBookmarksCount: UInt64, 
-        /**
-         * Number of tags that failed to export
-         */failedTagsCount: UInt64) {
-        self.failedBookmarksCount = failedBookmarksCount
-        self.failedTagsCount = failedTagsCount
-    }
+    
+    
+    case Workspace(message: String
+    )
+    case Repository(message: String
+    )
+    case IncompatibleRepository(message: String
+    )
+    case Backend(message: String
+    )
+    case CommitNotFound(id: String
+    )
+    case InvalidArgument(message: String
+    )
+    case Revset(message: String
+    )
+    case Template(message: String
+    )
+    case Transaction(message: String
+    )
+    case Git(message: String
+    )
+    case Cancelled(message: String
+    )
+    case Internal(message: String
+    )
 }
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeJjError: FfiConverterRustBuffer {
+    typealias SwiftType = JjError
 
-extension FfiGitExportStats: Equatable, Hashable {
-    public static func ==(lhs: FfiGitExportStats, rhs: FfiGitExportStats) -> Bool {
-        if lhs.failedBookmarksCount != rhs.failedBookmarksCount {
-            return false
-        }
-        if lhs.failedTagsCount != rhs.failedTagsCount {
-            return false
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> JjError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Workspace(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .Repository(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .IncompatibleRepository(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .Backend(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 5: return .CommitNotFound(
+            id: try FfiConverterString.read(from: &buf)
+            )
+        case 6: return .InvalidArgument(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 7: return .Revset(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 8: return .Template(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 9: return .Transaction(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 10: return .Git(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 11: return .Cancelled(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 12: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
         }
-        return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(failedBookmarksCount)
-        hasher.combine(failedTagsCount)
+    public static func write(_ value: JjError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .Workspace(message):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Repository(message):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .IncompatibleRepository(message):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Backend(message):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .CommitNotFound(id):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(id, into: &buf)
+            
+        
+        case let .InvalidArgument(message):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Revset(message):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Template(message):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Transaction(message):
+            writeInt(&buf, Int32(9))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Git(message):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Cancelled(message):
+            writeInt(&buf, Int32(11))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Internal(message):
+            writeInt(&buf, Int32(12))
+            FfiConverterString.write(message, into: &buf)
+            
+        }
     }
 }
 
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeFfiGitExportStats: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitExportStats {
-        return
-            try FfiGitExportStats(
-                failedBookmarksCount: FfiConverterUInt64.read(from: &buf), 
-                failedTagsCount: FfiConverterUInt64.read(from: &buf)
-        )
-    }
+extension JjError: Equatable, Hashable {}
 
-    public static func write(_ value: FfiGitExportStats, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.failedBookmarksCount, into: &buf)
-        FfiConverterUInt64.write(value.failedTagsCount, into: &buf)
+extension JjError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
     }
 }
 
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeFfiGitExportStats_lift(_ buf: RustBuffer) throws -> FfiGitExportStats {
-    return try FfiConverterTypeFfiGitExportStats.lift(buf)
-}
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGitExportStats_lower(_ value: FfiGitExportStats) -> RustBuffer {
-    return FfiConverterTypeFfiGitExportStats.lower(value)
-}
-
-
-/**
- * Statistics from a git import operation
- */
-public struct FfiGitImportStats {
-    /**
-     * Number of commits that were abandoned
-     */
-    public var abandonedCommitsCount: UInt64
-    /**
-     * Number of remote bookmarks that changed
-     */
-    public var changedRemoteBookmarksCount: UInt64
-    /**
-     * Number of remote tags that changed
-     */
-    public var changedRemoteTagsCount: UInt64
-    /**
-     * Number of refs that failed to import
-     */
-    public var failedRefsCount: UInt64
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Number of commits that were abandoned
-         */abandonedCommitsCount: UInt64, 
-        /**
-         * Number of remote bookmarks that changed
-         */changedRemoteBookmarksCount: UInt64, 
-        /**
-         * Number of remote tags that changed
-         */changedRemoteTagsCount: UInt64, 
-        /**
-         * Number of refs that failed to import
-         */failedRefsCount: UInt64) {
-        self.abandonedCommitsCount = abandonedCommitsCount
-        self.changedRemoteBookmarksCount = changedRemoteBookmarksCount
-        self.changedRemoteTagsCount = changedRemoteTagsCount
-        self.failedRefsCount = failedRefsCount
-    }
-}
-
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
 
-
-extension FfiGitImportStats: Equatable, Hashable {
-    public static func ==(lhs: FfiGitImportStats, rhs: FfiGitImportStats) -> Bool {
-        if lhs.abandonedCommitsCount != rhs.abandonedCommitsCount {
-            return false
-        }
-        if lhs.changedRemoteBookmarksCount != rhs.changedRemoteBookmarksCount {
-            return false
-        }
-        if lhs.changedRemoteTagsCount != rhs.changedRemoteTagsCount {
-            return false
-        }
-        if lhs.failedRefsCount != rhs.failedRefsCount {
-            return false
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return true
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(abandonedCommitsCount)
-        hasher.combine(changedRemoteBookmarksCount)
-        hasher.combine(changedRemoteTagsCount)
-        hasher.combine(failedRefsCount)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiGitImportStats: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitImportStats {
-        return
-            try FfiGitImportStats(
-                abandonedCommitsCount: FfiConverterUInt64.read(from: &buf), 
-                changedRemoteBookmarksCount: FfiConverterUInt64.read(from: &buf), 
-                changedRemoteTagsCount: FfiConverterUInt64.read(from: &buf), 
-                failedRefsCount: FfiConverterUInt64.read(from: &buf)
-        )
-    }
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
 
-    public static func write(_ value: FfiGitImportStats, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.abandonedCommitsCount, into: &buf)
-        FfiConverterUInt64.write(value.changedRemoteBookmarksCount, into: &buf)
-        FfiConverterUInt64.write(value.changedRemoteTagsCount, into: &buf)
-        FfiConverterUInt64.write(value.failedRefsCount, into: &buf)
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
     }
-}
 
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeFfiGitImportStats_lift(_ buf: RustBuffer) throws -> FfiGitImportStats {
-    return try FfiConverterTypeFfiGitImportStats.lift(buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGitImportStats_lower(_ value: FfiGitImportStats) -> RustBuffer {
-    return FfiConverterTypeFfiGitImportStats.lower(value)
-}
-
-
-/**
- * Statistics from a git push operation
- */
-public struct FfiGitPushStats {
-    /**
-     * Number of refs that were successfully pushed
-     */
-    public var pushedCount: UInt64
-    /**
-     * Number of refs that were rejected (lease failure)
-     */
-    public var rejectedCount: UInt64
-    /**
-     * Number of refs that were rejected by the remote
-     */
-    public var remoteRejectedCount: UInt64
-    /**
-     * Whether all refs were pushed successfully
-     */
-    public var allOk: Bool
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Number of refs that were successfully pushed
-         */pushedCount: UInt64, 
-        /**
-         * Number of refs that were rejected (lease failure)
-         */rejectedCount: UInt64, 
-        /**
-         * Number of refs that were rejected by the remote
-         */remoteRejectedCount: UInt64, 
-        /**
-         * Whether all refs were pushed successfully
-         */allOk: Bool) {
-        self.pushedCount = pushedCount
-        self.rejectedCount = rejectedCount
-        self.remoteRejectedCount = remoteRejectedCount
-        self.allOk = allOk
-    }
-}
+fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
+    typealias SwiftType = Bool?
 
-
-
-extension FfiGitPushStats: Equatable, Hashable {
-    public static func ==(lhs: FfiGitPushStats, rhs: FfiGitPushStats) -> Bool {
-        if lhs.pushedCount != rhs.pushedCount {
-            return false
-        }
-        if lhs.rejectedCount != rhs.rejectedCount {
-            return false
-        }
-        if lhs.remoteRejectedCount != rhs.remoteRejectedCount {
-            return false
-        }
-        if lhs.allOk != rhs.allOk {
-            return false
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return true
+        writeInt(&buf, Int8(1))
+        FfiConverterBool.write(value, into: &buf)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(pushedCount)
-        hasher.combine(rejectedCount)
-        hasher.combine(remoteRejectedCount)
-        hasher.combine(allOk)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterBool.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiGitPushStats: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGitPushStats {
-        return
-            try FfiGitPushStats(
-                pushedCount: FfiConverterUInt64.read(from: &buf), 
-                rejectedCount: FfiConverterUInt64.read(from: &buf), 
-                remoteRejectedCount: FfiConverterUInt64.read(from: &buf), 
-                allOk: FfiConverterBool.read(from: &buf)
-        )
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func write(_ value: FfiGitPushStats, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.pushedCount, into: &buf)
-        FfiConverterUInt64.write(value.rejectedCount, into: &buf)
-        FfiConverterUInt64.write(value.remoteRejectedCount, into: &buf)
-        FfiConverterBool.write(value.allOk, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGitPushStats_lift(_ buf: RustBuffer) throws -> FfiGitPushStats {
-    return try FfiConverterTypeFfiGitPushStats.lift(buf)
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGitPushStats_lower(_ value: FfiGitPushStats) -> RustBuffer {
-    return FfiConverterTypeFfiGitPushStats.lower(value)
-}
+fileprivate struct FfiConverterOptionTypeFfiCancellationToken: FfiConverterRustBuffer {
+    typealias SwiftType = FfiCancellationToken?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiCancellationToken.write(value, into: &buf)
+    }
 
-/**
- * A graph edge connecting to a parent commit
- */
-public struct FfiGraphEdge {
-    /**
-     * The target commit ID (parent)
-     */
-    public var target: FfiCommitId
-    /**
-     * The type of edge
-     */
-    public var edgeType: FfiGraphEdgeType
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The target commit ID (parent)
-         */target: FfiCommitId, 
-        /**
-         * The type of edge
-         */edgeType: FfiGraphEdgeType) {
-        self.target = target
-        self.edgeType = edgeType
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiCancellationToken.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeFfiGitCredentialProvider: FfiConverterRustBuffer {
+    typealias SwiftType = FfiGitCredentialProvider?
 
-
-extension FfiGraphEdge: Equatable, Hashable {
-    public static func ==(lhs: FfiGraphEdge, rhs: FfiGraphEdge) -> Bool {
-        if lhs.target != rhs.target {
-            return false
-        }
-        if lhs.edgeType != rhs.edgeType {
-            return false
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return true
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiGitCredentialProvider.write(value, into: &buf)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(target)
-        hasher.combine(edgeType)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiGitCredentialProvider.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiGraphEdge: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphEdge {
-        return
-            try FfiGraphEdge(
-                target: FfiConverterTypeFfiCommitId.read(from: &buf), 
-                edgeType: FfiConverterTypeFfiGraphEdgeType.read(from: &buf)
-        )
+fileprivate struct FfiConverterOptionTypeFfiGitProgress: FfiConverterRustBuffer {
+    typealias SwiftType = FfiGitProgress?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiGitProgress.write(value, into: &buf)
     }
 
-    public static func write(_ value: FfiGraphEdge, into buf: inout [UInt8]) {
-        FfiConverterTypeFfiCommitId.write(value.target, into: &buf)
-        FfiConverterTypeFfiGraphEdgeType.write(value.edgeType, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiGitProgress.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGraphEdge_lift(_ buf: RustBuffer) throws -> FfiGraphEdge {
-    return try FfiConverterTypeFfiGraphEdge.lift(buf)
+fileprivate struct FfiConverterOptionTypeFfiSnapshotProgress: FfiConverterRustBuffer {
+    typealias SwiftType = FfiSnapshotProgress?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiSnapshotProgress.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiSnapshotProgress.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGraphEdge_lower(_ value: FfiGraphEdge) -> RustBuffer {
-    return FfiConverterTypeFfiGraphEdge.lower(value)
-}
-
+fileprivate struct FfiConverterOptionTypeFfiCommit: FfiConverterRustBuffer {
+    typealias SwiftType = FfiCommit?
 
-/**
- * A log entry containing commit information and graph edges
- */
-public struct FfiLogEntry {
-    /**
-     * The commit information
-     */
-    public var commit: FfiCommit
-    /**
-     * Edges to parent commits in the graph
-     */
-    public var edges: [FfiGraphEdge]
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiCommit.write(value, into: &buf)
+    }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The commit information
-         */commit: FfiCommit, 
-        /**
-         * Edges to parent commits in the graph
-         */edges: [FfiGraphEdge]) {
-        self.commit = commit
-        self.edges = edges
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiCommit.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeFfiCommitId: FfiConverterRustBuffer {
+    typealias SwiftType = FfiCommitId?
 
-
-extension FfiLogEntry: Equatable, Hashable {
-    public static func ==(lhs: FfiLogEntry, rhs: FfiLogEntry) -> Bool {
-        if lhs.commit != rhs.commit {
-            return false
-        }
-        if lhs.edges != rhs.edges {
-            return false
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return true
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiCommitId.write(value, into: &buf)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(commit)
-        hasher.combine(edges)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiCommitId.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiLogEntry: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogEntry {
-        return
-            try FfiLogEntry(
-                commit: FfiConverterTypeFfiCommit.read(from: &buf), 
-                edges: FfiConverterSequenceTypeFfiGraphEdge.read(from: &buf)
-        )
+fileprivate struct FfiConverterOptionTypeFfiGitHeadSyncReport: FfiConverterRustBuffer {
+    typealias SwiftType = FfiGitHeadSyncReport?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiGitHeadSyncReport.write(value, into: &buf)
     }
 
-    public static func write(_ value: FfiLogEntry, into buf: inout [UInt8]) {
-        FfiConverterTypeFfiCommit.write(value.commit, into: &buf)
-        FfiConverterSequenceTypeFfiGraphEdge.write(value.edges, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiGitHeadSyncReport.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiLogEntry_lift(_ buf: RustBuffer) throws -> FfiLogEntry {
-    return try FfiConverterTypeFfiLogEntry.lift(buf)
+fileprivate struct FfiConverterOptionTypeFfiInitOptions: FfiConverterRustBuffer {
+    typealias SwiftType = FfiInitOptions?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiInitOptions.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiInitOptions.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiLogEntry_lower(_ value: FfiLogEntry) -> RustBuffer {
-    return FfiConverterTypeFfiLogEntry.lower(value)
-}
-
-
-/**
- * Options for log retrieval
- */
-public struct FfiLogOptions {
-    /**
-     * Revset expressions to evaluate (if empty, uses default)
-     */
-    public var revisions: [String]
-    /**
-     * Maximum number of commits to return (-1 for no limit)
-     */
-    public var limit: Int64
-    /**
-     * Whether to return commits in reverse order (oldest first)
-     */
-    public var reversed: Bool
+fileprivate struct FfiConverterOptionTypeFfiOperationMergeReport: FfiConverterRustBuffer {
+    typealias SwiftType = FfiOperationMergeReport?
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Revset expressions to evaluate (if empty, uses default)
-         */revisions: [String], 
-        /**
-         * Maximum number of commits to return (-1 for no limit)
-         */limit: Int64, 
-        /**
-         * Whether to return commits in reverse order (oldest first)
-         */reversed: Bool) {
-        self.revisions = revisions
-        self.limit = limit
-        self.reversed = reversed
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiOperationMergeReport.write(value, into: &buf)
     }
-}
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiOperationMergeReport.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeFfiSignature: FfiConverterRustBuffer {
+    typealias SwiftType = FfiSignature?
 
-extension FfiLogOptions: Equatable, Hashable {
-    public static func ==(lhs: FfiLogOptions, rhs: FfiLogOptions) -> Bool {
-        if lhs.revisions != rhs.revisions {
-            return false
-        }
-        if lhs.limit != rhs.limit {
-            return false
-        }
-        if lhs.reversed != rhs.reversed {
-            return false
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return true
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiSignature.write(value, into: &buf)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(revisions)
-        hasher.combine(limit)
-        hasher.combine(reversed)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiSignature.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiLogOptions: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogOptions {
-        return
-            try FfiLogOptions(
-                revisions: FfiConverterSequenceString.read(from: &buf), 
-                limit: FfiConverterInt64.read(from: &buf), 
-                reversed: FfiConverterBool.read(from: &buf)
-        )
+fileprivate struct FfiConverterOptionTypeFfiTimestamp: FfiConverterRustBuffer {
+    typealias SwiftType = FfiTimestamp?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiTimestamp.write(value, into: &buf)
     }
 
-    public static func write(_ value: FfiLogOptions, into buf: inout [UInt8]) {
-        FfiConverterSequenceString.write(value.revisions, into: &buf)
-        FfiConverterInt64.write(value.limit, into: &buf)
-        FfiConverterBool.write(value.reversed, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiTimestamp.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiLogOptions_lift(_ buf: RustBuffer) throws -> FfiLogOptions {
-    return try FfiConverterTypeFfiLogOptions.lift(buf)
+fileprivate struct FfiConverterOptionTypeFfiConflictMarkerStyle: FfiConverterRustBuffer {
+    typealias SwiftType = FfiConflictMarkerStyle?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiConflictMarkerStyle.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiConflictMarkerStyle.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiLogOptions_lower(_ value: FfiLogOptions) -> RustBuffer {
-    return FfiConverterTypeFfiLogOptions.lower(value)
-}
-
+fileprivate struct FfiConverterOptionTypeFfiGitCredential: FfiConverterRustBuffer {
+    typealias SwiftType = FfiGitCredential?
 
-/**
- * Result of a log operation
- */
-public struct FfiLogResult {
-    /**
-     * Log entries with graph information
-     */
-    public var entries: [FfiLogEntry]
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiGitCredential.write(value, into: &buf)
+    }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Log entries with graph information
-         */entries: [FfiLogEntry]) {
-        self.entries = entries
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiGitCredential.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeFfiTreeEntryType: FfiConverterRustBuffer {
+    typealias SwiftType = FfiTreeEntryType?
 
-
-extension FfiLogResult: Equatable, Hashable {
-    public static func ==(lhs: FfiLogResult, rhs: FfiLogResult) -> Bool {
-        if lhs.entries != rhs.entries {
-            return false
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return true
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFfiTreeEntryType.write(value, into: &buf)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(entries)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFfiTreeEntryType.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiLogResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiLogResult {
-        return
-            try FfiLogResult(
-                entries: FfiConverterSequenceTypeFfiLogEntry.read(from: &buf)
-        )
+fileprivate struct FfiConverterOptionSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceString.write(value, into: &buf)
     }
 
-    public static func write(_ value: FfiLogResult, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeFfiLogEntry.write(value.entries, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiLogResult_lift(_ buf: RustBuffer) throws -> FfiLogResult {
-    return try FfiConverterTypeFfiLogResult.lift(buf)
+fileprivate struct FfiConverterOptionSequenceTypeFfiCommitId: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiCommitId]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeFfiCommitId.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiLogResult_lower(_ value: FfiLogResult) -> RustBuffer {
-    return FfiConverterTypeFfiLogResult.lower(value)
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
+
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
 
-/**
- * Input data for creating a new commit via FFI
- */
-public struct FfiNewCommit {
-    /**
-     * Parent commit IDs
-     */
-    public var parentIds: [FfiCommitId]
-    /**
-     * Commit description/message
-     */
-    public var description: String
-    /**
-     * Author name (optional, uses settings default if not provided)
-     */
-    public var authorName: String?
-    /**
-     * Author email (optional, uses settings default if not provided)
-     */
-    public var authorEmail: String?
-    /**
-     * Author timestamp (optional, uses current time if not provided)
-     */
-    public var authorTimestamp: FfiTimestamp?
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Parent commit IDs
-         */parentIds: [FfiCommitId], 
-        /**
-         * Commit description/message
-         */description: String, 
-        /**
-         * Author name (optional, uses settings default if not provided)
-         */authorName: String?, 
-        /**
-         * Author email (optional, uses settings default if not provided)
-         */authorEmail: String?, 
-        /**
-         * Author timestamp (optional, uses current time if not provided)
-         */authorTimestamp: FfiTimestamp?) {
-        self.parentIds = parentIds
-        self.description = description
-        self.authorName = authorName
-        self.authorEmail = authorEmail
-        self.authorTimestamp = authorTimestamp
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiAbsorbSkippedPath: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiAbsorbSkippedPath]
 
-
-extension FfiNewCommit: Equatable, Hashable {
-    public static func ==(lhs: FfiNewCommit, rhs: FfiNewCommit) -> Bool {
-        if lhs.parentIds != rhs.parentIds {
-            return false
+    public static func write(_ value: [FfiAbsorbSkippedPath], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiAbsorbSkippedPath.write(item, into: &buf)
         }
-        if lhs.description != rhs.description {
-            return false
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiAbsorbSkippedPath] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiAbsorbSkippedPath]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiAbsorbSkippedPath.read(from: &buf))
         }
-        if lhs.authorName != rhs.authorName {
-            return false
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiActivityCount: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiActivityCount]
+
+    public static func write(_ value: [FfiActivityCount], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiActivityCount.write(item, into: &buf)
         }
-        if lhs.authorEmail != rhs.authorEmail {
-            return false
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiActivityCount] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiActivityCount]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiActivityCount.read(from: &buf))
         }
-        if lhs.authorTimestamp != rhs.authorTimestamp {
-            return false
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiBookmark: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiBookmark]
+
+    public static func write(_ value: [FfiBookmark], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiBookmark.write(item, into: &buf)
         }
-        return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(parentIds)
-        hasher.combine(description)
-        hasher.combine(authorName)
-        hasher.combine(authorEmail)
-        hasher.combine(authorTimestamp)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiBookmark] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiBookmark]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiBookmark.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiNewCommit: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiNewCommit {
-        return
-            try FfiNewCommit(
-                parentIds: FfiConverterSequenceTypeFfiCommitId.read(from: &buf), 
-                description: FfiConverterString.read(from: &buf), 
-                authorName: FfiConverterOptionString.read(from: &buf), 
-                authorEmail: FfiConverterOptionString.read(from: &buf), 
-                authorTimestamp: FfiConverterOptionTypeFfiTimestamp.read(from: &buf)
-        )
+fileprivate struct FfiConverterSequenceTypeFfiBookmarkChange: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiBookmarkChange]
+
+    public static func write(_ value: [FfiBookmarkChange], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiBookmarkChange.write(item, into: &buf)
+        }
     }
 
-    public static func write(_ value: FfiNewCommit, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeFfiCommitId.write(value.parentIds, into: &buf)
-        FfiConverterString.write(value.description, into: &buf)
-        FfiConverterOptionString.write(value.authorName, into: &buf)
-        FfiConverterOptionString.write(value.authorEmail, into: &buf)
-        FfiConverterOptionTypeFfiTimestamp.write(value.authorTimestamp, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiBookmarkChange] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiBookmarkChange]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiBookmarkChange.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiNewCommit_lift(_ buf: RustBuffer) throws -> FfiNewCommit {
-    return try FfiConverterTypeFfiNewCommit.lift(buf)
+fileprivate struct FfiConverterSequenceTypeFfiBookmarkRef: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiBookmarkRef]
+
+    public static func write(_ value: [FfiBookmarkRef], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiBookmarkRef.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiBookmarkRef] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiBookmarkRef]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiBookmarkRef.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiNewCommit_lower(_ value: FfiNewCommit) -> RustBuffer {
-    return FfiConverterTypeFfiNewCommit.lower(value)
+fileprivate struct FfiConverterSequenceTypeFfiChangeId: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiChangeId]
+
+    public static func write(_ value: [FfiChangeId], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiChangeId.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiChangeId] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiChangeId]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiChangeId.read(from: &buf))
+        }
+        return seq
+    }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiCommit: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiCommit]
 
-/**
- * Input data for rewriting an existing commit via FFI
- */
-public struct FfiRewriteCommit {
-    /**
-     * The commit ID to rewrite
-     */
-    public var commitId: FfiCommitId
-    /**
-     * New description (optional, keeps original if not provided)
-     */
-    public var newDescription: String?
-    /**
-     * New parent IDs (optional, keeps original if not provided)
-     */
-    public var newParentIds: [FfiCommitId]?
+    public static func write(_ value: [FfiCommit], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiCommit.write(item, into: &buf)
+        }
+    }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The commit ID to rewrite
-         */commitId: FfiCommitId, 
-        /**
-         * New description (optional, keeps original if not provided)
-         */newDescription: String?, 
-        /**
-         * New parent IDs (optional, keeps original if not provided)
-         */newParentIds: [FfiCommitId]?) {
-        self.commitId = commitId
-        self.newDescription = newDescription
-        self.newParentIds = newParentIds
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiCommit] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiCommit]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiCommit.read(from: &buf))
+        }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiCommitHeader: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiCommitHeader]
 
-
-extension FfiRewriteCommit: Equatable, Hashable {
-    public static func ==(lhs: FfiRewriteCommit, rhs: FfiRewriteCommit) -> Bool {
-        if lhs.commitId != rhs.commitId {
-            return false
+    public static func write(_ value: [FfiCommitHeader], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiCommitHeader.write(item, into: &buf)
         }
-        if lhs.newDescription != rhs.newDescription {
-            return false
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiCommitHeader] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiCommitHeader]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiCommitHeader.read(from: &buf))
         }
-        if lhs.newParentIds != rhs.newParentIds {
-            return false
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiCommitId: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiCommitId]
+
+    public static func write(_ value: [FfiCommitId], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiCommitId.write(item, into: &buf)
         }
-        return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(commitId)
-        hasher.combine(newDescription)
-        hasher.combine(newParentIds)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiCommitId] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiCommitId]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiCommitId.read(from: &buf))
+        }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiConflictSides: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiConflictSides]
+
+    public static func write(_ value: [FfiConflictSides], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiConflictSides.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiConflictSides] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiConflictSides]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiConflictSides.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiRewriteCommit: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiRewriteCommit {
-        return
-            try FfiRewriteCommit(
-                commitId: FfiConverterTypeFfiCommitId.read(from: &buf), 
-                newDescription: FfiConverterOptionString.read(from: &buf), 
-                newParentIds: FfiConverterOptionSequenceTypeFfiCommitId.read(from: &buf)
-        )
+fileprivate struct FfiConverterSequenceTypeFfiConflictTerm: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiConflictTerm]
+
+    public static func write(_ value: [FfiConflictTerm], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiConflictTerm.write(item, into: &buf)
+        }
     }
 
-    public static func write(_ value: FfiRewriteCommit, into buf: inout [UInt8]) {
-        FfiConverterTypeFfiCommitId.write(value.commitId, into: &buf)
-        FfiConverterOptionString.write(value.newDescription, into: &buf)
-        FfiConverterOptionSequenceTypeFfiCommitId.write(value.newParentIds, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiConflictTerm] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiConflictTerm]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiConflictTerm.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeFfiRewriteCommit_lift(_ buf: RustBuffer) throws -> FfiRewriteCommit {
-    return try FfiConverterTypeFfiRewriteCommit.lift(buf)
-}
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiRewriteCommit_lower(_ value: FfiRewriteCommit) -> RustBuffer {
-    return FfiConverterTypeFfiRewriteCommit.lower(value)
-}
-
+fileprivate struct FfiConverterSequenceTypeFfiContributorStats: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiContributorStats]
 
-/**
- * A commit signature (author or committer) for FFI
- */
-public struct FfiSignature {
-    /**
-     * Name of the person
-     */
-    public var name: String
-    /**
-     * Email address
-     */
-    public var email: String
-    /**
-     * Timestamp of the signature
-     */
-    public var timestamp: FfiTimestamp
+    public static func write(_ value: [FfiContributorStats], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiContributorStats.write(item, into: &buf)
+        }
+    }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Name of the person
-         */name: String, 
-        /**
-         * Email address
-         */email: String, 
-        /**
-         * Timestamp of the signature
-         */timestamp: FfiTimestamp) {
-        self.name = name
-        self.email = email
-        self.timestamp = timestamp
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiContributorStats] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiContributorStats]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiContributorStats.read(from: &buf))
+        }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiDiffEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiDiffEntry]
 
-
-extension FfiSignature: Equatable, Hashable {
-    public static func ==(lhs: FfiSignature, rhs: FfiSignature) -> Bool {
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.email != rhs.email {
-            return false
-        }
-        if lhs.timestamp != rhs.timestamp {
-            return false
+    public static func write(_ value: [FfiDiffEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiDiffEntry.write(item, into: &buf)
         }
-        return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(name)
-        hasher.combine(email)
-        hasher.combine(timestamp)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiDiffEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiDiffEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiDiffEntry.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiSignature: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiSignature {
-        return
-            try FfiSignature(
-                name: FfiConverterString.read(from: &buf), 
-                email: FfiConverterString.read(from: &buf), 
-                timestamp: FfiConverterTypeFfiTimestamp.read(from: &buf)
-        )
+fileprivate struct FfiConverterSequenceTypeFfiDiffHunk: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiDiffHunk]
+
+    public static func write(_ value: [FfiDiffHunk], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiDiffHunk.write(item, into: &buf)
+        }
     }
 
-    public static func write(_ value: FfiSignature, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.email, into: &buf)
-        FfiConverterTypeFfiTimestamp.write(value.timestamp, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiDiffHunk] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiDiffHunk]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiDiffHunk.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiSignature_lift(_ buf: RustBuffer) throws -> FfiSignature {
-    return try FfiConverterTypeFfiSignature.lift(buf)
+fileprivate struct FfiConverterSequenceTypeFfiDiffLine: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiDiffLine]
+
+    public static func write(_ value: [FfiDiffLine], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiDiffLine.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiDiffLine] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiDiffLine]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiDiffLine.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiSignature_lower(_ value: FfiSignature) -> RustBuffer {
-    return FfiConverterTypeFfiSignature.lower(value)
-}
+fileprivate struct FfiConverterSequenceTypeFfiDirectoryDiffSummary: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiDirectoryDiffSummary]
 
+    public static func write(_ value: [FfiDirectoryDiffSummary], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiDirectoryDiffSummary.write(item, into: &buf)
+        }
+    }
 
-/**
- * A timestamp for FFI
- */
-public struct FfiTimestamp {
-    /**
-     * Milliseconds since Unix epoch
-     */
-    public var millisSinceEpoch: Int64
-    /**
-     * Timezone offset in minutes from UTC
-     */
-    public var tzOffsetMinutes: Int32
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Milliseconds since Unix epoch
-         */millisSinceEpoch: Int64, 
-        /**
-         * Timezone offset in minutes from UTC
-         */tzOffsetMinutes: Int32) {
-        self.millisSinceEpoch = millisSinceEpoch
-        self.tzOffsetMinutes = tzOffsetMinutes
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiDirectoryDiffSummary] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiDirectoryDiffSummary]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiDirectoryDiffSummary.read(from: &buf))
+        }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiFailedRefExport: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiFailedRefExport]
 
-
-extension FfiTimestamp: Equatable, Hashable {
-    public static func ==(lhs: FfiTimestamp, rhs: FfiTimestamp) -> Bool {
-        if lhs.millisSinceEpoch != rhs.millisSinceEpoch {
-            return false
-        }
-        if lhs.tzOffsetMinutes != rhs.tzOffsetMinutes {
-            return false
+    public static func write(_ value: [FfiFailedRefExport], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiFailedRefExport.write(item, into: &buf)
         }
-        return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(millisSinceEpoch)
-        hasher.combine(tzOffsetMinutes)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiFailedRefExport] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiFailedRefExport]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiFailedRefExport.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiTimestamp: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiTimestamp {
-        return
-            try FfiTimestamp(
-                millisSinceEpoch: FfiConverterInt64.read(from: &buf), 
-                tzOffsetMinutes: FfiConverterInt32.read(from: &buf)
-        )
+fileprivate struct FfiConverterSequenceTypeFfiFileDiffStat: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiFileDiffStat]
+
+    public static func write(_ value: [FfiFileDiffStat], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiFileDiffStat.write(item, into: &buf)
+        }
     }
 
-    public static func write(_ value: FfiTimestamp, into buf: inout [UInt8]) {
-        FfiConverterInt64.write(value.millisSinceEpoch, into: &buf)
-        FfiConverterInt32.write(value.tzOffsetMinutes, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiFileDiffStat] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiFileDiffStat]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiFileDiffStat.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiTimestamp_lift(_ buf: RustBuffer) throws -> FfiTimestamp {
-    return try FfiConverterTypeFfiTimestamp.lift(buf)
+fileprivate struct FfiConverterSequenceTypeFfiGraphEdge: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiGraphEdge]
+
+    public static func write(_ value: [FfiGraphEdge], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiGraphEdge.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiGraphEdge] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiGraphEdge]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiGraphEdge.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiTimestamp_lower(_ value: FfiTimestamp) -> RustBuffer {
-    return FfiConverterTypeFfiTimestamp.lower(value)
-}
+fileprivate struct FfiConverterSequenceTypeFfiGraphLane: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiGraphLane]
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Graph edge type exposed via FFI
- */
+    public static func write(_ value: [FfiGraphLane], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiGraphLane.write(item, into: &buf)
+        }
+    }
 
-public enum FfiGraphEdgeType {
-    
-    /**
-     * Direct parent-child relationship
-     */
-    case direct
-    /**
-     * Indirect relationship (some commits in between are elided)
-     */
-    case indirect
-    /**
-     * Missing parent (incomplete history)
-     */
-    case missing
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiGraphLane] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiGraphLane]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiGraphLane.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiGraphEdgeType: FfiConverterRustBuffer {
-    typealias SwiftType = FfiGraphEdgeType
+fileprivate struct FfiConverterSequenceTypeFfiImportedRefChange: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiImportedRefChange]
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiGraphEdgeType {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .direct
-        
-        case 2: return .indirect
-        
-        case 3: return .missing
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [FfiImportedRefChange], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiImportedRefChange.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: FfiGraphEdgeType, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .direct:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .indirect:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .missing:
-            writeInt(&buf, Int32(3))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiImportedRefChange] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiImportedRefChange]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiImportedRefChange.read(from: &buf))
         }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGraphEdgeType_lift(_ buf: RustBuffer) throws -> FfiGraphEdgeType {
-    return try FfiConverterTypeFfiGraphEdgeType.lift(buf)
+fileprivate struct FfiConverterSequenceTypeFfiLogEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiLogEntry]
+
+    public static func write(_ value: [FfiLogEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiLogEntry.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiLogEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiLogEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiLogEntry.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiGraphEdgeType_lower(_ value: FfiGraphEdgeType) -> RustBuffer {
-    return FfiConverterTypeFfiGraphEdgeType.lower(value)
-}
-
-
-
-extension FfiGraphEdgeType: Equatable, Hashable {}
+fileprivate struct FfiConverterSequenceTypeFfiOperation: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiOperation]
 
+    public static func write(_ value: [FfiOperation], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiOperation.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiOperation] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiOperation]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiOperation.read(from: &buf))
+        }
+        return seq
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiPushUpdate: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiPushUpdate]
 
-/**
- * Unified error type exposed via FFI
- */
-public enum JjError {
+    public static func write(_ value: [FfiPushUpdate], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiPushUpdate.write(item, into: &buf)
+        }
+    }
 
-    
-    
-    case Workspace(message: String
-    )
-    case Repository(message: String
-    )
-    case Backend(message: String
-    )
-    case CommitNotFound(id: String
-    )
-    case InvalidArgument(message: String
-    )
-    case Revset(message: String
-    )
-    case Transaction(message: String
-    )
-    case Git(message: String
-    )
-    case Internal(message: String
-    )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiPushUpdate] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiPushUpdate]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiPushUpdate.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeJjError: FfiConverterRustBuffer {
-    typealias SwiftType = JjError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> JjError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
+fileprivate struct FfiConverterSequenceTypeFfiRejectedRef: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiRejectedRef]
 
-        
-        case 1: return .Workspace(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .Repository(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .Backend(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .CommitNotFound(
-            id: try FfiConverterString.read(from: &buf)
-            )
-        case 5: return .InvalidArgument(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 6: return .Revset(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 7: return .Transaction(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 8: return .Git(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 9: return .Internal(
-            message: try FfiConverterString.read(from: &buf)
-            )
+    public static func write(_ value: [FfiRejectedRef], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiRejectedRef.write(item, into: &buf)
+        }
+    }
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiRejectedRef] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiRejectedRef]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiRejectedRef.read(from: &buf))
         }
+        return seq
     }
+}
 
-    public static func write(_ value: JjError, into buf: inout [UInt8]) {
-        switch value {
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiRemoteBookmark: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiRemoteBookmark]
 
-        
+    public static func write(_ value: [FfiRemoteBookmark], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiRemoteBookmark.write(item, into: &buf)
+        }
+    }
 
-        
-        
-        case let .Workspace(message):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .Repository(message):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .Backend(message):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .CommitNotFound(id):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(id, into: &buf)
-            
-        
-        case let .InvalidArgument(message):
-            writeInt(&buf, Int32(5))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .Revset(message):
-            writeInt(&buf, Int32(6))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .Transaction(message):
-            writeInt(&buf, Int32(7))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .Git(message):
-            writeInt(&buf, Int32(8))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .Internal(message):
-            writeInt(&buf, Int32(9))
-            FfiConverterString.write(message, into: &buf)
-            
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiRemoteBookmark] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiRemoteBookmark]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiRemoteBookmark.read(from: &buf))
         }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFfiTag: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiTag]
 
-extension JjError: Equatable, Hashable {}
+    public static func write(_ value: [FfiTag], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiTag.write(item, into: &buf)
+        }
+    }
 
-extension JjError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiTag] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiTag]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiTag.read(from: &buf))
+        }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterSequenceTypeFfiTextRange: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiTextRange]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [FfiTextRange], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiTextRange.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiTextRange] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiTextRange]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiTextRange.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeFfiTimestamp: FfiConverterRustBuffer {
-    typealias SwiftType = FfiTimestamp?
+fileprivate struct FfiConverterSequenceTypeFfiTrailer: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiTrailer]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [FfiTrailer], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiTrailer.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeFfiTimestamp.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeFfiTimestamp.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiTrailer] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiTrailer]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiTrailer.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionSequenceTypeFfiCommitId: FfiConverterRustBuffer {
-    typealias SwiftType = [FfiCommitId]?
+fileprivate struct FfiConverterSequenceTypeFfiTreeEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiTreeEntry]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [FfiTreeEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFfiTreeEntry.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterSequenceTypeFfiCommitId.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiTreeEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FfiTreeEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFfiTreeEntry.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
+fileprivate struct FfiConverterSequenceTypeFfiUntrackedPath: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiUntrackedPath]
 
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
+    public static func write(_ value: [FfiUntrackedPath], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterString.write(item, into: &buf)
+            FfiConverterTypeFfiUntrackedPath.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiUntrackedPath] {
         let len: Int32 = try readInt(&buf)
-        var seq = [String]()
+        var seq = [FfiUntrackedPath]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
+            seq.append(try FfiConverterTypeFfiUntrackedPath.read(from: &buf))
         }
         return seq
     }
@@ -3148,23 +16323,23 @@ fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeFfiCommit: FfiConverterRustBuffer {
-    typealias SwiftType = [FfiCommit]
+fileprivate struct FfiConverterSequenceTypeFfiWorkspaceInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiWorkspaceInfo]
 
-    public static func write(_ value: [FfiCommit], into buf: inout [UInt8]) {
+    public static func write(_ value: [FfiWorkspaceInfo], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeFfiCommit.write(item, into: &buf)
+            FfiConverterTypeFfiWorkspaceInfo.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiCommit] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiWorkspaceInfo] {
         let len: Int32 = try readInt(&buf)
-        var seq = [FfiCommit]()
+        var seq = [FfiWorkspaceInfo]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeFfiCommit.read(from: &buf))
+            seq.append(try FfiConverterTypeFfiWorkspaceInfo.read(from: &buf))
         }
         return seq
     }
@@ -3173,23 +16348,23 @@ fileprivate struct FfiConverterSequenceTypeFfiCommit: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeFfiCommitId: FfiConverterRustBuffer {
-    typealias SwiftType = [FfiCommitId]
+fileprivate struct FfiConverterSequenceTypeFfiGraphLabel: FfiConverterRustBuffer {
+    typealias SwiftType = [FfiGraphLabel]
 
-    public static func write(_ value: [FfiCommitId], into buf: inout [UInt8]) {
+    public static func write(_ value: [FfiGraphLabel], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeFfiCommitId.write(item, into: &buf)
+            FfiConverterTypeFfiGraphLabel.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiCommitId] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiGraphLabel] {
         let len: Int32 = try readInt(&buf)
-        var seq = [FfiCommitId]()
+        var seq = [FfiGraphLabel]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeFfiCommitId.read(from: &buf))
+            seq.append(try FfiConverterTypeFfiGraphLabel.read(from: &buf))
         }
         return seq
     }
@@ -3198,52 +16373,173 @@ fileprivate struct FfiConverterSequenceTypeFfiCommitId: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeFfiGraphEdge: FfiConverterRustBuffer {
-    typealias SwiftType = [FfiGraphEdge]
-
-    public static func write(_ value: [FfiGraphEdge], into buf: inout [UInt8]) {
+fileprivate struct FfiConverterDictionaryStringString: FfiConverterRustBuffer {
+    public static func write(_ value: [String: String], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeFfiGraphEdge.write(item, into: &buf)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterString.write(value, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiGraphEdge] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: String] {
         let len: Int32 = try readInt(&buf)
-        var seq = [FfiGraphEdge]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeFfiGraphEdge.read(from: &buf))
+        var dict = [String: String]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterString.read(from: &buf)
+            dict[key] = value
         }
-        return seq
+        return dict
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeFfiLogEntry: FfiConverterRustBuffer {
-    typealias SwiftType = [FfiLogEntry]
-
-    public static func write(_ value: [FfiLogEntry], into buf: inout [UInt8]) {
+fileprivate struct FfiConverterDictionaryTypeFfiCommitIdSequenceTypeFfiCommitId: FfiConverterRustBuffer {
+    public static func write(_ value: [FfiCommitId: [FfiCommitId]], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeFfiLogEntry.write(item, into: &buf)
+        for (key, value) in value {
+            FfiConverterTypeFfiCommitId.write(key, into: &buf)
+            FfiConverterSequenceTypeFfiCommitId.write(value, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiLogEntry] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FfiCommitId: [FfiCommitId]] {
         let len: Int32 = try readInt(&buf)
-        var seq = [FfiLogEntry]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeFfiLogEntry.read(from: &buf))
+        var dict = [FfiCommitId: [FfiCommitId]]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterTypeFfiCommitId.read(from: &buf)
+            let value = try FfiConverterSequenceTypeFfiCommitId.read(from: &buf)
+            dict[key] = value
         }
-        return seq
+        return dict
+    }
+}
+private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
+private let UNIFFI_RUST_FUTURE_POLL_MAYBE_READY: Int8 = 1
+
+fileprivate let uniffiContinuationHandleMap = UniffiHandleMap<UnsafeContinuation<Int8, Never>>()
+
+fileprivate func uniffiRustCallAsync<F, T>(
+    rustFutureFunc: () -> UInt64,
+    pollFunc: (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
+    completeFunc: (UInt64, UnsafeMutablePointer<RustCallStatus>) -> F,
+    freeFunc: (UInt64) -> (),
+    liftFunc: (F) throws -> T,
+    errorHandler: ((RustBuffer) throws -> Swift.Error)?
+) async throws -> T {
+    // Make sure to call uniffiEnsureInitialized() since future creation doesn't have a
+    // RustCallStatus param, so doesn't use makeRustCall()
+    uniffiEnsureInitialized()
+    let rustFuture = rustFutureFunc()
+    defer {
+        freeFunc(rustFuture)
+    }
+    var pollResult: Int8;
+    repeat {
+        pollResult = await withUnsafeContinuation {
+            pollFunc(
+                rustFuture,
+                uniffiFutureContinuationCallback,
+                uniffiContinuationHandleMap.insert(obj: $0)
+            )
+        }
+    } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
+
+    return try liftFunc(makeRustCall(
+        { completeFunc(rustFuture, $0) },
+        errorHandler: errorHandler
+    ))
+}
+
+// Callback handlers for an async calls.  These are invoked by Rust when the future is ready.  They
+// lift the return value or error and resume the suspended function.
+fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: Int8) {
+    if let continuation = try? uniffiContinuationHandleMap.remove(handle: handle) {
+        continuation.resume(returning: pollResult)
+    } else {
+        print("uniffiFutureContinuationCallback invalid handle")
     }
 }
+/**
+ * Add trailers to a commit description
+ *
+ * Trailers with the same key and value as an existing trailer are skipped,
+ * so adding e.g. a `Signed-off-by` trailer twice is harmless.
+ */
+public func addDescriptionTrailers(description: String, trailers: [FfiTrailer])throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_add_description_trailers(
+        FfiConverterString.lower(description),
+        FfiConverterSequenceTypeFfiTrailer.lower(trailers),$0
+    )
+})
+}
+/**
+ * Check that the workspace at the given path can be opened by this library
+ *
+ * Returns the store types in use. Fails with `IncompatibleRepository` if the
+ * repository uses a format this library doesn't support, e.g. because it was
+ * created by a newer version of jj.
+ */
+public func checkWorkspaceCompatibility(workspacePath: String)throws  -> FfiRepoFormat {
+    return try  FfiConverterTypeFfiRepoFormat.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_check_workspace_compatibility(
+        FfiConverterString.lower(workspacePath),$0
+    )
+})
+}
+/**
+ * Clone a Git repository into a new workspace, like `jj git clone`
+ *
+ * `source` is the URL or path of the repository to clone. The remote's
+ * bookmarks are fetched, and the working copy is checked out on top of the
+ * remote's default branch, which becomes `trunk()` and is tracked by a
+ * local bookmark. `workspace_path` must not exist or be an empty directory.
+ */
+public func cloneGitWorkspace(source: String, workspacePath: String, userName: String, userEmail: String, options: FfiCloneOptions)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_clone_git_workspace(
+        FfiConverterString.lower(source),
+        FfiConverterString.lower(workspacePath),
+        FfiConverterString.lower(userName),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiCloneOptions.lower(options),$0
+    )
+})
+}
+/**
+ * Clone a Git repository into a new workspace, with settings from the
+ * user's jj config
+ *
+ * See `clone_git_workspace()` for how the repository is cloned and
+ * `FfiWorkspace::load_with_user_config()` for how the config is found.
+ */
+public func cloneGitWorkspaceWithUserConfig(source: String, workspacePath: String, options: FfiCloneOptions)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_clone_git_workspace_with_user_config(
+        FfiConverterString.lower(source),
+        FfiConverterString.lower(workspacePath),
+        FfiConverterTypeFfiCloneOptions.lower(options),$0
+    )
+})
+}
+/**
+ * Parse the trailers of a commit description
+ */
+public func descriptionTrailers(description: String) -> [FfiTrailer] {
+    return try!  FfiConverterSequenceTypeFfiTrailer.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_func_description_trailers(
+        FfiConverterString.lower(description),$0
+    )
+})
+}
 /**
  * Get abandoned commit IDs from import stats
  */
@@ -3266,6 +16562,68 @@ public func initColocatedGitWorkspace(workspacePath: String, userName: String, u
     )
 })
 }
+/**
+ * Initialize a new Git workspace with colocated Git backend and options
+ *
+ * An initial commit and bookmark can be created in the same operation.
+ * Git HEAD is set to the initial commit.
+ */
+public func initColocatedGitWorkspaceWithOptions(workspacePath: String, userName: String, userEmail: String, options: FfiInitOptions)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_init_colocated_git_workspace_with_options(
+        FfiConverterString.lower(workspacePath),
+        FfiConverterString.lower(userName),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiInitOptions.lower(options),$0
+    )
+})
+}
+/**
+ * Initialize a new Git workspace with colocated Git backend, with settings
+ * from the user's jj config
+ *
+ * See `FfiWorkspace::load_with_user_config()` for how the config is found.
+ */
+public func initColocatedGitWorkspaceWithUserConfig(workspacePath: String, options: FfiInitOptions?)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_init_colocated_git_workspace_with_user_config(
+        FfiConverterString.lower(workspacePath),
+        FfiConverterOptionTypeFfiInitOptions.lower(options),$0
+    )
+})
+}
+/**
+ * Initialize a new workspace backed by an existing Git repository at
+ * `git_repo_path`, like `jj git init --git-repo`
+ *
+ * `git_repo_path` may be a Git working tree or a (bare) Git directory. The
+ * Git repository is not colocated: jj keeps its own working copy in
+ * `workspace_path`, which starts on top of the Git repository's HEAD.
+ */
+public func initExternalGitWorkspace(workspacePath: String, gitRepoPath: String, userName: String, userEmail: String)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_init_external_git_workspace(
+        FfiConverterString.lower(workspacePath),
+        FfiConverterString.lower(gitRepoPath),
+        FfiConverterString.lower(userName),
+        FfiConverterString.lower(userEmail),$0
+    )
+})
+}
+/**
+ * Initialize a new workspace backed by an existing Git repository, with
+ * settings from the user's jj config
+ *
+ * See `FfiWorkspace::load_with_user_config()` for how the config is found.
+ */
+public func initExternalGitWorkspaceWithUserConfig(workspacePath: String, gitRepoPath: String)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_init_external_git_workspace_with_user_config(
+        FfiConverterString.lower(workspacePath),
+        FfiConverterString.lower(gitRepoPath),$0
+    )
+})
+}
 /**
  * Initialize a new Git workspace with internal Git backend
  */
@@ -3278,6 +16636,81 @@ public func initInternalGitWorkspace(workspacePath: String, userName: String, us
     )
 })
 }
+/**
+ * Initialize a new Git workspace with internal Git backend and options
+ *
+ * An initial commit and bookmark can be created in the same operation.
+ */
+public func initInternalGitWorkspaceWithOptions(workspacePath: String, userName: String, userEmail: String, options: FfiInitOptions)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_init_internal_git_workspace_with_options(
+        FfiConverterString.lower(workspacePath),
+        FfiConverterString.lower(userName),
+        FfiConverterString.lower(userEmail),
+        FfiConverterTypeFfiInitOptions.lower(options),$0
+    )
+})
+}
+/**
+ * Initialize a new Git workspace with internal Git backend, with settings
+ * from the user's jj config
+ *
+ * See `FfiWorkspace::load_with_user_config()` for how the config is found.
+ */
+public func initInternalGitWorkspaceWithUserConfig(workspacePath: String, options: FfiInitOptions?)throws  -> FfiWorkspace {
+    return try  FfiConverterTypeFfiWorkspace.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_init_internal_git_workspace_with_user_config(
+        FfiConverterString.lower(workspacePath),
+        FfiConverterOptionTypeFfiInitOptions.lower(options),$0
+    )
+})
+}
+/**
+ * Forward jj-lib's logs to `sink`, dropping records more verbose than
+ * `max_level`
+ *
+ * The first call installs a global `tracing` subscriber; later calls replace
+ * the sink and level. Fails if another subscriber was installed by the host
+ * process.
+ */
+public func installLogSink(sink: FfiLogSink, maxLevel: FfiLogLevel)throws  {try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_install_log_sink(
+        FfiConverterTypeFfiLogSink.lower(sink),
+        FfiConverterTypeFfiLogLevel.lower(maxLevel),$0
+    )
+}
+}
+/**
+ * Get information about this build of the library
+ */
+public func libraryInfo() -> FfiLibraryInfo {
+    return try!  FfiConverterTypeFfiLibraryInfo.lift(try! rustCall() {
+    uniffi_jj_ffi_fn_func_library_info($0
+    )
+})
+}
+/**
+ * Stop forwarding logs to the installed sink
+ */
+public func removeLogSink() {try! rustCall() {
+    uniffi_jj_ffi_fn_func_remove_log_sink($0
+    )
+}
+}
+/**
+ * Replace the trailers of a commit description
+ *
+ * Existing trailers are removed, so trailers can be modified or removed by
+ * editing the list returned by `description_trailers()`.
+ */
+public func setDescriptionTrailers(description: String, trailers: [FfiTrailer])throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeJjError.lift) {
+    uniffi_jj_ffi_fn_func_set_description_trailers(
+        FfiConverterString.lower(description),
+        FfiConverterSequenceTypeFfiTrailer.lower(trailers),$0
+    )
+})
+}
 
 private enum InitializationResult {
     case ok
@@ -3294,16 +16727,106 @@ private var initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
-    if (uniffi_jj_ffi_checksum_func_get_abandoned_commits_from_import() != 13864) {
+    if (uniffi_jj_ffi_checksum_func_add_description_trailers() != 60697) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_check_workspace_compatibility() != 22391) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_clone_git_workspace() != 47403) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_clone_git_workspace_with_user_config() != 62522) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_description_trailers() != 33263) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_get_abandoned_commits_from_import() != 49033) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_init_colocated_git_workspace() != 43947) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_init_colocated_git_workspace_with_options() != 60350) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_init_colocated_git_workspace_with_user_config() != 34908) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_init_external_git_workspace() != 62497) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_init_external_git_workspace_with_user_config() != 11751) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_init_internal_git_workspace() != 65260) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_init_internal_git_workspace_with_options() != 1929) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_init_internal_git_workspace_with_user_config() != 32102) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_install_log_sink() != 57514) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_library_info() != 25276) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_remove_log_sink() != 43949) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_func_set_description_trailers() != 27087) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficancellationtoken_cancel() != 32007) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficancellationtoken_is_cancelled() != 26365) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_generate_new_change_id() != 19572) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_set_author() != 4719) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_set_committer() != 27490) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_set_description() != 35811) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_set_parents() != 20166) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_set_sign_behavior() != 33417) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_func_init_colocated_git_workspace() != 43947) {
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_set_sign_key() != 29024) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_func_init_internal_git_workspace() != 65260) {
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_set_tree() != 18618) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_fficommitbuilder_write() != 21023) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigitcredentialprovider_credentials() != 58551) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigitprogress_on_progress() != 61262) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_method_ffigittransaction_commit() != 56256) {
+    if (uniffi_jj_ffi_checksum_method_ffigitprogress_on_sideband_message() != 49328) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_base_repo() != 7039) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_commit() != 18042) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffigittransaction_discard() != 20894) {
@@ -3312,25 +16835,133 @@ private var initializationResult: InitializationResult = {
     if (uniffi_jj_ffi_checksum_method_ffigittransaction_export_refs() != 21078) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_method_ffigittransaction_fetch() != 59760) {
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_fetch() != 19114) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_fetch_async() != 45316) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_fetch_with_options() != 15813) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffigittransaction_import_refs() != 13678) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_method_ffigittransaction_push_branches() != 31753) {
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_push_branches() != 30447) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_push_branches_async() != 11880) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_push_branches_with_options() != 29470) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_push_changes() != 7307) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_track_remote_bookmark() != 41092) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffigittransaction_untrack_remote_bookmark() != 37726) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffilogiterator_next_page() != 11428) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffilogsink_log() != 58912) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_activity_histogram() != 8434) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_bookmark_changes_between_ops() != 58770) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_bookmarks() != 21914) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_bookmarks_containing() != 55001) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_children() != 12721) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_commit_extra_headers() != 34163) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_conflict_sides() != 3199) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_conflicted_paths() != 35844) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_conflicts() != 59310) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_contributor_stats() != 21298) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_count_revset() != 21946) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_count_revset_with_options() != 46418) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_diff_directory_summary() != 8154) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_diff_file_hunks() != 48008) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_diff_stats() != 19850) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_diff_summary() != 65015) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset() != 38673) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_change_ids() != 62737) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_change_ids_with_options() != 55878) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_commits() != 24265) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_get_commit() != 24369) {
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_to_commits_with_options() != 14821) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_evaluate_revset_with_options() != 27760) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_export_graph() != 55841) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_export_patch() != 37246) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_export_revision() != 58760) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_find_operation_by_idempotency_key() != 718) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_get_commit() != 26469) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_get_commits() != 518) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_git_head() != 3713) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_is_hidden() != 52708) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_list_tree() != 13779) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_log() != 14835) {
@@ -3339,12 +16970,51 @@ private var initializationResult: InitializationResult = {
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_log_flat() != 21793) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_log_iterator() != 43890) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_mine() != 16069) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_new_tree_builder() != 39973) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_operation() != 49732) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_operation_id() != 7353) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_operation_log() != 7506) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_operation_merge_report() != 10064) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_read_file() != 25624) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_read_file_text() != 52145) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_reload_at_head() != 15229) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_remote_bookmarks() != 7531) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_render_commit_template() != 21027) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_resolve_change_id() != 25220) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_resolve_commit_prefix() != 41711) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_restore_to_operation() != 7533) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_root_change_id() != 39873) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3354,16 +17024,70 @@ private var initializationResult: InitializationResult = {
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_root_commit_id() != 20442) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_shortest_change_id_prefix() != 59835) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_shortest_commit_id_prefix() != 36943) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_start_git_transaction() != 60704) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_start_transaction() != 62165) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_method_ffitransaction_abandon_commit() != 54462) {
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_store_info() != 48873) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_tags() != 45380) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_trunk() != 44267) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_undo_latest_operation() != 58097) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_unpushed_commits() != 10375) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_visible_head_count() != 9686) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_visible_heads() != 43279) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffireadonlyrepo_write_merge_tool_files() != 48174) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_method_ffitransaction_commit() != 970) {
+    if (uniffi_jj_ffi_checksum_method_ffirepoloader_load_at_head() != 9781) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffirepoloader_load_at_operation() != 10513) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffisnapshotprogress_on_progress() != 4353) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_abandon_commit() != 32791) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_absorb() != 42737) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_add_trailers() != 61924) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_base_repo() != 32108) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_bookmarks() != 27245) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_commit() != 25916) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_commit_working_copy() != 54980) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffitransaction_create_commit_from_parent() != 61685) {
@@ -3375,28 +17099,197 @@ private var initializationResult: InitializationResult = {
     if (uniffi_jj_ffi_checksum_method_ffitransaction_discard() != 47963) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset() != 5489) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_to_change_ids() != 5706) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_to_change_ids_with_options() != 12004) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_to_commits() != 25137) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_to_commits_with_options() != 40351) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_evaluate_revset_with_options() != 15467) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_get_commit() != 27889) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_jj_ffi_checksum_method_ffitransaction_has_changes() != 10126) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_method_ffitransaction_rewrite_commit() != 53958) {
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_import_patch() != 48323) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_merge() != 40819) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_new_commit_builder() != 19869) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_parallelize() != 36023) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_rebase() != 43416) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_rebase_commits() != 43742) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_rebase_descendants() != 52586) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_resolve_conflict() != 32609) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_resolve_conflict_from_merge_tool() != 12873) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_rewrite_commit() != 63012) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_rewrite_commit_builder() != 37249) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_set_idempotency_key() != 57499) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_set_tag() != 16609) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_split() != 22828) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_squash() != 4562) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitransaction_squash_into() != 56629) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffitransaction_update_description() != 43191) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_method_ffiworkspace_repo() != 54533) {
+    if (uniffi_jj_ffi_checksum_method_ffitreebuilder_remove() != 5145) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitreebuilder_set_symlink() != 54576) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitreebuilder_write_file() != 31747) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffitreebuilder_write_tree() != 2655) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_conflict_marker_style() != 59853) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_edit() != 45867) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_finish_transaction() != 43631) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_fsmonitor() != 22622) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_gc() != 11969) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_git_head_sync_report() != 6332) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_is_colocated() != 52598) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_new_child() != 31410) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_operation_merge_report() != 45141) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_reindex() != 8041) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_reload() != 27759) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_repo() != 5338) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_repo_at_operation() != 2750) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_repo_format() != 31206) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_jj_ffi_checksum_method_ffiworkspace_repo_path() != 45615) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_restore_paths_from_parent() != 43662) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_set_conflict_marker_style() != 45088) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_set_fsmonitor() != 61616) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_snapshot() != 32589) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_snapshot_with_options() != 11916) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_track() != 38797) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_untrack() != 14693) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_untracked_files() != 24588) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_update_stale() != 9334) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_add() != 6660) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_forget() != 30348) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_list() != 37486) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_name() != 16553) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_jj_ffi_checksum_method_ffiworkspace_workspace_root() != 26542) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_jj_ffi_checksum_constructor_ffiworkspace_load() != 60897) {
+    if (uniffi_jj_ffi_checksum_constructor_fficancellationtoken_new() != 19558) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_constructor_ffirepoloader_new() != 8765) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_constructor_ffiworkspace_load() != 21480) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_jj_ffi_checksum_constructor_ffiworkspace_load_with_user_config() != 22639) {
         return InitializationResult.apiChecksumMismatch
     }
 
+    uniffiCallbackInitFfiGitCredentialProvider()
+    uniffiCallbackInitFfiGitProgress()
+    uniffiCallbackInitFfiLogSink()
+    uniffiCallbackInitFfiSnapshotProgress()
     return InitializationResult.ok
 }()
 
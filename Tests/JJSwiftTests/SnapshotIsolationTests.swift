@@ -0,0 +1,37 @@
+import Foundation
+import Testing
+@testable import JJSwift
+
+@Test func readonlyRepoIgnoresTransactionInFlight() throws {
+    let workspace = try makeWorkspace()
+    let repo = workspace.repo()
+    let before = try repo.evaluateRevset(revsetStr: "all()", userEmail: "test@example.com")
+
+    let tx = repo.startTransaction()
+    let commit = try tx.newCommitBuilder(parentIds: [repo.rootCommitId()])
+        .setDescription(description: "in flight")
+        .write()
+
+    // The transaction sees its own changes
+    let inTransaction = try tx.evaluateRevset(revsetStr: "all()", userEmail: "test@example.com")
+    #expect(inTransaction.contains(commit.id))
+
+    // The base repo keeps answering from the operation it was loaded at,
+    // including from other threads while the transaction is in flight
+    let lock = NSLock()
+    var concurrent: [[FfiCommitId]] = []
+    DispatchQueue.concurrentPerform(iterations: 4) { _ in
+        let ids = try? repo.evaluateRevset(revsetStr: "all()", userEmail: "test@example.com")
+        lock.lock()
+        concurrent.append(ids ?? [])
+        lock.unlock()
+    }
+    #expect(concurrent == Array(repeating: before, count: 4))
+    #expect(try tx.baseRepo().evaluateRevset(revsetStr: "all()", userEmail: "test@example.com") == before)
+
+    // Committing returns a new repo and leaves the old handles unchanged
+    let result = try tx.commit(description: "add commit")
+    #expect(try result.repo.evaluateRevset(revsetStr: "all()", userEmail: "test@example.com").contains(commit.id))
+    #expect(try repo.evaluateRevset(revsetStr: "all()", userEmail: "test@example.com") == before)
+    #expect(try tx.baseRepo().evaluateRevset(revsetStr: "all()", userEmail: "test@example.com") == before)
+}
@@ -0,0 +1,70 @@
+import Testing
+@testable import JJSwift
+
+private func makeCommit(description: String) throws -> (FfiReadonlyRepo, FfiCommit) {
+    let repo = try makeWorkspace().repo()
+    let tx = repo.startTransaction()
+    let commit = try tx.newCommitBuilder(parentIds: [repo.rootCommitId()])
+        .setDescription(description: description)
+        .write()
+    return (try tx.commit(description: "add commit").repo, commit)
+}
+
+@Test func rendersKeywordsAndMethods() throws {
+    let (repo, commit) = try makeCommit(description: "Hello world\n\nbody\n")
+    let rendered = try repo.renderCommitTemplate(
+        commitId: commit.id,
+        templateText: #"description.first_line() ++ " by " ++ author.name()"#
+    )
+    #expect(rendered == "Hello world by Test User")
+}
+
+@Test func rendersBuiltinAliases() throws {
+    let (repo, commit) = try makeCommit(description: "Hello world\n")
+    let oneline = try repo.renderCommitTemplate(commitId: commit.id, templateText: "builtin_log_oneline")
+    #expect(oneline.contains("Hello world"))
+    let trailer = try repo.renderCommitTemplate(
+        commitId: commit.id,
+        templateText: "format_signed_off_by_trailer(self)"
+    )
+    #expect(trailer == "Signed-off-by: Test User <test@example.com>\n")
+}
+
+@Test func rendersLambdas() throws {
+    let (repo, commit) = try makeCommit(description: "one\ntwo\nthree\n")
+    let rendered = try repo.renderCommitTemplate(
+        commitId: commit.id,
+        templateText: #"description.lines().filter(|l| l != "two").map(|l| l.upper()).join(",")"#
+    )
+    #expect(rendered == "ONE,THREE")
+}
+
+@Test func rejectsInvalidTemplates() throws {
+    let (repo, commit) = try makeCommit(description: "Hello\n")
+    for template in ["bogus", "description.nope()", #""unterminated"#, "if(true)"] {
+        #expect(throws: JjError.self) {
+            try repo.renderCommitTemplate(commitId: commit.id, templateText: template)
+        }
+    }
+}
+
+@Test func rejectsDeeplyNestedTemplates() throws {
+    let (repo, commit) = try makeCommit(description: "Hello\n")
+    let nested = String(repeating: "(", count: 32) + "1" + String(repeating: ")", count: 32)
+    #expect(try repo.renderCommitTemplate(commitId: commit.id, templateText: nested) == "1")
+
+    let tooDeep = [
+        String(repeating: "(", count: 1000) + "1" + String(repeating: ")", count: 1000),
+        String(repeating: "!", count: 1000) + "true",
+        "description" + String(repeating: ".first_line()", count: 1000),
+        String(repeating: "true || ", count: 1000) + "true",
+    ]
+    for template in tooDeep {
+        #expect(throws: JjError.self) {
+            try repo.renderCommitTemplate(commitId: commit.id, templateText: template)
+        }
+    }
+    // Brackets in string literals don't count
+    let literal = "\"" + String(repeating: "(", count: 1000) + "\""
+    #expect(try repo.renderCommitTemplate(commitId: commit.id, templateText: literal).count == 1000)
+}
@@ -0,0 +1,13 @@
+import Foundation
+@testable import JJSwift
+
+func makeWorkspace() throws -> FfiWorkspace {
+    let path = FileManager.default.temporaryDirectory
+        .appendingPathComponent("jj-swift-tests-\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: path, withIntermediateDirectories: true)
+    return try initInternalGitWorkspace(
+        workspacePath: path.path,
+        userName: "Test User",
+        userEmail: "test@example.com"
+    )
+}
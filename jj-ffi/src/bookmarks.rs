@@ -0,0 +1,290 @@
+//! Bookmark queries for FFI
+
+use std::sync::Arc;
+
+use jj_lib::backend::CommitId;
+use jj_lib::index::Index;
+use jj_lib::op_store::RefTarget;
+use jj_lib::ref_name::RemoteRefSymbol;
+use jj_lib::refs::diff_named_ref_targets;
+use jj_lib::repo::{ReadonlyRepo, Repo};
+use jj_lib::revset::RevsetExpression;
+use jj_lib::view::View;
+
+use crate::error::{JjError, Result};
+use crate::types::FfiCommitId;
+
+/// Remote name used by jj for the backing Git repository
+pub(crate) const LOCAL_GIT_REMOTE: &str = "git";
+
+/// A reference to a local or remote bookmark
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct FfiBookmarkRef {
+    /// Bookmark name
+    pub name: String,
+    /// Remote name (`None` for a local bookmark)
+    pub remote: Option<String>,
+}
+
+/// A local bookmark in the repository view
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiBookmark {
+    /// Bookmark name
+    pub name: String,
+    /// Target commit (`None` if the bookmark is conflicted)
+    pub target: Option<FfiCommitId>,
+    /// Whether the bookmark points to more than one commit
+    pub is_conflicted: bool,
+}
+
+/// A bookmark on a remote, with its relation to the local bookmark
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiRemoteBookmark {
+    /// Bookmark name
+    pub name: String,
+    /// Remote name
+    pub remote: String,
+    /// Target commit (`None` if the bookmark is conflicted)
+    pub target: Option<FfiCommitId>,
+    /// Whether the bookmark points to more than one commit
+    pub is_conflicted: bool,
+    /// Whether the local bookmark tracks the remote bookmark
+    pub is_tracked: bool,
+    /// Number of commits reachable from the local bookmark but not the
+    /// remote one (`None` if there is no local bookmark)
+    pub ahead: Option<u64>,
+    /// Number of commits reachable from the remote bookmark but not the
+    /// local one (`None` if there is no local bookmark)
+    pub behind: Option<u64>,
+}
+
+/// Kind of change to a bookmark between two operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiBookmarkChangeKind {
+    /// The bookmark was created
+    Added,
+    /// The bookmark was deleted
+    Removed,
+    /// The bookmark was moved to another target
+    Moved,
+}
+
+/// A change to a local or remote bookmark between two operations
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiBookmarkChange {
+    /// The changed bookmark
+    pub bookmark: FfiBookmarkRef,
+    /// Kind of change
+    pub kind: FfiBookmarkChangeKind,
+    /// Target before the change (`None` if absent or conflicted)
+    pub old_target: Option<FfiCommitId>,
+    /// Target after the change (`None` if absent or conflicted)
+    pub new_target: Option<FfiCommitId>,
+    /// Number of commits reachable from the new target but not the old one
+    /// (zero unless the bookmark was moved)
+    pub commits_added: u64,
+    /// Number of commits reachable from the old target but not the new one
+    /// (zero unless the bookmark was moved)
+    pub commits_removed: u64,
+}
+
+/// List the local bookmarks in the repository view, sorted by name
+pub fn local_bookmarks(repo: &dyn Repo) -> Vec<FfiBookmark> {
+    repo.view()
+        .local_bookmarks()
+        .map(|(name, target)| FfiBookmark {
+            name: name.as_str().to_string(),
+            target: target.as_normal().map(FfiCommitId::from),
+            is_conflicted: target.has_conflict(),
+        })
+        .collect()
+}
+
+/// Check whether any target of a bookmark is a descendant of a commit
+fn target_contains(index: &dyn Index, target: &RefTarget, commit_id: &CommitId) -> Result<bool> {
+    for target_id in target.added_ids() {
+        let contains = index
+            .is_ancestor(commit_id, target_id)
+            .map_err(|e| JjError::Internal {
+                message: format!("Index error: {}", e),
+            })?;
+        if contains {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// List the local and remote bookmarks whose targets contain a commit
+///
+/// Local bookmarks come first, followed by remote bookmarks, each sorted by
+/// name.
+pub fn bookmarks_containing(
+    repo: &Arc<ReadonlyRepo>,
+    commit_id: &FfiCommitId,
+) -> Result<Vec<FfiBookmarkRef>> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let index = repo.index();
+    let view = repo.view();
+
+    let mut bookmarks = Vec::new();
+    for (name, target) in view.local_bookmarks() {
+        if target_contains(index, target, &id)? {
+            bookmarks.push(FfiBookmarkRef {
+                name: name.as_str().to_string(),
+                remote: None,
+            });
+        }
+    }
+    for (symbol, remote_ref) in view.all_remote_bookmarks() {
+        if symbol.remote.as_str() == LOCAL_GIT_REMOTE {
+            continue;
+        }
+        if target_contains(index, &remote_ref.target, &id)? {
+            bookmarks.push(FfiBookmarkRef {
+                name: symbol.name.as_str().to_string(),
+                remote: Some(symbol.remote.as_str().to_string()),
+            });
+        }
+    }
+
+    Ok(bookmarks)
+}
+
+/// Count commits reachable from `heads` but not from `roots`
+fn count_range(repo: &dyn Repo, roots: &RefTarget, heads: &RefTarget) -> Result<u64> {
+    let roots = RevsetExpression::commits(roots.added_ids().cloned().collect());
+    let heads = RevsetExpression::commits(heads.added_ids().cloned().collect());
+    let revset = roots
+        .range(&heads)
+        .evaluate(repo)
+        .map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+    let mut count = 0;
+    for result in revset.iter() {
+        result.map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// List remote bookmark targets, excluding the backing Git repository
+fn remote_bookmark_targets(view: &View) -> impl Iterator<Item = (RemoteRefSymbol<'_>, &RefTarget)> {
+    view.all_remote_bookmarks()
+        .filter(|(symbol, _)| symbol.remote.as_str() != LOCAL_GIT_REMOTE)
+        .map(|(symbol, remote_ref)| (symbol, &remote_ref.target))
+}
+
+/// List the remote bookmarks, excluding the backing Git repository, sorted
+/// by name and remote
+///
+/// Ahead and behind counts are relative to the local bookmark of the same
+/// name, like the counts shown by `jj bookmark list`.
+pub fn remote_bookmarks(repo: &Arc<ReadonlyRepo>) -> Result<Vec<FfiRemoteBookmark>> {
+    let view = repo.view();
+    let mut bookmarks = Vec::new();
+    for (symbol, remote_ref) in view.all_remote_bookmarks() {
+        if symbol.remote.as_str() == LOCAL_GIT_REMOTE {
+            continue;
+        }
+        let local_target = view.get_local_bookmark(symbol.name);
+        let (ahead, behind) = if local_target.is_present() {
+            (
+                Some(count_range(&**repo, &remote_ref.target, local_target)?),
+                Some(count_range(&**repo, local_target, &remote_ref.target)?),
+            )
+        } else {
+            (None, None)
+        };
+        bookmarks.push(FfiRemoteBookmark {
+            name: symbol.name.as_str().to_string(),
+            remote: symbol.remote.as_str().to_string(),
+            target: remote_ref.target.as_normal().map(FfiCommitId::from),
+            is_conflicted: remote_ref.target.has_conflict(),
+            is_tracked: remote_ref.is_tracked(),
+            ahead,
+            behind,
+        });
+    }
+    Ok(bookmarks)
+}
+
+/// Describe the change of a bookmark from one target to another
+fn bookmark_change(
+    repo: &dyn Repo,
+    bookmark: FfiBookmarkRef,
+    old: &RefTarget,
+    new: &RefTarget,
+) -> Result<FfiBookmarkChange> {
+    let (kind, commits_added, commits_removed) = if old.is_absent() {
+        (FfiBookmarkChangeKind::Added, 0, 0)
+    } else if new.is_absent() {
+        (FfiBookmarkChangeKind::Removed, 0, 0)
+    } else {
+        let commits_added = count_range(repo, old, new)?;
+        let commits_removed = count_range(repo, new, old)?;
+        (FfiBookmarkChangeKind::Moved, commits_added, commits_removed)
+    };
+    Ok(FfiBookmarkChange {
+        bookmark,
+        kind,
+        old_target: old.as_normal().map(FfiCommitId::from),
+        new_target: new.as_normal().map(FfiCommitId::from),
+        commits_added,
+        commits_removed,
+    })
+}
+
+/// List the local and remote bookmarks that changed between two operations
+///
+/// Operations are given as IDs (or unique prefixes) or expressions like "@-".
+/// Commit counts are computed with the indexes of both operations merged, like
+/// `jj op diff`, so the operations don't need to be ancestors of each other.
+pub fn bookmark_changes_between_ops(
+    repo: &Arc<ReadonlyRepo>,
+    old_op: &str,
+    new_op: &str,
+) -> Result<Vec<FfiBookmarkChange>> {
+    let old_repo = crate::operation::load_at_operation(repo.loader(), old_op)?;
+    let new_repo = crate::operation::load_at_operation(repo.loader(), new_op)?;
+    let (old_view, new_view) = (old_repo.view(), new_repo.view());
+
+    // Merge the index of the old operation, so commits only visible there can
+    // be counted too
+    let mut tx = new_repo.start_transaction();
+    tx.repo_mut()
+        .merge_index(&old_repo)
+        .map_err(|e| JjError::Internal {
+            message: format!("Index error: {}", e),
+        })?;
+    let merged_repo = tx.repo();
+
+    let mut changes = Vec::new();
+    for (name, (old, new)) in
+        diff_named_ref_targets(old_view.local_bookmarks(), new_view.local_bookmarks())
+    {
+        let bookmark = FfiBookmarkRef {
+            name: name.as_str().to_string(),
+            remote: None,
+        };
+        changes.push(bookmark_change(merged_repo, bookmark, old, new)?);
+    }
+
+    for (symbol, (old, new)) in diff_named_ref_targets(
+        remote_bookmark_targets(old_view),
+        remote_bookmark_targets(new_view),
+    ) {
+        let bookmark = FfiBookmarkRef {
+            name: symbol.name.as_str().to_string(),
+            remote: Some(symbol.remote.as_str().to_string()),
+        };
+        changes.push(bookmark_change(merged_repo, bookmark, old, new)?);
+    }
+
+    Ok(changes)
+}
@@ -0,0 +1,77 @@
+//! Cancellation of long-running operations for FFI
+//!
+//! A token is shared between the host app and an operation. The app cancels
+//! it from any thread, and the operation checks it at safe points and fails
+//! with `JjError::Cancelled` without recording any changes.
+//!
+//! jj-lib can't stop a Git transfer or a working-copy scan early, so the
+//! progress callbacks it calls during them unwind when the token is
+//! cancelled, and the unwind is caught around the jj-lib call.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::{JjError, Result};
+
+/// A token for cancelling long-running operations
+#[derive(Debug, Default, uniffi::Object)]
+pub struct FfiCancellationToken {
+    cancelled: AtomicBool,
+}
+
+#[uniffi::export]
+impl FfiCancellationToken {
+    /// Create a token that is not cancelled
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Request cancellation of the operations using this token
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Check whether cancellation was requested
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Payload of the unwind that stops a jj-lib call from one of its callbacks
+struct Cancelled;
+
+fn cancelled_error() -> JjError {
+    JjError::Cancelled {
+        message: "Operation was cancelled".to_string(),
+    }
+}
+
+/// Fail with `JjError::Cancelled` if the token was cancelled
+pub(crate) fn check_cancelled(token: Option<&FfiCancellationToken>) -> Result<()> {
+    match token {
+        Some(token) if token.is_cancelled() => Err(cancelled_error()),
+        _ => Ok(()),
+    }
+}
+
+/// Unwind out of a callback called by jj-lib if the token was cancelled
+///
+/// Must only be called inside `catch_cancelled()`.
+pub(crate) fn abort_if_cancelled(token: Option<&FfiCancellationToken>) {
+    if token.is_some_and(FfiCancellationToken::is_cancelled) {
+        // Unlike `panic!()`, this doesn't run the panic hook
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Call `f`, failing with `JjError::Cancelled` if a callback called by it
+/// was stopped by `abort_if_cancelled()`
+pub(crate) fn catch_cancelled<T>(f: impl FnOnce() -> T) -> Result<T> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => Ok(value),
+        Err(payload) if payload.is::<Cancelled>() => Err(cancelled_error()),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
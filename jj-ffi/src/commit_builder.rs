@@ -0,0 +1,242 @@
+//! Commit builder for FFI
+//!
+//! `FfiCommitBuilder` collects the properties of a new or rewritten commit
+//! through chained setters and writes the commit in its transaction, so new
+//! properties can be added without changing existing records.
+
+use std::sync::{Arc, Mutex};
+
+use jj_lib::backend::{CommitId, Signature};
+use jj_lib::repo::Repo as _;
+use jj_lib::rewrite::merge_commit_trees;
+use jj_lib::signing::SignBehavior;
+
+use crate::error::{JjError, Result};
+use crate::rewrite::parse_commit_ids;
+use crate::transaction::FfiTransaction;
+use crate::types::{FfiCommit, FfiCommitId, FfiSignature};
+
+/// Which commits to sign when writing, mirroring `signing.behavior`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiSignBehavior {
+    /// Drop existing signatures
+    Drop,
+    /// Re-sign commits that were authored by the user and already signed
+    Keep,
+    /// Sign commits authored by the user, dropping others' signatures
+    Own,
+    /// Sign all commits
+    Force,
+}
+
+impl From<FfiSignBehavior> for SignBehavior {
+    fn from(behavior: FfiSignBehavior) -> Self {
+        match behavior {
+            FfiSignBehavior::Drop => Self::Drop,
+            FfiSignBehavior::Keep => Self::Keep,
+            FfiSignBehavior::Own => Self::Own,
+            FfiSignBehavior::Force => Self::Force,
+        }
+    }
+}
+
+/// Properties set on a builder, applied when the commit is written
+#[derive(Debug, Clone, Default)]
+struct CommitProperties {
+    description: Option<String>,
+    author: Option<FfiSignature>,
+    committer: Option<FfiSignature>,
+    parent_ids: Option<Vec<FfiCommitId>>,
+    tree_ids: Option<Vec<String>>,
+    sign_behavior: Option<FfiSignBehavior>,
+    sign_key: Option<String>,
+    generate_new_change_id: bool,
+}
+
+/// Builds a new commit, or a rewrite of an existing one, in a transaction
+///
+/// Created by `FfiTransaction::new_commit_builder()` or
+/// `FfiTransaction::rewrite_commit_builder()`. Setters return the builder so
+/// calls can be chained. Properties that aren't set default like in
+/// `jj new` and `jj describe`: new commits get the merged tree of their
+/// parents and the user's signature, and rewritten commits keep their
+/// properties except for the committer.
+#[derive(uniffi::Object)]
+pub struct FfiCommitBuilder {
+    transaction: Arc<FfiTransaction>,
+    /// The commit to rewrite, or `None` for a new commit
+    commit_id: Option<CommitId>,
+    properties: Mutex<CommitProperties>,
+    /// The commit written last by a rewrite builder, abandoned when the
+    /// builder is written again
+    rewritten_commit_id: Mutex<Option<CommitId>>,
+}
+
+impl FfiCommitBuilder {
+    pub(crate) fn new_commit(
+        transaction: Arc<FfiTransaction>,
+        parent_ids: Vec<FfiCommitId>,
+    ) -> Self {
+        Self {
+            transaction,
+            commit_id: None,
+            properties: Mutex::new(CommitProperties {
+                parent_ids: Some(parent_ids),
+                ..Default::default()
+            }),
+            rewritten_commit_id: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn rewrite_commit(transaction: Arc<FfiTransaction>, commit_id: CommitId) -> Self {
+        Self {
+            transaction,
+            commit_id: Some(commit_id),
+            properties: Mutex::default(),
+            rewritten_commit_id: Mutex::default(),
+        }
+    }
+
+    fn update(self: Arc<Self>, f: impl FnOnce(&mut CommitProperties)) -> Arc<Self> {
+        f(&mut self.properties.lock().unwrap());
+        self
+    }
+}
+
+#[uniffi::export]
+impl FfiCommitBuilder {
+    /// Set the description
+    ///
+    /// Trailers configured by `templates.commit_trailers` are added when the
+    /// commit is written.
+    pub fn set_description(self: Arc<Self>, description: String) -> Arc<Self> {
+        self.update(|properties| properties.description = Some(description))
+    }
+
+    /// Set the author
+    pub fn set_author(self: Arc<Self>, author: FfiSignature) -> Arc<Self> {
+        self.update(|properties| properties.author = Some(author))
+    }
+
+    /// Set the committer, instead of the user's signature at the current time
+    pub fn set_committer(self: Arc<Self>, committer: FfiSignature) -> Arc<Self> {
+        self.update(|properties| properties.committer = Some(committer))
+    }
+
+    /// Set the parents
+    pub fn set_parents(self: Arc<Self>, parent_ids: Vec<FfiCommitId>) -> Arc<Self> {
+        self.update(|properties| properties.parent_ids = Some(parent_ids))
+    }
+
+    /// Set the tree by its hex IDs, as listed in `FfiCommit::tree_ids`
+    pub fn set_tree(self: Arc<Self>, tree_ids: Vec<String>) -> Arc<Self> {
+        self.update(|properties| properties.tree_ids = Some(tree_ids))
+    }
+
+    /// Set which commits to sign, instead of the `signing.behavior` config
+    pub fn set_sign_behavior(self: Arc<Self>, behavior: FfiSignBehavior) -> Arc<Self> {
+        self.update(|properties| properties.sign_behavior = Some(behavior))
+    }
+
+    /// Set the signing key, instead of the `signing.key` config
+    pub fn set_sign_key(self: Arc<Self>, key: String) -> Arc<Self> {
+        self.update(|properties| properties.sign_key = Some(key))
+    }
+
+    /// Give the commit a new change ID, e.g. to duplicate a commit
+    pub fn generate_new_change_id(self: Arc<Self>) -> Arc<Self> {
+        self.update(|properties| properties.generate_new_change_id = true)
+    }
+
+    /// Write the commit to the transaction
+    ///
+    /// The builder can be written again, e.g. after changing some properties.
+    /// Writing a rewrite again abandons the commit written before, so only
+    /// the latest rewrite remains visible. A rewritten commit's descendants
+    /// are rebased onto the new commit by
+    /// `FfiTransaction::rebase_descendants()` or when the transaction is
+    /// committed.
+    pub fn write(&self) -> Result<FfiCommit> {
+        let properties = self.properties.lock().unwrap().clone();
+        self.transaction.with_transaction_mut(|tx| {
+            let mut_repo = tx.repo_mut();
+            let store = mut_repo.store().clone();
+            let parent_ids = match &properties.parent_ids {
+                Some(parent_ids) => {
+                    let parent_ids = parse_commit_ids(parent_ids)?;
+                    if parent_ids.is_empty() {
+                        return Err(JjError::InvalidArgument {
+                            message: "At least one parent commit ID is required".to_string(),
+                        });
+                    }
+                    Some(parent_ids)
+                }
+                None => None,
+            };
+            let mut tree = match &properties.tree_ids {
+                Some(tree_ids) => Some(crate::tree::merged_tree_from_ids(&store, tree_ids)?),
+                None => None,
+            };
+
+            let mut builder = match (&self.commit_id, parent_ids) {
+                (Some(commit_id), parent_ids) => {
+                    let commit = store.get_commit(commit_id)?;
+                    let mut builder = mut_repo.rewrite_commit(&commit).detach();
+                    if let Some(parent_ids) = parent_ids {
+                        builder.set_parents(parent_ids);
+                    }
+                    builder
+                }
+                (None, Some(parent_ids)) => {
+                    let parents = parent_ids
+                        .iter()
+                        .map(|id| store.get_commit(id))
+                        .collect::<std::result::Result<Vec<_>, _>>()?;
+                    let tree = match tree.take() {
+                        Some(tree) => tree,
+                        None => pollster::block_on(merge_commit_trees(mut_repo, &parents))?,
+                    };
+                    mut_repo.new_commit(parent_ids, tree).detach()
+                }
+                (None, None) => unreachable!("new commits are created with parents"),
+            };
+
+            if let Some(tree) = tree {
+                builder.set_tree(tree);
+            }
+            if let Some(author) = &properties.author {
+                builder.set_author(Signature::from(author));
+            }
+            if let Some(committer) = &properties.committer {
+                builder.set_committer(Signature::from(committer));
+            }
+            if let Some(behavior) = properties.sign_behavior {
+                builder.set_sign_behavior(behavior.into());
+            }
+            if let Some(key) = properties.sign_key {
+                builder.set_sign_key(key);
+            }
+            if properties.generate_new_change_id {
+                builder.generate_new_change_id();
+            }
+
+            let commit = match properties.description {
+                Some(description) => {
+                    builder.set_description(description);
+                    crate::trailers::write_with_configured_trailers(mut_repo, builder)?
+                }
+                None => builder.write(mut_repo)?,
+            };
+            if self.commit_id.is_some() {
+                let mut rewritten_commit_id = self.rewritten_commit_id.lock().unwrap();
+                if let Some(previous_id) = rewritten_commit_id.replace(commit.id().clone()) {
+                    if previous_id != *commit.id() {
+                        let previous = store.get_commit(&previous_id)?;
+                        mut_repo.record_abandoned_commit(&previous);
+                    }
+                }
+            }
+            Ok(FfiCommit::from(&commit))
+        })
+    }
+}
@@ -0,0 +1,362 @@
+//! Conflict inspection for FFI
+//!
+//! This module exposes the individual sides and bases of a conflicted path,
+//! so clients can build their own conflict viewers instead of relying on
+//! marker-materialized text. The versions of a conflicted file can also be
+//! written to disk for an external merge tool, and its result read back.
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use jj_lib::backend::{CommitId, CopyId, FileId, TreeValue};
+use jj_lib::commit::Commit;
+use jj_lib::conflicts::{
+    ConflictMaterializeOptions, choose_materialized_conflict_marker_len,
+    materialize_merge_result_to_bytes, update_from_content,
+};
+use jj_lib::merge::{Merge, MergedTreeValue};
+use jj_lib::merged_tree::MergedTreeBuilder;
+use jj_lib::repo::{MutableRepo, ReadonlyRepo, Repo};
+use jj_lib::repo_path::{RepoPath, RepoPathBuf};
+use jj_lib::store::Store;
+
+use crate::error::{JjError, Result};
+use crate::files::{conflict_marker_style, parse_repo_path, read_file_content};
+use crate::types::FfiCommitId;
+
+/// One term (a side or a base) of a conflicted file
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiConflictTerm {
+    /// Label used by jj's conflict markers, e.g. "side #1", or "base" if the
+    /// conflict has a single base and "base #1" otherwise
+    pub label: String,
+    /// File content of this term (`None` if the file is absent on this term)
+    pub content: Option<Vec<u8>>,
+}
+
+/// The sides and bases of a conflicted file
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiConflictSides {
+    /// Repository-relative path of the conflicted file
+    pub path: String,
+    /// Sides of the conflict (one more than the number of bases)
+    pub sides: Vec<FfiConflictTerm>,
+    /// Bases of the conflict
+    pub bases: Vec<FfiConflictTerm>,
+}
+
+/// Files written for an external merge tool, like Kaleidoscope or FileMerge
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiMergeToolFiles {
+    /// Path for the base version, the common ancestor of the sides
+    pub base: String,
+    /// Path for the left side
+    pub left: String,
+    /// Path for the right side
+    pub right: String,
+    /// Path for the merge result, which starts as the conflict materialized
+    /// with conflict markers
+    pub output: String,
+}
+
+/// Read the content of a single conflict term
+fn read_term(store: &Store, path: &RepoPath, term: &Option<FileId>) -> Result<Option<Vec<u8>>> {
+    term.as_ref()
+        .map(|id| read_file_content(store, path, id))
+        .transpose()
+}
+
+/// Split a conflicted file value into its sides and bases
+///
+/// Returns `None` if the conflict involves non-file values.
+fn sides_for_value(
+    store: &Store,
+    path: &RepoPath,
+    value: &MergedTreeValue,
+) -> Result<Option<FfiConflictSides>> {
+    let Some(file_ids) = value.to_file_merge() else {
+        return Ok(None);
+    };
+    let file_ids = file_ids.simplify();
+
+    let sides = file_ids
+        .adds()
+        .enumerate()
+        .map(|(i, term)| {
+            Ok(FfiConflictTerm {
+                label: format!("side #{}", i + 1),
+                content: read_term(store, path, term)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let num_bases = file_ids.removes().len();
+    let bases = file_ids
+        .removes()
+        .enumerate()
+        .map(|(i, term)| {
+            Ok(FfiConflictTerm {
+                label: if num_bases == 1 {
+                    "base".to_string()
+                } else {
+                    format!("base #{}", i + 1)
+                },
+                content: read_term(store, path, term)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(FfiConflictSides {
+        path: path.as_internal_file_string().to_string(),
+        sides,
+        bases,
+    }))
+}
+
+/// Get the sides and bases of a conflicted file in a commit
+pub fn conflict_sides(
+    repo: &Arc<ReadonlyRepo>,
+    commit_id: &FfiCommitId,
+    path: &str,
+) -> Result<FfiConflictSides> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let repo_path = parse_repo_path(path)?;
+
+    let store = repo.store();
+    let commit = store.get_commit(&id)?;
+    let value = commit.tree().path_value(&repo_path)?;
+    if value.is_resolved() {
+        return Err(JjError::InvalidArgument {
+            message: format!("Path is not conflicted: {}", path),
+        });
+    }
+
+    sides_for_value(store, &repo_path, &value)?.ok_or_else(|| JjError::InvalidArgument {
+        message: format!("Conflict at '{}' is not a file conflict", path),
+    })
+}
+
+/// Get the sides and bases of every conflicted file in a commit
+///
+/// Conflicts involving non-file values (e.g. a file and a directory) are
+/// skipped; use `conflicted_paths` to list all of them.
+pub fn conflicts(
+    repo: &Arc<ReadonlyRepo>,
+    commit_id: &FfiCommitId,
+) -> Result<Vec<FfiConflictSides>> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let store = repo.store();
+    let commit = store.get_commit(&id)?;
+
+    let mut conflicts = Vec::new();
+    for (path, value) in commit.tree().conflicts() {
+        if let Some(sides) = sides_for_value(store, &path, &value?)? {
+            conflicts.push(sides);
+        }
+    }
+    Ok(conflicts)
+}
+
+/// Get a commit and the value of a conflicted path in it
+fn conflicted_value(
+    store: &Arc<Store>,
+    commit_id: &FfiCommitId,
+    path: &str,
+) -> Result<(Commit, RepoPathBuf, MergedTreeValue)> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let repo_path = parse_repo_path(path)?;
+
+    let commit = store.get_commit(&id)?;
+    let value = commit.tree().path_value(&repo_path)?;
+    if value.is_resolved() {
+        return Err(JjError::InvalidArgument {
+            message: format!("Path is not conflicted: {}", path),
+        });
+    }
+    Ok((commit, repo_path, value))
+}
+
+/// Get the file IDs of a conflict that a merge tool can resolve, i.e. a file
+/// conflict with two sides after simplification
+fn merge_tool_file_ids(value: &MergedTreeValue, path: &str) -> Result<Merge<Option<FileId>>> {
+    let file_ids = value
+        .to_file_merge()
+        .ok_or_else(|| JjError::InvalidArgument {
+            message: format!("Conflict at '{}' is not a file conflict", path),
+        })?;
+    let num_sides = file_ids.simplify().num_sides();
+    if num_sides != 2 {
+        return Err(JjError::InvalidArgument {
+            message: format!(
+                "Conflict at '{}' has {} sides; merge tools only support 2",
+                path, num_sides
+            ),
+        });
+    }
+    Ok(file_ids)
+}
+
+/// Read the contents of the terms of a file conflict, with absent files
+/// read as empty
+fn read_terms(
+    store: &Store,
+    path: &RepoPath,
+    file_ids: &Merge<Option<FileId>>,
+) -> Result<Merge<Vec<u8>>> {
+    file_ids.try_map(|term| match term {
+        Some(id) => read_file_content(store, path, id),
+        None => Ok(Vec::new()),
+    })
+}
+
+/// Whether a file resolving a conflict should be executable, i.e. whether all
+/// sides of the conflict are executable
+fn resolved_executable(value: &MergedTreeValue) -> bool {
+    value.adds().flatten().all(|term| {
+        matches!(
+            term,
+            TreeValue::File {
+                executable: true,
+                ..
+            }
+        )
+    })
+}
+
+/// Rewrite a commit with a new value for a path, rebasing its descendants
+fn rewrite_path_value(
+    mut_repo: &mut MutableRepo,
+    commit: &Commit,
+    repo_path: RepoPathBuf,
+    value: MergedTreeValue,
+) -> Result<Commit> {
+    let mut tree_builder = MergedTreeBuilder::new(commit.tree());
+    tree_builder.set_or_remove(repo_path, value);
+    let new_tree = tree_builder.write_tree()?;
+
+    let new_commit = mut_repo.rewrite_commit(commit).set_tree(new_tree).write()?;
+    mut_repo.rebase_descendants()?;
+    Ok(new_commit)
+}
+
+/// Rewrite a commit with resolved content for a conflicted file
+///
+/// The resolved file is executable only if all conflict sides were
+/// executable. Descendants of the commit are rebased onto the new commit.
+pub(crate) fn resolve_conflict(
+    mut_repo: &mut MutableRepo,
+    commit_id: &FfiCommitId,
+    path: &str,
+    content: &[u8],
+) -> Result<Commit> {
+    let store = mut_repo.store().clone();
+    let (commit, repo_path, value) = conflicted_value(&store, commit_id, path)?;
+
+    let file_id = pollster::block_on(store.write_file(&repo_path, &mut &*content))?;
+    let new_value = Merge::normal(TreeValue::File {
+        id: file_id,
+        executable: resolved_executable(&value),
+        copy_id: CopyId::placeholder(),
+    });
+    rewrite_path_value(mut_repo, &commit, repo_path, new_value)
+}
+
+/// Write the base, sides and materialized conflict of a conflicted file for
+/// an external merge tool
+///
+/// Absent versions, e.g. of a file deleted on one side, are written as empty
+/// files. Conflict markers use the repository's configured style.
+pub fn write_merge_tool_files(
+    repo: &Arc<ReadonlyRepo>,
+    commit_id: &FfiCommitId,
+    path: &str,
+    files: &FfiMergeToolFiles,
+) -> Result<()> {
+    let store = repo.store();
+    let (_, repo_path, value) = conflicted_value(store, commit_id, path)?;
+    let file_ids = merge_tool_file_ids(&value, path)?.simplify();
+    let contents = read_terms(store, &repo_path, &file_ids)?;
+    let options = ConflictMaterializeOptions {
+        marker_style: conflict_marker_style(repo.settings()),
+        marker_len: None,
+        merge: store.merge_options().clone(),
+    };
+    let output = materialize_merge_result_to_bytes(&contents, &options);
+
+    let outputs: [(&str, &[u8]); 4] = [
+        (&files.base, contents.get_remove(0).unwrap()),
+        (&files.left, contents.get_add(0).unwrap()),
+        (&files.right, contents.get_add(1).unwrap()),
+        (&files.output, &output),
+    ];
+    for (dest, content) in outputs {
+        fs::write(dest, content).map_err(|e| JjError::InvalidArgument {
+            message: format!("Failed to write '{}': {}", dest, e),
+        })?;
+    }
+    Ok(())
+}
+
+/// Rewrite a commit with the result of an external merge tool for a
+/// conflicted file
+///
+/// The result may keep some of the conflict markers written by
+/// `write_merge_tool_files`, which leaves those hunks conflicted. Fails if
+/// the result leaves the conflict unchanged. Descendants of the commit are
+/// rebased onto the new commit.
+pub(crate) fn resolve_conflict_from_merge_tool(
+    mut_repo: &mut MutableRepo,
+    commit_id: &FfiCommitId,
+    path: &str,
+    output_path: &str,
+) -> Result<Commit> {
+    let content = fs::read(Path::new(output_path)).map_err(|e| JjError::InvalidArgument {
+        message: format!("Failed to read '{}': {}", output_path, e),
+    })?;
+    let store = mut_repo.store().clone();
+    let (commit, repo_path, value) = conflicted_value(&store, commit_id, path)?;
+    let file_ids = merge_tool_file_ids(&value, path)?;
+    let contents = read_terms(&store, &repo_path, &file_ids.simplify())?;
+    let marker_len = choose_materialized_conflict_marker_len(&contents);
+
+    let new_file_ids = pollster::block_on(update_from_content(
+        &file_ids, &store, &repo_path, &content, marker_len,
+    ))?;
+    if new_file_ids == file_ids {
+        return Err(JjError::InvalidArgument {
+            message: format!("The merge tool left the conflict at '{}' unchanged", path),
+        });
+    }
+    let new_value = match new_file_ids.into_resolved() {
+        Ok(Some(file_id)) => Merge::normal(TreeValue::File {
+            id: file_id,
+            executable: resolved_executable(&value),
+            copy_id: CopyId::placeholder(),
+        }),
+        Ok(None) => Merge::absent(),
+        Err(new_file_ids) => value.with_new_file_ids(&new_file_ids),
+    };
+    rewrite_path_value(mut_repo, &commit, repo_path, new_value)
+}
+
+/// List the paths with unresolved conflicts in a commit, sorted by path
+pub fn conflicted_paths(repo: &Arc<ReadonlyRepo>, commit_id: &FfiCommitId) -> Result<Vec<String>> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let commit = repo.store().get_commit(&id)?;
+    commit
+        .tree()
+        .conflicts()
+        .map(|(path, value)| {
+            value?;
+            Ok(path.as_internal_file_string().to_string())
+        })
+        .collect()
+}
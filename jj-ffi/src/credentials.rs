@@ -0,0 +1,326 @@
+//! Git credentials for FFI
+//!
+//! This module lets the host app supply credentials for Git remotes, e.g.
+//! from the keychain. jj-lib talks to remotes by spawning `git`, which
+//! ignores the credential hooks of `RemoteCallbacks`, so credentials are
+//! handed to the subprocess through its environment instead, by running it
+//! through a per-call wrapper script. Passwords and tokens are never written
+//! to disk: the credential helper reads them from a named pipe served by this
+//! process.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::thread::JoinHandle;
+
+use jj_lib::git::{self, GitSettings};
+use jj_lib::ref_name::RemoteName;
+use jj_lib::store::Store;
+use tempfile::TempDir;
+
+use crate::error::{JjError, Result};
+
+/// Name of the named pipe serving HTTPS credentials in the wrapper directory
+const CREDENTIAL_PIPE: &str = "credential";
+
+/// Credentials for a Git remote
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum FfiGitCredential {
+    /// Authenticate over SSH with a private key file (without passphrase)
+    SshKey { private_key_path: String },
+    /// Authenticate over SSH with the agent listening on a socket
+    SshAgent { socket_path: String },
+    /// Authenticate over HTTPS with a username and password or access token
+    UserPassword { username: String, password: String },
+}
+
+/// Supplies credentials for Git remotes, implemented by the host app
+#[uniffi::export(with_foreign)]
+pub trait FfiGitCredentialProvider: Send + Sync {
+    /// Get the credentials for a remote URL, or `None` to fall back to Git's
+    /// own configuration (credential helpers, `~/.ssh`, ...)
+    fn credentials(&self, remote_name: String, url: String) -> Option<FfiGitCredential>;
+}
+
+/// Which URL of a remote is being connected to
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RemoteDirection {
+    Fetch,
+    Push,
+}
+
+/// Look up the URL of a remote in the backing Git repository
+fn remote_url(store: &Store, remote: &RemoteName, direction: RemoteDirection) -> Result<String> {
+    let git_repo = git::get_git_repo(store).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    let git_remote = git_repo
+        .find_remote(remote.as_str())
+        .map_err(|e| JjError::Git {
+            message: format!("Failed to find remote '{}': {}", remote.as_str(), e),
+        })?;
+    let direction = match direction {
+        RemoteDirection::Fetch => gix::remote::Direction::Fetch,
+        RemoteDirection::Push => gix::remote::Direction::Push,
+    };
+    let url = git_remote.url(direction).ok_or_else(|| JjError::Git {
+        message: format!("Remote '{}' has no URL", remote.as_str()),
+    })?;
+    Ok(url.to_bstring().to_string())
+}
+
+/// Quote a value for a POSIX shell
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Credential helper that answers `get` requests with the credentials served
+/// on `pipe_path`
+fn credential_helper(pipe_path: &Path) -> String {
+    format!(
+        "!f() {{ test \"$1\" = get && cat {}; }}; f",
+        shell_quote(&pipe_path.to_string_lossy())
+    )
+}
+
+/// Environment variables for a `git` subprocess authenticating with
+/// `credential`
+///
+/// HTTPS credentials are read from the pipe at `pipe_path`, so the variables
+/// hold no secrets.
+fn credential_env(credential: &FfiGitCredential, pipe_path: &Path) -> Vec<(&'static str, String)> {
+    let mut vars = vec![("GIT_TERMINAL_PROMPT", "0".to_string())];
+    match credential {
+        FfiGitCredential::SshKey { private_key_path } => {
+            let command = format!(
+                "ssh -i {} -o IdentitiesOnly=yes -o BatchMode=yes",
+                shell_quote(private_key_path)
+            );
+            vars.push(("GIT_SSH_COMMAND", command));
+        }
+        FfiGitCredential::SshAgent { socket_path } => {
+            vars.push(("SSH_AUTH_SOCK", socket_path.clone()));
+        }
+        FfiGitCredential::UserPassword { .. } => {
+            // An empty helper resets the configured helpers, so ours is the only one
+            vars.extend([
+                ("GIT_CONFIG_COUNT", "2".to_string()),
+                ("GIT_CONFIG_KEY_0", "credential.helper".to_string()),
+                ("GIT_CONFIG_VALUE_0", String::new()),
+                ("GIT_CONFIG_KEY_1", "credential.helper".to_string()),
+                ("GIT_CONFIG_VALUE_1", credential_helper(pipe_path)),
+            ]);
+        }
+    }
+    vars
+}
+
+/// A `git` executable that authenticates a remote operation
+///
+/// When the provider supplies credentials, a wrapper script that sets up its
+/// own environment before running the configured `git` is written to a
+/// private temporary directory, so the process environment is never
+/// modified. HTTPS credentials are served on a named pipe next to it for as
+/// long as this is alive. The pipe and the wrapper are removed on drop.
+pub(crate) struct GitCredentials {
+    git_settings: GitSettings,
+    // Dropped first, so the pipe stops being served before it is removed
+    _credential_pipe: Option<CredentialPipe>,
+    _wrapper_dir: Option<TempDir>,
+}
+
+impl GitCredentials {
+    /// Ask the provider for the credentials of a remote and prepare a `git`
+    /// executable that uses them
+    pub(crate) fn new(
+        provider: Option<&Arc<dyn FfiGitCredentialProvider>>,
+        store: &Store,
+        remote: &RemoteName,
+        direction: RemoteDirection,
+        git_settings: &GitSettings,
+    ) -> Result<Self> {
+        let mut credentials = Self {
+            git_settings: git_settings.clone(),
+            _credential_pipe: None,
+            _wrapper_dir: None,
+        };
+        let Some(provider) = provider else {
+            return Ok(credentials);
+        };
+        let url = remote_url(store, remote, direction)?;
+        let Some(credential) = provider.credentials(remote.as_str().to_string(), url) else {
+            return Ok(credentials);
+        };
+
+        let (wrapper_dir, credential_pipe) =
+            write_git_wrapper(&git_settings.executable_path, &credential)?;
+        credentials.git_settings.executable_path = wrapper_dir.path().join("git");
+        credentials._credential_pipe = credential_pipe;
+        credentials._wrapper_dir = Some(wrapper_dir);
+        Ok(credentials)
+    }
+
+    /// Git settings whose executable authenticates with the credentials
+    pub(crate) fn git_settings(&self) -> &GitSettings {
+        &self.git_settings
+    }
+}
+
+/// Serves HTTPS credentials to the credential helper through a named pipe
+///
+/// A thread writes the credentials each time the helper opens the pipe, so
+/// they only ever pass through the pipe's buffer. The thread is stopped on
+/// drop.
+#[cfg_attr(not(unix), allow(dead_code))]
+struct CredentialPipe {
+    path: PathBuf,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+#[cfg(unix)]
+impl CredentialPipe {
+    /// Create the pipe at `path` and start serving `username` and `password`
+    fn serve(path: PathBuf, username: &str, password: &str) -> Result<Self> {
+        use std::ffi::CString;
+        use std::io::Write as _;
+        use std::os::unix::ffi::OsStrExt as _;
+        use std::sync::atomic::Ordering;
+
+        if [username, password]
+            .iter()
+            .any(|value| value.contains(['\n', '\0']))
+        {
+            return Err(JjError::InvalidArgument {
+                message: "Git credentials must not contain newlines or NUL characters".to_string(),
+            });
+        }
+        let c_path = CString::new(path.as_os_str().as_bytes()).map_err(|e| JjError::Internal {
+            message: format!("Invalid credential pipe path: {}", e),
+        })?;
+        // SAFETY: `c_path` is a valid NUL-terminated string
+        if unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) } != 0 {
+            return Err(JjError::Internal {
+                message: format!(
+                    "Failed to create credential pipe: {}",
+                    std::io::Error::last_os_error()
+                ),
+            });
+        }
+
+        let response = format!("username={}\npassword={}\n", username, password);
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = std::thread::Builder::new()
+            .name("jj-ffi-git-credentials".to_string())
+            .spawn({
+                let path = path.clone();
+                let stop = Arc::clone(&stop);
+                move || {
+                    // A helper that exits early must not kill the app with
+                    // SIGPIPE; the failed write is ignored instead
+                    // SAFETY: only changes the signal mask of this thread
+                    unsafe {
+                        let mut set = std::mem::zeroed();
+                        libc::sigemptyset(&mut set);
+                        libc::sigaddset(&mut set, libc::SIGPIPE);
+                        libc::pthread_sigmask(libc::SIG_BLOCK, &set, std::ptr::null_mut());
+                    }
+                    // Opening for writing blocks until the helper opens the pipe
+                    while let Ok(mut pipe) = std::fs::OpenOptions::new().write(true).open(&path) {
+                        if stop.load(Ordering::SeqCst) {
+                            break;
+                        }
+                        let _ = pipe.write_all(response.as_bytes());
+                    }
+                }
+            })
+            .map_err(|e| JjError::Internal {
+                message: format!("Failed to serve Git credentials: {}", e),
+            })?;
+        Ok(Self {
+            path,
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for CredentialPipe {
+    fn drop(&mut self) {
+        use std::os::unix::fs::OpenOptionsExt as _;
+        use std::sync::atomic::Ordering;
+
+        self.stop.store(true, Ordering::SeqCst);
+        // Holding the pipe open for reading unblocks the thread's next open
+        let reader = std::fs::OpenOptions::new()
+            .read(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(&self.path);
+        if let Some(thread) = self.thread.take() {
+            if reader.is_ok() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// Write a `git` wrapper that runs `git_path` with the environment for
+/// `credential`, serving HTTPS credentials on a pipe next to it
+#[cfg(unix)]
+fn write_git_wrapper(
+    git_path: &Path,
+    credential: &FfiGitCredential,
+) -> Result<(TempDir, Option<CredentialPipe>)> {
+    use std::io::Write as _;
+    use std::os::unix::fs::OpenOptionsExt as _;
+
+    let io_error = |e: std::io::Error| JjError::Internal {
+        message: format!("Failed to write Git credentials: {}", e),
+    };
+    // The directory is only accessible by the current user
+    let wrapper_dir = tempfile::Builder::new()
+        .prefix("jj-ffi-git-")
+        .tempdir()
+        .map_err(io_error)?;
+    let pipe_path = wrapper_dir.path().join(CREDENTIAL_PIPE);
+    let credential_pipe = match credential {
+        FfiGitCredential::UserPassword { username, password } => Some(CredentialPipe::serve(
+            pipe_path.clone(),
+            username,
+            password,
+        )?),
+        FfiGitCredential::SshKey { .. } | FfiGitCredential::SshAgent { .. } => None,
+    };
+    let mut script = String::from("#!/bin/sh\n");
+    for (key, value) in credential_env(credential, &pipe_path) {
+        script.push_str(&format!(
+            "{}={}\nexport {}\n",
+            key,
+            shell_quote(&value),
+            key
+        ));
+    }
+    script.push_str(&format!(
+        "exec {} \"$@\"\n",
+        shell_quote(&git_path.to_string_lossy())
+    ));
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o700)
+        .open(wrapper_dir.path().join("git"))
+        .map_err(io_error)?;
+    file.write_all(script.as_bytes()).map_err(io_error)?;
+    Ok((wrapper_dir, credential_pipe))
+}
+
+#[cfg(not(unix))]
+fn write_git_wrapper(
+    _git_path: &Path,
+    _credential: &FfiGitCredential,
+) -> Result<(TempDir, Option<CredentialPipe>)> {
+    Err(JjError::Git {
+        message: "Git credentials are not supported on this platform".to_string(),
+    })
+}
@@ -0,0 +1,519 @@
+//! Diff operations for FFI
+//!
+//! This module provides tree diff functionality similar to `jj diff`,
+//! exposing per-file and per-directory change summaries and line-level
+//! hunks via FFI.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use bstr::BStr;
+use futures::StreamExt as _;
+use jj_lib::backend::{CommitId, TreeValue};
+use jj_lib::commit::Commit;
+use jj_lib::conflicts::ConflictMarkerStyle;
+use jj_lib::copies::{CopiesTreeDiffEntryPath, CopyOperation, CopyRecords};
+use jj_lib::diff::DiffHunkKind;
+use jj_lib::diff_presentation::unified::{DiffLineType, unified_diff_hunks};
+use jj_lib::diff_presentation::{DiffTokenType, LineCompareMode, diff_by_line};
+use jj_lib::matchers::{EverythingMatcher, PrefixMatcher};
+use jj_lib::merge::{Diff, MergedTreeValue};
+use jj_lib::merged_tree::MergedTree;
+use jj_lib::repo::{ReadonlyRepo, Repo};
+use jj_lib::repo_path::{RepoPath, RepoPathBuf};
+use jj_lib::store::Store;
+
+use crate::error::{JjError, Result};
+use crate::files::{
+    conflict_marker_style, materialize_file_conflict, parse_repo_path, read_file_content,
+};
+use crate::tree::{FfiTreeEntryType, classify_value};
+use crate::types::FfiCommitId;
+
+/// Number of leading bytes inspected when deciding whether content is binary
+const BINARY_PEEK_SIZE: usize = 8000;
+
+/// Kind of change made to a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiChangeType {
+    /// The file was added
+    Added,
+    /// The file was modified in place
+    Modified,
+    /// The file was deleted
+    Deleted,
+    /// The file was renamed (and possibly modified)
+    Renamed,
+    /// The file was copied from another file (and possibly modified)
+    Copied,
+}
+
+/// A changed file in a diff
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiDiffEntry {
+    /// Repository-relative path of the file after the change
+    pub path: String,
+    /// Path the file was renamed or copied from, if any
+    pub source_path: Option<String>,
+    /// The kind of change
+    pub change_type: FfiChangeType,
+    /// Type of the entry before the change (absent for added files)
+    pub old_entry_type: Option<FfiTreeEntryType>,
+    /// Whether the entry was an executable file before the change
+    pub old_executable: bool,
+    /// Type of the entry after the change (absent for deleted files)
+    pub new_entry_type: Option<FfiTreeEntryType>,
+    /// Whether the entry is an executable file after the change
+    pub new_executable: bool,
+}
+
+/// Aggregated diff statistics for a directory, or for a single file
+/// directly inside the summarized directory
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiDirectoryDiffSummary {
+    /// Repository-relative path of the directory or file
+    pub path: String,
+    /// Whether the entry is a subdirectory rather than a file
+    pub is_directory: bool,
+    /// Number of changed files under this directory (recursively), or 1 for
+    /// a file
+    pub files_changed: u64,
+    /// Number of inserted lines under this directory
+    pub insertions: u64,
+    /// Number of deleted lines under this directory
+    pub deletions: u64,
+}
+
+/// Line counts of a changed file, like a line of `jj diff --stat`
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiFileDiffStat {
+    /// Repository-relative path of the file
+    pub path: String,
+    /// Number of inserted lines
+    pub insertions: u64,
+    /// Number of deleted lines
+    pub deletions: u64,
+    /// Whether either side of the file is binary (no lines are counted)
+    pub binary: bool,
+}
+
+/// Line counts of all files changed between two commits, like `jj diff --stat`
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiDiffStats {
+    /// Per-file line counts, ordered by path
+    pub files: Vec<FfiFileDiffStat>,
+    /// Total number of inserted lines
+    pub total_insertions: u64,
+    /// Total number of deleted lines
+    pub total_deletions: u64,
+}
+
+/// Type of a line in a diff hunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiDiffLineType {
+    /// Unchanged line shown for context
+    Context,
+    /// Line removed from the old content
+    Removed,
+    /// Line added in the new content
+    Added,
+}
+
+impl From<DiffLineType> for FfiDiffLineType {
+    fn from(line_type: DiffLineType) -> Self {
+        match line_type {
+            DiffLineType::Context => FfiDiffLineType::Context,
+            DiffLineType::Removed => FfiDiffLineType::Removed,
+            DiffLineType::Added => FfiDiffLineType::Added,
+        }
+    }
+}
+
+/// A single line in a diff hunk
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiDiffLine {
+    /// Type of the line
+    pub line_type: FfiDiffLineType,
+    /// Line content without the trailing newline (lossily decoded as UTF-8)
+    pub content: String,
+    /// 1-based line number in the old content (absent for added lines)
+    pub old_line_number: Option<u32>,
+    /// 1-based line number in the new content (absent for removed lines)
+    pub new_line_number: Option<u32>,
+    /// Ranges of changed words within `content` (empty for context lines or
+    /// if intraline ranges were not requested)
+    pub changed_ranges: Vec<FfiTextRange>,
+}
+
+/// A range of UTF-8 byte offsets within a line's content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Record)]
+pub struct FfiTextRange {
+    /// Offset of the first byte
+    pub start: u32,
+    /// Offset past the last byte
+    pub end: u32,
+}
+
+/// A hunk of a line-based diff
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiDiffHunk {
+    /// 1-based start line in the old content
+    pub old_start: u32,
+    /// Number of old lines covered by this hunk
+    pub old_count: u32,
+    /// 1-based start line in the new content
+    pub new_start: u32,
+    /// Number of new lines covered by this hunk
+    pub new_count: u32,
+    /// Lines of the hunk
+    pub lines: Vec<FfiDiffLine>,
+}
+
+/// Load a commit by its FFI ID
+fn load_commit(repo: &Arc<ReadonlyRepo>, commit_id: &FfiCommitId) -> Result<Commit> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    Ok(repo.store().get_commit(&id)?)
+}
+
+/// Collect the differences between two trees under the given directory
+fn collect_tree_diff(
+    from_tree: &MergedTree,
+    to_tree: &MergedTree,
+    dir: &RepoPath,
+) -> Result<Vec<(RepoPathBuf, Diff<MergedTreeValue>)>> {
+    let matcher = PrefixMatcher::new([dir]);
+    pollster::block_on(
+        from_tree
+            .diff_stream(to_tree, &matcher)
+            .map(|entry| Ok((entry.path, entry.values?)))
+            .collect::<Vec<Result<_>>>(),
+    )
+    .into_iter()
+    .collect()
+}
+
+/// Collect copy and rename records between two commits
+fn collect_copy_records(store: &Store, from: &Commit, to: &Commit) -> Result<CopyRecords> {
+    let records = pollster::block_on(
+        store
+            .get_copy_records(None, from.id(), to.id())?
+            .collect::<Vec<_>>(),
+    );
+    let mut copy_records = CopyRecords::default();
+    copy_records.add_records(records)?;
+    Ok(copy_records)
+}
+
+/// Classify a diff entry by its path information and before/after values
+fn change_type(path: &CopiesTreeDiffEntryPath, values: &Diff<MergedTreeValue>) -> FfiChangeType {
+    match path.copy_operation() {
+        Some(CopyOperation::Rename) => FfiChangeType::Renamed,
+        Some(CopyOperation::Copy) => FfiChangeType::Copied,
+        None if values.before.is_absent() => FfiChangeType::Added,
+        None if values.after.is_absent() => FfiChangeType::Deleted,
+        None => FfiChangeType::Modified,
+    }
+}
+
+/// Classify a tree value as `(type, executable)`, with no type if it's absent
+fn entry_type(value: &MergedTreeValue) -> (Option<FfiTreeEntryType>, bool) {
+    if value.is_absent() {
+        return (None, false);
+    }
+    let (entry_type, executable) = classify_value(&value.map(|value| value.as_ref()));
+    (Some(entry_type), executable)
+}
+
+/// Read the content of a tree value for line-based comparison
+///
+/// Conflicts are materialized with conflict markers; absent entries, trees,
+/// and submodules are treated as empty.
+fn content_for_diff(
+    store: &Store,
+    path: &RepoPath,
+    value: &MergedTreeValue,
+    marker_style: ConflictMarkerStyle,
+) -> Result<Vec<u8>> {
+    match value.as_resolved() {
+        Some(None) => Ok(Vec::new()),
+        Some(Some(TreeValue::File { id, .. })) => read_file_content(store, path, id),
+        Some(Some(TreeValue::Symlink(id))) => {
+            let target = pollster::block_on(store.read_symlink(path, id))?;
+            Ok(target.into_bytes())
+        }
+        Some(Some(_)) => Ok(Vec::new()),
+        None => {
+            Ok(materialize_file_conflict(store, path, value, marker_style)?.unwrap_or_default())
+        }
+    }
+}
+
+/// Whether the content is likely binary (same heuristic as Git)
+fn is_binary(content: &[u8]) -> bool {
+    content[..BINARY_PEEK_SIZE.min(content.len())].contains(&0)
+}
+
+/// Count inserted and deleted lines between two contents
+///
+/// Binary contents are reported as having no line changes.
+fn count_line_changes(left: &[u8], right: &[u8]) -> (u64, u64) {
+    if is_binary(left) || is_binary(right) {
+        return (0, 0);
+    }
+    let diff = diff_by_line([left, right], &LineCompareMode::Exact);
+    let mut insertions = 0;
+    let mut deletions = 0;
+    for hunk in diff.hunks() {
+        if hunk.kind == DiffHunkKind::Different {
+            deletions += hunk.contents[0].split_inclusive(|b| *b == b'\n').count() as u64;
+            insertions += hunk.contents[1].split_inclusive(|b| *b == b'\n').count() as u64;
+        }
+    }
+    (insertions, deletions)
+}
+
+/// Count inserted and deleted lines across all files between two trees
+pub(crate) fn tree_line_changes(
+    store: &Store,
+    marker_style: ConflictMarkerStyle,
+    from_tree: &MergedTree,
+    to_tree: &MergedTree,
+) -> Result<(u64, u64)> {
+    let mut insertions = 0;
+    let mut deletions = 0;
+    for (path, values) in collect_tree_diff(from_tree, to_tree, RepoPath::root())? {
+        let left = content_for_diff(store, &path, &values.before, marker_style)?;
+        let right = content_for_diff(store, &path, &values.after, marker_style)?;
+        let (file_insertions, file_deletions) = count_line_changes(&left, &right);
+        insertions += file_insertions;
+        deletions += file_deletions;
+    }
+    Ok((insertions, deletions))
+}
+
+/// Summarize changes per immediate subdirectory and file of `dir` between two
+/// commits
+pub fn diff_directory_summary(
+    repo: &Arc<ReadonlyRepo>,
+    from: &FfiCommitId,
+    to: &FfiCommitId,
+    dir: &str,
+) -> Result<Vec<FfiDirectoryDiffSummary>> {
+    let dir_path = parse_repo_path(dir.trim_end_matches('/'))?;
+    let from_tree = load_commit(repo, from)?.tree();
+    let to_tree = load_commit(repo, to)?.tree();
+    let store = repo.store();
+    let marker_style = conflict_marker_style(repo.settings());
+
+    let mut summaries: BTreeMap<RepoPathBuf, FfiDirectoryDiffSummary> = BTreeMap::new();
+    for (path, values) in collect_tree_diff(&from_tree, &to_tree, &dir_path)? {
+        let Some(relative) = path.strip_prefix(&dir_path) else {
+            continue;
+        };
+        // Files directly inside `dir` are summarized on their own
+        let mut components = relative.components();
+        let Some(child) = components.next() else {
+            continue;
+        };
+        let is_directory = components.next().is_some();
+        let child_path = dir_path.join(child);
+
+        let left = content_for_diff(store, &path, &values.before, marker_style)?;
+        let right = content_for_diff(store, &path, &values.after, marker_style)?;
+        let (insertions, deletions) = count_line_changes(&left, &right);
+
+        let summary = summaries
+            .entry(child_path)
+            .or_insert_with_key(|child_path| FfiDirectoryDiffSummary {
+                path: child_path.as_internal_file_string().to_string(),
+                is_directory,
+                files_changed: 0,
+                insertions: 0,
+                deletions: 0,
+            });
+        summary.files_changed += 1;
+        summary.insertions += insertions;
+        summary.deletions += deletions;
+    }
+
+    Ok(summaries.into_values().collect())
+}
+
+/// Count inserted and deleted lines per file between two commits
+pub fn diff_stats(
+    repo: &Arc<ReadonlyRepo>,
+    from: &FfiCommitId,
+    to: &FfiCommitId,
+) -> Result<FfiDiffStats> {
+    let from_tree = load_commit(repo, from)?.tree();
+    let to_tree = load_commit(repo, to)?.tree();
+    let store = repo.store();
+    let marker_style = conflict_marker_style(repo.settings());
+
+    let mut files = Vec::new();
+    for (path, values) in collect_tree_diff(&from_tree, &to_tree, RepoPath::root())? {
+        let left = content_for_diff(store, &path, &values.before, marker_style)?;
+        let right = content_for_diff(store, &path, &values.after, marker_style)?;
+        let (insertions, deletions) = count_line_changes(&left, &right);
+        files.push(FfiFileDiffStat {
+            path: path.as_internal_file_string().to_string(),
+            insertions,
+            deletions,
+            binary: is_binary(&left) || is_binary(&right),
+        });
+    }
+
+    Ok(FfiDiffStats {
+        total_insertions: files.iter().map(|file| file.insertions).sum(),
+        total_deletions: files.iter().map(|file| file.deletions).sum(),
+        files,
+    })
+}
+
+/// List the files changed between two commits
+pub fn diff_summary(
+    repo: &Arc<ReadonlyRepo>,
+    from: &FfiCommitId,
+    to: &FfiCommitId,
+) -> Result<Vec<FfiDiffEntry>> {
+    let from_commit = load_commit(repo, from)?;
+    let to_commit = load_commit(repo, to)?;
+    let store = repo.store();
+    let copy_records = collect_copy_records(store, &from_commit, &to_commit)?;
+
+    let from_tree = from_commit.tree();
+    let to_tree = to_commit.tree();
+    let entries = pollster::block_on(
+        from_tree
+            .diff_stream_with_copies(&to_tree, &EverythingMatcher, &copy_records)
+            .collect::<Vec<_>>(),
+    );
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let values = entry.values?;
+            let (old_entry_type, old_executable) = entry_type(&values.before);
+            let (new_entry_type, new_executable) = entry_type(&values.after);
+            Ok(FfiDiffEntry {
+                path: entry.path.target().as_internal_file_string().to_string(),
+                source_path: entry
+                    .path
+                    .source
+                    .as_ref()
+                    .map(|(source, _)| source.as_internal_file_string().to_string()),
+                change_type: change_type(&entry.path, &values),
+                old_entry_type,
+                old_executable,
+                new_entry_type,
+                new_executable,
+            })
+        })
+        .collect()
+}
+
+/// Decode the tokens of a diff line, and find the ranges of changed tokens
+///
+/// Ranges are offsets into the decoded content without the trailing newline.
+fn decode_line_tokens(
+    tokens: &[(DiffTokenType, &[u8])],
+    intraline: bool,
+) -> (String, Vec<FfiTextRange>) {
+    let mut content = String::new();
+    let mut changed_ranges: Vec<FfiTextRange> = Vec::new();
+    for (token_type, token) in tokens {
+        let start = content.len() as u32;
+        content.push_str(&String::from_utf8_lossy(token));
+        if intraline && *token_type == DiffTokenType::Different {
+            let end = content.trim_end_matches('\n').len() as u32;
+            match changed_ranges.last_mut() {
+                Some(range) if range.end == start => range.end = end,
+                _ if start < end => changed_ranges.push(FfiTextRange { start, end }),
+                _ => {}
+            }
+        }
+    }
+    if content.ends_with('\n') {
+        content.pop();
+    }
+    (content, changed_ranges)
+}
+
+/// Compute line-based diff hunks for a file between two commits
+///
+/// Binary files produce no hunks. If `intraline` is set, lines also carry the
+/// ranges of words that changed within them.
+pub fn diff_file_hunks(
+    repo: &Arc<ReadonlyRepo>,
+    from: &FfiCommitId,
+    to: &FfiCommitId,
+    path: &str,
+    context_lines: u32,
+    intraline: bool,
+) -> Result<Vec<FfiDiffHunk>> {
+    let repo_path = parse_repo_path(path)?;
+    let store = repo.store();
+    let marker_style = conflict_marker_style(repo.settings());
+    let from_value = load_commit(repo, from)?.tree().path_value(&repo_path)?;
+    let to_value = load_commit(repo, to)?.tree().path_value(&repo_path)?;
+
+    let left = content_for_diff(store, &repo_path, &from_value, marker_style)?;
+    let right = content_for_diff(store, &repo_path, &to_value, marker_style)?;
+    if is_binary(&left) || is_binary(&right) {
+        return Ok(Vec::new());
+    }
+
+    let hunks = unified_diff_hunks(
+        Diff::new(BStr::new(&left), BStr::new(&right)),
+        context_lines as usize,
+        LineCompareMode::Exact,
+    );
+
+    let hunks = hunks
+        .into_iter()
+        .map(|hunk| {
+            let mut old_line = hunk.left_line_range.start as u32;
+            let mut new_line = hunk.right_line_range.start as u32;
+            let lines = hunk
+                .lines
+                .into_iter()
+                .map(|(line_type, tokens)| {
+                    let (content, changed_ranges) = decode_line_tokens(&tokens, intraline);
+                    let (old_line_number, new_line_number) = match line_type {
+                        DiffLineType::Context => {
+                            old_line += 1;
+                            new_line += 1;
+                            (Some(old_line), Some(new_line))
+                        }
+                        DiffLineType::Removed => {
+                            old_line += 1;
+                            (Some(old_line), None)
+                        }
+                        DiffLineType::Added => {
+                            new_line += 1;
+                            (None, Some(new_line))
+                        }
+                    };
+                    FfiDiffLine {
+                        line_type: FfiDiffLineType::from(line_type),
+                        content,
+                        old_line_number,
+                        new_line_number,
+                        changed_ranges,
+                    }
+                })
+                .collect();
+            FfiDiffHunk {
+                old_start: hunk.left_line_range.start as u32 + 1,
+                old_count: hunk.left_line_range.len() as u32,
+                new_start: hunk.right_line_range.start as u32 + 1,
+                new_count: hunk.right_line_range.len() as u32,
+                lines,
+            }
+        })
+        .collect();
+
+    Ok(hunks)
+}
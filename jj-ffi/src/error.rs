@@ -14,6 +14,9 @@ pub enum JjError {
     #[error("Repository error: {message}")]
     Repository { message: String },
 
+    #[error("Incompatible repository: {message}")]
+    IncompatibleRepository { message: String },
+
     #[error("Backend error: {message}")]
     Backend { message: String },
 
@@ -26,12 +29,18 @@ pub enum JjError {
     #[error("Revset error: {message}")]
     Revset { message: String },
 
+    #[error("Template error: {message}")]
+    Template { message: String },
+
     #[error("Transaction error: {message}")]
     Transaction { message: String },
 
     #[error("Git error: {message}")]
     Git { message: String },
 
+    #[error("Cancelled: {message}")]
+    Cancelled { message: String },
+
     #[error("Internal error: {message}")]
     Internal { message: String },
 }
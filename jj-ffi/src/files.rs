@@ -0,0 +1,164 @@
+//! File content access for FFI
+
+use std::sync::Arc;
+
+use jj_lib::backend::{CommitId, FileId, TreeValue};
+use jj_lib::conflicts::{
+    ConflictMarkerStyle, ConflictMaterializeOptions, materialize_merge_result_to_bytes,
+};
+use jj_lib::merge::MergedTreeValue;
+use jj_lib::repo::{ReadonlyRepo, Repo};
+use jj_lib::repo_path::{RepoPath, RepoPathBuf};
+use jj_lib::settings::UserSettings;
+use jj_lib::store::Store;
+use tokio::io::AsyncReadExt as _;
+
+use crate::error::{JjError, Result};
+use crate::types::FfiCommitId;
+
+/// Style of conflict markers used when materializing conflicts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiConflictMarkerStyle {
+    /// A snapshot of one side and a series of diffs to apply
+    Diff,
+    /// A snapshot of each side and base
+    Snapshot,
+    /// Git's "diff3" style, for compatibility with external tools
+    Git,
+}
+
+impl From<FfiConflictMarkerStyle> for ConflictMarkerStyle {
+    fn from(style: FfiConflictMarkerStyle) -> Self {
+        match style {
+            FfiConflictMarkerStyle::Diff => ConflictMarkerStyle::Diff,
+            FfiConflictMarkerStyle::Snapshot => ConflictMarkerStyle::Snapshot,
+            FfiConflictMarkerStyle::Git => ConflictMarkerStyle::Git,
+        }
+    }
+}
+
+impl From<ConflictMarkerStyle> for FfiConflictMarkerStyle {
+    fn from(style: ConflictMarkerStyle) -> Self {
+        match style {
+            ConflictMarkerStyle::Diff | ConflictMarkerStyle::DiffExperimental => {
+                FfiConflictMarkerStyle::Diff
+            }
+            ConflictMarkerStyle::Snapshot => FfiConflictMarkerStyle::Snapshot,
+            ConflictMarkerStyle::Git => FfiConflictMarkerStyle::Git,
+        }
+    }
+}
+
+impl FfiConflictMarkerStyle {
+    /// Name of the style as used in the `ui.conflict-marker-style` setting
+    pub(crate) fn config_value(self) -> &'static str {
+        match self {
+            FfiConflictMarkerStyle::Diff => "diff",
+            FfiConflictMarkerStyle::Snapshot => "snapshot",
+            FfiConflictMarkerStyle::Git => "git",
+        }
+    }
+}
+
+/// Get the conflict marker style from the `ui.conflict-marker-style` setting
+pub(crate) fn conflict_marker_style(settings: &UserSettings) -> ConflictMarkerStyle {
+    settings
+        .get("ui.conflict-marker-style")
+        .unwrap_or(ConflictMarkerStyle::Diff)
+}
+
+/// Parse a repository-relative path given in internal ("/"-separated) form
+pub(crate) fn parse_repo_path(path: &str) -> Result<RepoPathBuf> {
+    RepoPathBuf::from_internal_string(path).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid path '{}': {}", path, e),
+    })
+}
+
+/// Read the full content of a file from the store
+pub(crate) fn read_file_content(store: &Store, path: &RepoPath, id: &FileId) -> Result<Vec<u8>> {
+    pollster::block_on(async {
+        let mut reader = store.read_file(path, id).await?;
+        let mut content = Vec::new();
+        reader
+            .read_to_end(&mut content)
+            .await
+            .map_err(|e| JjError::Backend {
+                message: format!(
+                    "Failed to read file '{}': {}",
+                    path.as_internal_file_string(),
+                    e
+                ),
+            })?;
+        Ok(content)
+    })
+}
+
+/// Materialize a file conflict as text with conflict markers
+///
+/// Returns `None` if the conflict involves non-file values.
+pub(crate) fn materialize_file_conflict(
+    store: &Store,
+    path: &RepoPath,
+    value: &MergedTreeValue,
+    marker_style: ConflictMarkerStyle,
+) -> Result<Option<Vec<u8>>> {
+    let Some(file_ids) = value.to_file_merge() else {
+        return Ok(None);
+    };
+    let contents = file_ids.simplify().try_map(|term| match term {
+        Some(id) => read_file_content(store, path, id),
+        None => Ok(Vec::new()),
+    })?;
+    let options = ConflictMaterializeOptions {
+        marker_style,
+        marker_len: None,
+        merge: store.merge_options().clone(),
+    };
+    Ok(Some(
+        materialize_merge_result_to_bytes(&contents, &options).into(),
+    ))
+}
+
+/// Read the content of a file in a commit
+///
+/// Conflicted files are materialized with conflict markers in the style
+/// configured in the repository settings.
+pub fn read_file(repo: &Arc<ReadonlyRepo>, commit_id: &FfiCommitId, path: &str) -> Result<Vec<u8>> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let repo_path = parse_repo_path(path)?;
+
+    let store = repo.store();
+    let commit = store.get_commit(&id)?;
+    let value = commit.tree().path_value(&repo_path)?;
+    match value.as_resolved() {
+        Some(Some(TreeValue::File { id, .. })) => read_file_content(store, &repo_path, id),
+        Some(None) => Err(JjError::InvalidArgument {
+            message: format!("File not found: {}", path),
+        }),
+        Some(Some(_)) => Err(JjError::InvalidArgument {
+            message: format!("Not a regular file: {}", path),
+        }),
+        None => {
+            let marker_style = conflict_marker_style(repo.settings());
+            materialize_file_conflict(store, &repo_path, &value, marker_style)?.ok_or_else(|| {
+                JjError::InvalidArgument {
+                    message: format!("Not a regular file: {}", path),
+                }
+            })
+        }
+    }
+}
+
+/// Read the content of a file in a commit as UTF-8 text
+pub fn read_file_text(
+    repo: &Arc<ReadonlyRepo>,
+    commit_id: &FfiCommitId,
+    path: &str,
+) -> Result<String> {
+    let content = read_file(repo, commit_id, path)?;
+    String::from_utf8(content).map_err(|_| JjError::InvalidArgument {
+        message: format!("File is not valid UTF-8: {}", path),
+    })
+}
@@ -1,19 +1,94 @@
 //! Git operations for FFI
 
+use std::error::Error as _;
+use std::num::NonZeroU32;
 use std::sync::{Arc, Mutex};
+use std::thread;
 
+use futures::channel::oneshot;
+
+use jj_lib::backend::{ChangeId, CommitId};
 use jj_lib::git::{
-    self, GitFetch, GitImportStats, GitSettings, RemoteCallbacks,
-    expand_fetch_refspecs,
+    self, FailedRefExportReason, GitExportStats, GitFetch, GitImportStats, GitPushStats,
+    GitSettings, expand_fetch_refspecs,
+};
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::op_store::{RefTarget, RemoteRef};
+use jj_lib::ref_name::{
+    GitRefNameBuf, RefName, RefNameBuf, RemoteName, RemoteRefSymbol, RemoteRefSymbolBuf,
+    WorkspaceName,
 };
-use jj_lib::ref_name::{RefName, RemoteName};
-use jj_lib::repo::Repo;
+use jj_lib::refs::BookmarkPushUpdate;
+use jj_lib::repo::{MutableRepo, ReadonlyRepo, Repo};
 use jj_lib::str_util::{StringExpression, StringPattern};
 use jj_lib::transaction::Transaction;
+use jj_lib::workspace::Workspace;
 
+use crate::cancel::{FfiCancellationToken, check_cancelled};
+use crate::credentials::{FfiGitCredentialProvider, GitCredentials, RemoteDirection};
 use crate::error::{JjError, Result};
+use crate::progress::{FfiGitProgress, with_progress_callbacks};
 use crate::repo::FfiReadonlyRepo;
-use crate::types::FfiCommitId;
+use crate::revset::RevsetWorkspace;
+use crate::types::{FfiChangeId, FfiCommitId};
+
+/// Prefix of bookmarks created by `FfiGitTransaction::push_changes()`, as in the jj CLI
+const PUSH_BOOKMARK_PREFIX: &str = "push-";
+
+/// Options for fetching from a remote
+///
+/// Use `clone_git_workspace()` to create a shallow clone of a remote.
+#[derive(Clone, uniffi::Record)]
+pub struct FfiFetchOptions {
+    /// Whether to delete remote bookmarks that no longer exist on the remote,
+    /// like `jj git fetch` does (they are kept otherwise)
+    pub prune: bool,
+    /// Number of commits to fetch from the tips, like `git fetch --depth`
+    /// (the full history is fetched if `None`)
+    ///
+    /// Fetching an already shallow repository with a larger depth deepens
+    /// it. Call `FfiWorkspace::reindex()` afterwards so commits imported
+    /// before see their newly fetched history.
+    pub depth: Option<u32>,
+    /// Asked for the credentials of the remote's fetch URL
+    pub credentials: Option<Arc<dyn FfiGitCredentialProvider>>,
+    /// Receives the transfer progress
+    pub progress: Option<Arc<dyn FfiGitProgress>>,
+    /// Cancels the fetch, checked during the transfer
+    ///
+    /// A cancelled fetch leaves the transaction unchanged.
+    pub cancellation: Option<Arc<FfiCancellationToken>>,
+}
+
+/// Prunes and fetches the full history, like `jj git fetch`
+impl Default for FfiFetchOptions {
+    fn default() -> Self {
+        Self {
+            prune: true,
+            depth: None,
+            credentials: None,
+            progress: None,
+            cancellation: None,
+        }
+    }
+}
+
+/// Options for pushing to a remote
+#[derive(Clone, Default, uniffi::Record)]
+pub struct FfiPushOptions {
+    /// Whether to only report the updates that would be pushed, without
+    /// pushing anything
+    pub dry_run: bool,
+    /// Asked for the credentials of the remote's push URL
+    pub credentials: Option<Arc<dyn FfiGitCredentialProvider>>,
+    /// Receives the transfer progress
+    pub progress: Option<Arc<dyn FfiGitProgress>>,
+    /// Cancels the push, checked during the transfer
+    ///
+    /// Refs may already be updated on the remote when a push is cancelled,
+    /// but the transaction is left unchanged.
+    pub cancellation: Option<Arc<FfiCancellationToken>>,
+}
 
 /// Statistics from a git import operation
 #[derive(Debug, Clone, uniffi::Record)]
@@ -26,6 +101,27 @@ pub struct FfiGitImportStats {
     pub changed_remote_tags_count: u64,
     /// Number of refs that failed to import
     pub failed_refs_count: u64,
+    /// Commits that were abandoned because they were superseded by imported commits
+    pub abandoned_commits: Vec<FfiCommitId>,
+    /// Remote bookmarks that changed, sorted by name and remote
+    pub changed_remote_bookmarks: Vec<FfiImportedRefChange>,
+    /// Remote tags that changed, sorted by name and remote
+    pub changed_remote_tags: Vec<FfiImportedRefChange>,
+    /// Full names of the Git refs that failed to import, sorted by name
+    pub failed_refs: Vec<String>,
+}
+
+/// A remote bookmark or tag that changed during a git import
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiImportedRefChange {
+    /// Bookmark or tag name
+    pub name: String,
+    /// Remote name ("git" for refs of the backing Git repository)
+    pub remote: String,
+    /// Target before the import (`None` if absent or conflicted)
+    pub old_target: Option<FfiCommitId>,
+    /// Target after the import (`None` if absent or conflicted)
+    pub new_target: Option<FfiCommitId>,
 }
 
 impl From<&GitImportStats> for FfiGitImportStats {
@@ -35,10 +131,39 @@ impl From<&GitImportStats> for FfiGitImportStats {
             changed_remote_bookmarks_count: stats.changed_remote_bookmarks.len() as u64,
             changed_remote_tags_count: stats.changed_remote_tags.len() as u64,
             failed_refs_count: stats.failed_ref_names.len() as u64,
+            abandoned_commits: stats
+                .abandoned_commits
+                .iter()
+                .map(FfiCommitId::from)
+                .collect(),
+            changed_remote_bookmarks: imported_ref_changes(&stats.changed_remote_bookmarks),
+            changed_remote_tags: imported_ref_changes(&stats.changed_remote_tags),
+            failed_refs: stats
+                .failed_ref_names
+                .iter()
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+                .collect(),
         }
     }
 }
 
+/// Convert the remote refs changed by an import
+fn imported_ref_changes(
+    changes: &[(RemoteRefSymbolBuf, (RemoteRef, RefTarget))],
+) -> Vec<FfiImportedRefChange> {
+    changes
+        .iter()
+        .map(
+            |(symbol, (old_remote_ref, new_target))| FfiImportedRefChange {
+                name: symbol.name.as_str().to_string(),
+                remote: symbol.remote.as_str().to_string(),
+                old_target: old_remote_ref.target.as_normal().map(FfiCommitId::from),
+                new_target: new_target.as_normal().map(FfiCommitId::from),
+            },
+        )
+        .collect()
+}
+
 /// Statistics from a git export operation
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct FfiGitExportStats {
@@ -46,6 +171,92 @@ pub struct FfiGitExportStats {
     pub failed_bookmarks_count: u64,
     /// Number of tags that failed to export
     pub failed_tags_count: u64,
+    /// Bookmarks that failed to export, sorted by name and remote
+    pub failed_bookmarks: Vec<FfiFailedRefExport>,
+    /// Tags that failed to export, sorted by name
+    pub failed_tags: Vec<FfiFailedRefExport>,
+}
+
+/// Why a bookmark or tag could not be exported to Git
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiRefExportFailureReason {
+    /// The name is not allowed in Git
+    InvalidGitName,
+    /// The ref was conflicted after the last import (a re-import should fix it)
+    ConflictedOldState,
+    /// The ref points to the root commit, which Git doesn't have
+    OnRootCommit,
+    /// The ref was deleted in jj but modified in Git
+    DeletedInJjModifiedInGit,
+    /// The ref was added in jj but added with a different target in Git
+    AddedInJjAddedInGit,
+    /// The ref was modified in jj but deleted in Git
+    ModifiedInJjDeletedInGit,
+    /// The Git ref could not be deleted
+    FailedToDelete { message: String },
+    /// The Git ref could not be updated
+    FailedToSet { message: String },
+}
+
+impl From<&FailedRefExportReason> for FfiRefExportFailureReason {
+    fn from(reason: &FailedRefExportReason) -> Self {
+        match reason {
+            FailedRefExportReason::InvalidGitName => Self::InvalidGitName,
+            FailedRefExportReason::ConflictedOldState => Self::ConflictedOldState,
+            FailedRefExportReason::OnRootCommit => Self::OnRootCommit,
+            FailedRefExportReason::DeletedInJjModifiedInGit => Self::DeletedInJjModifiedInGit,
+            FailedRefExportReason::AddedInJjAddedInGit => Self::AddedInJjAddedInGit,
+            FailedRefExportReason::ModifiedInJjDeletedInGit => Self::ModifiedInJjDeletedInGit,
+            FailedRefExportReason::FailedToDelete(e) => Self::FailedToDelete {
+                message: e.to_string(),
+            },
+            FailedRefExportReason::FailedToSet(e) => Self::FailedToSet {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+/// A bookmark or tag that could not be exported to Git
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiFailedRefExport {
+    /// Bookmark or tag name
+    pub name: String,
+    /// Remote name ("git" for local bookmarks and tags)
+    pub remote: String,
+    /// Why the ref could not be exported
+    pub reason: FfiRefExportFailureReason,
+    /// Human-readable description of the reason
+    pub message: String,
+}
+
+impl From<&GitExportStats> for FfiGitExportStats {
+    fn from(stats: &GitExportStats) -> Self {
+        Self {
+            failed_bookmarks_count: stats.failed_bookmarks.len() as u64,
+            failed_tags_count: stats.failed_tags.len() as u64,
+            failed_bookmarks: failed_ref_exports(&stats.failed_bookmarks),
+            failed_tags: failed_ref_exports(&stats.failed_tags),
+        }
+    }
+}
+
+/// Convert the refs that failed to export, with their reasons
+fn failed_ref_exports(
+    failed: &[(RemoteRefSymbolBuf, FailedRefExportReason)],
+) -> Vec<FfiFailedRefExport> {
+    failed
+        .iter()
+        .map(|(symbol, reason)| FfiFailedRefExport {
+            name: symbol.name.as_str().to_string(),
+            remote: symbol.remote.as_str().to_string(),
+            reason: FfiRefExportFailureReason::from(reason),
+            message: match reason.source() {
+                Some(source) => format!("{}: {}", reason, source),
+                None => reason.to_string(),
+            },
+        })
+        .collect()
 }
 
 /// Statistics from a git push operation
@@ -59,15 +270,147 @@ pub struct FfiGitPushStats {
     pub remote_rejected_count: u64,
     /// Whether all refs were pushed successfully
     pub all_ok: bool,
+    /// Full names of the refs that were pushed, e.g. "refs/heads/main"
+    /// (including deleted refs)
+    pub pushed_refs: Vec<String>,
+    /// Full names of the pushed refs that were deleted on the remote, or
+    /// would be deleted in a dry run
+    pub deleted_refs: Vec<String>,
+    /// Refs that were rejected (lease failure)
+    pub rejected_refs: Vec<FfiRejectedRef>,
+    /// Refs that were rejected by the remote
+    pub remote_rejected_refs: Vec<FfiRejectedRef>,
+    /// Bookmark updates that were pushed, or would be pushed in a dry run
+    pub updates: Vec<FfiPushUpdate>,
+}
+
+/// A bookmark update sent to a remote by a push
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiPushUpdate {
+    /// Bookmark name
+    pub bookmark: String,
+    /// Full name of the remote ref, e.g. "refs/heads/main"
+    pub ref_name: String,
+    /// Target on the remote before the push (`None` if the ref is new)
+    pub old_target: Option<FfiCommitId>,
+    /// Target on the remote after the push (`None` if the ref is deleted)
+    pub new_target: Option<FfiCommitId>,
+    /// Whether the update moves the ref backwards or sideways, so commits on
+    /// the remote would be lost
+    pub is_force: bool,
+}
+
+/// A ref that could not be pushed
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiRejectedRef {
+    /// Full ref name, e.g. "refs/heads/main"
+    pub name: String,
+    /// Why the ref was rejected, if reported
+    pub reason: Option<String>,
+}
+
+impl From<&GitPushStats> for FfiGitPushStats {
+    fn from(stats: &GitPushStats) -> Self {
+        Self {
+            pushed_count: stats.pushed.len() as u64,
+            rejected_count: stats.rejected.len() as u64,
+            remote_rejected_count: stats.remote_rejected.len() as u64,
+            all_ok: stats.all_ok(),
+            pushed_refs: stats
+                .pushed
+                .iter()
+                .map(|name| name.as_str().to_string())
+                .collect(),
+            deleted_refs: Vec::new(),
+            rejected_refs: rejected_refs(&stats.rejected),
+            remote_rejected_refs: rejected_refs(&stats.remote_rejected),
+            updates: Vec::new(),
+        }
+    }
+}
+
+/// Describe a bookmark update to push
+fn push_update(
+    repo: &dyn Repo,
+    name: &RefName,
+    update: &BookmarkPushUpdate,
+) -> Result<FfiPushUpdate> {
+    let is_force = match (&update.old_target, &update.new_target) {
+        (Some(old), Some(new)) => {
+            !repo
+                .index()
+                .is_ancestor(old, new)
+                .map_err(|e| JjError::Internal {
+                    message: format!("Index error: {}", e),
+                })?
+        }
+        _ => false,
+    };
+    Ok(FfiPushUpdate {
+        bookmark: name.as_str().to_string(),
+        ref_name: format!("refs/heads/{}", name.as_str()),
+        old_target: update.old_target.as_ref().map(FfiCommitId::from),
+        new_target: update.new_target.as_ref().map(FfiCommitId::from),
+        is_force,
+    })
+}
+
+/// Convert refs rejected by a push, with their reasons
+fn rejected_refs(refs: &[(GitRefNameBuf, Option<String>)]) -> Vec<FfiRejectedRef> {
+    refs.iter()
+        .map(|(name, reason)| FfiRejectedRef {
+            name: name.as_str().to_string(),
+            reason: reason.clone(),
+        })
+        .collect()
+}
+
+/// What was reconciled after Git HEAD was moved by an external tool
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiGitHeadSyncReport {
+    /// Git HEAD as last recorded by jj
+    pub old_git_head: Option<FfiCommitId>,
+    /// Git HEAD as found in the Git repository
+    pub new_git_head: Option<FfiCommitId>,
+    /// Working-copy commit created on top of the new Git HEAD
+    pub new_working_copy_commit: Option<FfiCommitId>,
+    /// Statistics from importing Git refs
+    pub import_stats: FfiGitImportStats,
+}
+
+/// An extra header of a Git commit object
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiCommitHeader {
+    /// Header name, e.g. "change-id" or "gpgsig"
+    pub key: String,
+    /// Header value (lossily decoded as UTF-8)
+    pub value: String,
+}
+
+/// HEAD of the backing Git repository
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiGitHead {
+    /// Full name of the ref HEAD points to, e.g. "refs/heads/main" (`None`
+    /// if HEAD is detached)
+    pub ref_name: Option<String>,
+    /// Commit HEAD resolves to (`None` if the ref HEAD points to doesn't
+    /// exist yet)
+    pub commit_id: Option<FfiCommitId>,
+    /// Whether HEAD points directly to a commit rather than to a ref
+    pub is_detached: bool,
 }
 
 /// A Git transaction for performing Git operations
 ///
-/// This wraps a jj Transaction and provides Git-specific operations.
+/// This wraps a jj Transaction and provides Git-specific operations. Like
+/// `FfiTransaction`, it is isolated from the repository it was started from
+/// until it is committed.
 #[derive(uniffi::Object)]
 pub struct FfiGitTransaction {
     inner: Mutex<Option<Transaction>>,
+    base_repo: Arc<ReadonlyRepo>,
     git_settings: GitSettings,
+    workspace: Option<RevsetWorkspace>,
 }
 
 // SAFETY: FfiGitTransaction is protected by a Mutex, ensuring synchronized access
@@ -75,10 +418,17 @@ unsafe impl Send for FfiGitTransaction {}
 unsafe impl Sync for FfiGitTransaction {}
 
 impl FfiGitTransaction {
-    pub(crate) fn new(transaction: Transaction, git_settings: GitSettings) -> Self {
+    pub(crate) fn new(
+        transaction: Transaction,
+        git_settings: GitSettings,
+        workspace: Option<RevsetWorkspace>,
+    ) -> Self {
+        let base_repo = Arc::clone(transaction.base_repo());
         Self {
             inner: Mutex::new(Some(transaction)),
+            base_repo,
             git_settings,
+            workspace,
         }
     }
 
@@ -103,52 +453,50 @@ impl FfiGitTransaction {
             message: "Transaction has already been committed or discarded".to_string(),
         })
     }
-}
 
-#[uniffi::export]
-impl FfiGitTransaction {
-    /// Import refs from the underlying Git repository
-    ///
-    /// This synchronizes the jj view with any changes made directly in Git.
-    pub fn import_refs(&self) -> Result<FfiGitImportStats> {
+    /// Fetch from a remote, optionally asking it for its default branch
+    pub(crate) fn fetch_impl(
+        &self,
+        remote_name: &str,
+        branch_patterns: &[String],
+        options: &FfiFetchOptions,
+        get_default_branch: bool,
+    ) -> Result<(FfiGitImportStats, Option<RefNameBuf>)> {
+        let cancellation = options.cancellation.as_deref();
+        let depth = options
+            .depth
+            .map(|depth| {
+                NonZeroU32::new(depth).ok_or_else(|| JjError::InvalidArgument {
+                    message: "Fetch depth must be positive".to_string(),
+                })
+            })
+            .transpose()?;
         self.with_transaction_mut(|tx, git_settings| {
-            let stats = git::import_refs(tx.repo_mut(), git_settings).map_err(|e| JjError::Git {
+            check_cancelled(cancellation)?;
+            let remote = RemoteName::new(remote_name);
+            let credentials = GitCredentials::new(
+                options.credentials.as_ref(),
+                tx.repo().store(),
+                remote,
+                RemoteDirection::Fetch,
+                git_settings,
+            )?;
+            // Git always prunes, so refs to keep are restored after fetching
+            let git_repo = git::get_git_repo(tx.repo().store()).map_err(|e| JjError::Git {
                 message: e.to_string(),
             })?;
-            Ok(FfiGitImportStats::from(&stats))
-        })
-    }
-
-    /// Export refs to the underlying Git repository
-    ///
-    /// This synchronizes the Git refs with changes made in jj.
-    pub fn export_refs(&self) -> Result<FfiGitExportStats> {
-        self.with_transaction_mut(|tx, _git_settings| {
-            let stats = git::export_refs(tx.repo_mut()).map_err(|e| JjError::Git {
-                message: e.to_string(),
-            })?;
-            Ok(FfiGitExportStats {
-                failed_bookmarks_count: stats.failed_bookmarks.len() as u64,
-                failed_tags_count: stats.failed_tags.len() as u64,
-            })
-        })
-    }
-
-    /// Fetch from a remote
-    ///
-    /// Fetches the specified branches (or all branches if empty) from the remote.
-    pub fn fetch(
-        &self,
-        remote_name: String,
-        branch_patterns: Vec<String>,
-    ) -> Result<FfiGitImportStats> {
-        self.with_transaction_mut(|tx, git_settings| {
-            let remote = RemoteName::new(&remote_name);
+            let refs_to_keep = if options.prune {
+                Vec::new()
+            } else {
+                remote_tracking_refs(&git_repo, remote)?
+            };
 
             // Create GitFetch helper
             let mut git_fetch =
-                GitFetch::new(tx.repo_mut(), git_settings).map_err(|e| JjError::Git {
-                    message: e.to_string(),
+                GitFetch::new(tx.repo_mut(), credentials.git_settings()).map_err(|e| {
+                    JjError::Git {
+                        message: e.to_string(),
+                    }
                 })?;
 
             // Build branch expression
@@ -180,87 +528,354 @@ impl FfiGitTransaction {
             })?;
 
             // Perform fetch
-            let callbacks = RemoteCallbacks::default();
-            git_fetch
-                .fetch(remote, refspecs, callbacks, None, None)
-                .map_err(|e| JjError::Git {
-                    message: e.to_string(),
-                })?;
+            with_progress_callbacks(options.progress.as_ref(), cancellation, |callbacks| {
+                git_fetch.fetch(remote, refspecs, callbacks, depth, None)
+            })?
+            .map_err(|e| JjError::Git {
+                message: e.to_string(),
+            })?;
+            restore_missing_refs(&git_repo, &refs_to_keep)?;
+            check_cancelled(cancellation)?;
 
             // Import the fetched refs
             let stats = git_fetch.import_refs().map_err(|e| JjError::Git {
                 message: e.to_string(),
             })?;
+            let default_branch = if get_default_branch {
+                git_fetch
+                    .get_default_branch(remote)
+                    .map_err(|e| JjError::Git {
+                        message: e.to_string(),
+                    })?
+            } else {
+                None
+            };
+
+            Ok((FfiGitImportStats::from(&stats), default_branch))
+        })
+    }
+}
 
+#[uniffi::export]
+impl FfiGitTransaction {
+    /// Get the repository at the operation the transaction was started from
+    ///
+    /// Remains available after the transaction is committed or discarded.
+    pub fn base_repo(&self) -> Arc<FfiReadonlyRepo> {
+        Arc::new(FfiReadonlyRepo::with_workspace(
+            Arc::clone(&self.base_repo),
+            self.workspace.clone(),
+        ))
+    }
+
+    /// Import refs from the underlying Git repository
+    ///
+    /// This synchronizes the jj view with any changes made directly in Git.
+    pub fn import_refs(&self) -> Result<FfiGitImportStats> {
+        self.with_transaction_mut(|tx, git_settings| {
+            let stats = git::import_refs(tx.repo_mut(), git_settings).map_err(|e| JjError::Git {
+                message: e.to_string(),
+            })?;
             Ok(FfiGitImportStats::from(&stats))
         })
     }
 
+    /// Export refs to the underlying Git repository
+    ///
+    /// This synchronizes the Git refs with changes made in jj.
+    pub fn export_refs(&self) -> Result<FfiGitExportStats> {
+        self.with_transaction_mut(|tx, _git_settings| {
+            let stats = git::export_refs(tx.repo_mut()).map_err(|e| JjError::Git {
+                message: e.to_string(),
+            })?;
+            Ok(FfiGitExportStats::from(&stats))
+        })
+    }
+
+    /// Fetch from a remote
+    ///
+    /// Fetches the specified branches (or all branches if empty) from the remote.
+    /// Remote bookmarks that no longer exist on the remote are deleted and the
+    /// full history is fetched, like `jj git fetch`.
+    pub fn fetch(
+        &self,
+        remote_name: String,
+        branch_patterns: Vec<String>,
+    ) -> Result<FfiGitImportStats> {
+        self.fetch_with_options(remote_name, branch_patterns, FfiFetchOptions::default())
+    }
+
+    /// Fetch from a remote with the given options
+    ///
+    /// Same as `fetch`, but with options for pruning, shallow fetches,
+    /// credentials, progress and cancellation.
+    pub fn fetch_with_options(
+        &self,
+        remote_name: String,
+        branch_patterns: Vec<String>,
+        options: FfiFetchOptions,
+    ) -> Result<FfiGitImportStats> {
+        let (stats, _default_branch) =
+            self.fetch_impl(&remote_name, &branch_patterns, &options, false)?;
+        Ok(stats)
+    }
+
     /// Push branches to a remote
     ///
-    /// Pushes the specified local branches to the remote.
+    /// Pushes the specified local branches to the remote. A branch that was
+    /// deleted locally but is still tracked on the remote is deleted from the
+    /// remote, like `jj git push --deleted`.
     pub fn push_branches(
         &self,
         remote_name: String,
         branch_names: Vec<String>,
     ) -> Result<FfiGitPushStats> {
+        self.push_branches_with_options(remote_name, branch_names, FfiPushOptions::default())
+    }
+
+    /// Push branches to a remote with the given options
+    ///
+    /// Same as `push_branches`, but with options for dry runs, credentials,
+    /// progress and cancellation. With `dry_run`, nothing is pushed and the
+    /// returned stats only list the updates that would be pushed.
+    pub fn push_branches_with_options(
+        &self,
+        remote_name: String,
+        branch_names: Vec<String>,
+        options: FfiPushOptions,
+    ) -> Result<FfiGitPushStats> {
+        let cancellation = options.cancellation.as_deref();
         self.with_transaction_mut(|tx, git_settings| {
             let remote = RemoteName::new(&remote_name);
 
             // Build the push targets from branch names
-            let mut branch_updates = Vec::new();
+            let mut branch_updates: Vec<(RefNameBuf, BookmarkPushUpdate)> = Vec::new();
             let view = tx.repo().view();
 
             for branch_name in &branch_names {
                 let ref_name = RefName::new(branch_name);
                 let local_target = view.get_local_bookmark(ref_name);
-                if local_target.is_absent() {
-                    return Err(JjError::Git {
-                        message: format!("Branch '{}' not found", branch_name),
-                    });
-                }
 
                 // Get the remote tracking branch's current target (if any)
                 let symbol = ref_name.to_remote_symbol(remote);
                 let remote_ref = view.get_remote_bookmark(symbol);
+                // A deleted local branch deletes its tracked remote branch
+                if local_target.is_absent() && !(remote_ref.is_tracked() && remote_ref.is_present())
+                {
+                    return Err(JjError::Git {
+                        message: format!("Branch '{}' not found", branch_name),
+                    });
+                }
+                if local_target.has_conflict() {
+                    return Err(JjError::Git {
+                        message: format!("Branch '{}' is conflicted", branch_name),
+                    });
+                }
                 let old_target = remote_ref.target.as_normal().cloned();
                 let new_target = local_target.as_normal().cloned();
 
                 branch_updates.push((
                     branch_name.as_str().into(),
-                    jj_lib::refs::BookmarkPushUpdate {
+                    BookmarkPushUpdate {
                         old_target,
                         new_target,
                     },
                 ));
             }
 
-            let targets = git::GitBranchPushTargets { branch_updates };
-            let callbacks = RemoteCallbacks::default();
+            let updates = branch_updates
+                .iter()
+                .map(|(name, update)| push_update(tx.repo(), name, update))
+                .collect::<Result<Vec<_>>>()?;
+            let deleted_ref_names = || {
+                updates
+                    .iter()
+                    .filter(|update| update.new_target.is_none())
+                    .map(|update| update.ref_name.clone())
+            };
+            if options.dry_run {
+                return Ok(FfiGitPushStats {
+                    deleted_refs: deleted_ref_names().collect(),
+                    updates,
+                    ..FfiGitPushStats::from(&GitPushStats::default())
+                });
+            }
 
-            let stats = git::push_branches(tx.repo_mut(), git_settings, remote, &targets, callbacks)
+            let targets = git::GitBranchPushTargets { branch_updates };
+            let credentials = GitCredentials::new(
+                options.credentials.as_ref(),
+                tx.repo().store(),
+                remote,
+                RemoteDirection::Push,
+                git_settings,
+            )?;
+            check_cancelled(cancellation)?;
+            let stats =
+                with_progress_callbacks(options.progress.as_ref(), cancellation, |callbacks| {
+                    git::push_branches(
+                        tx.repo_mut(),
+                        credentials.git_settings(),
+                        remote,
+                        &targets,
+                        callbacks,
+                    )
+                })?
                 .map_err(|e| JjError::Git {
                     message: e.to_string(),
                 })?;
 
+            let deleted_refs = deleted_ref_names()
+                .filter(|ref_name| {
+                    stats
+                        .pushed
+                        .iter()
+                        .any(|pushed| pushed.as_str() == ref_name)
+                })
+                .collect();
             Ok(FfiGitPushStats {
-                pushed_count: stats.pushed.len() as u64,
-                rejected_count: stats.rejected.len() as u64,
-                remote_rejected_count: stats.remote_rejected.len() as u64,
-                all_ok: stats.all_ok(),
+                deleted_refs,
+                updates,
+                ..FfiGitPushStats::from(&stats)
             })
         })
     }
 
+    /// Push changes to a remote with generated bookmarks, like `jj git push -c`
+    ///
+    /// A "push-<change id>" bookmark is created or moved to the visible commit
+    /// of each change in this transaction, then the bookmarks are pushed like
+    /// in `push_branches_with_options()`. The bookmarks are kept even if the
+    /// push fails or is a dry run; discard the transaction to drop them.
+    pub fn push_changes(
+        &self,
+        remote_name: String,
+        change_ids: Vec<FfiChangeId>,
+        options: FfiPushOptions,
+    ) -> Result<FfiGitPushStats> {
+        let bookmark_names = self.with_transaction_mut(|tx, _| {
+            let mut bookmark_names = Vec::new();
+            for change_id in &change_ids {
+                let id = ChangeId::try_from(change_id)?;
+                let commit_ids = tx
+                    .repo()
+                    .resolve_change_id(&id)
+                    .map_err(|e| JjError::Internal {
+                        message: format!("Index error: {}", e),
+                    })?
+                    .unwrap_or_default();
+                let commit_id = match commit_ids.as_slice() {
+                    [commit_id] => commit_id.clone(),
+                    [] => {
+                        return Err(JjError::CommitNotFound {
+                            id: change_id.hex.clone(),
+                        });
+                    }
+                    _ => {
+                        return Err(JjError::InvalidArgument {
+                            message: format!("Change {} is divergent", change_id.hex),
+                        });
+                    }
+                };
+                let name = format!("{}{}", PUSH_BOOKMARK_PREFIX, &id.reverse_hex()[..12]);
+                tx.repo_mut()
+                    .set_local_bookmark_target(RefName::new(&name), RefTarget::normal(commit_id));
+                bookmark_names.push(name);
+            }
+            Ok(bookmark_names)
+        })?;
+        self.push_branches_with_options(remote_name, bookmark_names, options)
+    }
+
+    /// Fetch from a remote without blocking the calling thread
+    ///
+    /// Same as `fetch_with_options`, but runs on a background thread.
+    pub async fn fetch_async(
+        self: Arc<Self>,
+        remote_name: String,
+        branch_patterns: Vec<String>,
+        options: FfiFetchOptions,
+    ) -> Result<FfiGitImportStats> {
+        run_in_background(move || self.fetch_with_options(remote_name, branch_patterns, options))
+            .await
+    }
+
+    /// Push branches to a remote without blocking the calling thread
+    ///
+    /// Same as `push_branches_with_options`, but runs on a background thread.
+    pub async fn push_branches_async(
+        self: Arc<Self>,
+        remote_name: String,
+        branch_names: Vec<String>,
+        options: FfiPushOptions,
+    ) -> Result<FfiGitPushStats> {
+        run_in_background(move || {
+            self.push_branches_with_options(remote_name, branch_names, options)
+        })
+        .await
+    }
+
+    /// Start tracking a remote bookmark
+    ///
+    /// The remote bookmark's target is merged into the local bookmark of the
+    /// same name, creating it if needed. Tracking an already-tracked bookmark
+    /// is a no-op.
+    pub fn track_remote_bookmark(&self, name: String, remote: String) -> Result<()> {
+        self.with_transaction_mut(|tx, _| {
+            let symbol = remote_bookmark_symbol(&name, &remote)?;
+            let remote_ref = tx.repo().view().get_remote_bookmark(symbol);
+            if remote_ref.is_absent() {
+                return Err(JjError::Git {
+                    message: format!("Remote bookmark '{}@{}' not found", name, remote),
+                });
+            }
+            if remote_ref.is_tracked() {
+                return Ok(());
+            }
+            tx.repo_mut()
+                .track_remote_bookmark(symbol)
+                .map_err(|e| JjError::Internal {
+                    message: format!("Index error: {}", e),
+                })
+        })
+    }
+
+    /// Stop tracking a remote bookmark
+    ///
+    /// The local bookmark is left in place. Untracking a bookmark that is not
+    /// tracked is a no-op.
+    pub fn untrack_remote_bookmark(&self, name: String, remote: String) -> Result<()> {
+        self.with_transaction_mut(|tx, _| {
+            let symbol = remote_bookmark_symbol(&name, &remote)?;
+            let remote_ref = tx.repo().view().get_remote_bookmark(symbol);
+            if remote_ref.is_absent() {
+                return Err(JjError::Git {
+                    message: format!("Remote bookmark '{}@{}' not found", name, remote),
+                });
+            }
+            if remote_ref.is_tracked() {
+                tx.repo_mut().untrack_remote_bookmark(symbol);
+            }
+            Ok(())
+        })
+    }
+
     /// Commit the git transaction and return the updated repository
+    ///
+    /// In a colocated workspace, bookmarks and tags are exported to Git first.
     pub fn commit(&self, description: String) -> Result<Arc<FfiReadonlyRepo>> {
-        let inner = self.take_transaction()?;
+        let mut inner = self.take_transaction()?;
+        if let Some(workspace) = self.workspace.as_ref().filter(|w| w.is_colocated()) {
+            export_to_colocated_git(inner.repo_mut(), &self.base_repo, workspace.name())?;
+        }
 
         let repo = inner.commit(&description).map_err(|e| JjError::Transaction {
             message: e.to_string(),
         })?;
 
-        Ok(Arc::new(FfiReadonlyRepo::new(repo)))
+        Ok(Arc::new(FfiReadonlyRepo::with_workspace(
+            repo,
+            self.workspace.clone(),
+        )))
     }
 
     /// Discard the git transaction without committing
@@ -270,10 +885,255 @@ impl FfiGitTransaction {
     }
 }
 
+/// Run blocking work on a new thread and wait for its result asynchronously
+async fn run_in_background<T, F>(f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+    thread::Builder::new()
+        .name("jj-ffi-git".to_string())
+        .spawn(move || {
+            // The receiver is gone only if the caller stopped waiting
+            let _ = sender.send(f());
+        })
+        .map_err(|e| JjError::Internal {
+            message: format!("Failed to spawn background thread: {}", e),
+        })?;
+    receiver.await.map_err(|_| JjError::Internal {
+        message: "Background operation panicked".to_string(),
+    })?
+}
+
+/// Build a remote bookmark symbol, rejecting the backing Git repository
+fn remote_bookmark_symbol<'a>(name: &'a str, remote: &'a str) -> Result<RemoteRefSymbol<'a>> {
+    let remote_name = RemoteName::new(remote);
+    if remote_name == git::REMOTE_NAME_FOR_LOCAL_GIT_REPO {
+        return Err(JjError::InvalidArgument {
+            message: format!("Bookmarks of the '{}' remote cannot be tracked", remote),
+        });
+    }
+    Ok(RefName::new(name).to_remote_symbol(remote_name))
+}
+
 /// Get abandoned commit IDs from import stats
 #[uniffi::export]
-pub fn get_abandoned_commits_from_import(_stats: &FfiGitImportStats) -> Vec<FfiCommitId> {
-    // Note: This is a simplified version - the actual abandoned commits would need
-    // to be stored separately if needed
-    Vec::new()
+pub fn get_abandoned_commits_from_import(stats: &FfiGitImportStats) -> Vec<FfiCommitId> {
+    stats.abandoned_commits.clone()
+}
+
+/// List the remote-tracking Git refs of a remote with their targets
+fn remote_tracking_refs(
+    git_repo: &gix::Repository,
+    remote: &RemoteName,
+) -> Result<Vec<(gix::refs::FullName, gix::ObjectId)>> {
+    let git_error = |e: &dyn std::fmt::Display| JjError::Git {
+        message: format!("Failed to read remote-tracking refs: {}", e),
+    };
+    let prefix = format!("refs/remotes/{}/", remote.as_str());
+    let platform = git_repo.references().map_err(|e| git_error(&e))?;
+    let mut refs = Vec::new();
+    for reference in platform.remote_branches().map_err(|e| git_error(&e))? {
+        let reference = reference.map_err(|e| git_error(&e))?;
+        if !reference.name().as_bstr().starts_with(prefix.as_bytes()) {
+            continue;
+        }
+        // Symbolic refs like "refs/remotes/origin/HEAD" aren't pruned
+        if let Some(id) = reference.target().try_id() {
+            refs.push((reference.name().to_owned(), id.to_owned()));
+        }
+    }
+    Ok(refs)
+}
+
+/// Recreate the given Git refs that no longer exist
+fn restore_missing_refs(
+    git_repo: &gix::Repository,
+    refs: &[(gix::refs::FullName, gix::ObjectId)],
+) -> Result<()> {
+    for (name, id) in refs {
+        let git_error = |e: &dyn std::fmt::Display| JjError::Git {
+            message: format!("Failed to restore ref '{}': {}", name.as_bstr(), e),
+        };
+        let exists = git_repo
+            .try_find_reference(name.as_ref())
+            .map_err(|e| git_error(&e))?
+            .is_some();
+        if !exists {
+            git_repo
+                .reference(
+                    name.clone(),
+                    *id,
+                    gix::refs::transaction::PreviousValue::MustNotExist,
+                    "fetch: keep ref deleted on remote",
+                )
+                .map_err(|e| git_error(&e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Check whether the workspace shares its working directory with Git
+pub(crate) fn is_colocated(workspace: &Workspace) -> bool {
+    let Ok(git_backend) = git::get_git_backend(workspace.repo_loader().store()) else {
+        return false;
+    };
+    let Some(git_workdir) = git_backend.git_workdir() else {
+        return false;
+    };
+    match (
+        git_workdir.canonicalize(),
+        workspace.workspace_root().canonicalize(),
+    ) {
+        (Ok(git_workdir), Ok(workspace_root)) => git_workdir == workspace_root,
+        _ => false,
+    }
+}
+
+/// Update Git HEAD and refs from a transaction in a colocated workspace
+///
+/// Like the jj CLI does before committing every transaction, Git HEAD is
+/// moved to the parent of a changed working-copy commit and bookmarks and
+/// tags are exported. Refs that fail to export are left unchanged in Git.
+pub(crate) fn export_to_colocated_git(
+    mut_repo: &mut MutableRepo,
+    base_repo: &ReadonlyRepo,
+    workspace_name: &WorkspaceName,
+) -> Result<()> {
+    let old_wc_commit_id = base_repo.view().get_wc_commit_id(workspace_name);
+    let new_wc_commit_id = mut_repo.view().get_wc_commit_id(workspace_name).cloned();
+    if let Some(wc_commit_id) = new_wc_commit_id {
+        if old_wc_commit_id != Some(&wc_commit_id) {
+            let wc_commit = mut_repo.store().get_commit(&wc_commit_id)?;
+            git::reset_head(mut_repo, &wc_commit).map_err(|e| JjError::Git {
+                message: e.to_string(),
+            })?;
+        }
+    }
+    git::export_refs(mut_repo).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    Ok(())
+}
+
+/// Reconcile a colocated workspace with changes made by external Git tools
+///
+/// Imports Git HEAD and refs. If HEAD was moved, a new working-copy commit is
+/// created on top of it and the working-copy state is reset to it without
+/// touching files on disk. Returns no report if nothing had to be reconciled.
+pub(crate) fn sync_colocated_git_head(
+    workspace: &mut Workspace,
+    repo: Arc<ReadonlyRepo>,
+) -> Result<(Arc<ReadonlyRepo>, Option<FfiGitHeadSyncReport>)> {
+    if !is_colocated(workspace) {
+        return Ok((repo, None));
+    }
+    let git_settings = GitSettings::from_settings(repo.settings()).map_err(|e| JjError::Git {
+        message: format!("Failed to load Git settings: {}", e),
+    })?;
+
+    let mut tx = repo.start_transaction();
+    let old_git_head = tx.repo().view().git_head().as_normal().cloned();
+    git::import_head(tx.repo_mut()).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    let new_git_head = tx.repo().view().git_head().as_normal().cloned();
+    let stats = git::import_refs(tx.repo_mut(), &git_settings).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    if !tx.repo().has_changes() {
+        return Ok((repo, None));
+    }
+
+    let new_wc_commit = match &new_git_head {
+        Some(head_id) if old_git_head != new_git_head => {
+            let head_commit = tx.repo().store().get_commit(head_id)?;
+            let wc_commit = tx
+                .repo_mut()
+                .check_out(workspace.workspace_name().to_owned(), &head_commit)
+                .map_err(|e| JjError::Transaction {
+                    message: e.to_string(),
+                })?;
+            Some(wc_commit)
+        }
+        _ => None,
+    };
+    tx.repo_mut().rebase_descendants()?;
+    let repo = tx.commit("import git head")?;
+
+    if let Some(wc_commit) = &new_wc_commit {
+        let workspace_error = |message: String| JjError::Workspace { message };
+        let mut locked_ws = workspace
+            .start_working_copy_mutation()
+            .map_err(|e| workspace_error(e.to_string()))?;
+        pollster::block_on(locked_ws.locked_wc().reset(wc_commit))
+            .map_err(|e| workspace_error(e.to_string()))?;
+        locked_ws
+            .finish(repo.op_id().clone())
+            .map_err(|e| workspace_error(e.to_string()))?;
+    }
+
+    let report = FfiGitHeadSyncReport {
+        old_git_head: old_git_head.as_ref().map(FfiCommitId::from),
+        new_git_head: new_git_head.as_ref().map(FfiCommitId::from),
+        new_working_copy_commit: new_wc_commit.map(|commit| FfiCommitId::from(commit.id())),
+        import_stats: FfiGitImportStats::from(&stats),
+    };
+    Ok((repo, Some(report)))
+}
+
+/// Read HEAD of the backing Git repository
+///
+/// HEAD is read from disk, so it reflects changes made by external Git tools
+/// that haven't been imported yet.
+pub fn git_head(repo: &ReadonlyRepo) -> Result<FfiGitHead> {
+    let git_repo = git::get_git_repo(repo.store()).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    let head = git_repo.head().map_err(|e| JjError::Git {
+        message: format!("Failed to read Git HEAD: {}", e),
+    })?;
+    Ok(FfiGitHead {
+        ref_name: head.referent_name().map(|name| name.as_bstr().to_string()),
+        commit_id: head
+            .id()
+            .map(|id| FfiCommitId::new(id.to_hex().to_string())),
+        is_detached: head.is_detached(),
+    })
+}
+
+/// Read the extra headers stored in the Git object of a commit
+///
+/// These are headers beyond tree/parent/author/committer, such as those
+/// preserved on commits imported from Git.
+pub fn commit_extra_headers(
+    repo: &Arc<ReadonlyRepo>,
+    commit_id: &FfiCommitId,
+) -> Result<Vec<FfiCommitHeader>> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    // The root commit is virtual and has no Git object
+    if id == *repo.store().root_commit_id() {
+        return Ok(Vec::new());
+    }
+    let git_repo = git::get_git_repo(repo.store()).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    let git_error = |e: &dyn std::fmt::Display| JjError::Git {
+        message: format!("Failed to read commit {}: {}", id.hex(), e),
+    };
+
+    let oid = gix::ObjectId::try_from(id.as_bytes()).map_err(|e| git_error(&e))?;
+    let commit = git_repo.find_commit(oid).map_err(|e| git_error(&e))?;
+    let decoded = commit.decode().map_err(|e| git_error(&e))?;
+    Ok(decoded
+        .extra_headers
+        .iter()
+        .map(|(key, value)| FfiCommitHeader {
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+        .collect())
 }
@@ -0,0 +1,182 @@
+//! Commit graph export for FFI
+//!
+//! This module renders the subgraph selected by a revset as DOT or Mermaid
+//! text, so tooling can visualize history without walking the graph itself.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use jj_lib::backend::CommitId;
+use jj_lib::commit::Commit;
+use jj_lib::graph::{GraphEdgeType, TopoGroupedGraphIterator};
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::{ReadonlyRepo, Repo};
+
+use crate::error::{JjError, Result};
+use crate::revset::{RevsetContext, RevsetWorkspace, resolve_revset};
+
+/// Number of hex digits shown in ID labels
+const SHORT_ID_LENGTH: usize = 12;
+
+/// Output format of a graph export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiGraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+/// A piece of information shown in the label of each commit node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiGraphLabel {
+    /// Short change ID
+    ChangeId,
+    /// Short commit ID
+    CommitId,
+    /// First line of the description
+    Description,
+    /// Author name
+    Author,
+    /// Local bookmarks pointing at the commit
+    Bookmarks,
+}
+
+/// Options for exporting the commit graph
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiGraphExportOptions {
+    /// Output format
+    pub format: FfiGraphFormat,
+    /// Label lines of each node, in order (change ID and description if empty)
+    pub labels: Vec<FfiGraphLabel>,
+}
+
+/// Build the label lines of a commit node
+fn label_lines(
+    commit: &Commit,
+    labels: &[FfiGraphLabel],
+    bookmarks: &HashMap<CommitId, Vec<String>>,
+) -> Vec<String> {
+    labels
+        .iter()
+        .filter_map(|label| match label {
+            FfiGraphLabel::ChangeId => Some(short(&commit.change_id().reverse_hex())),
+            FfiGraphLabel::CommitId => Some(short(&commit.id().hex())),
+            FfiGraphLabel::Description => {
+                let first_line = commit.description().lines().next().unwrap_or_default();
+                Some(first_line.to_string())
+            }
+            FfiGraphLabel::Author => Some(commit.author().name.clone()),
+            FfiGraphLabel::Bookmarks => bookmarks.get(commit.id()).map(|names| names.join(" ")),
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+fn short(hex: &str) -> String {
+    hex[..hex.len().min(SHORT_ID_LENGTH)].to_string()
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+/// Export the commits selected by a revset as DOT or Mermaid text
+///
+/// Edges point from children to parents. Edges that skip commits outside the
+/// revset are drawn dashed; edges to parents missing from the history are
+/// omitted.
+pub fn export_graph(
+    repo: &Arc<ReadonlyRepo>,
+    context: &RevsetContext,
+    revset_str: &str,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+    options: &FfiGraphExportOptions,
+) -> Result<String> {
+    let expression = resolve_revset(repo.as_ref(), context, revset_str, user_email, workspace)?;
+    let revset = expression
+        .evaluate(repo.as_ref())
+        .map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+
+    let labels = if options.labels.is_empty() {
+        vec![FfiGraphLabel::ChangeId, FfiGraphLabel::Description]
+    } else {
+        options.labels.clone()
+    };
+    let mut bookmarks: HashMap<CommitId, Vec<String>> = HashMap::new();
+    for (name, target) in repo.view().local_bookmarks() {
+        for id in target.added_ids() {
+            bookmarks
+                .entry(id.clone())
+                .or_default()
+                .push(name.as_str().to_string());
+        }
+    }
+
+    let mut nodes = String::new();
+    let mut edges = String::new();
+    for result in TopoGroupedGraphIterator::new(revset.iter_graph(), |id| id) {
+        let (commit_id, graph_edges) = result.map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+        let commit = repo.store().get_commit(&commit_id)?;
+        let node = commit_id.hex();
+        let lines = label_lines(&commit, &labels, &bookmarks);
+
+        match options.format {
+            FfiGraphFormat::Dot => {
+                let label = lines
+                    .iter()
+                    .map(|line| escape_dot(line))
+                    .collect::<Vec<_>>()
+                    .join("\\n");
+                writeln!(nodes, "  \"{node}\" [label=\"{label}\"];").unwrap();
+            }
+            FfiGraphFormat::Mermaid => {
+                let label = lines
+                    .iter()
+                    .map(|line| escape_mermaid(line))
+                    .collect::<Vec<_>>()
+                    .join("<br>");
+                writeln!(nodes, "  c{node}[\"{label}\"]").unwrap();
+            }
+        }
+
+        for edge in &graph_edges {
+            let target = edge.target.hex();
+            match (options.format, edge.edge_type) {
+                (_, GraphEdgeType::Missing) => {}
+                (FfiGraphFormat::Dot, GraphEdgeType::Direct) => {
+                    writeln!(edges, "  \"{node}\" -> \"{target}\";").unwrap();
+                }
+                (FfiGraphFormat::Dot, GraphEdgeType::Indirect) => {
+                    writeln!(edges, "  \"{node}\" -> \"{target}\" [style=dashed];").unwrap();
+                }
+                (FfiGraphFormat::Mermaid, GraphEdgeType::Direct) => {
+                    writeln!(edges, "  c{node} --> c{target}").unwrap();
+                }
+                (FfiGraphFormat::Mermaid, GraphEdgeType::Indirect) => {
+                    writeln!(edges, "  c{node} -.-> c{target}").unwrap();
+                }
+            }
+        }
+    }
+
+    let output = match options.format {
+        FfiGraphFormat::Dot => {
+            format!("digraph commits {{\n  node [shape=box];\n{nodes}{edges}}}\n")
+        }
+        FfiGraphFormat::Mermaid => format!("flowchart TD\n{nodes}{edges}"),
+    };
+    Ok(output)
+}
@@ -0,0 +1,229 @@
+//! Library and repository format information for FFI
+//!
+//! This module reports what this build of the library supports and which
+//! store formats a repository uses, so apps can gate features, produce
+//! useful bug reports and reject repositories written by a newer jj.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use jj_lib::default_index::DefaultIndexStore;
+#[cfg(feature = "git")]
+use jj_lib::git;
+#[cfg(feature = "git")]
+use jj_lib::git_backend::GitBackend;
+use jj_lib::local_working_copy::LocalWorkingCopy;
+use jj_lib::repo::{ReadonlyRepo, Repo as _};
+use jj_lib::simple_backend::SimpleBackend;
+use jj_lib::simple_op_heads_store::SimpleOpHeadsStore;
+use jj_lib::simple_op_store::SimpleOpStore;
+
+use crate::error::{JjError, Result};
+
+/// Version of jj-lib this library is built against (keep in sync with Cargo.toml)
+const JJ_LIB_VERSION: &str = "0.36.0";
+
+/// Signing backends built into jj-lib
+const SIGNING_BACKENDS: [&str; 3] = ["gpg", "gpgsm", "ssh"];
+
+/// Information about this build of the library
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiLibraryInfo {
+    /// Version of the jj-ffi crate
+    pub version: String,
+    /// Version of jj-lib
+    pub jj_lib_version: String,
+    /// Supported commit backend types
+    pub commit_backends: Vec<String>,
+    /// Supported operation store types
+    pub op_stores: Vec<String>,
+    /// Supported operation heads store types
+    pub op_heads_stores: Vec<String>,
+    /// Supported index store types
+    pub index_stores: Vec<String>,
+    /// Supported working copy types
+    pub working_copies: Vec<String>,
+    /// Enabled optional features, e.g. "git"
+    pub features: Vec<String>,
+    /// Available commit signing backends
+    pub signing_backends: Vec<String>,
+}
+
+/// Store types used by a repository
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiRepoFormat {
+    /// Commit backend type, e.g. "git"
+    pub commit_backend: String,
+    /// Operation store type
+    pub op_store: String,
+    /// Operation heads store type
+    pub op_heads_store: String,
+    /// Index store type
+    pub index_store: String,
+    /// Working copy type (`None` when inspecting a repo without a workspace)
+    pub working_copy: Option<String>,
+}
+
+/// Stores of a loaded repository
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiStoreInfo {
+    /// Commit backend type, e.g. "git" or "simple"
+    pub commit_backend: String,
+    /// Path of the backing Git repository (`None` for non-Git backends)
+    pub git_repo_path: Option<String>,
+    /// Length of commit IDs in bytes
+    pub commit_id_length: u32,
+    /// Length of change IDs in bytes
+    pub change_id_length: u32,
+    /// Operation store type
+    pub op_store: String,
+    /// Operation heads store type
+    pub op_heads_store: String,
+    /// Index store type
+    pub index_store: String,
+}
+
+fn supported_commit_backends() -> Vec<&'static str> {
+    vec![
+        SimpleBackend::name(),
+        #[cfg(feature = "git")]
+        GitBackend::name(),
+    ]
+}
+
+/// Get information about this build of the library
+#[uniffi::export]
+pub fn library_info() -> FfiLibraryInfo {
+    let to_strings = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+    FfiLibraryInfo {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        jj_lib_version: JJ_LIB_VERSION.to_string(),
+        commit_backends: to_strings(&supported_commit_backends()),
+        op_stores: to_strings(&[SimpleOpStore::name()]),
+        op_heads_stores: to_strings(&[SimpleOpHeadsStore::name()]),
+        index_stores: to_strings(&[DefaultIndexStore::name()]),
+        working_copies: to_strings(&[LocalWorkingCopy::name()]),
+        features: to_strings(&[
+            #[cfg(feature = "git")]
+            "git",
+            #[cfg(feature = "watchman")]
+            "watchman",
+        ]),
+        signing_backends: to_strings(&SIGNING_BACKENDS),
+    }
+}
+
+/// Describe the stores of a loaded repository
+pub(crate) fn store_info(repo: &ReadonlyRepo) -> FfiStoreInfo {
+    let store = repo.store();
+    #[cfg(feature = "git")]
+    let git_repo_path = git::get_git_backend(store)
+        .ok()
+        .map(|backend| backend.git_repo_path().to_string_lossy().to_string());
+    #[cfg(not(feature = "git"))]
+    let git_repo_path = None;
+    FfiStoreInfo {
+        commit_backend: store.backend().name().to_string(),
+        git_repo_path,
+        commit_id_length: store.commit_id_length() as u32,
+        change_id_length: store.change_id_length() as u32,
+        op_store: repo.loader().op_store().name().to_string(),
+        op_heads_store: repo.op_heads_store().name().to_string(),
+        index_store: repo.index_store().name().to_string(),
+    }
+}
+
+/// Read the type file of a store
+fn read_store_type(store_path: &Path) -> Result<String> {
+    let path = store_path.join("type");
+    fs::read_to_string(&path).map_err(|e| JjError::Repository {
+        message: format!("Failed to read '{}': {}", path.display(), e),
+    })
+}
+
+/// Check that a store type is supported by this library
+fn check_store_type(store: &str, store_type: &str, supported: &[&str]) -> Result<()> {
+    if supported.contains(&store_type) {
+        return Ok(());
+    }
+    Err(JjError::IncompatibleRepository {
+        message: format!(
+            "Unsupported {} type '{}'; the repository may have been created by a newer \
+             version of jj than jj-lib {}",
+            store, store_type, JJ_LIB_VERSION
+        ),
+    })
+}
+
+/// Find the repo directory of a workspace, following `.jj/repo` pointer files
+fn workspace_repo_path(jj_dir: &Path) -> Result<PathBuf> {
+    let repo_path = jj_dir.join("repo");
+    if !repo_path.is_file() {
+        return Ok(repo_path);
+    }
+    let target = fs::read_to_string(&repo_path).map_err(|e| JjError::Workspace {
+        message: format!("Failed to read '{}': {}", repo_path.display(), e),
+    })?;
+    Ok(jj_dir.join(target))
+}
+
+/// Read the store types of a repo directory and check that they are supported
+pub(crate) fn check_repo_format(
+    repo_path: &Path,
+    working_copy_path: Option<&Path>,
+) -> Result<FfiRepoFormat> {
+    let format = FfiRepoFormat {
+        commit_backend: read_store_type(&repo_path.join("store"))?,
+        op_store: read_store_type(&repo_path.join("op_store"))?,
+        op_heads_store: read_store_type(&repo_path.join("op_heads"))?,
+        index_store: read_store_type(&repo_path.join("index"))?,
+        working_copy: working_copy_path.map(read_store_type).transpose()?,
+    };
+
+    check_store_type(
+        "commit backend",
+        &format.commit_backend,
+        &supported_commit_backends(),
+    )?;
+    check_store_type(
+        "operation store",
+        &format.op_store,
+        &[SimpleOpStore::name()],
+    )?;
+    check_store_type(
+        "operation heads store",
+        &format.op_heads_store,
+        &[SimpleOpHeadsStore::name()],
+    )?;
+    check_store_type(
+        "index store",
+        &format.index_store,
+        &[DefaultIndexStore::name()],
+    )?;
+    if let Some(working_copy) = &format.working_copy {
+        check_store_type("working copy", working_copy, &[LocalWorkingCopy::name()])?;
+    }
+    Ok(format)
+}
+
+/// Check that a workspace can be opened by this library
+pub(crate) fn check_workspace_format(workspace_root: &Path) -> Result<FfiRepoFormat> {
+    let jj_dir = workspace_root.join(".jj");
+    if !jj_dir.is_dir() {
+        return Err(JjError::Workspace {
+            message: format!("No jj workspace found at '{}'", workspace_root.display()),
+        });
+    }
+    let repo_path = workspace_repo_path(&jj_dir)?;
+    check_repo_format(&repo_path, Some(&jj_dir.join("working_copy")))
+}
+
+/// Check that the workspace at the given path can be opened by this library
+///
+/// Returns the store types in use. Fails with `IncompatibleRepository` if the
+/// repository uses a format this library doesn't support, e.g. because it was
+/// created by a newer version of jj.
+#[uniffi::export]
+pub fn check_workspace_compatibility(workspace_path: String) -> Result<FfiRepoFormat> {
+    check_workspace_format(Path::new(&workspace_path))
+}
@@ -0,0 +1,156 @@
+//! Graph layout for FFI
+//!
+//! This module assigns the commits and edges of a log to columns, like the
+//! graph renderer of `jj log`, so clients can draw the graph row by row
+//! without implementing the layout themselves.
+//!
+//! Each row has one node. Lanes keep their column from row to row; a lane
+//! ends when it reaches its target node, and the freed column is reused by
+//! later lanes.
+
+use jj_lib::backend::CommitId;
+use jj_lib::graph::{GraphEdge, GraphEdgeType};
+
+use crate::log::FfiGraphEdgeType;
+use crate::types::FfiCommitId;
+
+/// A lane leaving a node towards one of its graph parents
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct FfiGraphLane {
+    /// Column of the lane in the following rows
+    pub column: u32,
+    /// The target commit ID (parent)
+    pub target: FfiCommitId,
+    /// The type of edge
+    pub edge_type: FfiGraphEdgeType,
+}
+
+/// Position of a log entry's node and lanes in the graph
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct FfiGraphLayout {
+    /// Column of the node
+    pub column: u32,
+    /// Columns of lanes from the rows above that end at the node, including
+    /// the node's own column if a lane ends there
+    pub incoming_columns: Vec<u32>,
+    /// Columns of lanes that pass the node unchanged, from the rows above
+    /// to the rows below
+    pub passing_columns: Vec<u32>,
+    /// Lanes towards the node's parents, in the order of the entry's edges
+    ///
+    /// A lane moves diagonally when its column differs from the node's.
+    /// Lanes of `Missing` edges end right below the node.
+    pub outgoing: Vec<FfiGraphLane>,
+    /// Number of columns used by the row, including the lanes below it
+    pub width: u32,
+}
+
+/// Assigns columns to the rows of a graph, one row at a time
+#[derive(Debug, Default)]
+pub(crate) struct GraphLayouter {
+    /// Commit expected by the lane in each column
+    columns: Vec<Option<CommitId>>,
+}
+
+impl GraphLayouter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Find a free column, adding one if all are in use
+    fn free_column(&mut self) -> usize {
+        match self.columns.iter().position(Option::is_none) {
+            Some(column) => column,
+            None => {
+                self.columns.push(None);
+                self.columns.len() - 1
+            }
+        }
+    }
+
+    /// Lay out the next row, whose node has the given edges
+    pub(crate) fn next_row(
+        &mut self,
+        id: &CommitId,
+        edges: &[GraphEdge<CommitId>],
+    ) -> FfiGraphLayout {
+        let mut incoming_columns = Vec::new();
+        let mut passing_columns = Vec::new();
+        for (column, expected) in self.columns.iter_mut().enumerate() {
+            match expected {
+                Some(expected_id) if expected_id == id => {
+                    incoming_columns.push(column as u32);
+                    *expected = None;
+                }
+                Some(_) => passing_columns.push(column as u32),
+                None => {}
+            }
+        }
+        let column = match incoming_columns.first() {
+            Some(&column) => column as usize,
+            None => self.free_column(),
+        };
+
+        // The first parent continues in the node's column, unless it already
+        // has a lane. Missing edges are placed last, as they only need their
+        // column for a single row.
+        let mut lane_columns = vec![0; edges.len()];
+        let mut missing_columns = Vec::new();
+        let (missing, present): (Vec<_>, Vec<_>) = edges
+            .iter()
+            .enumerate()
+            .partition(|(_, edge)| edge.edge_type == GraphEdgeType::Missing);
+        for (index, edge) in present {
+            let existing = self
+                .columns
+                .iter()
+                .position(|expected| expected.as_ref() == Some(&edge.target));
+            lane_columns[index] = match existing {
+                Some(lane_column) => lane_column,
+                None => {
+                    let lane_column = if self.columns[column].is_none() {
+                        column
+                    } else {
+                        self.free_column()
+                    };
+                    self.columns[lane_column] = Some(edge.target.clone());
+                    lane_column
+                }
+            };
+        }
+        for (index, edge) in missing {
+            let lane_column = if self.columns[column].is_none() {
+                column
+            } else {
+                self.free_column()
+            };
+            self.columns[lane_column] = Some(edge.target.clone());
+            missing_columns.push(lane_column);
+            lane_columns[index] = lane_column;
+        }
+
+        let width = self.columns.len().max(column + 1);
+        for lane_column in missing_columns {
+            self.columns[lane_column] = None;
+        }
+        while self.columns.last().is_some_and(Option::is_none) {
+            self.columns.pop();
+        }
+
+        FfiGraphLayout {
+            column: column as u32,
+            incoming_columns,
+            passing_columns,
+            outgoing: edges
+                .iter()
+                .zip(lane_columns)
+                .map(|(edge, lane_column)| FfiGraphLane {
+                    column: lane_column as u32,
+                    target: FfiCommitId::from(&edge.target),
+                    edge_type: FfiGraphEdgeType::from(edge.edge_type),
+                })
+                .collect(),
+            width: width as u32,
+        }
+    }
+}
@@ -3,30 +3,107 @@
 //! This crate provides FFI bindings to expose jj-lib functionality
 //! to other languages via UniFFI.
 
+pub mod bookmarks;
+pub mod cancel;
+pub mod commit_builder;
+pub mod conflicts;
+#[cfg(feature = "git")]
+pub mod credentials;
+pub mod diff;
 pub mod error;
+pub mod files;
 #[cfg(feature = "git")]
 pub mod git;
+pub mod graph;
+pub mod info;
+pub mod layout;
+pub mod loader;
 pub mod log;
+pub mod logging;
+pub mod operation;
+mod patch;
+pub mod prefix;
+#[cfg(feature = "git")]
+pub mod progress;
 pub mod repo;
 pub mod revset;
+pub mod rewrite;
+pub mod snapshot;
+pub mod stats;
+pub mod tags;
+pub mod template;
+pub mod trailers;
 pub mod transaction;
+pub mod tree;
 pub mod types;
 pub mod workspace;
 
 // Re-export main types for convenience
+pub use bookmarks::{
+    FfiBookmark, FfiBookmarkChange, FfiBookmarkChangeKind, FfiBookmarkRef, FfiRemoteBookmark,
+};
+pub use cancel::FfiCancellationToken;
+pub use commit_builder::{FfiCommitBuilder, FfiSignBehavior};
+pub use conflicts::{FfiConflictSides, FfiConflictTerm, FfiMergeToolFiles};
+pub use diff::{
+    FfiChangeType, FfiDiffEntry, FfiDiffHunk, FfiDiffLine, FfiDiffLineType, FfiDiffStats,
+    FfiDirectoryDiffSummary, FfiFileDiffStat, FfiTextRange,
+};
 pub use error::JjError;
-pub use log::{FfiGraphEdge, FfiGraphEdgeType, FfiLogEntry, FfiLogOptions, FfiLogResult};
+pub use files::FfiConflictMarkerStyle;
+pub use graph::{FfiGraphExportOptions, FfiGraphFormat, FfiGraphLabel};
+pub use info::{
+    FfiLibraryInfo, FfiRepoFormat, FfiStoreInfo, check_workspace_compatibility, library_info,
+};
+pub use layout::{FfiGraphLane, FfiGraphLayout};
+pub use loader::FfiRepoLoader;
+pub use log::{
+    FfiCommitRefs, FfiGraphEdge, FfiGraphEdgeType, FfiLogEntry, FfiLogIterator, FfiLogOptions,
+    FfiLogResult,
+};
+pub use logging::{FfiLogLevel, FfiLogRecord, FfiLogSink, install_log_sink, remove_log_sink};
+pub use operation::{FfiOperation, FfiOperationMergeReport};
 pub use repo::FfiReadonlyRepo;
-pub use transaction::FfiTransaction;
+pub use revset::FfiRevsetOptions;
+pub use rewrite::{
+    FfiAbsorbResult, FfiAbsorbSkippedPath, FfiRebaseDestination, FfiRebaseStats, FfiRebaseTarget,
+    FfiSplitResult, FfiSquashOptions,
+};
+pub use snapshot::{
+    FfiFsmonitor, FfiSnapshotOptions, FfiSnapshotProgress, FfiSnapshotProgressUpdate,
+    FfiSnapshotResult, FfiUntrackedPath, FfiUntrackedReason,
+};
+pub use stats::{FfiActivityBucket, FfiActivityCount, FfiContributorStats};
+pub use tags::FfiTag;
+pub use trailers::{
+    FfiTrailer, add_description_trailers, description_trailers, set_description_trailers,
+};
+pub use transaction::{FfiTransaction, FfiTransactionResult};
+pub use tree::{FfiExportFormat, FfiTreeBuilder, FfiTreeEntry, FfiTreeEntryType};
 pub use types::{
     FfiChangeId, FfiCommit, FfiCommitId, FfiNewCommit, FfiRewriteCommit, FfiSignature, FfiTimestamp,
 };
-pub use workspace::FfiWorkspace;
+pub use workspace::{FfiWorkspace, FfiWorkspaceInfo};
 
 #[cfg(feature = "git")]
-pub use git::{FfiGitExportStats, FfiGitImportStats, FfiGitPushStats, FfiGitTransaction};
+pub use credentials::{FfiGitCredential, FfiGitCredentialProvider};
+#[cfg(feature = "git")]
+pub use git::{
+    FfiCommitHeader, FfiFailedRefExport, FfiFetchOptions, FfiGitExportStats, FfiGitHead,
+    FfiGitHeadSyncReport, FfiGitImportStats, FfiGitPushStats, FfiGitTransaction,
+    FfiImportedRefChange, FfiPushOptions, FfiPushUpdate, FfiRefExportFailureReason,
+    FfiRejectedRef,
+};
+#[cfg(feature = "git")]
+pub use progress::{FfiGitProgress, FfiGitProgressUpdate};
 #[cfg(feature = "git")]
-pub use workspace::{init_colocated_git_workspace, init_internal_git_workspace};
+pub use workspace::{
+    FfiCloneOptions, FfiInitOptions, clone_git_workspace, clone_git_workspace_with_user_config,
+    init_colocated_git_workspace, init_colocated_git_workspace_with_options,
+    init_colocated_git_workspace_with_user_config, init_external_git_workspace,
+    init_external_git_workspace_with_user_config, init_internal_git_workspace,
+    init_internal_git_workspace_with_options, init_internal_git_workspace_with_user_config,
+};
 
 // UniFFI scaffolding
 uniffi::setup_scaffolding!();
@@ -0,0 +1,58 @@
+//! Repository loading for FFI
+//!
+//! This module loads repositories without a workspace or working copy, for
+//! headless consumers that only need to serve read queries.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use jj_lib::repo::{RepoLoader, StoreFactories};
+
+use crate::error::Result;
+use crate::info::check_repo_format;
+use crate::repo::FfiReadonlyRepo;
+use crate::workspace::{create_user_settings, with_config_aliases};
+
+/// A repository loader exposed via FFI
+///
+/// Loads repositories directly from the repo directory (`.jj/repo`),
+/// skipping workspace and working-copy setup.
+#[derive(uniffi::Object)]
+pub struct FfiRepoLoader {
+    inner: RepoLoader,
+}
+
+#[uniffi::export]
+impl FfiRepoLoader {
+    /// Create a loader for the repo directory at the given path
+    #[uniffi::constructor]
+    pub fn new(repo_path: String, user_name: String, user_email: String) -> Result<Arc<Self>> {
+        check_repo_format(Path::new(&repo_path), None)?;
+        let settings = create_user_settings(&user_name, &user_email)?;
+        let settings = with_config_aliases(&settings, Path::new(&repo_path))?;
+        let store_factories = StoreFactories::default();
+        let inner =
+            RepoLoader::init_from_file_system(&settings, Path::new(&repo_path), &store_factories)?;
+        Ok(Arc::new(Self { inner }))
+    }
+
+    /// Load the repository at the current head operation
+    ///
+    /// Divergent operations are merged like the `jj` CLI does; see
+    /// `FfiReadonlyRepo::operation_merge_report()`.
+    pub fn load_at_head(&self) -> Result<Arc<FfiReadonlyRepo>> {
+        let (repo, operation_merge) = crate::operation::load_at_head(&self.inner)?;
+        Ok(Arc::new(
+            FfiReadonlyRepo::new(repo).with_operation_merge(operation_merge),
+        ))
+    }
+
+    /// Load the repository at a given operation
+    ///
+    /// Accepts an operation ID (or unique prefix) or an operation expression
+    /// such as "@" or "@-".
+    pub fn load_at_operation(&self, operation: String) -> Result<Arc<FfiReadonlyRepo>> {
+        let repo = crate::operation::load_at_operation(&self.inner, &operation)?;
+        Ok(Arc::new(FfiReadonlyRepo::new(repo)))
+    }
+}
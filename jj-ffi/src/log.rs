@@ -4,20 +4,25 @@
 //! exposing graph-based commit history via FFI.
 
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use chrono::Local;
 use jj_lib::backend::CommitId;
-use jj_lib::graph::{GraphEdge, GraphEdgeType, TopoGroupedGraphIterator, reverse_graph};
+use jj_lib::commit::Commit;
+use jj_lib::graph::{GraphEdge, GraphEdgeType, GraphNode, TopoGroupedGraphIterator, reverse_graph};
+use jj_lib::object_id::ObjectId as _;
 use jj_lib::repo::{ReadonlyRepo, Repo};
 use jj_lib::revset::{
-    RevsetAliasesMap, RevsetDiagnostics, RevsetExpression, RevsetExtensions, RevsetIteratorExt,
-    RevsetParseContext, SymbolResolver, parse,
+    ResolvedRevsetExpression, RevsetEvaluationError, RevsetExpression, RevsetIteratorExt,
 };
-use jj_lib::time_util::DatePatternContext;
+use jj_lib::view::View;
 
+use crate::bookmarks::{FfiBookmarkRef, LOCAL_GIT_REMOTE};
 use crate::error::{JjError, Result};
-use crate::types::{FfiCommit, FfiCommitId};
+use crate::layout::{FfiGraphLane, FfiGraphLayout, GraphLayouter};
+use crate::revset::{
+    RevsetContext, RevsetWorkspace, files_filter, parse_revsets, resolve_expression,
+};
+use crate::types::{FfiCommit, FfiCommitId, FfiSignature};
 
 /// Graph edge type exposed via FFI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
@@ -58,6 +63,89 @@ impl FfiGraphEdge {
     }
 }
 
+/// Refs pointing at a commit, as shown next to it by `jj log`
+///
+/// Conflicted refs are listed on each of the commits they point to.
+#[derive(Debug, Clone, Default, uniffi::Record)]
+pub struct FfiCommitRefs {
+    /// Local bookmarks, sorted by name
+    pub local_bookmarks: Vec<String>,
+    /// Remote bookmarks (excluding the backing Git repository), sorted by
+    /// name and remote
+    pub remote_bookmarks: Vec<FfiBookmarkRef>,
+    /// Tags, sorted by name
+    pub tags: Vec<String>,
+    /// Names of the workspaces that have this commit as working-copy commit
+    pub working_copies: Vec<String>,
+}
+
+impl FfiCommitRefs {
+    fn estimated_size(&self) -> usize {
+        let strings_size = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| size_of::<String>() + name.len())
+                .sum::<usize>()
+        };
+        strings_size(&self.local_bookmarks)
+            + strings_size(&self.tags)
+            + strings_size(&self.working_copies)
+            + self
+                .remote_bookmarks
+                .iter()
+                .map(|bookmark| {
+                    size_of::<FfiBookmarkRef>()
+                        + bookmark.name.len()
+                        + bookmark.remote.as_ref().map_or(0, String::len)
+                })
+                .sum::<usize>()
+    }
+}
+
+/// Refs of all commits in a view, looked up by commit
+pub(crate) struct CommitRefsIndex {
+    refs: HashMap<CommitId, FfiCommitRefs>,
+}
+
+impl CommitRefsIndex {
+    pub(crate) fn new(view: &View) -> Self {
+        let mut refs: HashMap<CommitId, FfiCommitRefs> = HashMap::new();
+        for (name, target) in view.local_bookmarks() {
+            for id in target.added_ids() {
+                let entry = refs.entry(id.clone()).or_default();
+                entry.local_bookmarks.push(name.as_str().to_string());
+            }
+        }
+        for (symbol, remote_ref) in view.all_remote_bookmarks() {
+            if symbol.remote.as_str() == LOCAL_GIT_REMOTE {
+                continue;
+            }
+            for id in remote_ref.target.added_ids() {
+                let entry = refs.entry(id.clone()).or_default();
+                entry.remote_bookmarks.push(FfiBookmarkRef {
+                    name: symbol.name.as_str().to_string(),
+                    remote: Some(symbol.remote.as_str().to_string()),
+                });
+            }
+        }
+        for (name, target) in view.local_tags() {
+            for id in target.added_ids() {
+                let entry = refs.entry(id.clone()).or_default();
+                entry.tags.push(name.as_str().to_string());
+            }
+        }
+        for (name, id) in view.wc_commit_ids() {
+            let entry = refs.entry(id.clone()).or_default();
+            entry.working_copies.push(name.as_str().to_string());
+        }
+        Self { refs }
+    }
+
+    pub(crate) fn get(&self, id: &CommitId) -> FfiCommitRefs {
+        self.refs.get(id).cloned().unwrap_or_default()
+    }
+}
+
 /// A log entry containing commit information and graph edges
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct FfiLogEntry {
@@ -65,6 +153,72 @@ pub struct FfiLogEntry {
     pub commit: FfiCommit,
     /// Edges to parent commits in the graph
     pub edges: Vec<FfiGraphEdge>,
+    /// Bookmarks, tags and working copies pointing at the commit
+    pub refs: FfiCommitRefs,
+    /// Whether the commit's tree equals the auto-merged trees of its parents,
+    /// i.e. the commit makes no changes (`None` unless requested with
+    /// `include_is_empty`)
+    pub is_empty: Option<bool>,
+    /// Columns of the node and its lanes for drawing the graph
+    pub layout: FfiGraphLayout,
+}
+
+impl FfiLogEntry {
+    fn new(
+        repo: &dyn Repo,
+        commit: &Commit,
+        edges: &[GraphEdge<CommitId>],
+        refs: &CommitRefsIndex,
+        layouter: &mut GraphLayouter,
+        include_id_prefixes: bool,
+        include_is_empty: bool,
+    ) -> Result<Self> {
+        let log_commit = log_commit(repo, commit, include_id_prefixes)?;
+        Self::with_log_commit(
+            repo,
+            commit,
+            log_commit,
+            edges,
+            refs,
+            layouter,
+            include_is_empty,
+        )
+    }
+
+    /// Create an entry for a commit already converted by `log_commit()`
+    fn with_log_commit(
+        repo: &dyn Repo,
+        commit: &Commit,
+        log_commit: FfiCommit,
+        edges: &[GraphEdge<CommitId>],
+        refs: &CommitRefsIndex,
+        layouter: &mut GraphLayouter,
+        include_is_empty: bool,
+    ) -> Result<Self> {
+        let is_empty = if include_is_empty {
+            Some(commit.is_empty(repo)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            commit: log_commit,
+            edges: edges.iter().map(FfiGraphEdge::from_graph_edge).collect(),
+            refs: refs.get(commit.id()),
+            is_empty,
+            layout: layouter.next_row(commit.id(), edges),
+        })
+    }
+
+    /// Approximate the memory footprint of the entry in bytes
+    fn estimated_size(&self) -> u64 {
+        let layout = &self.layout;
+        let layout_size = (layout.incoming_columns.len() + layout.passing_columns.len())
+            * size_of::<u32>()
+            + layout.outgoing.len() * (size_of::<FfiGraphLane>() + self.commit.id.hex.len());
+        estimated_size(&self.commit, self.edges.len())
+            + self.refs.estimated_size() as u64
+            + layout_size as u64
+    }
 }
 
 /// Options for log retrieval
@@ -76,6 +230,28 @@ pub struct FfiLogOptions {
     pub limit: i64,
     /// Whether to return commits in reverse order (oldest first)
     pub reversed: bool,
+    /// Approximate memory budget in bytes for materialized commits (`None` for no budget)
+    pub max_bytes: Option<u64>,
+    /// Whether to fill in the shortest unique ID prefix lengths of commits
+    pub include_id_prefixes: bool,
+    /// Whether to fill in `is_empty` of entries, which compares the tree of
+    /// each commit with those of its parents
+    pub include_is_empty: bool,
+    /// Fileset expressions restricting the log to commits that modify
+    /// matching files, like `jj log <paths>` (all commits if empty)
+    ///
+    /// Paths are relative to the workspace root, or to the repository root
+    /// for repositories not loaded from a workspace.
+    pub paths: Vec<String>,
+}
+
+/// Convert a commit for a log, with its ID prefix lengths if requested
+fn log_commit(repo: &dyn Repo, commit: &Commit, include_id_prefixes: bool) -> Result<FfiCommit> {
+    if include_id_prefixes {
+        FfiCommit::with_id_prefixes(commit, repo)
+    } else {
+        Ok(FfiCommit::from(commit))
+    }
 }
 
 /// Result of a log operation
@@ -83,64 +259,118 @@ pub struct FfiLogOptions {
 pub struct FfiLogResult {
     /// Log entries with graph information
     pub entries: Vec<FfiLogEntry>,
+    /// Whether entries were cut short by `limit` or `max_bytes`, i.e. more
+    /// entries exist
+    pub truncated: bool,
+    /// Number of entries left out when truncated, as estimated by the revset
+    /// engine (`None` if not truncated)
+    ///
+    /// Counting only walks the revset, without loading the remaining commits.
+    pub remaining_count: Option<u64>,
 }
 
-/// Evaluate log with graph information
-pub fn evaluate_log(
-    repo: &Arc<ReadonlyRepo>,
+/// Tracks the memory budget while materializing log entries
+pub(crate) struct MemoryBudget {
+    remaining: Option<u64>,
+}
+
+impl MemoryBudget {
+    pub(crate) fn new(max_bytes: Option<u64>) -> Self {
+        Self {
+            remaining: max_bytes,
+        }
+    }
+
+    /// Charge an entry of the given size against the budget, returning false
+    /// if it doesn't fit
+    pub(crate) fn charge(&mut self, size: u64) -> bool {
+        let Some(remaining) = &mut self.remaining else {
+            return true;
+        };
+        if size > *remaining {
+            return false;
+        }
+        *remaining -= size;
+        true
+    }
+}
+
+/// Approximate the memory footprint of a materialized commit in bytes
+fn estimated_size(commit: &FfiCommit, edge_count: usize) -> u64 {
+    let edges_size = edge_count * (size_of::<FfiGraphEdge>() + commit.id.hex.len());
+    (size_of::<FfiLogEntry>() - size_of::<FfiCommit>() + edges_size) as u64
+        + estimated_commit_size(commit)
+}
+
+/// Approximate the memory footprint of a commit outside of a log entry in bytes
+pub(crate) fn estimated_commit_size(commit: &FfiCommit) -> u64 {
+    let signature_size = |signature: &FfiSignature| signature.name.len() + signature.email.len();
+    let hex_size = commit.id.hex.len();
+    let size = size_of::<FfiCommit>()
+        + commit.change_id.hex.len()
+        + hex_size
+        + commit.description.len()
+        + signature_size(&commit.author)
+        + signature_size(&commit.committer)
+        + commit.parent_ids.len() * (size_of::<FfiCommitId>() + hex_size)
+        + commit.tree_ids.len() * (size_of::<String>() + hex_size);
+    size as u64
+}
+
+/// Approximate the memory footprint of a graph node buffered to reverse the
+/// graph in bytes
+fn estimated_node_size(node: &GraphNode<CommitId>) -> u64 {
+    let (id, edges) = node;
+    let size = size_of::<GraphNode<CommitId>>()
+        + id.as_bytes().len()
+        + edges.len() * (size_of::<GraphEdge<CommitId>>() + id.as_bytes().len());
+    size as u64
+}
+
+/// Resolve the union of the log's revisions (all visible commits if empty),
+/// restricted to the commits modifying the log's paths
+fn resolve_log_revisions(
+    repo: &dyn Repo,
+    context: &RevsetContext,
     options: &FfiLogOptions,
     user_email: &str,
-) -> Result<FfiLogResult> {
-    let aliases_map = RevsetAliasesMap::new();
-    let extensions = RevsetExtensions::new();
-    let date_context = DatePatternContext::from(Local::now());
-
-    let context = RevsetParseContext {
-        aliases_map: &aliases_map,
-        local_variables: HashMap::new(),
-        user_email,
-        date_pattern_context: date_context,
-        default_ignored_remote: None,
-        use_glob_by_default: false,
-        extensions: &extensions,
-        workspace: None,
-    };
-
-    // Build revset expression
+    workspace: Option<&RevsetWorkspace>,
+) -> Result<Arc<ResolvedRevsetExpression>> {
     let revset_expression = if options.revisions.is_empty() {
         // Default: all visible commits
         RevsetExpression::all()
     } else {
-        // Union of all provided revisions
-        let mut expressions = Vec::new();
-        let mut diagnostics = RevsetDiagnostics::new();
-        for rev_str in &options.revisions {
-            let expr = parse(&mut diagnostics, rev_str, &context).map_err(|e| JjError::Revset {
-                message: e.to_string(),
-            })?;
-            expressions.push(expr);
-        }
-        // Union all expressions
-        expressions
+        let revset_strs = options.revisions.iter().map(String::as_str);
+        parse_revsets(context, revset_strs, user_email, workspace)?
             .into_iter()
             .reduce(|a, b| a.union(&b))
             .unwrap_or_else(RevsetExpression::none)
     };
+    let revset_expression = if options.paths.is_empty() {
+        revset_expression
+    } else {
+        revset_expression.intersection(&files_filter(&options.paths, workspace)?)
+    };
+    resolve_expression(repo, context, &revset_expression)
+}
 
-    // Resolve and evaluate
-    let symbol_resolver = SymbolResolver::new(repo.as_ref(), extensions.symbol_resolvers());
-    let resolved_expression = revset_expression
-        .resolve_user_expression(repo.as_ref(), &symbol_resolver)
+/// Evaluate log with graph information
+///
+/// In reversed order, `limit` and `max_bytes` select the newest commits,
+/// like `jj log --reversed --limit`.
+pub fn evaluate_log(
+    repo: &Arc<ReadonlyRepo>,
+    context: &RevsetContext,
+    options: &FfiLogOptions,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+) -> Result<FfiLogResult> {
+    let revset = resolve_log_revisions(repo.as_ref(), context, options, user_email, workspace)?
+        .evaluate(repo.as_ref())
         .map_err(|e| JjError::Revset {
             message: e.to_string(),
         })?;
 
-    let revset = resolved_expression.evaluate(repo.as_ref()).map_err(|e| {
-        JjError::Revset {
-            message: e.to_string(),
-        }
-    })?;
-
     let store = repo.store();
     let limit = if options.limit < 0 {
         usize::MAX
@@ -149,96 +379,113 @@ pub fn evaluate_log(
     };
 
     // Use TopoGroupedGraphIterator for proper graph ordering
-    let graph_iter = TopoGroupedGraphIterator::new(revset.iter_graph(), |id| id);
-    let graph_iter = graph_iter.take(limit);
+    let mut graph_iter = TopoGroupedGraphIterator::new(revset.iter_graph(), |id| id);
+    let refs = CommitRefsIndex::new(repo.view());
+    let mut layouter = GraphLayouter::new();
+    let mut budget = MemoryBudget::new(options.max_bytes);
+    let mut entries = Vec::new();
+    let mut truncated = false;
+
+    if options.reversed {
+        // Select the newest commits within the limit and budget, then reverse
+        // the graph of the selected commits only
+        let mut selected: Vec<GraphNodeResult> = Vec::new();
+        let mut commits = HashMap::new();
+        for result in graph_iter.by_ref() {
+            if selected.len() == limit {
+                truncated = true;
+                break;
+            }
+            let (commit_id, edges) = result.map_err(|e| JjError::Revset {
+                message: e.to_string(),
+            })?;
+            let commit = store.get_commit(&commit_id)?;
+            let log_commit = log_commit(repo.as_ref(), &commit, options.include_id_prefixes)?;
+            let size = estimated_size(&log_commit, edges.len())
+                + refs.get(&commit_id).estimated_size() as u64;
+            if !budget.charge(size) {
+                truncated = true;
+                break;
+            }
+            commits.insert(commit_id.clone(), (commit, log_commit));
+            selected.push(Ok((commit_id, edges)));
+        }
+        let reversed =
+            reverse_graph(selected.into_iter(), |id| id).map_err(|e| JjError::Revset {
+                message: e.to_string(),
+            })?;
+
+        for (commit_id, edges) in reversed {
+            let (commit, log_commit) = commits.remove(&commit_id).expect("selected commit");
+            entries.push(FfiLogEntry::with_log_commit(
+                repo.as_ref(),
+                &commit,
+                log_commit,
+                &edges,
+                &refs,
+                &mut layouter,
+                options.include_is_empty,
+            )?);
+        }
+    } else {
+        for result in graph_iter.by_ref() {
+            if entries.len() == limit {
+                truncated = true;
+                break;
+            }
+            let (commit_id, edges) = result.map_err(|e| JjError::Revset {
+                message: e.to_string(),
+            })?;
+            let entry = FfiLogEntry::new(
+                repo.as_ref(),
+                &store.get_commit(&commit_id)?,
+                &edges,
+                &refs,
+                &mut layouter,
+                options.include_id_prefixes,
+                options.include_is_empty,
+            )?;
+            if !budget.charge(entry.estimated_size()) {
+                truncated = true;
+                break;
+            }
+            entries.push(entry);
+        }
+    }
 
-    let entries: Vec<FfiLogEntry> = if options.reversed {
-        // Reverse the graph
-        let reversed = reverse_graph(graph_iter, |id| id).map_err(|e| JjError::Revset {
+    let remaining_count = if truncated {
+        let (count, _) = revset.count_estimate().map_err(|e| JjError::Revset {
             message: e.to_string(),
         })?;
-
-        reversed
-            .into_iter()
-            .map(|(commit_id, edges)| {
-                let commit = store.get_commit(&commit_id)?;
-                Ok(FfiLogEntry {
-                    commit: FfiCommit::from(&commit),
-                    edges: edges.iter().map(FfiGraphEdge::from_graph_edge).collect(),
-                })
-            })
-            .collect::<Result<Vec<_>>>()?
+        Some(count.saturating_sub(entries.len()) as u64)
     } else {
-        graph_iter
-            .map(|result| {
-                let (commit_id, edges) = result.map_err(|e| JjError::Revset {
-                    message: e.to_string(),
-                })?;
-                let commit = store.get_commit(&commit_id)?;
-                Ok(FfiLogEntry {
-                    commit: FfiCommit::from(&commit),
-                    edges: edges.iter().map(FfiGraphEdge::from_graph_edge).collect(),
-                })
-            })
-            .collect::<Result<Vec<_>>>()?
+        None
     };
 
-    Ok(FfiLogResult { entries })
+    Ok(FfiLogResult {
+        entries,
+        truncated,
+        remaining_count,
+    })
 }
 
 /// Evaluate log without graph information (flat list)
+///
+/// Stops at `max_bytes` without reporting truncation; use `evaluate_log` to
+/// find out whether the result is partial.
 pub fn evaluate_log_flat(
     repo: &Arc<ReadonlyRepo>,
+    context: &RevsetContext,
     options: &FfiLogOptions,
     user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
 ) -> Result<Vec<FfiCommit>> {
-    let aliases_map = RevsetAliasesMap::new();
-    let extensions = RevsetExtensions::new();
-    let date_context = DatePatternContext::from(Local::now());
-
-    let context = RevsetParseContext {
-        aliases_map: &aliases_map,
-        local_variables: HashMap::new(),
-        user_email,
-        date_pattern_context: date_context,
-        default_ignored_remote: None,
-        use_glob_by_default: false,
-        extensions: &extensions,
-        workspace: None,
-    };
-
-    // Build revset expression
-    let revset_expression = if options.revisions.is_empty() {
-        RevsetExpression::all()
-    } else {
-        let mut expressions = Vec::new();
-        let mut diagnostics = RevsetDiagnostics::new();
-        for rev_str in &options.revisions {
-            let expr = parse(&mut diagnostics, rev_str, &context).map_err(|e| JjError::Revset {
-                message: e.to_string(),
-            })?;
-            expressions.push(expr);
-        }
-        expressions
-            .into_iter()
-            .reduce(|a, b| a.union(&b))
-            .unwrap_or_else(RevsetExpression::none)
-    };
-
-    // Resolve and evaluate
-    let symbol_resolver = SymbolResolver::new(repo.as_ref(), extensions.symbol_resolvers());
-    let resolved_expression = revset_expression
-        .resolve_user_expression(repo.as_ref(), &symbol_resolver)
+    let revset = resolve_log_revisions(repo.as_ref(), context, options, user_email, workspace)?
+        .evaluate(repo.as_ref())
         .map_err(|e| JjError::Revset {
             message: e.to_string(),
         })?;
 
-    let revset = resolved_expression.evaluate(repo.as_ref()).map_err(|e| {
-        JjError::Revset {
-            message: e.to_string(),
-        }
-    })?;
-
     let store = repo.store();
     let limit = if options.limit < 0 {
         usize::MAX
@@ -246,33 +493,132 @@ pub fn evaluate_log_flat(
         options.limit as usize
     };
 
+    let mut budget = MemoryBudget::new(options.max_bytes);
     let iter = revset.iter().take(limit);
 
-    let commits: Vec<FfiCommit> = if options.reversed {
-        let commit_ids: Vec<CommitId> = iter
-            .collect::<std::result::Result<Vec<_>, _>>()
+    let mut commits = Vec::new();
+    for result in iter.commits(store) {
+        let commit = result.map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+        let commit = log_commit(repo.as_ref(), &commit, options.include_id_prefixes)?;
+        if !budget.charge(estimated_size(&commit, 0)) {
+            break;
+        }
+        commits.push(commit);
+    }
+    // Like the graph log, a reversed log lists the newest commits within the
+    // limit and budget
+    if options.reversed {
+        commits.reverse();
+    }
+
+    Ok(commits)
+}
+
+type GraphNodeResult = std::result::Result<GraphNode<CommitId>, RevsetEvaluationError>;
+
+/// Iterator over the log, loading commits one page at a time
+///
+/// Unlike `evaluate_log`, only the commits of the requested page are read,
+/// so large histories can be loaded as the user scrolls. The graph itself
+/// (commit IDs and edges only) is computed on creation.
+#[derive(uniffi::Object)]
+pub struct FfiLogIterator {
+    nodes: Mutex<std::vec::IntoIter<GraphNode<CommitId>>>,
+    layouter: Mutex<GraphLayouter>,
+    refs: CommitRefsIndex,
+    repo: Arc<ReadonlyRepo>,
+    include_id_prefixes: bool,
+    include_is_empty: bool,
+}
+
+impl FfiLogIterator {
+    /// Start iterating over the log
+    ///
+    /// `limit` caps the total number of entries. The page size bounds the
+    /// memory used per call, so `max_bytes` only bounds the graph computed up
+    /// front; creation fails if it doesn't fit.
+    pub(crate) fn new(
+        repo: &Arc<ReadonlyRepo>,
+        context: &RevsetContext,
+        options: &FfiLogOptions,
+        user_email: &str,
+        workspace: Option<&RevsetWorkspace>,
+    ) -> Result<Self> {
+        let revset = resolve_log_revisions(repo.as_ref(), context, options, user_email, workspace)?
+            .evaluate(repo.as_ref())
             .map_err(|e| JjError::Revset {
                 message: e.to_string(),
             })?;
+        let limit = if options.limit < 0 {
+            usize::MAX
+        } else {
+            options.limit as usize
+        };
 
-        commit_ids
-            .into_iter()
-            .rev()
-            .map(|id| {
-                let commit = store.get_commit(&id)?;
-                Ok(FfiCommit::from(&commit))
-            })
-            .collect::<Result<Vec<_>>>()?
-    } else {
-        iter.commits(store)
-            .map(|result| {
-                let commit = result.map_err(|e| JjError::Revset {
+        let graph_iter = TopoGroupedGraphIterator::new(revset.iter_graph(), |id| id).take(limit);
+        let mut budget = MemoryBudget::new(options.max_bytes);
+        let mut nodes = Vec::new();
+        for result in graph_iter {
+            let node = result.map_err(|e| JjError::Revset {
+                message: e.to_string(),
+            })?;
+            if !budget.charge(estimated_node_size(&node)) {
+                return Err(JjError::InvalidArgument {
+                    message: "The log graph exceeds max_bytes; set a limit".to_string(),
+                });
+            }
+            nodes.push(node);
+        }
+        if options.reversed {
+            nodes = reverse_graph(nodes.into_iter().map(GraphNodeResult::Ok), |id| id).map_err(
+                |e| JjError::Revset {
                     message: e.to_string(),
-                })?;
-                Ok(FfiCommit::from(&commit))
-            })
-            .collect::<Result<Vec<_>>>()?
-    };
+                },
+            )?;
+        }
 
-    Ok(commits)
+        Ok(Self {
+            nodes: Mutex::new(nodes.into_iter()),
+            layouter: Mutex::new(GraphLayouter::new()),
+            refs: CommitRefsIndex::new(repo.view()),
+            repo: Arc::clone(repo),
+            include_id_prefixes: options.include_id_prefixes,
+            include_is_empty: options.include_is_empty,
+        })
+    }
+}
+
+#[uniffi::export]
+impl FfiLogIterator {
+    /// Get the next page of at most `page_size` log entries
+    ///
+    /// Returns an empty list once the log is exhausted.
+    pub fn next_page(&self, page_size: u32) -> Result<Vec<FfiLogEntry>> {
+        let mut nodes = self.nodes.lock().map_err(|_| JjError::Internal {
+            message: "Failed to acquire log iterator lock".to_string(),
+        })?;
+        let mut layouter = self.layouter.lock().map_err(|_| JjError::Internal {
+            message: "Failed to acquire log iterator lock".to_string(),
+        })?;
+        let store = self.repo.store();
+        let mut entries = Vec::new();
+        while entries.len() < page_size as usize {
+            let Some((commit_id, edges)) = nodes.next() else {
+                break;
+            };
+            let commit = store.get_commit(&commit_id)?;
+            entries.push(FfiLogEntry::new(
+                self.repo.as_ref(),
+                &commit,
+                &edges,
+                &self.refs,
+                &mut layouter,
+                self.include_id_prefixes,
+                self.include_is_empty,
+            )?);
+        }
+        Ok(entries)
+    }
 }
@@ -0,0 +1,281 @@
+//! Diagnostic logging for FFI
+//!
+//! jj-lib reports what it is doing through `tracing` spans and events. This
+//! module installs a subscriber that forwards them to a sink implemented by
+//! the host app, so issues can be diagnosed from the app's logs.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use tracing::field::{Field, Visit};
+use tracing::level_filters::LevelFilter;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+use crate::error::{JjError, Result};
+
+/// Severity of a log record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiLogLevel {
+    /// Failures
+    Error,
+    /// Unexpected conditions that don't cause failures
+    Warn,
+    /// Progress of operations
+    Info,
+    /// Details useful for debugging
+    Debug,
+    /// Very verbose details, e.g. of each object access
+    Trace,
+}
+
+impl From<&Level> for FfiLogLevel {
+    fn from(level: &Level) -> Self {
+        match *level {
+            Level::ERROR => Self::Error,
+            Level::WARN => Self::Warn,
+            Level::INFO => Self::Info,
+            Level::DEBUG => Self::Debug,
+            Level::TRACE => Self::Trace,
+        }
+    }
+}
+
+impl From<FfiLogLevel> for LevelFilter {
+    fn from(level: FfiLogLevel) -> Self {
+        match level {
+            FfiLogLevel::Error => LevelFilter::ERROR,
+            FfiLogLevel::Warn => LevelFilter::WARN,
+            FfiLogLevel::Info => LevelFilter::INFO,
+            FfiLogLevel::Debug => LevelFilter::DEBUG,
+            FfiLogLevel::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+/// A log event emitted by jj-lib or this library
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiLogRecord {
+    /// Severity of the event
+    pub level: FfiLogLevel,
+    /// Module path of the code that emitted the event, e.g. `jj_lib::git`
+    pub target: String,
+    /// The event's message, followed by its other fields as `key=value`
+    pub message: String,
+    /// Spans the event was emitted in, outermost first, each formatted as
+    /// its name followed by its fields
+    pub spans: Vec<String>,
+}
+
+/// Receives log records, implemented by the host app
+///
+/// Methods are called on the thread that emitted the event. Events emitted
+/// while the sink is running are dropped.
+#[uniffi::export(with_foreign)]
+pub trait FfiLogSink: Send + Sync {
+    /// Called with each event at or below the sink's level
+    fn log(&self, record: FfiLogRecord);
+}
+
+/// Sink and level of the installed subscriber
+struct SinkConfig {
+    sink: Arc<dyn FfiLogSink>,
+    max_level: LevelFilter,
+}
+
+static SINK: Mutex<Option<SinkConfig>> = Mutex::new(None);
+/// Whether the subscriber was installed as the global default
+static INSTALLED: OnceLock<bool> = OnceLock::new();
+
+thread_local! {
+    /// Spans entered on this thread, innermost last
+    static SPAN_STACK: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+    /// Whether the sink is running on this thread
+    static IN_SINK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Formats the fields of a span or event
+#[derive(Default)]
+struct FieldFormatter {
+    message: String,
+    fields: String,
+}
+
+impl FieldFormatter {
+    fn push(&mut self, field: &Field, value: fmt::Arguments<'_>) {
+        if field.name() == "message" {
+            let _ = self.message.write_fmt(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn finish(self) -> String {
+        self.message + &self.fields
+    }
+}
+
+impl Visit for FieldFormatter {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, format_args!("{}", value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push(field, format_args!("{:?}", value));
+    }
+}
+
+struct SpanData {
+    /// Name and fields of the span
+    text: String,
+    ref_count: usize,
+}
+
+/// Subscriber forwarding events to the sink in `SINK`
+#[derive(Default)]
+struct SinkSubscriber {
+    next_id: AtomicU64,
+    spans: Mutex<HashMap<u64, SpanData>>,
+}
+
+impl SinkSubscriber {
+    fn max_level() -> LevelFilter {
+        SINK.lock()
+            .unwrap()
+            .as_ref()
+            .map_or(LevelFilter::OFF, |config| config.max_level)
+    }
+}
+
+impl Subscriber for SinkSubscriber {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        *metadata.level() <= Self::max_level()
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(Self::max_level())
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut formatter = FieldFormatter::default();
+        attributes.record(&mut formatter);
+        let text = format!("{}{}", attributes.metadata().name(), formatter.finish());
+        self.spans
+            .lock()
+            .unwrap()
+            .insert(id, SpanData { text, ref_count: 1 });
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut formatter = FieldFormatter::default();
+        values.record(&mut formatter);
+        if let Some(data) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            data.text.push_str(&formatter.finish());
+        }
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        if IN_SINK.get() {
+            return;
+        }
+        let Some(sink) = SINK
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|config| config.sink.clone())
+        else {
+            return;
+        };
+        let mut formatter = FieldFormatter::default();
+        event.record(&mut formatter);
+        let spans = {
+            let spans = self.spans.lock().unwrap();
+            SPAN_STACK.with_borrow(|stack| {
+                stack
+                    .iter()
+                    .filter_map(|id| spans.get(id).map(|data| data.text.clone()))
+                    .collect()
+            })
+        };
+        let metadata = event.metadata();
+        let record = FfiLogRecord {
+            level: FfiLogLevel::from(metadata.level()),
+            target: metadata.target().to_string(),
+            message: formatter.finish(),
+            spans,
+        };
+        IN_SINK.set(true);
+        sink.log(record);
+        IN_SINK.set(false);
+    }
+
+    fn enter(&self, span: &Id) {
+        SPAN_STACK.with_borrow_mut(|stack| stack.push(span.into_u64()));
+    }
+
+    fn exit(&self, span: &Id) {
+        SPAN_STACK.with_borrow_mut(|stack| {
+            if let Some(index) = stack.iter().rposition(|id| *id == span.into_u64()) {
+                stack.remove(index);
+            }
+        });
+    }
+
+    fn clone_span(&self, span: &Id) -> Id {
+        if let Some(data) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            data.ref_count += 1;
+        }
+        span.clone()
+    }
+
+    fn try_close(&self, span: Id) -> bool {
+        let mut spans = self.spans.lock().unwrap();
+        let Some(data) = spans.get_mut(&span.into_u64()) else {
+            return false;
+        };
+        data.ref_count -= 1;
+        if data.ref_count > 0 {
+            return false;
+        }
+        spans.remove(&span.into_u64());
+        true
+    }
+}
+
+/// Forward jj-lib's logs to `sink`, dropping records more verbose than
+/// `max_level`
+///
+/// The first call installs a global `tracing` subscriber; later calls replace
+/// the sink and level. Fails if another subscriber was installed by the host
+/// process.
+#[uniffi::export]
+pub fn install_log_sink(sink: Arc<dyn FfiLogSink>, max_level: FfiLogLevel) -> Result<()> {
+    let installed = *INSTALLED
+        .get_or_init(|| tracing::subscriber::set_global_default(SinkSubscriber::default()).is_ok());
+    if !installed {
+        return Err(JjError::Internal {
+            message: "Failed to install log sink: a tracing subscriber is already installed"
+                .to_string(),
+        });
+    }
+    *SINK.lock().unwrap() = Some(SinkConfig {
+        sink,
+        max_level: max_level.into(),
+    });
+    tracing::callsite::rebuild_interest_cache();
+    Ok(())
+}
+
+/// Stop forwarding logs to the installed sink
+#[uniffi::export]
+pub fn remove_log_sink() {
+    *SINK.lock().unwrap() = None;
+    tracing::callsite::rebuild_interest_cache();
+}
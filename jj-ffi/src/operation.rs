@@ -0,0 +1,270 @@
+//! Operation log operations for FFI
+
+use std::collections::HashMap;
+use std::slice;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use jj_lib::backend::MillisSinceEpoch;
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::op_heads_store;
+use jj_lib::op_store::{OpStoreError, OperationId};
+use jj_lib::op_walk;
+use jj_lib::operation::Operation;
+use jj_lib::repo::{ReadonlyRepo, Repo as _, RepoLoader, RepoLoaderError};
+
+use crate::error::{JjError, Result};
+use crate::types::FfiTimestamp;
+
+/// Operation metadata tag holding a client-supplied idempotency key
+pub(crate) const IDEMPOTENCY_KEY_TAG: &str = "idempotency-key";
+
+/// An operation in the operation log
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiOperation {
+    /// Operation ID (hex string)
+    pub id: String,
+    /// IDs of the parent operations (more than one for merged operations)
+    pub parent_ids: Vec<String>,
+    /// Description of the operation
+    pub description: String,
+    /// Time the operation's transaction was started
+    pub start_time: FfiTimestamp,
+    /// Time the operation was committed
+    pub end_time: FfiTimestamp,
+    /// Name of the user who made the operation
+    pub username: String,
+    /// Name of the host the operation was made on
+    pub hostname: String,
+    /// Whether the operation only snapshots the working copy
+    pub is_snapshot: bool,
+    /// Tags set on the operation, e.g. by `FfiTransaction::set_tag()`
+    pub tags: HashMap<String, String>,
+}
+
+impl From<&Operation> for FfiOperation {
+    fn from(op: &Operation) -> Self {
+        let metadata = op.metadata();
+        Self {
+            id: op.id().hex(),
+            parent_ids: op.parent_ids().iter().map(|id| id.hex()).collect(),
+            description: metadata.description.clone(),
+            start_time: FfiTimestamp::from(&metadata.time.start),
+            end_time: FfiTimestamp::from(&metadata.time.end),
+            username: metadata.username.clone(),
+            hostname: metadata.hostname.clone(),
+            is_snapshot: metadata.is_snapshot,
+            tags: metadata.tags.clone(),
+        }
+    }
+}
+
+/// Divergent operations merged when a repository was loaded
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiOperationMergeReport {
+    /// IDs of the merged operation heads, oldest first
+    pub merged_operation_ids: Vec<String>,
+    /// ID of the operation merging them
+    pub merge_operation_id: String,
+}
+
+/// Load a repository at its latest operation
+///
+/// Like the `jj` CLI, divergent operation heads, e.g. from the app and the
+/// CLI committing concurrently, are merged into a new operation, which is
+/// described by the returned report.
+pub(crate) fn load_at_head(
+    loader: &RepoLoader,
+) -> Result<(Arc<ReadonlyRepo>, Option<FfiOperationMergeReport>)> {
+    let mut report = None;
+    let op = op_heads_store::resolve_op_heads(
+        loader.op_heads_store().as_ref(),
+        loader.op_store(),
+        |op_heads| -> std::result::Result<Operation, RepoLoaderError> {
+            let merged_operation_ids = op_heads.iter().map(|op| op.id().hex()).collect();
+            let merge_op =
+                loader.merge_operations(op_heads, Some("reconcile divergent operations"))?;
+            report = Some(FfiOperationMergeReport {
+                merged_operation_ids,
+                merge_operation_id: merge_op.id().hex(),
+            });
+            Ok(merge_op)
+        },
+    )?;
+    Ok((loader.load_at(&op)?, report))
+}
+
+/// List the operations leading to the repository's operation, newest first
+///
+/// At most `limit` operations are returned if given.
+pub fn operation_log(repo: &Arc<ReadonlyRepo>, limit: Option<u32>) -> Result<Vec<FfiOperation>> {
+    let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+    op_walk::walk_ancestors(slice::from_ref(repo.operation()))
+        .take(limit)
+        .map(|op| {
+            let op = op.map_err(|e| JjError::Repository {
+                message: e.to_string(),
+            })?;
+            Ok(FfiOperation::from(&op))
+        })
+        .collect()
+}
+
+/// Load a repository at an operation ID (or unique prefix) or expression like "@-"
+pub(crate) fn load_at_operation(loader: &RepoLoader, operation: &str) -> Result<Arc<ReadonlyRepo>> {
+    let op =
+        op_walk::resolve_op_for_load(loader, operation).map_err(|e| JjError::InvalidArgument {
+            message: format!("Invalid operation '{}': {}", operation, e),
+        })?;
+    Ok(loader.load_at(&op)?)
+}
+
+/// Revert the effects of the latest operation, like `jj undo`
+///
+/// Creates a new operation restoring the view of the latest operation's
+/// parent, and returns the repository at that new operation along with the
+/// divergent operations merged to find the latest operation.
+pub fn undo_latest_operation(
+    repo: &Arc<ReadonlyRepo>,
+) -> Result<(Arc<ReadonlyRepo>, Option<FfiOperationMergeReport>)> {
+    let (head_repo, operation_merge) = load_at_head(repo.loader())?;
+    let op = head_repo.operation();
+
+    let mut parents = op.parents();
+    let parent_op = match (parents.next(), parents.next()) {
+        (Some(parent), None) => parent.map_err(|e| JjError::Repository {
+            message: e.to_string(),
+        })?,
+        (None, _) => {
+            return Err(JjError::InvalidArgument {
+                message: "Cannot undo the root operation".to_string(),
+            });
+        }
+        (Some(_), Some(_)) => {
+            return Err(JjError::InvalidArgument {
+                message: "Cannot undo a merge operation".to_string(),
+            });
+        }
+    };
+    let parent_repo = repo.loader().load_at(&parent_op)?;
+
+    let mut tx = head_repo.start_transaction();
+    tx.repo_mut().merge(&head_repo, &parent_repo)?;
+    tx.repo_mut().rebase_descendants()?;
+    let new_repo = tx.commit(format!("undo operation {}", op.id().hex()))?;
+    Ok((new_repo, operation_merge))
+}
+
+/// Restore the repository to its state at an operation, like `jj op restore`
+///
+/// `operation` is an operation ID (or unique prefix) or an expression like
+/// "@-". Creates a new operation on top of the head operation whose view
+/// equals the target operation's view, and returns the repository at that
+/// new operation along with the divergent operations merged to find the head
+/// operation. The working copy is left for the caller to update.
+pub fn restore_to_operation(
+    repo: &Arc<ReadonlyRepo>,
+    operation: &str,
+) -> Result<(Arc<ReadonlyRepo>, Option<FfiOperationMergeReport>)> {
+    let (head_repo, operation_merge) = load_at_head(repo.loader())?;
+    let target_repo = load_at_operation(repo.loader(), operation)?;
+
+    let mut tx = head_repo.start_transaction();
+    tx.repo_mut()
+        .set_view(target_repo.view().store_view().clone());
+    let new_repo = tx.commit(format!(
+        "restore to operation {}",
+        target_repo.op_id().hex()
+    ))?;
+    Ok((new_repo, operation_merge))
+}
+
+/// Abandon the operations that ended before `keep_newer`, like `jj op abandon ..<op>`
+///
+/// The newer operations are reparented onto the root operation, which gives
+/// them new IDs. The head operation is always kept. Returns the new head
+/// operation ID, or `None` if no operation was abandoned.
+pub(crate) fn abandon_operations_before(
+    loader: &RepoLoader,
+    head_op: &Operation,
+    keep_newer: SystemTime,
+) -> Result<Option<OperationId>> {
+    let keep_newer = MillisSinceEpoch(
+        keep_newer
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis() as i64),
+    );
+    let op_store_error = |e: OpStoreError| JjError::Repository {
+        message: e.to_string(),
+    };
+    let mut abandon_head = None;
+    for op in op_walk::walk_ancestors(slice::from_ref(head_op)).skip(1) {
+        let op = op.map_err(op_store_error)?;
+        if op.metadata().time.end.timestamp < keep_newer {
+            abandon_head = Some(op);
+            break;
+        }
+    }
+    let root_op = loader.root_operation();
+    let Some(abandon_head) = abandon_head.filter(|op| op.id() != root_op.id()) else {
+        return Ok(None);
+    };
+
+    let stats = op_walk::reparent_range(
+        loader.op_store().as_ref(),
+        slice::from_ref(&abandon_head),
+        slice::from_ref(head_op),
+        &root_op,
+    )
+    .map_err(op_store_error)?;
+    let new_head_id = stats.new_head_ids[0].clone();
+    pollster::block_on(
+        loader
+            .op_heads_store()
+            .update_op_heads(slice::from_ref(head_op.id()), &new_head_id),
+    )
+    .map_err(|e| JjError::Repository {
+        message: e.to_string(),
+    })?;
+    Ok(Some(new_head_id))
+}
+
+/// Delete unreachable operations and objects older than `keep_newer`, like `jj util gc`
+pub(crate) fn gc(repo: &ReadonlyRepo, keep_newer: SystemTime) -> Result<()> {
+    repo.op_store()
+        .gc(slice::from_ref(repo.op_id()), keep_newer)
+        .map_err(|e| JjError::Repository {
+            message: e.to_string(),
+        })?;
+    repo.store().gc(repo.index(), keep_newer)?;
+    Ok(())
+}
+
+/// Find the latest operation tagged with an idempotency key
+///
+/// Searches all ancestors of the head operation, so operations committed
+/// through other repository handles are found as well. Returns the operation
+/// ID, or `None` if no operation carries the key.
+pub fn find_operation_by_idempotency_key(
+    repo: &Arc<ReadonlyRepo>,
+    key: &str,
+) -> Result<Option<String>> {
+    let (head_repo, operation_merge) = load_at_head(repo.loader())?;
+    if let Some(report) = operation_merge {
+        tracing::info!(
+            "Merged divergent operations {} into {}",
+            report.merged_operation_ids.join(", "),
+            report.merge_operation_id
+        );
+    }
+    for op in op_walk::walk_ancestors(slice::from_ref(head_repo.operation())) {
+        let op = op.map_err(|e| JjError::Repository {
+            message: e.to_string(),
+        })?;
+        let tag = op.metadata().tags.get(IDEMPOTENCY_KEY_TAG);
+        if tag.is_some_and(|tag| tag == key) {
+            return Ok(Some(op.id().hex()));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,836 @@
+//! Patch import and export for FFI
+//!
+//! This module converts commits to and from `git format-patch` style text,
+//! allowing changes to be exchanged without a shared remote.
+
+use std::borrow::Cow;
+use std::io::Write as _;
+use std::sync::Arc;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bstr::ByteSlice as _;
+use chrono::{DateTime, FixedOffset};
+use futures::StreamExt as _;
+use jj_lib::backend::{
+    ChangeId, CommitId, CopyId, MillisSinceEpoch, Signature, Timestamp, TreeValue,
+};
+use jj_lib::commit::Commit;
+use jj_lib::conflicts::{ConflictMarkerStyle, ConflictMaterializeOptions, materialize_tree_value};
+use jj_lib::diff_presentation::LineCompareMode;
+use jj_lib::diff_presentation::unified::{
+    DiffLineType, GitDiffPart, git_diff_part, unified_diff_hunks,
+};
+use jj_lib::matchers::EverythingMatcher;
+use jj_lib::merge::{Diff, Merge, MergedTreeValue};
+use jj_lib::merged_tree::MergedTreeBuilder;
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::{MutableRepo, ReadonlyRepo, Repo};
+use jj_lib::repo_path::RepoPath;
+use jj_lib::store::Store;
+
+use crate::error::{JjError, Result};
+use crate::files::{parse_repo_path, read_file_content};
+use crate::types::FfiCommitId;
+
+/// Name of the trailer carrying the jj change ID
+const CHANGE_ID_TRAILER: &str = "Change-Id";
+
+/// Number of context lines around each hunk
+const CONTEXT_LINES: usize = 3;
+
+/// Date used in the mbox "From" line, as emitted by `git format-patch`
+const MBOX_FROM_DATE: &str = "Mon Sep 17 00:00:00 2001";
+
+/// Maximum length of an RFC 2047 encoded word, including its delimiters
+const ENCODED_WORD_LEN: usize = 75;
+
+fn patch_error(message: impl Into<String>) -> JjError {
+    JjError::InvalidArgument {
+        message: format!("Invalid patch: {}", message.into()),
+    }
+}
+
+/// Format a timestamp as an RFC 2822 date
+fn format_date(timestamp: &Timestamp) -> String {
+    let offset = FixedOffset::east_opt(timestamp.tz_offset * 60)
+        .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+    DateTime::from_timestamp_millis(timestamp.timestamp.0)
+        .unwrap_or_default()
+        .with_timezone(&offset)
+        .to_rfc2822()
+}
+
+/// Parse an RFC 2822 date into a timestamp
+fn parse_date(date: &str) -> Result<Timestamp> {
+    let datetime = DateTime::parse_from_rfc2822(date.trim())
+        .map_err(|e| patch_error(format!("bad date '{}': {}", date, e)))?;
+    Ok(Timestamp {
+        timestamp: MillisSinceEpoch(datetime.timestamp_millis()),
+        tz_offset: datetime.offset().local_minus_utc() / 60,
+    })
+}
+
+/// Encode a header value as RFC 2047 encoded words unless it is printable
+/// ASCII, like `git format-patch` does
+///
+/// Long values are split into several words on folded lines.
+fn encode_header(value: &str) -> Cow<'_, str> {
+    if value.bytes().all(|b| (b' '..=b'~').contains(&b)) && !value.contains("=?") {
+        return Cow::Borrowed(value);
+    }
+    let max_text_len = ENCODED_WORD_LEN - "=?UTF-8?q??=".len();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for c in value.chars() {
+        let encoded = if c == ' ' {
+            "_".to_string()
+        } else if c.is_ascii_alphanumeric() || "!*+-/".contains(c) {
+            c.to_string()
+        } else {
+            c.encode_utf8(&mut [0; 4])
+                .bytes()
+                .map(|b| format!("={:02X}", b))
+                .collect()
+        };
+        if !word.is_empty() && word.len() + encoded.len() > max_text_len {
+            words.push(std::mem::take(&mut word));
+        }
+        word.push_str(&encoded);
+    }
+    words.push(word);
+    Cow::Owned(
+        words
+            .iter()
+            .map(|word| format!("=?UTF-8?q?{}?=", word))
+            .collect::<Vec<_>>()
+            .join("\n "),
+    )
+}
+
+/// Decode a single RFC 2047 encoded word at the start of `text`
+///
+/// Returns the decoded text and the length of the encoded word, or `None`
+/// if `text` doesn't start with an encoded word in a known charset.
+fn decode_encoded_word(text: &str) -> Option<(String, usize)> {
+    let rest = text.strip_prefix("=?")?;
+    let (charset, rest) = rest.split_once('?')?;
+    let (encoding, rest) = rest.split_once('?')?;
+    let (encoded, tail) = rest.split_once("?=")?;
+    if encoded.contains(char::is_whitespace) {
+        return None;
+    }
+    let bytes = if encoding.eq_ignore_ascii_case("q") {
+        let mut bytes = Vec::new();
+        let mut chars = encoded.bytes();
+        while let Some(b) = chars.next() {
+            match b {
+                b'_' => bytes.push(b' '),
+                b'=' => {
+                    let hex = [chars.next()?, chars.next()?];
+                    bytes.push(hex::decode(hex).ok()?[0]);
+                }
+                b => bytes.push(b),
+            }
+        }
+        bytes
+    } else if encoding.eq_ignore_ascii_case("b") {
+        BASE64.decode(encoded).ok()?
+    } else {
+        return None;
+    };
+    // Ignore an RFC 2231 language suffix like "UTF-8*en"
+    let charset = charset.split('*').next().unwrap_or_default();
+    let decoded =
+        if charset.eq_ignore_ascii_case("utf-8") || charset.eq_ignore_ascii_case("us-ascii") {
+            String::from_utf8(bytes).ok()?
+        } else if charset.eq_ignore_ascii_case("iso-8859-1") {
+            bytes.iter().map(|&b| char::from(b)).collect()
+        } else {
+            return None;
+        };
+    Some((decoded, text.len() - tail.len()))
+}
+
+/// Decode the RFC 2047 encoded words in a header value
+///
+/// Whitespace between adjacent encoded words is dropped, as the words were
+/// only split to keep header lines short.
+fn decode_header(value: &str) -> Cow<'_, str> {
+    if !value.contains("=?") {
+        return Cow::Borrowed(value);
+    }
+    let mut decoded = String::new();
+    let mut rest = value;
+    let mut after_word = false;
+    while let Some(start) = rest.find("=?") {
+        let (before, candidate) = rest.split_at(start);
+        match decode_encoded_word(candidate) {
+            Some((word, len)) => {
+                if !(after_word && before.trim().is_empty()) {
+                    decoded.push_str(before);
+                }
+                decoded.push_str(&word);
+                rest = &candidate[len..];
+                after_word = true;
+            }
+            None => {
+                decoded.push_str(before);
+                decoded.push_str("=?");
+                rest = &candidate[2..];
+                after_word = false;
+            }
+        }
+    }
+    decoded.push_str(rest);
+    Cow::Owned(decoded)
+}
+
+/// Format a line range in unified diff hunk header form
+fn format_range(range: &std::ops::Range<usize>) -> String {
+    match range.len() {
+        0 => format!("{},0", range.start),
+        1 => format!("{}", range.start + 1),
+        len => format!("{},{}", range.start + 1, len),
+    }
+}
+
+/// Read a tree value as a Git diff part
+fn diff_part(store: &Store, path: &RepoPath, value: MergedTreeValue) -> Result<GitDiffPart> {
+    let materialized = pollster::block_on(materialize_tree_value(store, path, value))?;
+    let options = ConflictMaterializeOptions {
+        marker_style: ConflictMarkerStyle::Git,
+        marker_len: None,
+        merge: store.merge_options().clone(),
+    };
+    git_diff_part(path, materialized, &options).map_err(|e| JjError::Backend {
+        message: e.to_string(),
+    })
+}
+
+/// Write the Git-style diff of a single file
+///
+/// Fails for binary content, which `import_patch()` couldn't apply.
+fn write_file_diff(
+    out: &mut Vec<u8>,
+    path: &RepoPath,
+    left: GitDiffPart,
+    right: GitDiffPart,
+) -> Result<()> {
+    let path = path.as_internal_file_string();
+    let _ = writeln!(out, "diff --git a/{path} b/{path}");
+    match (left.mode, right.mode) {
+        (None, Some(mode)) => {
+            let _ = writeln!(out, "new file mode {mode}");
+            let _ = writeln!(out, "index {}..{}", left.hash, right.hash);
+        }
+        (Some(mode), None) => {
+            let _ = writeln!(out, "deleted file mode {mode}");
+            let _ = writeln!(out, "index {}..{}", left.hash, right.hash);
+        }
+        (Some(old_mode), Some(new_mode)) if old_mode != new_mode => {
+            let _ = writeln!(out, "old mode {old_mode}");
+            let _ = writeln!(out, "new mode {new_mode}");
+            let _ = writeln!(out, "index {}..{}", left.hash, right.hash);
+        }
+        (Some(mode), Some(_)) => {
+            let _ = writeln!(out, "index {}..{} {mode}", left.hash, right.hash);
+        }
+        (None, None) => {}
+    }
+
+    if left.content.contents == right.content.contents {
+        return Ok(());
+    }
+    let left_name = left
+        .mode
+        .map_or("/dev/null".to_string(), |_| format!("a/{path}"));
+    let right_name = right
+        .mode
+        .map_or("/dev/null".to_string(), |_| format!("b/{path}"));
+    if left.content.is_binary || right.content.is_binary {
+        return Err(JjError::InvalidArgument {
+            message: format!("Cannot export binary changes to '{}' as a patch", path),
+        });
+    }
+
+    let _ = writeln!(out, "--- {left_name}");
+    let _ = writeln!(out, "+++ {right_name}");
+    let hunks = unified_diff_hunks(
+        Diff::new(
+            left.content.contents.as_bstr(),
+            right.content.contents.as_bstr(),
+        ),
+        CONTEXT_LINES,
+        LineCompareMode::Exact,
+    );
+    for hunk in hunks {
+        let _ = writeln!(
+            out,
+            "@@ -{} +{} @@",
+            format_range(&hunk.left_line_range),
+            format_range(&hunk.right_line_range)
+        );
+        for (line_type, tokens) in &hunk.lines {
+            let prefix = match line_type {
+                DiffLineType::Context => b' ',
+                DiffLineType::Removed => b'-',
+                DiffLineType::Added => b'+',
+            };
+            let line: Vec<u8> = tokens
+                .iter()
+                .flat_map(|(_, token)| token.iter())
+                .copied()
+                .collect();
+            out.push(prefix);
+            out.extend_from_slice(&line);
+            if !line.ends_with(b"\n") {
+                out.extend_from_slice(b"\n\\ No newline at end of file\n");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Format a single commit as a patch
+fn format_commit_patch(
+    repo: &Arc<ReadonlyRepo>,
+    commit: &Commit,
+    index: usize,
+    total: usize,
+) -> Result<Vec<u8>> {
+    let store = repo.store();
+    let mut out = Vec::new();
+
+    let author = commit.author();
+    let mut lines = commit.description().trim_end().lines();
+    let subject = lines.next().unwrap_or_default();
+    let body = lines.collect::<Vec<_>>().join("\n");
+    let subject_prefix = if total > 1 {
+        format!("[PATCH {}/{}]", index + 1, total)
+    } else {
+        "[PATCH]".to_string()
+    };
+
+    let _ = writeln!(out, "From {} {}", commit.id().hex(), MBOX_FROM_DATE);
+    let _ = writeln!(
+        out,
+        "From: {} <{}>",
+        encode_header(&author.name),
+        author.email
+    );
+    let _ = writeln!(out, "Date: {}", format_date(&author.timestamp));
+    let _ = writeln!(
+        out,
+        "Subject: {} {}",
+        subject_prefix,
+        encode_header(subject)
+    );
+    out.push(b'\n');
+    let body = body.trim();
+    if !body.is_empty() {
+        let _ = writeln!(out, "{}\n", body);
+    }
+    let _ = writeln!(
+        out,
+        "{}: {}",
+        CHANGE_ID_TRAILER,
+        commit.change_id().reverse_hex()
+    );
+    out.extend_from_slice(b"---\n");
+
+    let parent_tree = commit.parent_tree(repo.as_ref())?;
+    let entries = pollster::block_on(
+        parent_tree
+            .diff_stream(&commit.tree(), &EverythingMatcher)
+            .collect::<Vec<_>>(),
+    );
+    for entry in entries {
+        let values = entry.values?;
+        let left = diff_part(store, &entry.path, values.before)?;
+        let right = diff_part(store, &entry.path, values.after)?;
+        write_file_diff(&mut out, &entry.path, left, right)?;
+    }
+
+    let _ = writeln!(out, "-- \njj-ffi {}\n", env!("CARGO_PKG_VERSION"));
+    Ok(out)
+}
+
+/// Export commits as `git format-patch` style text
+///
+/// File contents are copied verbatim, so the patch is only valid UTF-8 if
+/// the changed files are. Author names and subjects that aren't ASCII are
+/// RFC 2047 encoded. Fails if a commit changes a binary file.
+pub fn export_patch(repo: &Arc<ReadonlyRepo>, commit_ids: &[FfiCommitId]) -> Result<Vec<u8>> {
+    let commits = commit_ids
+        .iter()
+        .map(|commit_id| {
+            let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+                message: format!("Invalid commit ID: {}", e),
+            })?;
+            Ok(repo.store().get_commit(&id)?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut out = Vec::new();
+    for (index, commit) in commits.iter().enumerate() {
+        out.extend(format_commit_patch(repo, commit, index, commits.len())?);
+    }
+    Ok(out)
+}
+
+/// A hunk of a parsed file patch
+struct PatchHunk {
+    old_start: usize,
+    old_lines: Vec<Vec<u8>>,
+    new_lines: Vec<Vec<u8>>,
+}
+
+/// Where the content of a patched file comes from, if not from its own path
+struct FileSource {
+    path: String,
+    /// Whether the source is removed (`rename from`) or kept (`copy from`)
+    is_rename: bool,
+}
+
+/// A parsed patch for a single file
+struct FilePatch {
+    path: String,
+    source: Option<FileSource>,
+    deleted: bool,
+    new_mode: Option<String>,
+    hunks: Vec<PatchHunk>,
+}
+
+/// A parsed patch for a single commit
+struct CommitPatch {
+    author: Option<Signature>,
+    description: String,
+    change_id: Option<ChangeId>,
+    files: Vec<FilePatch>,
+}
+
+/// Strip a `[PATCH ...]` prefix from a subject line
+fn strip_subject_prefix(subject: &str) -> &str {
+    let subject = subject.trim();
+    match subject
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+    {
+        Some((tag, rest)) if tag.starts_with("PATCH") => rest.trim_start(),
+        _ => subject,
+    }
+}
+
+/// Parse a `Name <email>` header value
+fn parse_person(value: &str) -> (String, String) {
+    match value.rsplit_once('<') {
+        Some((name, email)) => (
+            decode_header(name.trim().trim_matches('"')).into_owned(),
+            email.trim_end().trim_end_matches('>').to_string(),
+        ),
+        None => (String::new(), value.trim().to_string()),
+    }
+}
+
+/// Parse a hunk header like `@@ -1,3 +1,4 @@`
+fn parse_hunk_header(line: &str) -> Result<usize> {
+    let old_range = line
+        .strip_prefix("@@ -")
+        .and_then(|rest| rest.split_whitespace().next())
+        .ok_or_else(|| patch_error(format!("bad hunk header '{}'", line)))?;
+    let start = old_range.split(',').next().unwrap_or_default();
+    start
+        .parse()
+        .map_err(|_| patch_error(format!("bad hunk header '{}'", line)))
+}
+
+/// Strip the `a/` or `b/` prefix of a path in a diff header
+fn strip_diff_path(path: &str) -> Option<String> {
+    let path = path.trim_end();
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(
+        path.strip_prefix("a/")
+            .or_else(|| path.strip_prefix("b/"))
+            .unwrap_or(path)
+            .to_string(),
+    )
+}
+
+/// Decode a header line of a patch, without its line ending
+fn header_line(line: &[u8]) -> Cow<'_, str> {
+    line.trim_end_with(|c| c == '\r').to_str_lossy()
+}
+
+/// Parse the diff section of a patch into file patches
+///
+/// Lines are kept as bytes, without their `\n` terminator, so file contents
+/// that aren't UTF-8 or use CRLF line endings are applied unchanged.
+fn parse_file_patches(lines: &[&[u8]]) -> Result<Vec<FilePatch>> {
+    let is_end = |line: &[u8]| header_line(line) == "-- ";
+    let mut files: Vec<FilePatch> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = header_line(lines[i]);
+        if line == "-- " {
+            break;
+        }
+        let Some(header) = line.strip_prefix("diff --git ") else {
+            i += 1;
+            continue;
+        };
+        let mut file = FilePatch {
+            path: header
+                .split_once(" b/")
+                .map(|(_, path)| path.to_string())
+                .ok_or_else(|| patch_error(format!("bad diff header '{}'", line)))?,
+            source: None,
+            deleted: false,
+            new_mode: None,
+            hunks: Vec::new(),
+        };
+        i += 1;
+
+        while i < lines.len() && !lines[i].starts_with(b"diff --git ") && !is_end(lines[i]) {
+            let line = header_line(lines[i]);
+            if let Some(mode) = line.strip_prefix("new file mode ") {
+                file.new_mode = Some(mode.trim().to_string());
+            } else if line.starts_with("deleted file mode ") {
+                file.deleted = true;
+            } else if let Some(mode) = line.strip_prefix("new mode ") {
+                file.new_mode = Some(mode.trim().to_string());
+            } else if let Some(path) = line.strip_prefix("rename from ") {
+                file.source = Some(FileSource {
+                    path: path.to_string(),
+                    is_rename: true,
+                });
+            } else if let Some(path) = line.strip_prefix("copy from ") {
+                file.source = Some(FileSource {
+                    path: path.to_string(),
+                    is_rename: false,
+                });
+            } else if let Some(path) = line
+                .strip_prefix("rename to ")
+                .or_else(|| line.strip_prefix("copy to "))
+            {
+                file.path = path.to_string();
+            } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+                return Err(patch_error(format!(
+                    "binary changes to '{}' are not supported",
+                    file.path
+                )));
+            } else if let Some(path) = line.strip_prefix("+++ ") {
+                if let Some(path) = strip_diff_path(path) {
+                    file.path = path;
+                }
+            } else if line.starts_with("@@ ") {
+                let mut hunk = PatchHunk {
+                    old_start: parse_hunk_header(&line)?,
+                    old_lines: Vec::new(),
+                    new_lines: Vec::new(),
+                };
+                i += 1;
+                // Which side(s) the previous line belonged to, for "\ No newline"
+                let mut last = (false, false);
+                while i < lines.len() {
+                    let line = lines[i];
+                    let content = || [&line[1..], b"\n"].concat();
+                    if line.starts_with(b" ") || line.is_empty() {
+                        let content = if line.is_empty() {
+                            b"\n".to_vec()
+                        } else {
+                            content()
+                        };
+                        hunk.old_lines.push(content.clone());
+                        hunk.new_lines.push(content);
+                        last = (true, true);
+                    } else if line.starts_with(b"-") && !is_end(line) {
+                        hunk.old_lines.push(content());
+                        last = (true, false);
+                    } else if line.starts_with(b"+") {
+                        hunk.new_lines.push(content());
+                        last = (false, true);
+                    } else if line.starts_with(b"\\") {
+                        if last.0 {
+                            hunk.old_lines.last_mut().map(|l| l.pop());
+                        }
+                        if last.1 {
+                            hunk.new_lines.last_mut().map(|l| l.pop());
+                        }
+                    } else {
+                        break;
+                    }
+                    i += 1;
+                }
+                file.hunks.push(hunk);
+                continue;
+            }
+            i += 1;
+        }
+        files.push(file);
+    }
+    Ok(files)
+}
+
+/// Parse a single mbox-style patch into its metadata and file patches
+fn parse_commit_patch(text: &[u8]) -> Result<CommitPatch> {
+    let lines: Vec<&[u8]> = text
+        .lines_with_terminator()
+        .map(|line| line.strip_suffix(b"\n").unwrap_or(line))
+        .collect();
+    let mut i = 0;
+    if lines.first().is_some_and(|line| line.starts_with(b"From "))
+        && !lines[0].starts_with(b"From: ")
+    {
+        i += 1;
+    }
+
+    // Headers
+    let mut author_name_email = None;
+    let mut date = None;
+    let mut subject = String::new();
+    let mut last_header = "";
+    while i < lines.len() && !header_line(lines[i]).is_empty() {
+        let line = header_line(lines[i]);
+        if line.starts_with([' ', '\t']) {
+            if last_header == "subject" {
+                subject.push(' ');
+                subject.push_str(line.trim());
+            }
+        } else if let Some(value) = line.strip_prefix("From: ") {
+            author_name_email = Some(parse_person(value));
+            last_header = "from";
+        } else if let Some(value) = line.strip_prefix("Date: ") {
+            date = Some(parse_date(value)?);
+            last_header = "date";
+        } else if let Some(value) = line.strip_prefix("Subject: ") {
+            subject = value.to_string();
+            last_header = "subject";
+        } else if line.starts_with("diff --git ") {
+            break;
+        } else {
+            last_header = "";
+        }
+        i += 1;
+    }
+
+    // Message body up to the "---" separator or the first diff
+    let mut body = Vec::new();
+    while i < lines.len() {
+        let line = header_line(lines[i]);
+        if line == "---" || line.starts_with("diff --git ") {
+            break;
+        }
+        body.push(line);
+        i += 1;
+    }
+    let mut change_id = None;
+    let change_id_prefix = format!("{}: ", CHANGE_ID_TRAILER);
+    while let Some(line) = body.last() {
+        if let Some(value) = line.strip_prefix(&change_id_prefix) {
+            change_id = change_id.or_else(|| ChangeId::try_from_reverse_hex(value.trim()));
+        } else if !line.trim().is_empty() {
+            break;
+        }
+        body.pop();
+    }
+    let body = body.join("\n");
+
+    let subject = decode_header(&subject);
+    let subject = strip_subject_prefix(&subject);
+    let mut description = subject.to_string();
+    if !body.trim().is_empty() {
+        description.push_str("\n\n");
+        description.push_str(body.trim());
+    }
+    if !description.is_empty() {
+        description.push('\n');
+    }
+
+    let author = match (author_name_email, date) {
+        (Some((name, email)), date) => Some(Signature {
+            name,
+            email,
+            timestamp: date.unwrap_or_else(Timestamp::now),
+        }),
+        (None, _) => None,
+    };
+
+    Ok(CommitPatch {
+        author,
+        description,
+        change_id,
+        files: parse_file_patches(&lines[i..])?,
+    })
+}
+
+/// Split mbox text into individual patches
+fn split_patches(text: &[u8]) -> Vec<&[u8]> {
+    let mut starts: Vec<usize> = Vec::new();
+    let mut offset = 0;
+    for line in text.lines_with_terminator() {
+        if line.starts_with(b"From ") && line.trim_end().ends_with(MBOX_FROM_DATE.as_bytes()) {
+            starts.push(offset);
+        }
+        offset += line.len();
+    }
+    if starts.is_empty() {
+        return vec![text];
+    }
+    starts.push(text.len());
+    starts.windows(2).map(|w| &text[w[0]..w[1]]).collect()
+}
+
+/// Apply the hunks of a file patch to the original content
+fn apply_hunks(path: &str, original: &[u8], hunks: &[PatchHunk]) -> Result<Vec<u8>> {
+    let lines: Vec<&[u8]> = original.split_inclusive(|b| *b == b'\n').collect();
+    let mut result: Vec<u8> = Vec::new();
+    let mut pos = 0;
+    for hunk in hunks {
+        // Hunk start is 1-based, or 0 when inserting into an empty file
+        let expected = hunk.old_start.saturating_sub(1).max(pos);
+        let matches_at = |start: usize| {
+            start + hunk.old_lines.len() <= lines.len()
+                && hunk
+                    .old_lines
+                    .iter()
+                    .zip(&lines[start..])
+                    .all(|(a, b)| a.as_slice() == *b)
+        };
+        let start = (0..=lines.len())
+            .flat_map(|delta| [expected.checked_add(delta), expected.checked_sub(delta)])
+            .flatten()
+            .filter(|start| *start >= pos && *start <= lines.len())
+            .find(|start| matches_at(*start))
+            .ok_or_else(|| patch_error(format!("hunk does not apply to '{}'", path)))?;
+        for line in &lines[pos..start] {
+            result.extend_from_slice(line);
+        }
+        for line in &hunk.new_lines {
+            result.extend_from_slice(line);
+        }
+        pos = start + hunk.old_lines.len();
+    }
+    for line in &lines[pos..] {
+        result.extend_from_slice(line);
+    }
+    Ok(result)
+}
+
+/// Read the content of a path in a tree for patching
+fn read_for_patch(store: &Store, path: &RepoPath, value: &MergedTreeValue) -> Result<Vec<u8>> {
+    match value.as_resolved() {
+        Some(None) => Ok(Vec::new()),
+        Some(Some(TreeValue::File { id, .. })) => read_file_content(store, path, id),
+        Some(Some(TreeValue::Symlink(id))) => {
+            Ok(pollster::block_on(store.read_symlink(path, id))?.into_bytes())
+        }
+        _ => Err(patch_error(format!(
+            "cannot apply changes to '{}': not a resolved file",
+            path.as_internal_file_string()
+        ))),
+    }
+}
+
+/// Create commits from `git format-patch` style text on top of a destination
+///
+/// Each patch in the text becomes one commit, stacked in order. Commits keep
+/// the change ID of their `Change-Id` trailer, if any.
+pub(crate) fn import_patch(
+    mut_repo: &mut MutableRepo,
+    text: &[u8],
+    destination: &FfiCommitId,
+) -> Result<Vec<Commit>> {
+    let destination_id = CommitId::try_from(destination).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let store = mut_repo.store().clone();
+    let mut parent = store.get_commit(&destination_id)?;
+
+    let patches = split_patches(text)
+        .into_iter()
+        .map(parse_commit_patch)
+        .collect::<Result<Vec<_>>>()?;
+    if patches.iter().all(|patch| patch.files.is_empty()) {
+        return Err(patch_error("no file changes found"));
+    }
+
+    let mut commits = Vec::new();
+    for patch in patches {
+        let parent_tree = parent.tree();
+        let mut tree_builder = MergedTreeBuilder::new(parent_tree.clone());
+        for file in &patch.files {
+            let path = parse_repo_path(&file.path)?;
+            if file.deleted {
+                tree_builder.set_or_remove(path, Merge::absent());
+                continue;
+            }
+
+            // Renamed and copied files are patched starting from their source
+            let source_path = match &file.source {
+                Some(source) => {
+                    let source_path = parse_repo_path(&source.path)?;
+                    if source.is_rename {
+                        tree_builder.set_or_remove(source_path.clone(), Merge::absent());
+                    }
+                    source_path
+                }
+                None => path.clone(),
+            };
+            let old_value = parent_tree.path_value(&source_path)?;
+            let original = read_for_patch(&store, &source_path, &old_value)?;
+            let content = apply_hunks(&file.path, &original, &file.hunks)?;
+
+            let old_executable = matches!(
+                old_value.as_resolved(),
+                Some(Some(TreeValue::File {
+                    executable: true,
+                    ..
+                }))
+            );
+            let old_symlink = matches!(old_value.as_resolved(), Some(Some(TreeValue::Symlink(_))));
+            let new_value = match file.new_mode.as_deref() {
+                Some("120000") => None,
+                Some("100755") => Some(true),
+                Some(_) => Some(false),
+                None if old_symlink => None,
+                None => Some(old_executable),
+            };
+            let value = match new_value {
+                Some(executable) => {
+                    let id = pollster::block_on(store.write_file(&path, &mut content.as_slice()))?;
+                    TreeValue::File {
+                        id,
+                        executable,
+                        copy_id: CopyId::placeholder(),
+                    }
+                }
+                None => {
+                    let target = String::from_utf8(content).map_err(|_| {
+                        patch_error(format!("symlink target of '{}' is not UTF-8", file.path))
+                    })?;
+                    TreeValue::Symlink(pollster::block_on(store.write_symlink(&path, &target))?)
+                }
+            };
+            tree_builder.set_or_remove(path, Merge::normal(value));
+        }
+        let tree = tree_builder.write_tree()?;
+
+        let mut builder = mut_repo
+            .new_commit(vec![parent.id().clone()], tree)
+            .set_description(&patch.description);
+        if let Some(author) = patch.author {
+            builder = builder.set_author(author);
+        }
+        if let Some(change_id) = patch.change_id {
+            builder = builder.set_change_id(change_id);
+        }
+        let commit = builder.write()?;
+        commits.push(commit.clone());
+        parent = commit;
+    }
+
+    Ok(commits)
+}
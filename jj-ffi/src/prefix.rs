@@ -0,0 +1,59 @@
+//! Shortest unique ID prefixes for FFI
+//!
+//! This module looks up how many leading digits of a commit or change ID are
+//! needed to identify it among all IDs in the index, like the abbreviated IDs
+//! shown by `jj log`.
+
+use jj_lib::backend::{ChangeId, CommitId};
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::Repo;
+
+use crate::error::{JjError, Result};
+use crate::types::{FfiChangeId, FfiCommitId};
+
+/// Length of the shortest unique prefix of a commit ID
+pub(crate) fn commit_id_prefix_len(repo: &dyn Repo, id: &CommitId) -> Result<usize> {
+    repo.index()
+        .shortest_unique_commit_id_prefix_len(id)
+        .map_err(|e| JjError::Internal {
+            message: format!("Index error: {}", e),
+        })
+}
+
+/// Length of the shortest unique prefix of a change ID
+pub(crate) fn change_id_prefix_len(repo: &dyn Repo, id: &ChangeId) -> Result<usize> {
+    repo.shortest_unique_change_id_prefix_len(id)
+        .map_err(|e| JjError::Internal {
+            message: format!("Index error: {}", e),
+        })
+}
+
+/// Truncate a hex ID to `prefix_len` digits, but no fewer than `min_len`
+fn truncate_hex(mut hex: String, prefix_len: usize, min_len: u32) -> String {
+    hex.truncate(prefix_len.max(min_len as usize));
+    hex
+}
+
+/// Get the shortest unique prefix of a commit ID, padded to `min_len` digits
+pub fn shortest_commit_id_prefix(
+    repo: &dyn Repo,
+    commit_id: &FfiCommitId,
+    min_len: u32,
+) -> Result<String> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let prefix_len = commit_id_prefix_len(repo, &id)?;
+    Ok(truncate_hex(id.hex(), prefix_len, min_len))
+}
+
+/// Get the shortest unique prefix of a change ID, padded to `min_len` digits
+pub fn shortest_change_id_prefix(
+    repo: &dyn Repo,
+    change_id: &FfiChangeId,
+    min_len: u32,
+) -> Result<String> {
+    let id = ChangeId::try_from(change_id)?;
+    let prefix_len = change_id_prefix_len(repo, &id)?;
+    Ok(truncate_hex(id.reverse_hex(), prefix_len, min_len))
+}
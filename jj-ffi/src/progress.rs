@@ -0,0 +1,92 @@
+//! Git network progress reporting for FFI
+//!
+//! This module forwards the progress that `git` reports while talking to a
+//! remote to a callback implemented by the host app.
+
+use std::sync::Arc;
+
+use jj_lib::git::{Progress, RemoteCallbacks};
+
+use crate::cancel::{FfiCancellationToken, abort_if_cancelled, catch_cancelled};
+use crate::error::Result;
+
+/// A progress update of a Git network operation
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiGitProgressUpdate {
+    /// Overall progress from 0.0 to 1.0, combining counting, compressing,
+    /// receiving and resolving objects
+    pub overall: f32,
+    /// Number of bytes transferred so far, if reported by the transport
+    pub bytes_downloaded: Option<u64>,
+}
+
+impl From<&Progress> for FfiGitProgressUpdate {
+    fn from(progress: &Progress) -> Self {
+        Self {
+            overall: progress.overall,
+            bytes_downloaded: progress.bytes_downloaded,
+        }
+    }
+}
+
+/// Receives progress of Git network operations, implemented by the host app
+///
+/// Methods are called on the thread running the operation.
+#[uniffi::export(with_foreign)]
+pub trait FfiGitProgress: Send + Sync {
+    /// Called whenever the transfer progress changes
+    fn on_progress(&self, update: FfiGitProgressUpdate);
+
+    /// Called with each line of messages sent by the remote (the `remote: `
+    /// lines printed by `git`)
+    fn on_sideband_message(&self, message: String);
+}
+
+/// Call `f` with remote callbacks that report to `progress` and stop the
+/// transfer when `cancellation` is cancelled
+///
+/// `git` only reports progress while transferring, so a transfer is
+/// cancelled at its next progress update.
+pub(crate) fn with_progress_callbacks<T>(
+    progress: Option<&Arc<dyn FfiGitProgress>>,
+    cancellation: Option<&FfiCancellationToken>,
+    f: impl FnOnce(RemoteCallbacks<'_>) -> T,
+) -> Result<T> {
+    if progress.is_none() && cancellation.is_none() {
+        return Ok(f(RemoteCallbacks::default()));
+    }
+
+    // Sideband data arrives in chunks, each line followed by its terminator
+    let mut line = Vec::new();
+    let mut on_progress = |update: &Progress| {
+        abort_if_cancelled(cancellation);
+        if let Some(progress) = progress {
+            progress.on_progress(update.into());
+        }
+    };
+    let mut on_sideband = |data: &[u8]| {
+        abort_if_cancelled(cancellation);
+        if let Some(progress) = progress {
+            match data {
+                [b'\r' | b'\n'] => flush_sideband_line(progress.as_ref(), &mut line),
+                _ => line.extend_from_slice(data),
+            }
+        }
+    };
+    let mut callbacks = RemoteCallbacks::default();
+    callbacks.progress = Some(&mut on_progress);
+    callbacks.sideband_progress = Some(&mut on_sideband);
+    let result = catch_cancelled(|| f(callbacks))?;
+    if let Some(progress) = progress {
+        flush_sideband_line(progress.as_ref(), &mut line);
+    }
+    Ok(result)
+}
+
+fn flush_sideband_line(progress: &dyn FfiGitProgress, line: &mut Vec<u8>) {
+    let message = String::from_utf8_lossy(line).trim_end().to_string();
+    line.clear();
+    if !message.is_empty() {
+        progress.on_sideband_message(message);
+    }
+}
@@ -5,35 +5,172 @@ use std::sync::Arc;
 use jj_lib::backend::CommitId;
 #[cfg(feature = "git")]
 use jj_lib::git::GitSettings;
-use jj_lib::object_id::{HexPrefix, PrefixResolution};
+use jj_lib::object_id::{HexPrefix, ObjectId as _, PrefixResolution};
 use jj_lib::repo::{ReadonlyRepo, Repo};
 
+use crate::bookmarks::{FfiBookmark, FfiBookmarkChange, FfiBookmarkRef, FfiRemoteBookmark};
+use crate::conflicts::{FfiConflictSides, FfiMergeToolFiles};
+use crate::diff::{FfiDiffEntry, FfiDiffHunk, FfiDiffStats, FfiDirectoryDiffSummary};
 use crate::error::{JjError, Result};
 #[cfg(feature = "git")]
-use crate::git::FfiGitTransaction;
-use crate::log::{FfiLogOptions, FfiLogResult};
+use crate::git::{FfiCommitHeader, FfiGitHead, FfiGitTransaction};
+use crate::graph::FfiGraphExportOptions;
+use crate::info::FfiStoreInfo;
+use crate::log::{FfiLogIterator, FfiLogOptions, FfiLogResult};
+use crate::operation::{FfiOperation, FfiOperationMergeReport};
+use crate::revset::{FfiRevsetOptions, RevsetContext, RevsetContextCache, RevsetWorkspace};
+use crate::stats::{FfiActivityBucket, FfiActivityCount, FfiContributorStats};
+use crate::tags::FfiTag;
 use crate::transaction::FfiTransaction;
+use crate::tree::{FfiExportFormat, FfiTreeBuilder, FfiTreeEntry};
 use crate::types::{FfiChangeId, FfiCommit, FfiCommitId};
 
 /// A readonly repository exposed via FFI
+///
+/// A repository handle is pinned to a single operation and never changes, so
+/// it can be queried from any thread, including while a transaction started
+/// from it is in flight. Use `reload_at_head()` to observe newer operations.
+///
+/// Handles obtained from an `FfiWorkspace` resolve `@` in revsets to the
+/// workspace's working-copy commit; handles from `FfiRepoLoader` don't.
+///
+/// The revset aliases are prepared on the first revset query and reused by
+/// later queries on the same handle and its transactions.
 #[derive(uniffi::Object)]
 pub struct FfiReadonlyRepo {
     inner: Arc<ReadonlyRepo>,
+    workspace: Option<RevsetWorkspace>,
+    revset_context: Arc<RevsetContextCache>,
+    /// Divergent operations merged when the repository was loaded at head
+    operation_merge: Option<FfiOperationMergeReport>,
 }
 
 impl FfiReadonlyRepo {
     pub fn new(repo: Arc<ReadonlyRepo>) -> Self {
-        Self { inner: repo }
+        Self {
+            inner: repo,
+            workspace: None,
+            revset_context: Arc::default(),
+            operation_merge: None,
+        }
+    }
+
+    /// Create a repository handle that resolves `@` in revsets to the
+    /// working-copy commit of the given workspace
+    pub(crate) fn with_workspace(
+        repo: Arc<ReadonlyRepo>,
+        workspace: Option<RevsetWorkspace>,
+    ) -> Self {
+        Self {
+            inner: repo,
+            workspace,
+            revset_context: Arc::default(),
+            operation_merge: None,
+        }
+    }
+
+    /// Attach the report of the divergent operations merged by loading the
+    /// repository
+    pub(crate) fn with_operation_merge(
+        mut self,
+        operation_merge: Option<FfiOperationMergeReport>,
+    ) -> Self {
+        self.operation_merge = operation_merge;
+        self
     }
 
     pub fn inner(&self) -> &Arc<ReadonlyRepo> {
         &self.inner
     }
+
+    /// Get the context for parsing revsets, preparing it on first use
+    fn revset_context(&self) -> Result<Arc<RevsetContext>> {
+        self.revset_context.get(self.inner.as_ref())
+    }
 }
 
 #[uniffi::export]
 impl FfiReadonlyRepo {
+    /// Get the ID of the operation this repository was loaded at
+    pub fn operation_id(&self) -> String {
+        self.inner.op_id().hex()
+    }
+
+    /// Get the operation this repository was loaded at, with its metadata
+    pub fn operation(&self) -> FfiOperation {
+        FfiOperation::from(self.inner.operation())
+    }
+
+    /// List the operations leading to this repository's operation, newest first
+    ///
+    /// Like `jj op log`, merged operations are followed through all parents.
+    /// At most `limit` operations are returned if given.
+    pub fn operation_log(&self, limit: Option<u32>) -> Result<Vec<FfiOperation>> {
+        crate::operation::operation_log(&self.inner, limit)
+    }
+
+    /// Load the repository at the latest operation
+    ///
+    /// Returns a new handle that sees operations made since this one was
+    /// loaded, e.g. by the `jj` CLI. Divergent operations are merged like the
+    /// CLI does; see `operation_merge_report()` of the new handle.
+    pub fn reload_at_head(&self) -> Result<Arc<FfiReadonlyRepo>> {
+        let (repo, operation_merge) = crate::operation::load_at_head(self.inner.loader())?;
+        Ok(Arc::new(
+            FfiReadonlyRepo::with_workspace(repo, self.workspace.clone())
+                .with_operation_merge(operation_merge),
+        ))
+    }
+
+    /// Get the divergent operations merged when this repository was loaded
+    /// by `reload_at_head()` or `FfiRepoLoader::load_at_head()`, or before
+    /// the operation of `undo_latest_operation()` or `restore_to_operation()`
+    ///
+    /// Returns `None` if the repository had a single latest operation or was
+    /// loaded otherwise.
+    pub fn operation_merge_report(&self) -> Option<FfiOperationMergeReport> {
+        self.operation_merge.clone()
+    }
+
+    /// Undo the latest operation in the repository, like `jj undo`
+    ///
+    /// Returns the repository at the new operation. Files on disk are not
+    /// updated, so if the undo changes a workspace's working-copy commit, the
+    /// workspace is stale until `FfiWorkspace::update_stale()` is called.
+    pub fn undo_latest_operation(&self) -> Result<Arc<FfiReadonlyRepo>> {
+        let (repo, operation_merge) = crate::operation::undo_latest_operation(&self.inner)?;
+        Ok(Arc::new(
+            FfiReadonlyRepo::with_workspace(repo, self.workspace.clone())
+                .with_operation_merge(operation_merge),
+        ))
+    }
+
+    /// Restore the repository to its state at an operation, like `jj op restore`
+    ///
+    /// `op_id` is an operation ID (or unique prefix) or an expression like
+    /// "@-". Returns the repository at the new operation. Files on disk are
+    /// not updated, so if the restore changes a workspace's working-copy
+    /// commit, the workspace is stale until `FfiWorkspace::update_stale()` is
+    /// called.
+    pub fn restore_to_operation(&self, op_id: String) -> Result<Arc<FfiReadonlyRepo>> {
+        let (repo, operation_merge) = crate::operation::restore_to_operation(&self.inner, &op_id)?;
+        Ok(Arc::new(
+            FfiReadonlyRepo::with_workspace(repo, self.workspace.clone())
+                .with_operation_merge(operation_merge),
+        ))
+    }
+
+    /// Find the latest operation committed with an idempotency key
+    ///
+    /// Returns the operation ID, or `None` if no such operation exists.
+    pub fn find_operation_by_idempotency_key(&self, key: String) -> Result<Option<String>> {
+        crate::operation::find_operation_by_idempotency_key(&self.inner, &key)
+    }
+
     /// Get a commit by its commit ID (hex string)
+    ///
+    /// Hidden commits, such as rewritten or abandoned ones, can be fetched
+    /// too. Use `is_hidden()` to tell them apart.
     pub fn get_commit(&self, commit_id: &FfiCommitId) -> Result<FfiCommit> {
         let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
             message: format!("Invalid commit ID: {}", e),
@@ -43,6 +180,44 @@ impl FfiReadonlyRepo {
         Ok(FfiCommit::from(&commit))
     }
 
+    /// Get several commits by their commit IDs in one call
+    ///
+    /// Commits are returned in the order of `commit_ids`. Fails if any of the
+    /// commits cannot be found.
+    pub fn get_commits(&self, commit_ids: Vec<FfiCommitId>) -> Result<Vec<FfiCommit>> {
+        let store = self.inner.store();
+        commit_ids
+            .iter()
+            .map(|commit_id| {
+                let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+                    message: format!("Invalid commit ID: {}", e),
+                })?;
+                let commit = store.get_commit(&id)?;
+                Ok(FfiCommit::from(&commit))
+            })
+            .collect()
+    }
+
+    /// Check whether a commit is hidden, e.g. because it was rewritten or abandoned
+    pub fn is_hidden(&self, commit_id: &FfiCommitId) -> Result<bool> {
+        let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+            message: format!("Invalid commit ID: {}", e),
+        })?;
+
+        let commit = self.inner.store().get_commit(&id)?;
+        commit
+            .is_hidden(self.inner.as_ref())
+            .map_err(|e| JjError::Internal {
+                message: format!("Index error: {}", e),
+            })
+    }
+
+    /// Describe the stores of the repository, e.g. to hide Git features for
+    /// repositories without a Git backend
+    pub fn store_info(&self) -> FfiStoreInfo {
+        crate::info::store_info(&self.inner)
+    }
+
     /// Get the root commit of the repository
     pub fn root_commit(&self) -> FfiCommit {
         let commit = self.inner.store().root_commit();
@@ -76,6 +251,28 @@ impl FfiReadonlyRepo {
         }
     }
 
+    /// Get the shortest prefix that uniquely identifies a commit ID
+    ///
+    /// The prefix is padded to at least `min_len` digits.
+    pub fn shortest_commit_id_prefix(
+        &self,
+        commit_id: &FfiCommitId,
+        min_len: u32,
+    ) -> Result<String> {
+        crate::prefix::shortest_commit_id_prefix(self.inner.as_ref(), commit_id, min_len)
+    }
+
+    /// Get the shortest prefix that uniquely identifies a change ID
+    ///
+    /// The prefix is padded to at least `min_len` digits.
+    pub fn shortest_change_id_prefix(
+        &self,
+        change_id: &FfiChangeId,
+        min_len: u32,
+    ) -> Result<String> {
+        crate::prefix::shortest_change_id_prefix(self.inner.as_ref(), change_id, min_len)
+    }
+
     /// Resolve a commit ID prefix (returns all matching commits)
     pub fn resolve_commit_prefix(&self, prefix: &str) -> Result<Vec<FfiCommitId>> {
         let hex_prefix = HexPrefix::try_from_hex(prefix).ok_or_else(|| JjError::InvalidArgument {
@@ -102,18 +299,361 @@ impl FfiReadonlyRepo {
     }
 
     /// Evaluate a revset expression and return matching commit IDs
-    pub fn evaluate_revset(&self, revset_str: String, user_email: String) -> Result<Vec<FfiCommitId>> {
-        crate::revset::evaluate_revset(&self.inner, &revset_str, &user_email)
+    pub fn evaluate_revset(
+        &self,
+        revset_str: String,
+        user_email: String,
+    ) -> Result<Vec<FfiCommitId>> {
+        self.evaluate_revset_with_options(revset_str, user_email, FfiRevsetOptions::default())
+    }
+
+    /// Same as `evaluate_revset`, with options like a cancellation token
+    pub fn evaluate_revset_with_options(
+        &self,
+        revset_str: String,
+        user_email: String,
+        options: FfiRevsetOptions,
+    ) -> Result<Vec<FfiCommitId>> {
+        crate::revset::evaluate_revset(
+            self.inner.as_ref(),
+            self.revset_context()?.as_ref(),
+            &revset_str,
+            &user_email,
+            self.workspace.as_ref(),
+            &options,
+        )
     }
 
     /// Evaluate a revset expression and return matching commits
-    pub fn evaluate_revset_to_commits(&self, revset_str: String, user_email: String) -> Result<Vec<FfiCommit>> {
-        crate::revset::evaluate_revset_to_commits(&self.inner, &revset_str, &user_email)
+    pub fn evaluate_revset_to_commits(
+        &self,
+        revset_str: String,
+        user_email: String,
+    ) -> Result<Vec<FfiCommit>> {
+        self.evaluate_revset_to_commits_with_options(
+            revset_str,
+            user_email,
+            FfiRevsetOptions::default(),
+        )
+    }
+
+    /// Same as `evaluate_revset_to_commits`, with options like a cancellation token
+    pub fn evaluate_revset_to_commits_with_options(
+        &self,
+        revset_str: String,
+        user_email: String,
+        options: FfiRevsetOptions,
+    ) -> Result<Vec<FfiCommit>> {
+        crate::revset::evaluate_revset_to_commits(
+            self.inner.as_ref(),
+            self.revset_context()?.as_ref(),
+            &revset_str,
+            &user_email,
+            self.workspace.as_ref(),
+            &options,
+        )
+    }
+
+    /// Evaluate a revset expression and return the change IDs of matching
+    /// commits, without duplicates, in revset order
+    pub fn evaluate_revset_to_change_ids(
+        &self,
+        revset_str: String,
+        user_email: String,
+    ) -> Result<Vec<FfiChangeId>> {
+        self.evaluate_revset_to_change_ids_with_options(
+            revset_str,
+            user_email,
+            FfiRevsetOptions::default(),
+        )
+    }
+
+    /// Same as `evaluate_revset_to_change_ids`, with options like a cancellation token
+    pub fn evaluate_revset_to_change_ids_with_options(
+        &self,
+        revset_str: String,
+        user_email: String,
+        options: FfiRevsetOptions,
+    ) -> Result<Vec<FfiChangeId>> {
+        crate::revset::evaluate_revset_to_change_ids(
+            self.inner.as_ref(),
+            self.revset_context()?.as_ref(),
+            &revset_str,
+            &user_email,
+            self.workspace.as_ref(),
+            &options,
+        )
     }
 
     /// Count commits matching a revset expression
     pub fn count_revset(&self, revset_str: String, user_email: String) -> Result<u64> {
-        crate::revset::count_revset(&self.inner, &revset_str, &user_email)
+        self.count_revset_with_options(revset_str, user_email, FfiRevsetOptions::default())
+    }
+
+    /// Same as `count_revset`, with options like a cancellation token
+    pub fn count_revset_with_options(
+        &self,
+        revset_str: String,
+        user_email: String,
+        options: FfiRevsetOptions,
+    ) -> Result<u64> {
+        crate::revset::count_revset(
+            &self.inner,
+            self.revset_context()?.as_ref(),
+            &revset_str,
+            &user_email,
+            self.workspace.as_ref(),
+            &options,
+        )
+    }
+
+    /// Get the trunk commit (latest of main/master/trunk on origin/upstream)
+    pub fn trunk(&self) -> Result<FfiCommit> {
+        crate::revset::trunk(&self.inner)
+    }
+
+    /// Get visible commits authored by the given email
+    pub fn mine(&self, user_email: String) -> Result<Vec<FfiCommitId>> {
+        crate::revset::mine(&self.inner, &user_email)
+    }
+
+    /// Get the visible head commits, newest first
+    pub fn visible_heads(&self) -> Result<Vec<FfiCommitId>> {
+        crate::revset::visible_heads(&self.inner)
+    }
+
+    /// Get the visible children of a commit, newest first
+    pub fn children(&self, commit_id: &FfiCommitId) -> Result<Vec<FfiCommitId>> {
+        crate::revset::children(&self.inner, commit_id)
+    }
+
+    /// Count the visible head commits, without listing them
+    pub fn visible_head_count(&self) -> u64 {
+        self.inner.view().heads().len() as u64
+    }
+
+    /// Get commits of tracked bookmarks that have not been pushed yet
+    pub fn unpushed_commits(&self) -> Result<Vec<FfiCommitId>> {
+        crate::revset::unpushed_commits(&self.inner)
+    }
+
+    /// Compute per-author commit statistics for commits in a revset
+    ///
+    /// Line stats are only computed if `include_line_stats` is set, as they
+    /// require diffing every commit.
+    pub fn contributor_stats(
+        &self,
+        revset_str: String,
+        user_email: String,
+        include_line_stats: bool,
+    ) -> Result<Vec<FfiContributorStats>> {
+        crate::stats::contributor_stats(
+            &self.inner,
+            self.revset_context()?.as_ref(),
+            &revset_str,
+            &user_email,
+            self.workspace.as_ref(),
+            include_line_stats,
+        )
+    }
+
+    /// Count commits in a revset per day or week of their author date
+    pub fn activity_histogram(
+        &self,
+        revset_str: String,
+        user_email: String,
+        bucket: FfiActivityBucket,
+    ) -> Result<Vec<FfiActivityCount>> {
+        crate::stats::activity_histogram(
+            &self.inner,
+            self.revset_context()?.as_ref(),
+            &revset_str,
+            &user_email,
+            self.workspace.as_ref(),
+            bucket,
+        )
+    }
+
+    /// Export the commits selected by a revset as DOT or Mermaid text
+    pub fn export_graph(
+        &self,
+        revset_str: String,
+        user_email: String,
+        options: FfiGraphExportOptions,
+    ) -> Result<String> {
+        crate::graph::export_graph(
+            &self.inner,
+            self.revset_context()?.as_ref(),
+            &revset_str,
+            &user_email,
+            self.workspace.as_ref(),
+            &options,
+        )
+    }
+
+    /// Read the content of a file in a commit
+    ///
+    /// Conflicted files are returned with conflict markers.
+    pub fn read_file(&self, commit_id: &FfiCommitId, path: String) -> Result<Vec<u8>> {
+        crate::files::read_file(&self.inner, commit_id, &path)
+    }
+
+    /// Read the content of a file in a commit as UTF-8 text
+    ///
+    /// Fails if the content is not valid UTF-8.
+    pub fn read_file_text(&self, commit_id: &FfiCommitId, path: String) -> Result<String> {
+        crate::files::read_file_text(&self.inner, commit_id, &path)
+    }
+
+    /// List the entries directly inside a directory of a commit's tree
+    ///
+    /// Use `""` as `path_prefix` to list the repository root.
+    pub fn list_tree(&self, commit_id: &FfiCommitId, path_prefix: String) -> Result<Vec<FfiTreeEntry>> {
+        crate::tree::list_tree(&self.inner, commit_id, &path_prefix)
+    }
+
+    /// Export a commit's tree to a directory or zip archive
+    ///
+    /// The working copy is not touched. The destination must not exist (or be
+    /// an empty directory). Returns the number of exported entries.
+    pub fn export_revision(
+        &self,
+        commit_id: &FfiCommitId,
+        destination: String,
+        format: FfiExportFormat,
+    ) -> Result<u64> {
+        crate::tree::export_revision(&self.inner, commit_id, &destination, format)
+    }
+
+    /// Start building a tree from the tree with the given hex IDs, or from an
+    /// empty tree
+    ///
+    /// The IDs are in the form of `FfiCommit::tree_ids`, e.g. to change the
+    /// content of an existing commit.
+    pub fn new_tree_builder(
+        &self,
+        base_tree_ids: Option<Vec<String>>,
+    ) -> Result<Arc<FfiTreeBuilder>> {
+        let builder = FfiTreeBuilder::new(self.inner.store(), base_tree_ids.as_deref())?;
+        Ok(Arc::new(builder))
+    }
+
+    /// Export commits as `git format-patch` style text
+    ///
+    /// Each commit is diffed against its parents and carries its change ID in
+    /// a `Change-Id` trailer. File contents are included byte for byte, so the
+    /// patch is returned as bytes rather than a string. Exporting a commit that
+    /// changes a binary file fails, as the patch couldn't be imported again.
+    pub fn export_patch(&self, commit_ids: Vec<FfiCommitId>) -> Result<Vec<u8>> {
+        crate::patch::export_patch(&self.inner, &commit_ids)
+    }
+
+    /// List the local bookmarks in the repository
+    pub fn bookmarks(&self) -> Vec<FfiBookmark> {
+        crate::bookmarks::local_bookmarks(self.inner.as_ref())
+    }
+
+    /// List the remote bookmarks with their tracking status and how far
+    /// they are ahead of or behind the local bookmarks
+    pub fn remote_bookmarks(&self) -> Result<Vec<FfiRemoteBookmark>> {
+        crate::bookmarks::remote_bookmarks(&self.inner)
+    }
+
+    /// List the local and remote bookmarks that contain a commit
+    pub fn bookmarks_containing(&self, commit_id: &FfiCommitId) -> Result<Vec<FfiBookmarkRef>> {
+        crate::bookmarks::bookmarks_containing(&self.inner, commit_id)
+    }
+
+    /// List the local tags in the repository
+    pub fn tags(&self) -> Vec<FfiTag> {
+        crate::tags::tags(&self.inner)
+    }
+
+    /// List the bookmarks that moved, appeared or disappeared between two operations
+    pub fn bookmark_changes_between_ops(
+        &self,
+        old_op: String,
+        new_op: String,
+    ) -> Result<Vec<FfiBookmarkChange>> {
+        crate::bookmarks::bookmark_changes_between_ops(&self.inner, &old_op, &new_op)
+    }
+
+    /// Get the sides and bases of every conflicted file in a commit
+    pub fn conflicts(&self, commit_id: &FfiCommitId) -> Result<Vec<FfiConflictSides>> {
+        crate::conflicts::conflicts(&self.inner, commit_id)
+    }
+
+    /// List the paths with unresolved conflicts in a commit
+    pub fn conflicted_paths(&self, commit_id: &FfiCommitId) -> Result<Vec<String>> {
+        crate::conflicts::conflicted_paths(&self.inner, commit_id)
+    }
+
+    /// Get the sides and bases of a conflicted file in a commit
+    ///
+    /// Each term carries its own content, allowing clients to render
+    /// multi-pane conflict views instead of marker-materialized text.
+    pub fn conflict_sides(&self, commit_id: &FfiCommitId, path: String) -> Result<FfiConflictSides> {
+        crate::conflicts::conflict_sides(&self.inner, commit_id, &path)
+    }
+
+    /// Write the versions of a conflicted file for an external merge tool
+    ///
+    /// Only conflicts with two sides are supported. After the tool has
+    /// written its result to `files.output`, pass it to
+    /// `FfiTransaction::resolve_conflict_from_merge_tool()`.
+    pub fn write_merge_tool_files(
+        &self,
+        commit_id: &FfiCommitId,
+        path: String,
+        files: FfiMergeToolFiles,
+    ) -> Result<()> {
+        crate::conflicts::write_merge_tool_files(&self.inner, commit_id, &path, &files)
+    }
+
+    /// List the files changed between two commits
+    ///
+    /// Renames and copies are detected when the backend records them.
+    pub fn diff_summary(&self, from: &FfiCommitId, to: &FfiCommitId) -> Result<Vec<FfiDiffEntry>> {
+        crate::diff::diff_summary(&self.inner, from, to)
+    }
+
+    /// Count inserted and deleted lines per file between two commits
+    ///
+    /// Like `jj diff --stat`, this is cheaper than computing hunks for each
+    /// file. Binary files are flagged and count no lines.
+    pub fn diff_stats(&self, from: &FfiCommitId, to: &FfiCommitId) -> Result<FfiDiffStats> {
+        crate::diff::diff_stats(&self.inner, from, to)
+    }
+
+    /// Summarize changes between two commits per subdirectory
+    ///
+    /// Returns one entry for each immediate subdirectory of `dir` (use `""`
+    /// for the repository root) containing changed files, with file and line
+    /// counts aggregated recursively, and one entry for each changed file
+    /// directly inside `dir`.
+    pub fn diff_directory_summary(
+        &self,
+        from: &FfiCommitId,
+        to: &FfiCommitId,
+        dir: String,
+    ) -> Result<Vec<FfiDirectoryDiffSummary>> {
+        crate::diff::diff_directory_summary(&self.inner, from, to, &dir)
+    }
+
+    /// Compute line-based diff hunks for a file between two commits
+    ///
+    /// `context_lines` controls how many unchanged lines surround each hunk.
+    /// If `intraline` is set, added and removed lines also carry the ranges of
+    /// the words that changed within them, for word-level highlighting.
+    pub fn diff_file_hunks(
+        &self,
+        from: &FfiCommitId,
+        to: &FfiCommitId,
+        path: String,
+        context_lines: u32,
+        intraline: bool,
+    ) -> Result<Vec<FfiDiffHunk>> {
+        crate::diff::diff_file_hunks(&self.inner, from, to, &path, context_lines, intraline)
     }
 
     /// Get log with graph information
@@ -131,7 +671,13 @@ impl FfiReadonlyRepo {
     /// - `["::"]` - All commits
     /// - `["main..@"]` - Commits from main to current
     pub fn log(&self, options: FfiLogOptions, user_email: String) -> Result<FfiLogResult> {
-        crate::log::evaluate_log(&self.inner, &options, &user_email)
+        crate::log::evaluate_log(
+            &self.inner,
+            self.revset_context()?.as_ref(),
+            &options,
+            &user_email,
+            self.workspace.as_ref(),
+        )
     }
 
     /// Get log as a flat list without graph information
@@ -139,23 +685,95 @@ impl FfiReadonlyRepo {
     /// Returns a list of commits without graph edge information.
     /// More efficient when graph visualization is not needed.
     pub fn log_flat(&self, options: FfiLogOptions, user_email: String) -> Result<Vec<FfiCommit>> {
-        crate::log::evaluate_log_flat(&self.inner, &options, &user_email)
+        crate::log::evaluate_log_flat(
+            &self.inner,
+            self.revset_context()?.as_ref(),
+            &options,
+            &user_email,
+            self.workspace.as_ref(),
+        )
+    }
+
+    /// Get log with graph information one page at a time
+    ///
+    /// Returns an iterator whose `next_page()` loads only the commits of the
+    /// requested page, for lazily loading large histories.
+    pub fn log_iterator(
+        &self,
+        options: FfiLogOptions,
+        user_email: String,
+    ) -> Result<Arc<FfiLogIterator>> {
+        let iterator = FfiLogIterator::new(
+            &self.inner,
+            self.revset_context()?.as_ref(),
+            &options,
+            &user_email,
+            self.workspace.as_ref(),
+        )?;
+        Ok(Arc::new(iterator))
+    }
+
+    /// Render a commit with a template in jj's template language
+    ///
+    /// For example, `change_id.short() ++ " " ++ description.first_line()`,
+    /// or an alias like `builtin_log_oneline`.
+    pub fn render_commit_template(
+        &self,
+        commit_id: &FfiCommitId,
+        template_text: String,
+    ) -> Result<String> {
+        crate::template::render_commit_template(
+            &self.inner,
+            self.revset_context()?.as_ref(),
+            self.workspace.as_ref(),
+            commit_id,
+            &template_text,
+        )
     }
 
     /// Start a new transaction for making changes to the repository
     pub fn start_transaction(&self) -> Arc<FfiTransaction> {
         let tx = self.inner.start_transaction();
-        Arc::new(FfiTransaction::new(tx))
+        Arc::new(FfiTransaction::new(
+            tx,
+            self.workspace.clone(),
+            Arc::clone(&self.revset_context),
+        ))
+    }
+}
+
+/// Methods backed by Git, which uniffi can only leave out of the bindings
+/// as a whole block
+#[cfg(feature = "git")]
+#[uniffi::export]
+impl FfiReadonlyRepo {
+    /// Read the extra headers stored in the Git object of a commit
+    ///
+    /// Kept separate from `FfiCommit` so log queries don't have to re-read
+    /// every Git object.
+    pub fn commit_extra_headers(&self, commit_id: &FfiCommitId) -> Result<Vec<FfiCommitHeader>> {
+        crate::git::commit_extra_headers(&self.inner, commit_id)
+    }
+
+    /// Get HEAD of the backing Git repository
+    ///
+    /// In a colocated workspace, this is what `git` currently points at,
+    /// which is normally the parent of the working-copy commit.
+    pub fn git_head(&self) -> Result<FfiGitHead> {
+        crate::git::git_head(&self.inner)
     }
 
     /// Start a new Git transaction for performing Git operations
-    #[cfg(feature = "git")]
     pub fn start_git_transaction(&self) -> Result<Arc<FfiGitTransaction>> {
         let settings = self.inner.settings();
         let git_settings = GitSettings::from_settings(settings).map_err(|e| JjError::Git {
             message: format!("Failed to load Git settings: {}", e),
         })?;
         let tx = self.inner.start_transaction();
-        Ok(Arc::new(FfiGitTransaction::new(tx, git_settings)))
+        Ok(Arc::new(FfiGitTransaction::new(
+            tx,
+            git_settings,
+            self.workspace.clone(),
+        )))
     }
 }
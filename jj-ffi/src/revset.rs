@@ -1,66 +1,316 @@
 //! Revset operations for FFI
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 use chrono::Local;
+use jj_lib::backend::CommitId;
+use jj_lib::fileset::{self, FilesetDiagnostics, FilesetExpression};
+use jj_lib::ref_name::{RefName, RemoteName, WorkspaceName, WorkspaceNameBuf};
 use jj_lib::repo::{ReadonlyRepo, Repo};
+use jj_lib::repo_path::RepoPathUiConverter;
 use jj_lib::revset::{
-    RevsetAliasesMap, RevsetDiagnostics, RevsetExtensions, RevsetParseContext, SymbolResolver,
-    parse,
+    ResolvedRevsetExpression, Revset, RevsetAliasesMap, RevsetDiagnostics, RevsetExpression,
+    RevsetExtensions, RevsetFilterPredicate, RevsetIteratorExt as _, RevsetParseContext,
+    RevsetWorkspaceContext, SymbolResolver, UserRevsetExpression, parse,
 };
+use jj_lib::str_util::{StringExpression, StringPattern};
 use jj_lib::time_util::DatePatternContext;
+use jj_lib::workspace::Workspace;
 
+use crate::cancel::{FfiCancellationToken, check_cancelled};
 use crate::error::{JjError, Result};
-use crate::types::{FfiCommit, FfiCommitId};
+use crate::log::{MemoryBudget, estimated_commit_size};
+use crate::types::{FfiChangeId, FfiCommit, FfiCommitId};
 
-/// Evaluate a revset expression and return matching commit IDs
-pub fn evaluate_revset(
-    repo: &Arc<ReadonlyRepo>,
-    revset_str: &str,
-    user_email: &str,
-) -> Result<Vec<FfiCommitId>> {
-    let aliases_map = RevsetAliasesMap::new();
-    let extensions = RevsetExtensions::new();
-    let date_context = DatePatternContext::from(Local::now());
-
-    let context = RevsetParseContext {
-        aliases_map: &aliases_map,
-        local_variables: HashMap::new(),
-        user_email,
-        date_pattern_context: date_context,
-        default_ignored_remote: None,
-        use_glob_by_default: false,
-        extensions: &extensions,
-        workspace: None,
+/// Build the revset aliases of a repository
+///
+/// Aliases are read from the `revset-aliases` tables of the repository's
+/// settings in order of precedence, starting with the defaults of the `jj`
+/// CLI like `trunk()` and `immutable_heads()`.
+pub(crate) fn revset_aliases(repo: &dyn Repo) -> Result<RevsetAliasesMap> {
+    let mut aliases_map = RevsetAliasesMap::new();
+    let invalid_alias = |decl: &str, message: String| JjError::Revset {
+        message: format!("Invalid revset alias '{}': {}", decl, message),
     };
+    for layer in repo.base_repo().settings().config().layers() {
+        let table = match layer.look_up_table("revset-aliases") {
+            Ok(Some(table)) => table,
+            Ok(None) => continue,
+            Err(_) => {
+                return Err(JjError::Revset {
+                    message: "Invalid revset-aliases config: expected a table".to_string(),
+                });
+            }
+        };
+        for (decl, item) in table.iter() {
+            let defn = item
+                .as_str()
+                .ok_or_else(|| invalid_alias(decl, "expected a string".to_string()))?;
+            aliases_map
+                .insert(decl, defn)
+                .map_err(|e| invalid_alias(decl, e.to_string()))?;
+        }
+    }
+    Ok(aliases_map)
+}
 
-    let mut diagnostics = RevsetDiagnostics::new();
-    let user_expression = parse(&mut diagnostics, revset_str, &context).map_err(|e| {
+/// Aliases and extensions for parsing and resolving revsets
+///
+/// Building the aliases parses the `revset-aliases` config, so a context is
+/// prepared once per repository handle and reused by its queries.
+pub struct RevsetContext {
+    aliases_map: RevsetAliasesMap,
+    extensions: Arc<RevsetExtensions>,
+}
+
+impl RevsetContext {
+    pub(crate) fn new(repo: &dyn Repo) -> Result<Self> {
+        Ok(Self {
+            aliases_map: revset_aliases(repo)?,
+            extensions: Arc::new(RevsetExtensions::new()),
+        })
+    }
+
+    /// Context for parsing revsets
+    ///
+    /// Without a workspace, `@` and file paths can't be resolved.
+    pub(crate) fn parse_context<'a>(
+        &'a self,
+        user_email: &'a str,
+        workspace: Option<&'a RevsetWorkspace>,
+    ) -> RevsetParseContext<'a> {
+        RevsetParseContext {
+            aliases_map: &self.aliases_map,
+            local_variables: HashMap::new(),
+            user_email,
+            date_pattern_context: DatePatternContext::from(Local::now()),
+            default_ignored_remote: None,
+            use_glob_by_default: false,
+            extensions: &self.extensions,
+            workspace: workspace.map(RevsetWorkspace::context),
+        }
+    }
+
+    pub(crate) fn extensions(&self) -> &Arc<RevsetExtensions> {
+        &self.extensions
+    }
+}
+
+/// A `RevsetContext` that is prepared on first use
+#[derive(Default)]
+pub(crate) struct RevsetContextCache {
+    context: Mutex<Option<Arc<RevsetContext>>>,
+}
+
+impl RevsetContextCache {
+    /// Get the cached context, preparing it from the repository's settings
+    /// if needed
+    ///
+    /// Failures aren't cached, so an invalid alias is reported on every call.
+    pub(crate) fn get(&self, repo: &dyn Repo) -> Result<Arc<RevsetContext>> {
+        let mut context = self.context.lock().unwrap();
+        if let Some(context) = context.as_ref() {
+            return Ok(Arc::clone(context));
+        }
+        let new_context = Arc::new(RevsetContext::new(repo)?);
+        *context = Some(Arc::clone(&new_context));
+        Ok(new_context)
+    }
+}
+
+/// Options for evaluating a revset
+#[derive(Debug, Clone, Default, uniffi::Record)]
+pub struct FfiRevsetOptions {
+    /// Cancels the evaluation, checked before each matching commit
+    pub cancellation: Option<Arc<FfiCancellationToken>>,
+    /// Approximate memory budget in bytes for the returned IDs or commits
+    /// (`None` for no budget)
+    ///
+    /// The evaluation fails once the result exceeds the budget.
+    pub max_bytes: Option<u64>,
+}
+
+impl FfiRevsetOptions {
+    fn budget(&self) -> MemoryBudget {
+        MemoryBudget::new(self.max_bytes)
+    }
+
+    /// Error for a result exceeding `max_bytes`
+    fn budget_exceeded(&self) -> JjError {
         JjError::Revset {
-            message: e.to_string(),
+            message: format!(
+                "Revset result exceeds the memory budget of {} bytes",
+                self.max_bytes.unwrap_or_default()
+            ),
         }
-    })?;
+    }
+}
+
+/// The workspace in which `@` and file paths in revsets are resolved
+#[derive(Debug, Clone)]
+pub struct RevsetWorkspace {
+    name: WorkspaceNameBuf,
+    path_converter: RepoPathUiConverter,
+    #[cfg(feature = "git")]
+    colocated: bool,
+}
+
+impl RevsetWorkspace {
+    pub(crate) fn new(workspace: &Workspace) -> Self {
+        let root = workspace.workspace_root().to_owned();
+        Self {
+            name: workspace.workspace_name().to_owned(),
+            path_converter: RepoPathUiConverter::Fs {
+                cwd: root.clone(),
+                base: root,
+            },
+            #[cfg(feature = "git")]
+            colocated: crate::git::is_colocated(workspace),
+        }
+    }
+
+    /// Name of the workspace
+    pub(crate) fn name(&self) -> &WorkspaceName {
+        &self.name
+    }
+
+    /// Converter between paths relative to the workspace root and repo paths
+    pub(crate) fn path_converter(&self) -> &RepoPathUiConverter {
+        &self.path_converter
+    }
 
-    let symbol_resolver = SymbolResolver::new(repo.as_ref(), extensions.symbol_resolvers());
-    let resolved_expression = user_expression
-        .resolve_user_expression(repo.as_ref(), &symbol_resolver)
+    /// Whether the workspace shares its working directory with Git
+    #[cfg(feature = "git")]
+    pub(crate) fn is_colocated(&self) -> bool {
+        self.colocated
+    }
+
+    fn context(&self) -> RevsetWorkspaceContext<'_> {
+        RevsetWorkspaceContext {
+            path_converter: &self.path_converter,
+            workspace_name: &self.name,
+        }
+    }
+}
+
+/// Parse revset expressions with the repository's aliases
+///
+/// Without a workspace, `@` and file paths can't be resolved.
+pub(crate) fn parse_revsets<'a>(
+    context: &RevsetContext,
+    revset_strs: impl IntoIterator<Item = &'a str>,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+) -> Result<Vec<Arc<UserRevsetExpression>>> {
+    let parse_context = context.parse_context(user_email, workspace);
+    let mut diagnostics = RevsetDiagnostics::new();
+    revset_strs
+        .into_iter()
+        .map(|revset_str| {
+            parse(&mut diagnostics, revset_str, &parse_context).map_err(|e| JjError::Revset {
+                message: e.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Build a revset filter matching commits that modify files in any of the
+/// given fileset expressions
+///
+/// Paths are relative to the workspace root, or to the repository root
+/// without a workspace.
+pub(crate) fn files_filter(
+    filesets: &[String],
+    workspace: Option<&RevsetWorkspace>,
+) -> Result<Arc<UserRevsetExpression>> {
+    let repo_root_converter = RepoPathUiConverter::Fs {
+        cwd: PathBuf::new(),
+        base: PathBuf::new(),
+    };
+    let path_converter =
+        workspace.map_or(&repo_root_converter, |workspace| &workspace.path_converter);
+    let mut diagnostics = FilesetDiagnostics::new();
+    let expressions = filesets
+        .iter()
+        .map(|text| {
+            fileset::parse(&mut diagnostics, text, path_converter).map_err(|e| {
+                JjError::InvalidArgument {
+                    message: format!("Invalid fileset '{}': {}", text, e),
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(RevsetExpression::filter(RevsetFilterPredicate::File(
+        FilesetExpression::union_all(expressions),
+    )))
+}
+
+/// Resolve the symbols of a parsed revset expression against a repository
+pub(crate) fn resolve_expression(
+    repo: &dyn Repo,
+    context: &RevsetContext,
+    expression: &UserRevsetExpression,
+) -> Result<Arc<ResolvedRevsetExpression>> {
+    let symbol_resolver = SymbolResolver::new(repo, context.extensions.symbol_resolvers());
+    expression
+        .resolve_user_expression(repo, &symbol_resolver)
         .map_err(|e| JjError::Revset {
             message: e.to_string(),
-        })?;
+        })
+}
 
-    let revset = resolved_expression.evaluate(repo.as_ref()).map_err(|e| {
-        JjError::Revset {
+/// Parse and resolve a revset expression against a repository
+pub(crate) fn resolve_revset(
+    repo: &dyn Repo,
+    context: &RevsetContext,
+    revset_str: &str,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+) -> Result<Arc<ResolvedRevsetExpression>> {
+    let expressions = parse_revsets(context, [revset_str], user_email, workspace)?;
+    resolve_expression(repo, context, &expressions[0])
+}
+
+/// Parse, resolve and evaluate a revset expression
+fn evaluate<'index>(
+    repo: &'index dyn Repo,
+    context: &RevsetContext,
+    revset_str: &str,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+) -> Result<Box<dyn Revset + 'index>> {
+    resolve_revset(repo, context, revset_str, user_email, workspace)?
+        .evaluate(repo)
+        .map_err(|e| JjError::Revset {
             message: e.to_string(),
-        }
-    })?;
+        })
+}
 
+/// Evaluate a revset expression and return matching commit IDs
+pub fn evaluate_revset(
+    repo: &dyn Repo,
+    context: &RevsetContext,
+    revset_str: &str,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+    options: &FfiRevsetOptions,
+) -> Result<Vec<FfiCommitId>> {
+    let revset = evaluate(repo, context, revset_str, user_email, workspace)?;
+
+    let mut budget = options.budget();
     let mut commit_ids = Vec::new();
     for result in revset.iter() {
+        check_cancelled(options.cancellation.as_deref())?;
         let commit_id = result.map_err(|e| JjError::Revset {
             message: e.to_string(),
         })?;
-        commit_ids.push(FfiCommitId::from(&commit_id));
+        let commit_id = FfiCommitId::from(&commit_id);
+        if !budget.charge((size_of::<FfiCommitId>() + commit_id.hex.len()) as u64) {
+            return Err(options.budget_exceeded());
+        }
+        commit_ids.push(commit_id);
     }
 
     Ok(commit_ids)
@@ -68,98 +318,81 @@ pub fn evaluate_revset(
 
 /// Evaluate a revset expression and return matching commits
 pub fn evaluate_revset_to_commits(
-    repo: &Arc<ReadonlyRepo>,
+    repo: &dyn Repo,
+    context: &RevsetContext,
     revset_str: &str,
     user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+    options: &FfiRevsetOptions,
 ) -> Result<Vec<FfiCommit>> {
-    use jj_lib::revset::RevsetIteratorExt;
-
-    let aliases_map = RevsetAliasesMap::new();
-    let extensions = RevsetExtensions::new();
-    let date_context = DatePatternContext::from(Local::now());
-
-    let context = RevsetParseContext {
-        aliases_map: &aliases_map,
-        local_variables: HashMap::new(),
-        user_email,
-        date_pattern_context: date_context,
-        default_ignored_remote: None,
-        use_glob_by_default: false,
-        extensions: &extensions,
-        workspace: None,
-    };
-
-    let mut diagnostics = RevsetDiagnostics::new();
-    let user_expression = parse(&mut diagnostics, revset_str, &context).map_err(|e| {
-        JjError::Revset {
-            message: e.to_string(),
-        }
-    })?;
-
-    let symbol_resolver = SymbolResolver::new(repo.as_ref(), extensions.symbol_resolvers());
-    let resolved_expression = user_expression
-        .resolve_user_expression(repo.as_ref(), &symbol_resolver)
-        .map_err(|e| JjError::Revset {
-            message: e.to_string(),
-        })?;
-
-    let revset = resolved_expression.evaluate(repo.as_ref()).map_err(|e| {
-        JjError::Revset {
-            message: e.to_string(),
-        }
-    })?;
+    let revset = evaluate(repo, context, revset_str, user_email, workspace)?;
 
     let store = repo.store();
+    let mut budget = options.budget();
     let mut commits = Vec::new();
     for result in revset.iter().commits(store) {
+        check_cancelled(options.cancellation.as_deref())?;
         let commit = result.map_err(|e| JjError::Revset {
             message: e.to_string(),
         })?;
-        commits.push(FfiCommit::from(&commit));
+        let commit = FfiCommit::from(&commit);
+        if !budget.charge(estimated_commit_size(&commit)) {
+            return Err(options.budget_exceeded());
+        }
+        commits.push(commit);
     }
 
     Ok(commits)
 }
 
-/// Count commits matching a revset expression
-pub fn count_revset(repo: &Arc<ReadonlyRepo>, revset_str: &str, user_email: &str) -> Result<u64> {
-    let aliases_map = RevsetAliasesMap::new();
-    let extensions = RevsetExtensions::new();
-    let date_context = DatePatternContext::from(Local::now());
-
-    let context = RevsetParseContext {
-        aliases_map: &aliases_map,
-        local_variables: HashMap::new(),
-        user_email,
-        date_pattern_context: date_context,
-        default_ignored_remote: None,
-        use_glob_by_default: false,
-        extensions: &extensions,
-        workspace: None,
-    };
+/// Evaluate a revset expression and return the change IDs of matching commits
+///
+/// Change IDs are listed once, in the order of their first matching commit,
+/// so divergent changes appear once.
+pub fn evaluate_revset_to_change_ids(
+    repo: &dyn Repo,
+    context: &RevsetContext,
+    revset_str: &str,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+    options: &FfiRevsetOptions,
+) -> Result<Vec<FfiChangeId>> {
+    let revset = evaluate(repo, context, revset_str, user_email, workspace)?;
 
-    let mut diagnostics = RevsetDiagnostics::new();
-    let user_expression = parse(&mut diagnostics, revset_str, &context).map_err(|e| {
-        JjError::Revset {
+    let mut budget = options.budget();
+    let mut seen = HashSet::new();
+    let mut change_ids = Vec::new();
+    for result in revset.commit_change_ids() {
+        check_cancelled(options.cancellation.as_deref())?;
+        let (_, change_id) = result.map_err(|e| JjError::Revset {
             message: e.to_string(),
+        })?;
+        if seen.insert(change_id.clone()) {
+            let change_id = FfiChangeId::from(&change_id);
+            if !budget.charge((size_of::<FfiChangeId>() + change_id.hex.len()) as u64) {
+                return Err(options.budget_exceeded());
+            }
+            change_ids.push(change_id);
         }
-    })?;
+    }
 
-    let symbol_resolver = SymbolResolver::new(repo.as_ref(), extensions.symbol_resolvers());
-    let resolved_expression = user_expression
-        .resolve_user_expression(repo.as_ref(), &symbol_resolver)
-        .map_err(|e| JjError::Revset {
-            message: e.to_string(),
-        })?;
+    Ok(change_ids)
+}
 
-    let revset = resolved_expression.evaluate(repo.as_ref()).map_err(|e| {
-        JjError::Revset {
-            message: e.to_string(),
-        }
-    })?;
+/// Count commits matching a revset expression
+pub fn count_revset(
+    repo: &Arc<ReadonlyRepo>,
+    context: &RevsetContext,
+    revset_str: &str,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+    options: &FfiRevsetOptions,
+) -> Result<u64> {
+    let revset = evaluate(repo.as_ref(), context, revset_str, user_email, workspace)?;
 
     let mut count = 0u64;
     for result in revset.iter() {
+        check_cancelled(options.cancellation.as_deref())?;
         result.map_err(|e| JjError::Revset {
             message: e.to_string(),
         })?;
@@ -168,3 +401,116 @@ pub fn count_revset(repo: &Arc<ReadonlyRepo>, revset_str: &str, user_email: &str
 
     Ok(count)
 }
+
+/// Bookmark names considered as trunk candidates, in priority order
+const TRUNK_BOOKMARKS: [&str; 3] = ["main", "master", "trunk"];
+
+/// Remote names considered as trunk candidates, in priority order
+const TRUNK_REMOTES: [&str; 2] = ["origin", "upstream"];
+
+/// Evaluate an already-resolved revset expression and return matching commit IDs
+fn evaluate_resolved(
+    repo: &Arc<ReadonlyRepo>,
+    expression: Arc<ResolvedRevsetExpression>,
+) -> Result<Vec<FfiCommitId>> {
+    let revset = expression
+        .evaluate(repo.as_ref())
+        .map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+
+    let mut commit_ids = Vec::new();
+    for result in revset.iter() {
+        let commit_id = result.map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+        commit_ids.push(FfiCommitId::from(&commit_id));
+    }
+
+    Ok(commit_ids)
+}
+
+/// Get the trunk commit, like the `trunk()` revset alias
+///
+/// Picks the latest of the `main`, `master` or `trunk` bookmarks on the
+/// `origin` or `upstream` remotes, falling back to the root commit.
+pub fn trunk(repo: &Arc<ReadonlyRepo>) -> Result<FfiCommit> {
+    let view = repo.view();
+    let candidates: Vec<CommitId> = TRUNK_REMOTES
+        .iter()
+        .flat_map(|remote| {
+            TRUNK_BOOKMARKS
+                .iter()
+                .map(move |name| RefName::new(name).to_remote_symbol(RemoteName::new(remote)))
+        })
+        .flat_map(|symbol| view.get_remote_bookmark(symbol).target.added_ids().cloned())
+        .collect();
+    let expression = RevsetExpression::commits(candidates)
+        .union(&RevsetExpression::root())
+        .latest(1);
+
+    let commit_id = evaluate_resolved(repo, expression)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| JjError::Internal {
+            message: "Failed to resolve trunk".to_string(),
+        })?;
+    let id = CommitId::try_from(&commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let commit = repo.store().get_commit(&id)?;
+    Ok(FfiCommit::from(&commit))
+}
+
+/// Get visible commits authored by the given email, like the `mine()` revset
+pub fn mine(repo: &Arc<ReadonlyRepo>, user_email: &str) -> Result<Vec<FfiCommitId>> {
+    let pattern = StringPattern::exact_i(user_email);
+    let predicate = RevsetFilterPredicate::AuthorEmail(StringExpression::pattern(pattern));
+    evaluate_resolved(repo, RevsetExpression::filter(predicate))
+}
+
+/// Get the visible head commits
+pub fn visible_heads(repo: &Arc<ReadonlyRepo>) -> Result<Vec<FfiCommitId>> {
+    evaluate_resolved(repo, RevsetExpression::visible_heads())
+}
+
+/// Get the visible children of a commit, newest first
+pub fn children(repo: &Arc<ReadonlyRepo>, commit_id: &FfiCommitId) -> Result<Vec<FfiCommitId>> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let exists = repo.index().has_id(&id).map_err(|e| JjError::Internal {
+        message: format!("Index error: {}", e),
+    })?;
+    if !exists {
+        return Err(JjError::CommitNotFound {
+            id: commit_id.hex.clone(),
+        });
+    }
+    evaluate_resolved(repo, RevsetExpression::commits(vec![id]).children())
+}
+
+/// Get commits of local bookmarks that are not yet on their tracked remotes
+///
+/// For each local bookmark with tracked remote bookmarks, this includes the
+/// commits reachable from the local bookmark but not from its remotes.
+pub fn unpushed_commits(repo: &Arc<ReadonlyRepo>) -> Result<Vec<FfiCommitId>> {
+    let view = repo.view();
+    let ranges: Vec<_> = view
+        .bookmarks()
+        .filter_map(|(_, targets)| {
+            let remote_ids: Vec<CommitId> = targets
+                .remote_refs
+                .iter()
+                .filter(|(_, remote_ref)| remote_ref.is_tracked())
+                .flat_map(|(_, remote_ref)| remote_ref.target.added_ids().cloned())
+                .collect();
+            if remote_ids.is_empty() {
+                return None;
+            }
+            let local_ids = targets.local_target.added_ids().cloned().collect();
+            Some(RevsetExpression::commits(remote_ids).range(&RevsetExpression::commits(local_ids)))
+        })
+        .collect();
+    evaluate_resolved(repo, RevsetExpression::union_all(&ranges))
+}
@@ -0,0 +1,587 @@
+//! History rewriting for FFI
+//!
+//! This module implements operations that move commits or their changes
+//! around in the graph using jj-lib's rewrite machinery, so descendants
+//! follow their ancestors.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use jj_lib::absorb::{AbsorbSource, absorb_hunks, split_hunks_to_trees};
+use jj_lib::backend::CommitId;
+use jj_lib::commit::Commit;
+use jj_lib::matchers::{EverythingMatcher, PrefixMatcher};
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::{MutableRepo, Repo};
+use jj_lib::revset::{ResolvedRevsetExpression, RevsetExpression};
+use jj_lib::rewrite::{
+    CommitWithSelection, MoveCommitsLocation, MoveCommitsStats, MoveCommitsTarget, RebaseOptions,
+    move_commits, restore_tree, squash_commits,
+};
+
+use crate::error::{JjError, Result};
+use crate::files::parse_repo_path;
+use crate::types::{FfiCommit, FfiCommitId};
+
+/// Statistics about a rebase
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiRebaseStats {
+    /// Number of source commits (and their descendants) that were rebased
+    pub num_rebased_targets: u32,
+    /// Number of other descendants that were rebased
+    pub num_rebased_descendants: u32,
+    /// Number of commits that were already in place and left unchanged
+    pub num_skipped_rebases: u32,
+    /// Number of commits that were abandoned because they became empty
+    pub num_abandoned_empty: u32,
+}
+
+impl From<&MoveCommitsStats> for FfiRebaseStats {
+    fn from(stats: &MoveCommitsStats) -> Self {
+        Self {
+            num_rebased_targets: stats.num_rebased_targets,
+            num_rebased_descendants: stats.num_rebased_descendants,
+            num_skipped_rebases: stats.num_skipped_rebases,
+            num_abandoned_empty: stats.num_abandoned_empty,
+        }
+    }
+}
+
+/// Commits to rebase, mirroring the `-r`, `-s` and `-b` options of `jj rebase`
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum FfiRebaseTarget {
+    /// Only the given commits; their descendants are rebased onto their
+    /// parents (`-r`)
+    Revisions { commit_ids: Vec<FfiCommitId> },
+    /// The given commits and their descendants (`-s`)
+    Source { commit_ids: Vec<FfiCommitId> },
+    /// The branches containing the given commits: the commits not already
+    /// on the destination, with their descendants (`-b`)
+    Branch { commit_ids: Vec<FfiCommitId> },
+}
+
+/// Where to move rebased commits, mirroring the `-d`, `-A` and `-B` options
+/// of `jj rebase`
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum FfiRebaseDestination {
+    /// Onto the given parents (`-d`)
+    Onto { parent_ids: Vec<FfiCommitId> },
+    /// Between the given commits and their children (`--insert-after`)
+    InsertAfter { commit_ids: Vec<FfiCommitId> },
+    /// Between the given commits and their parents (`--insert-before`)
+    InsertBefore { commit_ids: Vec<FfiCommitId> },
+}
+
+/// Options for squashing a commit into another, mirroring `jj squash`
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiSquashOptions {
+    /// Description of the resulting commit (overrides the other description options)
+    pub description: Option<String>,
+    /// Keep the destination's description instead of combining descriptions
+    pub use_destination_message: bool,
+    /// Keep the source commit even if it becomes empty
+    pub keep_emptied: bool,
+}
+
+/// The two commits resulting from a split
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiSplitResult {
+    /// Commit with the selected paths (keeps the original change ID)
+    pub first: FfiCommit,
+    /// Commit with the remaining changes, on top of `first`
+    pub second: FfiCommit,
+}
+
+/// A path whose changes could not be absorbed
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiAbsorbSkippedPath {
+    /// Repository-relative path in internal ("/"-separated) form
+    pub path: String,
+    /// Why the changes were skipped
+    pub reason: String,
+}
+
+/// The commits rewritten by an absorb
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiAbsorbResult {
+    /// The source commit with the absorbed changes removed, or `None` if it
+    /// was abandoned or nothing was absorbed
+    pub rewritten_source: Option<FfiCommit>,
+    /// Commits the changes were absorbed into, parents first
+    pub rewritten_destinations: Vec<FfiCommit>,
+    /// Number of other descendants that were rebased
+    pub num_rebased: u32,
+    /// Paths whose changes were left in the source commit
+    pub skipped_paths: Vec<FfiAbsorbSkippedPath>,
+}
+
+pub(crate) fn parse_commit_ids(commit_ids: &[FfiCommitId]) -> Result<Vec<CommitId>> {
+    commit_ids
+        .iter()
+        .map(CommitId::try_from)
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| JjError::InvalidArgument {
+            message: format!("Invalid commit ID: {}", e),
+        })
+}
+
+fn parse_commit_id(commit_id: &FfiCommitId) -> Result<CommitId> {
+    CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })
+}
+
+fn is_ancestor(
+    repo: &MutableRepo,
+    ancestor_id: &CommitId,
+    descendant_id: &CommitId,
+) -> Result<bool> {
+    repo.index()
+        .is_ancestor(ancestor_id, descendant_id)
+        .map_err(|e| JjError::Internal {
+            message: format!("Index error: {}", e),
+        })
+}
+
+/// Check that a commit exists and is visible in the transaction
+///
+/// Commits rewritten or abandoned earlier in the same transaction are hidden
+/// and can no longer be used as rewrite sources or destinations.
+fn ensure_visible(repo: &MutableRepo, id: &CommitId) -> Result<()> {
+    repo.store().get_commit(id)?;
+    for head_id in repo.view().heads() {
+        if is_ancestor(repo, id, head_id)? {
+            return Ok(());
+        }
+    }
+    Err(JjError::InvalidArgument {
+        message: format!("Commit {} is not visible", id.hex()),
+    })
+}
+
+/// Evaluate a revset expression to commit IDs, in reverse topological order
+fn evaluate_ids(
+    repo: &MutableRepo,
+    expression: Arc<ResolvedRevsetExpression>,
+) -> Result<Vec<CommitId>> {
+    let revset_error = |e: jj_lib::revset::RevsetEvaluationError| JjError::Revset {
+        message: e.to_string(),
+    };
+    let revset = expression.evaluate(repo).map_err(revset_error)?;
+    let ids = revset.iter().collect::<std::result::Result<Vec<_>, _>>();
+    ids.map_err(revset_error)
+}
+
+/// Rebase commits to a new location in the graph, like `jj rebase`
+///
+/// Conflicts resulting from the rebase are recorded in the rebased commits.
+/// Descendants of rewritten commits elsewhere in the graph are rebased as
+/// well.
+pub(crate) fn rebase(
+    mut_repo: &mut MutableRepo,
+    target: &FfiRebaseTarget,
+    destination: &FfiRebaseDestination,
+) -> Result<FfiRebaseStats> {
+    let target_ids = match target {
+        FfiRebaseTarget::Revisions { commit_ids }
+        | FfiRebaseTarget::Source { commit_ids }
+        | FfiRebaseTarget::Branch { commit_ids } => parse_commit_ids(commit_ids)?,
+    };
+    if target_ids.is_empty() {
+        return Err(JjError::InvalidArgument {
+            message: "At least one source commit ID is required".to_string(),
+        });
+    }
+    let destination_ids = match destination {
+        FfiRebaseDestination::Onto { parent_ids } => parse_commit_ids(parent_ids)?,
+        FfiRebaseDestination::InsertAfter { commit_ids }
+        | FfiRebaseDestination::InsertBefore { commit_ids } => parse_commit_ids(commit_ids)?,
+    };
+    if destination_ids.is_empty() {
+        return Err(JjError::InvalidArgument {
+            message: "At least one destination commit ID is required".to_string(),
+        });
+    }
+
+    let root_commit_id = mut_repo.store().root_commit_id().clone();
+    for id in target_ids.iter().chain(&destination_ids) {
+        ensure_visible(mut_repo, id)?;
+    }
+    if target_ids.contains(&root_commit_id) {
+        return Err(JjError::InvalidArgument {
+            message: "Cannot rebase the root commit".to_string(),
+        });
+    }
+
+    let (new_parent_ids, new_child_ids) = match destination {
+        FfiRebaseDestination::Onto { .. } => (destination_ids, vec![]),
+        FfiRebaseDestination::InsertAfter { .. } => {
+            let children = RevsetExpression::commits(destination_ids.clone()).children();
+            let new_child_ids = evaluate_ids(mut_repo, children)?;
+            (destination_ids, new_child_ids)
+        }
+        FfiRebaseDestination::InsertBefore { .. } => {
+            if destination_ids.contains(&root_commit_id) {
+                return Err(JjError::InvalidArgument {
+                    message: "Cannot insert a commit before the root commit".to_string(),
+                });
+            }
+            let parents = RevsetExpression::commits(destination_ids.clone()).parents();
+            (evaluate_ids(mut_repo, parents)?, destination_ids)
+        }
+    };
+    for child_id in &new_child_ids {
+        for parent_id in &new_parent_ids {
+            if is_ancestor(mut_repo, child_id, parent_id)? {
+                return Err(JjError::InvalidArgument {
+                    message: format!(
+                        "Cannot insert between {} and its descendant {}",
+                        child_id.hex(),
+                        parent_id.hex()
+                    ),
+                });
+            }
+        }
+    }
+
+    let target = match target {
+        FfiRebaseTarget::Revisions { .. } => {
+            let commits = RevsetExpression::commits(target_ids);
+            MoveCommitsTarget::Commits(evaluate_ids(mut_repo, commits)?)
+        }
+        FfiRebaseTarget::Source { .. } => MoveCommitsTarget::Roots(target_ids),
+        FfiRebaseTarget::Branch { .. } => {
+            let roots = RevsetExpression::commits(new_parent_ids.clone())
+                .range(&RevsetExpression::commits(target_ids))
+                .roots();
+            MoveCommitsTarget::Roots(evaluate_ids(mut_repo, roots)?)
+        }
+    };
+    // Moved subtrees can't be rebased onto or inserted before their own
+    // commits
+    if let MoveCommitsTarget::Roots(root_ids) = &target {
+        for root_id in root_ids {
+            for parent_id in &new_parent_ids {
+                if is_ancestor(mut_repo, root_id, parent_id)? {
+                    return Err(JjError::InvalidArgument {
+                        message: format!(
+                            "Cannot rebase {} onto its descendant {}",
+                            root_id.hex(),
+                            parent_id.hex()
+                        ),
+                    });
+                }
+            }
+            for child_id in &new_child_ids {
+                if is_ancestor(mut_repo, root_id, child_id)? {
+                    return Err(JjError::InvalidArgument {
+                        message: format!(
+                            "Cannot insert {} before its descendant {}",
+                            root_id.hex(),
+                            child_id.hex()
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    let location = MoveCommitsLocation {
+        new_parent_ids,
+        new_child_ids,
+        target,
+    };
+    let stats = move_commits(mut_repo, &location, &RebaseOptions::default())?;
+    mut_repo.rebase_descendants()?;
+    Ok(FfiRebaseStats::from(&stats))
+}
+
+/// Combine the non-empty descriptions of squashed commits, destination first
+fn combine_descriptions<'a>(descriptions: impl IntoIterator<Item = &'a str>) -> String {
+    let descriptions: Vec<_> = descriptions
+        .into_iter()
+        .map(str::trim_end)
+        .filter(|description| !description.is_empty())
+        .collect();
+    if descriptions.is_empty() {
+        String::new()
+    } else {
+        descriptions.join("\n\n") + "\n"
+    }
+}
+
+/// Move the changes of a commit into another commit
+///
+/// If `destination_id` is `None`, the commit is squashed into its parent.
+/// Unless `keep_emptied` is set, the source commit is abandoned. Without an
+/// explicit description, the descriptions of the destination and the
+/// abandoned source are combined. Returns the rewritten destination.
+pub(crate) fn squash(
+    mut_repo: &mut MutableRepo,
+    source_id: &FfiCommitId,
+    destination_id: Option<&FfiCommitId>,
+    options: &FfiSquashOptions,
+) -> Result<Commit> {
+    let source_id = parse_commit_id(source_id)?;
+    ensure_visible(mut_repo, &source_id)?;
+    let store = mut_repo.store().clone();
+    let source = store.get_commit(&source_id)?;
+
+    let destination_id = match destination_id {
+        Some(id) => parse_commit_id(id)?,
+        None => match source.parent_ids() {
+            [parent_id] => parent_id.clone(),
+            _ => {
+                return Err(JjError::InvalidArgument {
+                    message: "Cannot squash a merge commit without a destination".to_string(),
+                });
+            }
+        },
+    };
+    ensure_visible(mut_repo, &destination_id)?;
+    if source_id == *store.root_commit_id() || destination_id == *store.root_commit_id() {
+        return Err(JjError::InvalidArgument {
+            message: "Cannot squash the root commit".to_string(),
+        });
+    }
+    if source_id == destination_id {
+        return Err(JjError::InvalidArgument {
+            message: "Source and destination must be different commits".to_string(),
+        });
+    }
+    let destination = store.get_commit(&destination_id)?;
+
+    let selection = CommitWithSelection {
+        selected_tree: source.tree(),
+        parent_tree: source.parent_tree(mut_repo)?,
+        commit: source,
+    };
+    let Some(squashed) =
+        squash_commits(mut_repo, &[selection], &destination, options.keep_emptied)?
+    else {
+        // Nothing to move, e.g. an empty source that is kept
+        return Ok(destination);
+    };
+
+    let description = match &options.description {
+        Some(description) => description.clone(),
+        None if options.use_destination_message => destination.description().to_string(),
+        None => combine_descriptions(
+            std::iter::once(destination.description()).chain(
+                squashed
+                    .abandoned_commits
+                    .iter()
+                    .map(|commit| commit.description()),
+            ),
+        ),
+    };
+    let new_commit = squashed
+        .commit_builder
+        .set_description(description)
+        .write()?;
+    mut_repo.rebase_descendants()?;
+    Ok(new_commit)
+}
+
+/// Split a commit into one with the changes to the given paths and one with
+/// the remaining changes, like `jj split <paths>`
+///
+/// Paths select files or whole directories. Both commits keep the original
+/// description. Descendants and bookmarks of the original commit move to the
+/// second commit.
+pub(crate) fn split(
+    mut_repo: &mut MutableRepo,
+    commit_id: &FfiCommitId,
+    paths: &[String],
+) -> Result<FfiSplitResult> {
+    let id = parse_commit_id(commit_id)?;
+    ensure_visible(mut_repo, &id)?;
+    if id == *mut_repo.store().root_commit_id() {
+        return Err(JjError::InvalidArgument {
+            message: "Cannot split the root commit".to_string(),
+        });
+    }
+    if paths.is_empty() {
+        return Err(JjError::InvalidArgument {
+            message: "At least one path is required to split a commit".to_string(),
+        });
+    }
+    let repo_paths = paths
+        .iter()
+        .map(|path| parse_repo_path(path))
+        .collect::<Result<Vec<_>>>()?;
+    let matcher = PrefixMatcher::new(&repo_paths);
+
+    let commit = mut_repo.store().get_commit(&id)?;
+    let parent_tree = commit.parent_tree(mut_repo)?;
+    let selected_tree = pollster::block_on(restore_tree(&commit.tree(), &parent_tree, &matcher))?;
+
+    let first = mut_repo
+        .rewrite_commit(&commit)
+        .set_tree(selected_tree)
+        .write()?;
+    let second = mut_repo
+        .rewrite_commit(&commit)
+        .set_parents(vec![first.id().clone()])
+        .set_tree(commit.tree())
+        .generate_new_change_id()
+        .write()?;
+    mut_repo.set_rewritten_commit(commit.id().clone(), second.id().clone());
+    mut_repo.rebase_descendants()?;
+
+    Ok(FfiSplitResult {
+        first: FfiCommit::from(&first),
+        second: FfiCommit::from(&second),
+    })
+}
+
+/// Move the changes of a commit into the destination ancestors that last
+/// modified the changed lines, like `jj absorb`
+///
+/// Changes that can't be attributed to a single destination stay in the
+/// source commit, which is abandoned if it becomes empty.
+pub(crate) fn absorb(
+    mut_repo: &mut MutableRepo,
+    source_id: &CommitId,
+    destinations: &Arc<ResolvedRevsetExpression>,
+) -> Result<FfiAbsorbResult> {
+    ensure_visible(mut_repo, source_id)?;
+    if source_id == mut_repo.store().root_commit_id() {
+        return Err(JjError::InvalidArgument {
+            message: "Cannot absorb the root commit".to_string(),
+        });
+    }
+    let commit = mut_repo.store().get_commit(source_id)?;
+    let source = AbsorbSource::from_commit(mut_repo, commit)?;
+    let selected_trees = pollster::block_on(split_hunks_to_trees(
+        mut_repo,
+        &source,
+        destinations,
+        &EverythingMatcher,
+    ))
+    .map_err(|e| JjError::Internal {
+        message: format!("Absorb error: {}", e),
+    })?;
+
+    let stats = absorb_hunks(mut_repo, &source, selected_trees.target_commits)?;
+    mut_repo.rebase_descendants()?;
+    Ok(FfiAbsorbResult {
+        rewritten_source: stats.rewritten_source.as_ref().map(FfiCommit::from),
+        rewritten_destinations: stats
+            .rewritten_destinations
+            .iter()
+            .map(FfiCommit::from)
+            .collect(),
+        num_rebased: stats.num_rebased as u32,
+        skipped_paths: selected_trees
+            .skipped_paths
+            .into_iter()
+            .map(|(path, reason)| FfiAbsorbSkippedPath {
+                path: path.as_internal_file_string().to_string(),
+                reason,
+            })
+            .collect(),
+    })
+}
+
+/// Make commits siblings of each other, like `jj parallelize`
+///
+/// Each commit gets the parents of its ancestors among the commits instead,
+/// and children of the commits get the parallelized ancestors among the
+/// commits as parents. Returns the parallelized commits, children first.
+///
+/// Fails if the commits are not connected, i.e. if a commit between two of
+/// them is not among them.
+pub(crate) fn parallelize(
+    mut_repo: &mut MutableRepo,
+    commit_ids: &[FfiCommitId],
+) -> Result<Vec<Commit>> {
+    let ids = parse_commit_ids(commit_ids)?;
+    for id in &ids {
+        ensure_visible(mut_repo, id)?;
+    }
+    if ids.contains(mut_repo.store().root_commit_id()) {
+        return Err(JjError::InvalidArgument {
+            message: "Cannot parallelize the root commit".to_string(),
+        });
+    }
+    let target_ids = evaluate_ids(mut_repo, RevsetExpression::commits(ids))?;
+    let connected_ids = evaluate_ids(
+        mut_repo,
+        RevsetExpression::commits(target_ids.clone()).connected(),
+    )?;
+    if connected_ids.len() != target_ids.len() {
+        return Err(JjError::InvalidArgument {
+            message: "Cannot parallelize since the target revisions are not connected".to_string(),
+        });
+    }
+
+    // Parents of the commits after parallelizing, with parents among the
+    // commits replaced by their own new parents
+    let mut new_target_parents: HashMap<CommitId, Vec<CommitId>> = HashMap::new();
+    // Commits that replace each of the commits as a parent of other commits
+    let mut new_child_parents: HashMap<CommitId, Vec<CommitId>> = HashMap::new();
+    for id in target_ids.iter().rev() {
+        let commit = mut_repo.store().get_commit(id)?;
+        let mut new_parents = Vec::new();
+        let mut child_parents = Vec::new();
+        for old_parent_id in commit.parent_ids() {
+            match new_target_parents.get(old_parent_id) {
+                Some(parent_ids) => {
+                    extend_unique(&mut new_parents, parent_ids);
+                    extend_unique(&mut child_parents, &new_child_parents[old_parent_id]);
+                }
+                None => extend_unique(&mut new_parents, [old_parent_id]),
+            }
+        }
+        extend_unique(&mut child_parents, [id]);
+        new_target_parents.insert(id.clone(), new_parents);
+        new_child_parents.insert(id.clone(), child_parents);
+    }
+
+    let mut parallelized = Vec::new();
+    mut_repo.transform_descendants(target_ids.clone(), async |mut rewriter| {
+        let old_commit_id = rewriter.old_commit().id().clone();
+        if let Some(new_parents) = new_target_parents.get(&old_commit_id) {
+            rewriter.set_new_rewritten_parents(new_parents);
+        } else {
+            let mut new_parents = Vec::new();
+            for old_parent_id in rewriter.old_commit().parent_ids() {
+                match new_child_parents.get(old_parent_id) {
+                    Some(parent_ids) => extend_unique(&mut new_parents, parent_ids),
+                    None => extend_unique(&mut new_parents, [old_parent_id]),
+                }
+            }
+            rewriter.set_new_rewritten_parents(&new_parents);
+        }
+        let is_target = new_target_parents.contains_key(&old_commit_id);
+        if rewriter.parents_changed() {
+            let commit = rewriter.rebase().await?.write()?;
+            if is_target {
+                parallelized.push((old_commit_id, commit));
+            }
+        } else if is_target {
+            parallelized.push((old_commit_id, rewriter.old_commit().clone()));
+        }
+        Ok(())
+    })?;
+    mut_repo.rebase_descendants()?;
+
+    // Descendants are visited parents first, so restore the children-first order
+    let mut commits = Vec::new();
+    for id in &target_ids {
+        if let Some((_, commit)) = parallelized.iter().find(|(old_id, _)| old_id == id) {
+            commits.push(commit.clone());
+        }
+    }
+    Ok(commits)
+}
+
+/// Append IDs that are not in `ids` yet, preserving their order
+fn extend_unique<'a>(ids: &mut Vec<CommitId>, new_ids: impl IntoIterator<Item = &'a CommitId>) {
+    for id in new_ids {
+        if !ids.contains(id) {
+            ids.push(id.clone());
+        }
+    }
+}
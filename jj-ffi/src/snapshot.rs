@@ -0,0 +1,410 @@
+//! Working-copy snapshot options for FFI
+//!
+//! Snapshot settings default to the `snapshot.max-new-file-size` and
+//! `snapshot.auto-track` config values, like in the `jj` CLI, and can be
+//! overridden per snapshot.
+//!
+//! With a filesystem monitor configured by `fsmonitor.backend`, snapshots
+//! only check the files the monitor reports as changed instead of walking
+//! the whole working copy.
+
+#[cfg(feature = "git")]
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt as _;
+use jj_lib::fileset::{self, FilesetDiagnostics};
+use jj_lib::fsmonitor::FsmonitorSettings;
+use jj_lib::gitignore::GitIgnoreFile;
+use jj_lib::matchers::{EverythingMatcher, Matcher, NothingMatcher, PrefixMatcher, UnionMatcher};
+use jj_lib::merged_tree::MergedTree;
+use jj_lib::repo_path::{RepoPath, RepoPathBuf, RepoPathUiConverter};
+use jj_lib::settings::{HumanByteSize, UserSettings};
+use jj_lib::working_copy::{SnapshotOptions, SnapshotProgress, SnapshotStats, UntrackedReason};
+use jj_lib::workspace::Workspace;
+
+use crate::cancel::{FfiCancellationToken, abort_if_cancelled};
+use crate::error::{JjError, Result};
+use crate::files::FfiConflictMarkerStyle;
+use crate::repo::FfiReadonlyRepo;
+
+/// Largest untracked file that starts being tracked on snapshot (1 MiB, as in the jj CLI)
+const DEFAULT_MAX_NEW_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Fileset of new files that start being tracked on snapshot, as in the jj CLI
+const DEFAULT_AUTO_TRACK: &str = "all()";
+
+/// Minimum time between progress updates while scanning
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Options for snapshotting the working copy
+#[derive(Clone, Default, uniffi::Record)]
+pub struct FfiSnapshotOptions {
+    /// Largest untracked file that starts being tracked, in bytes (uses
+    /// `snapshot.max-new-file-size` or 1 MiB if not provided)
+    pub max_new_file_size: Option<u64>,
+    /// Fileset of untracked files that start being tracked (uses
+    /// `snapshot.auto-track` or `all()` if not provided)
+    pub auto_track: Option<String>,
+    /// Conflict marker style used to parse conflicts in files (keeps the
+    /// workspace's style if not provided)
+    ///
+    /// The style also becomes the workspace's style for later working-copy
+    /// updates, like `FfiWorkspace::set_conflict_marker_style()`.
+    pub conflict_marker_style: Option<FfiConflictMarkerStyle>,
+    /// Receives the progress of scanning large working copies
+    pub progress: Option<Arc<dyn FfiSnapshotProgress>>,
+    /// Cancels the snapshot, checked while scanning the files
+    ///
+    /// A cancelled snapshot records nothing.
+    pub cancellation: Option<Arc<FfiCancellationToken>>,
+}
+
+/// Filesystem monitor used to find changed files when snapshotting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiFsmonitor {
+    /// No monitor; every snapshot walks the whole working copy
+    None,
+    /// Watchman, which must be installed on the system
+    ///
+    /// Snapshots fall back to walking the working copy if Watchman can't be
+    /// queried.
+    Watchman {
+        /// Whether to register a Watchman trigger that runs
+        /// `jj debug snapshot` when files change, which requires the `jj`
+        /// CLI
+        register_snapshot_trigger: bool,
+    },
+}
+
+impl FfiFsmonitor {
+    /// The `fsmonitor` config table selecting this monitor
+    pub(crate) fn config_toml(self) -> String {
+        let (backend, register_snapshot_trigger) = match self {
+            FfiFsmonitor::None => ("none", false),
+            FfiFsmonitor::Watchman {
+                register_snapshot_trigger,
+            } => ("watchman", register_snapshot_trigger),
+        };
+        format!(
+            r#"
+[fsmonitor]
+backend = "{}"
+
+[fsmonitor.watchman]
+register-snapshot-trigger = {}
+"#,
+            backend, register_snapshot_trigger
+        )
+    }
+}
+
+/// Get the filesystem monitor from the `fsmonitor.backend` setting
+///
+/// Invalid settings are reported as no monitor; loading the working copy
+/// fails with them anyway.
+pub(crate) fn fsmonitor(settings: &UserSettings) -> FfiFsmonitor {
+    match FsmonitorSettings::from_settings(settings) {
+        Ok(FsmonitorSettings::Watchman(config)) => FfiFsmonitor::Watchman {
+            register_snapshot_trigger: config.register_trigger,
+        },
+        _ => FfiFsmonitor::None,
+    }
+}
+
+/// Why a new file in the working copy was not tracked
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiUntrackedReason {
+    /// The file is larger than the maximum size of new files
+    FileTooLarge { size: u64, max_size: u64 },
+    /// The file doesn't match the auto-track fileset
+    FileNotAutoTracked,
+}
+
+impl From<&UntrackedReason> for FfiUntrackedReason {
+    fn from(reason: &UntrackedReason) -> Self {
+        match reason {
+            UntrackedReason::FileTooLarge { size, max_size } => Self::FileTooLarge {
+                size: *size,
+                max_size: *max_size,
+            },
+            UntrackedReason::FileNotAutoTracked => Self::FileNotAutoTracked,
+        }
+    }
+}
+
+/// A new file in the working copy that was left untracked
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct FfiUntrackedPath {
+    /// Repository-relative path in internal ("/"-separated) form
+    pub path: String,
+    /// Why the file was not tracked
+    pub reason: FfiUntrackedReason,
+}
+
+/// Result of snapshotting the working copy
+#[derive(uniffi::Record)]
+pub struct FfiSnapshotResult {
+    /// The repository after the snapshot
+    pub repo: Arc<FfiReadonlyRepo>,
+    /// New files that were left untracked
+    pub untracked_paths: Vec<FfiUntrackedPath>,
+}
+
+/// Convert the untracked paths of snapshot stats
+pub(crate) fn untracked_paths(stats: &SnapshotStats) -> Vec<FfiUntrackedPath> {
+    stats
+        .untracked_paths
+        .iter()
+        .map(|(path, reason)| FfiUntrackedPath {
+            path: path.as_internal_file_string().to_string(),
+            reason: FfiUntrackedReason::from(reason),
+        })
+        .collect()
+}
+
+/// Progress of a snapshot
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiSnapshotProgressUpdate {
+    /// Number of files scanned so far
+    pub files_scanned: u64,
+    /// Number of new files that started being tracked (zero until finished)
+    pub files_added: u64,
+    /// Repository-relative path of the file scanned last, in internal
+    /// ("/"-separated) form (empty once finished)
+    pub current_path: String,
+    /// Whether scanning finished, which is the last update
+    pub finished: bool,
+}
+
+/// Receives progress of snapshots, implemented by the host app
+///
+/// Methods are called on the threads scanning the working copy.
+#[uniffi::export(with_foreign)]
+pub trait FfiSnapshotProgress: Send + Sync {
+    /// Called at most every 50 ms while files are scanned, and once when
+    /// scanning finished
+    fn on_progress(&self, update: FfiSnapshotProgressUpdate);
+}
+
+/// Counts scanned files and reports them to the app's progress callback
+struct SnapshotProgressReporter {
+    progress: Arc<dyn FfiSnapshotProgress>,
+    files_scanned: AtomicU64,
+    next_update: Mutex<Instant>,
+}
+
+impl SnapshotProgressReporter {
+    fn file_scanned(&self, path: &RepoPath) {
+        let files_scanned = self.files_scanned.fetch_add(1, Ordering::Relaxed) + 1;
+        // Another thread is reporting; this file is counted in the next update
+        let Ok(mut next_update) = self.next_update.try_lock() else {
+            return;
+        };
+        let now = Instant::now();
+        if now < *next_update {
+            return;
+        }
+        *next_update = now + PROGRESS_INTERVAL;
+        self.progress.on_progress(FfiSnapshotProgressUpdate {
+            files_scanned,
+            files_added: 0,
+            current_path: path.as_internal_file_string().to_string(),
+            finished: false,
+        });
+    }
+}
+
+/// Ignore patterns applying to the whole working copy, like in the `jj` CLI
+///
+/// These are the user's global Git excludes, i.e. `core.excludesFile` or
+/// `$XDG_CONFIG_HOME/git/ignore`, followed by the Git repository's
+/// `info/exclude`.
+#[cfg(feature = "git")]
+fn base_ignores(workspace: &Workspace) -> Result<Arc<GitIgnoreFile>> {
+    let excludes_file_path = |config: &gix::config::File| -> Option<PathBuf> {
+        match config.string("core.excludesFile") {
+            // Like git, a relative path is relative to the workspace root
+            Some(value) => {
+                let path = std::str::from_utf8(&value)
+                    .ok()
+                    .map(jj_lib::file_util::expand_home_path)?;
+                Some(workspace.workspace_root().join(path))
+            }
+            None => xdg_config_home().map(|dir| dir.join("git").join("ignore")),
+        }
+    };
+    let ignore_error = |e: jj_lib::gitignore::GitIgnoreError| JjError::Workspace {
+        message: e.to_string(),
+    };
+
+    let mut git_ignores = GitIgnoreFile::empty();
+    let store = workspace.repo_loader().store();
+    if let Ok(git_backend) = jj_lib::git::get_git_backend(store) {
+        let git_repo = git_backend.git_repo();
+        if let Some(path) = excludes_file_path(&git_repo.config_snapshot()) {
+            git_ignores = git_ignores
+                .chain_with_file("", path)
+                .map_err(ignore_error)?;
+        }
+        let info_exclude = git_backend.git_repo_path().join("info").join("exclude");
+        git_ignores = git_ignores
+            .chain_with_file("", info_exclude)
+            .map_err(ignore_error)?;
+    } else if let Some(path) = gix::config::File::from_globals()
+        .ok()
+        .and_then(|config| excludes_file_path(&config))
+    {
+        git_ignores = git_ignores
+            .chain_with_file("", path)
+            .map_err(ignore_error)?;
+    }
+    Ok(git_ignores)
+}
+
+#[cfg(not(feature = "git"))]
+fn base_ignores(_workspace: &Workspace) -> Result<Arc<GitIgnoreFile>> {
+    Ok(GitIgnoreFile::empty())
+}
+
+#[cfg(feature = "git")]
+fn xdg_config_home() -> Option<PathBuf> {
+    match std::env::var("XDG_CONFIG_HOME") {
+        Ok(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => std::env::var("HOME")
+            .ok()
+            .map(|home| Path::new(&home).join(".config")),
+    }
+}
+
+/// Resolved snapshot settings, which own the matchers borrowed by `SnapshotOptions`
+pub(crate) struct SnapshotSettings {
+    base_ignores: Arc<GitIgnoreFile>,
+    start_tracking_matcher: Box<dyn Matcher>,
+    force_tracking_matcher: Box<dyn Matcher>,
+    max_new_file_size: u64,
+    progress: Option<Arc<SnapshotProgressReporter>>,
+    progress_callback: Option<Box<SnapshotProgress<'static>>>,
+    cancellation: Option<Arc<FfiCancellationToken>>,
+}
+
+impl SnapshotSettings {
+    /// Resolve the options against the workspace's settings
+    pub(crate) fn new(workspace: &Workspace, options: &FfiSnapshotOptions) -> Result<Self> {
+        let settings = workspace.settings();
+        let workspace_root = workspace.workspace_root();
+        let max_new_file_size = match options.max_new_file_size {
+            Some(size) => size,
+            None => settings
+                .get_value_with("snapshot.max-new-file-size", HumanByteSize::try_from)
+                .map(|size| size.0)
+                .unwrap_or(DEFAULT_MAX_NEW_FILE_SIZE),
+        };
+        let auto_track = match &options.auto_track {
+            Some(auto_track) => auto_track.clone(),
+            None => settings
+                .get_string("snapshot.auto-track")
+                .unwrap_or_else(|_| DEFAULT_AUTO_TRACK.to_string()),
+        };
+        let path_converter = RepoPathUiConverter::Fs {
+            cwd: workspace_root.to_owned(),
+            base: workspace_root.to_owned(),
+        };
+        let mut diagnostics = FilesetDiagnostics::new();
+        let expression =
+            fileset::parse(&mut diagnostics, &auto_track, &path_converter).map_err(|e| {
+                JjError::InvalidArgument {
+                    message: format!("Invalid auto-track fileset '{}': {}", auto_track, e),
+                }
+            })?;
+        let progress = options.progress.clone().map(|progress| {
+            Arc::new(SnapshotProgressReporter {
+                progress,
+                files_scanned: AtomicU64::new(0),
+                next_update: Mutex::new(Instant::now()),
+            })
+        });
+        // The callback is called for each scanned file, so it also checks
+        // for cancellation
+        let progress_callback = if progress.is_some() || options.cancellation.is_some() {
+            let reporter = progress.clone();
+            let cancellation = options.cancellation.clone();
+            let callback: Box<SnapshotProgress<'static>> = Box::new(move |path: &RepoPath| {
+                abort_if_cancelled(cancellation.as_deref());
+                if let Some(reporter) = &reporter {
+                    reporter.file_scanned(path);
+                }
+            });
+            Some(callback)
+        } else {
+            None
+        };
+        Ok(Self {
+            base_ignores: base_ignores(workspace)?,
+            start_tracking_matcher: expression.to_matcher(),
+            force_tracking_matcher: Box::new(NothingMatcher),
+            max_new_file_size,
+            progress,
+            progress_callback,
+            cancellation: options.cancellation.clone(),
+        })
+    }
+
+    /// Token cancelling snapshots with these settings
+    ///
+    /// The scan must run inside `catch_cancelled()`.
+    pub(crate) fn cancellation(&self) -> Option<&FfiCancellationToken> {
+        self.cancellation.as_deref()
+    }
+
+    /// Send the final progress update, counting the files added to the tree
+    pub(crate) fn finish_progress(&self, old_tree: &MergedTree, new_tree: &MergedTree) {
+        let Some(reporter) = &self.progress else {
+            return;
+        };
+        let files_added = pollster::block_on(
+            old_tree
+                .diff_stream(new_tree, &EverythingMatcher)
+                .filter(|entry| {
+                    let added = entry
+                        .values
+                        .as_ref()
+                        .is_ok_and(|values| values.before.is_absent());
+                    async move { added }
+                })
+                .count(),
+        );
+        reporter.progress.on_progress(FfiSnapshotProgressUpdate {
+            files_scanned: reporter.files_scanned.load(Ordering::Relaxed),
+            files_added: files_added as u64,
+            current_path: String::new(),
+            finished: true,
+        });
+    }
+
+    /// Also track the given paths, even if they are ignored or too large,
+    /// like `jj file track`
+    pub(crate) fn with_forced_paths(self, paths: &[RepoPathBuf]) -> Self {
+        Self {
+            start_tracking_matcher: Box::new(UnionMatcher::new(
+                self.start_tracking_matcher,
+                PrefixMatcher::new(paths),
+            )),
+            force_tracking_matcher: Box::new(PrefixMatcher::new(paths)),
+            ..self
+        }
+    }
+
+    /// Options for snapshotting with these settings
+    pub(crate) fn options(&self) -> SnapshotOptions<'_> {
+        SnapshotOptions {
+            base_ignores: Arc::clone(&self.base_ignores),
+            progress: self.progress_callback.as_deref(),
+            start_tracking_matcher: self.start_tracking_matcher.as_ref(),
+            force_tracking_matcher: self.force_tracking_matcher.as_ref(),
+            max_new_file_size: self.max_new_file_size,
+        }
+    }
+}
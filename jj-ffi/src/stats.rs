@@ -0,0 +1,179 @@
+//! Repository statistics for FFI
+//!
+//! This module aggregates commit history in Rust, so clients don't need to
+//! stream the whole log across the FFI boundary to build summaries.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use chrono::{DateTime, Datelike as _, Days, FixedOffset, NaiveDate};
+use jj_lib::backend::Timestamp;
+use jj_lib::repo::{ReadonlyRepo, Repo};
+use jj_lib::revset::RevsetIteratorExt;
+
+use crate::diff::tree_line_changes;
+use crate::error::{JjError, Result};
+use crate::files::conflict_marker_style;
+use crate::revset::{RevsetContext, RevsetWorkspace, resolve_revset};
+
+/// Commit statistics for a single author
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiContributorStats {
+    /// Author name (as of their most recent commit)
+    pub name: String,
+    /// Author email
+    pub email: String,
+    /// Number of commits authored
+    pub commit_count: u64,
+    /// Number of inserted lines (`None` unless line stats were requested)
+    pub insertions: Option<u64>,
+    /// Number of deleted lines (`None` unless line stats were requested)
+    pub deletions: Option<u64>,
+}
+
+/// Time span covered by an activity histogram bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiActivityBucket {
+    /// One calendar day
+    Day,
+    /// One week starting on Monday
+    Week,
+}
+
+/// Number of commits in an activity histogram bucket
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiActivityCount {
+    /// First day of the bucket, as "YYYY-MM-DD"
+    pub start_date: String,
+    /// Number of commits authored within the bucket
+    pub count: u64,
+}
+
+/// Compute per-author commit statistics for commits in a revset
+///
+/// Authors are grouped by case-insensitive email and sorted by commit count
+/// (descending). Line stats compare each commit against its parents.
+pub fn contributor_stats(
+    repo: &Arc<ReadonlyRepo>,
+    context: &RevsetContext,
+    revset_str: &str,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+    include_line_stats: bool,
+) -> Result<Vec<FfiContributorStats>> {
+    let expression = resolve_revset(repo.as_ref(), context, revset_str, user_email, workspace)?;
+    let revset = expression
+        .evaluate(repo.as_ref())
+        .map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+
+    let store = repo.store();
+    let marker_style = conflict_marker_style(repo.settings());
+    let mut stats: HashMap<String, FfiContributorStats> = HashMap::new();
+    for result in revset.iter().commits(store) {
+        let commit = result.map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+        if commit.id() == store.root_commit_id() {
+            continue;
+        }
+
+        let author = commit.author();
+        let entry =
+            stats
+                .entry(author.email.to_lowercase())
+                .or_insert_with(|| FfiContributorStats {
+                    name: author.name.clone(),
+                    email: author.email.clone(),
+                    commit_count: 0,
+                    insertions: include_line_stats.then_some(0),
+                    deletions: include_line_stats.then_some(0),
+                });
+        entry.commit_count += 1;
+
+        if include_line_stats {
+            let parent_tree = commit.parent_tree(repo.as_ref())?;
+            let (insertions, deletions) =
+                tree_line_changes(store, marker_style, &parent_tree, &commit.tree())?;
+            entry.insertions = entry.insertions.map(|n| n + insertions);
+            entry.deletions = entry.deletions.map(|n| n + deletions);
+        }
+    }
+
+    let mut stats: Vec<_> = stats.into_values().collect();
+    stats.sort_by(|a, b| {
+        b.commit_count
+            .cmp(&a.commit_count)
+            .then_with(|| a.email.cmp(&b.email))
+    });
+    Ok(stats)
+}
+
+/// Get the date of a timestamp in its own timezone
+fn local_date(timestamp: &Timestamp) -> Option<NaiveDate> {
+    let offset = FixedOffset::east_opt(timestamp.tz_offset * 60)?;
+    let datetime = DateTime::from_timestamp_millis(timestamp.timestamp.0)?;
+    Some(datetime.with_timezone(&offset).date_naive())
+}
+
+/// Get the first day of the bucket containing a date
+fn bucket_start(date: NaiveDate, bucket: FfiActivityBucket) -> NaiveDate {
+    match bucket {
+        FfiActivityBucket::Day => date,
+        FfiActivityBucket::Week => date - Days::new(date.weekday().num_days_from_monday().into()),
+    }
+}
+
+/// Count commits in a revset per day or week of their author date
+///
+/// Dates are taken in each commit's own timezone. Buckets are sorted by date,
+/// and empty buckets between the first and last one are included.
+pub fn activity_histogram(
+    repo: &Arc<ReadonlyRepo>,
+    context: &RevsetContext,
+    revset_str: &str,
+    user_email: &str,
+    workspace: Option<&RevsetWorkspace>,
+    bucket: FfiActivityBucket,
+) -> Result<Vec<FfiActivityCount>> {
+    let expression = resolve_revset(repo.as_ref(), context, revset_str, user_email, workspace)?;
+    let revset = expression
+        .evaluate(repo.as_ref())
+        .map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+
+    let store = repo.store();
+    let mut counts: BTreeMap<NaiveDate, u64> = BTreeMap::new();
+    for result in revset.iter().commits(store) {
+        let commit = result.map_err(|e| JjError::Revset {
+            message: e.to_string(),
+        })?;
+        if commit.id() == store.root_commit_id() {
+            continue;
+        }
+        let Some(date) = local_date(&commit.author().timestamp) else {
+            continue;
+        };
+        *counts.entry(bucket_start(date, bucket)).or_default() += 1;
+    }
+
+    let (Some(first), Some(last)) = (counts.first_key_value(), counts.last_key_value()) else {
+        return Ok(Vec::new());
+    };
+    let step = match bucket {
+        FfiActivityBucket::Day => Days::new(1),
+        FfiActivityBucket::Week => Days::new(7),
+    };
+    let (mut date, last) = (*first.0, *last.0);
+    let mut histogram = Vec::new();
+    while date <= last {
+        histogram.push(FfiActivityCount {
+            start_date: date.format("%Y-%m-%d").to_string(),
+            count: counts.get(&date).copied().unwrap_or(0),
+        });
+        date = date + step;
+    }
+    Ok(histogram)
+}
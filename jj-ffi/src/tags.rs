@@ -0,0 +1,30 @@
+//! Tag queries for FFI
+
+use std::sync::Arc;
+
+use jj_lib::repo::ReadonlyRepo;
+
+use crate::types::FfiCommitId;
+
+/// A local tag in the repository view
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiTag {
+    /// Tag name
+    pub name: String,
+    /// Target commit (`None` if the tag is conflicted)
+    pub target: Option<FfiCommitId>,
+    /// Whether the tag points to more than one commit
+    pub is_conflicted: bool,
+}
+
+/// List the local tags in the repository view, sorted by name
+pub fn tags(repo: &Arc<ReadonlyRepo>) -> Vec<FfiTag> {
+    repo.view()
+        .local_tags()
+        .map(|(name, target)| FfiTag {
+            name: name.as_str().to_string(),
+            target: target.as_normal().map(FfiCommitId::from),
+            is_conflicted: target.has_conflict(),
+        })
+        .collect()
+}
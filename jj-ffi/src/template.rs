@@ -0,0 +1,208 @@
+//! Commit template rendering for FFI
+//!
+//! jj's template language is implemented by jj-cli rather than jj-lib, so
+//! templates are parsed and evaluated by jj-cli's templater. Templates
+//! written for `jj log -T` render the same, including the aliases of the
+//! `template-aliases` config like `builtin_log_compact`. Output is plain
+//! text; labels are accepted but not rendered.
+
+use std::iter;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use jj_cli::commit_templater::{CommitTemplateLanguage, CommitTemplateLanguageExtension};
+use jj_cli::revset_util::parse_immutable_heads_expression;
+use jj_cli::template_builder;
+use jj_cli::template_parser::{TemplateAliasesMap, TemplateDiagnostics, TemplateParseError};
+use jj_lib::backend::CommitId;
+use jj_lib::commit::Commit;
+use jj_lib::id_prefix::IdPrefixContext;
+use jj_lib::ref_name::WorkspaceName;
+use jj_lib::repo::{ReadonlyRepo, Repo};
+use jj_lib::repo_path::RepoPathUiConverter;
+use jj_lib::revset::RevsetDiagnostics;
+use jj_lib::settings::UserSettings;
+
+use crate::error::{JjError, Result};
+use crate::files::conflict_marker_style;
+use crate::revset::{RevsetContext, RevsetWorkspace};
+use crate::types::FfiCommitId;
+
+/// Maximum nesting depth of a template
+///
+/// Templates are parsed and evaluated recursively, so deeply nested ones
+/// could overflow the stack of the calling thread.
+const MAX_TEMPLATE_DEPTH: usize = 32;
+
+fn template_error(message: impl Into<String>) -> JjError {
+    JjError::Template {
+        message: message.into(),
+    }
+}
+
+/// Describe a parse error along with the errors it was caused by, e.g. in
+/// an alias
+fn template_parse_error(err: &TemplateParseError) -> JjError {
+    let messages: Vec<String> = iter::successors(Some(err), |err| err.origin())
+        .map(|err| err.to_string())
+        .collect();
+    template_error(format!(
+        "Failed to parse template: {}",
+        messages.join("\nCaused by: ")
+    ))
+}
+
+/// Reject templates nested deeper than `MAX_TEMPLATE_DEPTH`
+///
+/// Besides parentheses, brackets and braces, each method call of a chain and
+/// each operator other than `++` nests the expression one level deeper.
+/// String literals are skipped, so brackets in them don't count.
+fn check_template_depth(template_text: &str) -> Result<()> {
+    // Method calls and operators since the last `++` or comma, saved when
+    // entering brackets and restored when leaving them
+    let mut chain = 0usize;
+    let mut outer_chains = Vec::new();
+    let mut outer_depth = 0usize;
+    let mut chars = template_text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '\'' => {
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                }
+            }
+            '(' | '[' | '{' => {
+                outer_depth += chain + 1;
+                outer_chains.push(chain);
+                chain = 0;
+            }
+            ')' | ']' | '}' => {
+                if let Some(outer_chain) = outer_chains.pop() {
+                    outer_depth -= outer_chain + 1;
+                    chain = outer_chain;
+                }
+            }
+            '.' | '!' | '-' | '|' | '&' | '=' | '<' | '>' | '*' | '/' | '%' => chain += 1,
+            ',' | '+' => chain = 0,
+            _ => {}
+        }
+        if outer_depth + chain > MAX_TEMPLATE_DEPTH {
+            return Err(template_error(format!(
+                "Template is nested deeper than {} levels",
+                MAX_TEMPLATE_DEPTH
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Build the template aliases of the repository's settings
+///
+/// Aliases are read from the `template-aliases` tables in order of
+/// precedence, starting with the defaults of the `jj` CLI.
+fn template_aliases(settings: &UserSettings) -> Result<TemplateAliasesMap> {
+    let mut aliases_map = TemplateAliasesMap::new();
+    let invalid_alias = |decl: &str, message: String| {
+        template_error(format!("Invalid template alias '{}': {}", decl, message))
+    };
+    for layer in settings.config().layers() {
+        let table = match layer.look_up_table("template-aliases") {
+            Ok(Some(table)) => table,
+            Ok(None) => continue,
+            Err(_) => {
+                return Err(template_error(
+                    "Invalid template-aliases config: expected a table",
+                ));
+            }
+        };
+        for (decl, item) in table.iter() {
+            let defn = item
+                .as_str()
+                .ok_or_else(|| invalid_alias(decl, "expected a string".to_string()))?;
+            aliases_map
+                .insert(decl, defn)
+                .map_err(|e| invalid_alias(decl, e.to_string()))?;
+        }
+    }
+    Ok(aliases_map)
+}
+
+/// Render a commit with a template written in jj's template language
+pub fn render_commit_template(
+    repo: &Arc<ReadonlyRepo>,
+    context: &RevsetContext,
+    workspace: Option<&RevsetWorkspace>,
+    commit_id: &FfiCommitId,
+    template_text: &str,
+) -> Result<String> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let commit = repo.store().get_commit(&id)?;
+    render_template(repo.as_ref(), context, workspace, &commit, template_text)
+}
+
+/// Render a commit with a template, e.g. a commit being built in a transaction
+///
+/// Without a workspace, paths are relative to the repository root and
+/// `working_copies` refers to the default workspace.
+pub(crate) fn render_template(
+    repo: &dyn Repo,
+    context: &RevsetContext,
+    workspace: Option<&RevsetWorkspace>,
+    commit: &Commit,
+    template_text: &str,
+) -> Result<String> {
+    check_template_depth(template_text)?;
+    let settings = repo.base_repo().settings();
+    let aliases_map = template_aliases(settings)?;
+
+    let default_path_converter = RepoPathUiConverter::Fs {
+        cwd: PathBuf::new(),
+        base: PathBuf::new(),
+    };
+    let path_converter = workspace.map_or(&default_path_converter, RevsetWorkspace::path_converter);
+    let workspace_name = workspace.map_or(WorkspaceName::DEFAULT, RevsetWorkspace::name);
+    let parse_context = context.parse_context(settings.user_email(), workspace);
+    let immutable_heads =
+        parse_immutable_heads_expression(&mut RevsetDiagnostics::new(), &parse_context).map_err(
+            |e| JjError::Revset {
+                message: format!("Invalid immutable_heads(): {}", e),
+            },
+        )?;
+    let id_prefix_context = IdPrefixContext::new(Arc::clone(context.extensions()));
+    let extensions: [Arc<dyn CommitTemplateLanguageExtension>; 0] = [];
+    let language = CommitTemplateLanguage::new(
+        repo,
+        path_converter,
+        workspace_name,
+        parse_context,
+        &id_prefix_context,
+        immutable_heads.ancestors(),
+        conflict_marker_style(settings),
+        &extensions,
+    );
+
+    let template = template_builder::parse(
+        &language,
+        &mut TemplateDiagnostics::new(),
+        template_text,
+        &aliases_map,
+    )
+    .map_err(|e| template_parse_error(&e))?;
+    let output = template.format_plain_text(commit);
+    Ok(String::from_utf8_lossy(&output).into_owned())
+}
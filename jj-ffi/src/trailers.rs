@@ -0,0 +1,182 @@
+//! Commit description trailers for FFI
+//!
+//! Trailers are the `Key: Value` lines in the last paragraph of a commit
+//! description, like `Signed-off-by` or `Co-authored-by`. Like in the `jj`
+//! CLI, trailers rendered from the `templates.commit_trailers` config are
+//! added when a commit is described.
+
+use jj_lib::commit::Commit;
+use jj_lib::commit_builder::DetachedCommitBuilder;
+use jj_lib::repo::MutableRepo;
+use jj_lib::settings::UserSettings;
+use jj_lib::trailer::{Trailer, parse_description_trailers, parse_trailers};
+
+use crate::error::{JjError, Result};
+use crate::revset::RevsetContext;
+
+/// A `Key: Value` trailer of a commit description
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct FfiTrailer {
+    /// Key, e.g. `Signed-off-by`
+    pub key: String,
+    /// Value, which may span multiple lines
+    pub value: String,
+}
+
+impl From<Trailer> for FfiTrailer {
+    fn from(trailer: Trailer) -> Self {
+        Self {
+            key: trailer.key,
+            value: trailer.value,
+        }
+    }
+}
+
+fn validate_trailers(trailers: &[FfiTrailer]) -> Result<()> {
+    for trailer in trailers {
+        let valid_key = !trailer.key.is_empty()
+            && trailer
+                .key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !valid_key {
+            return Err(JjError::InvalidArgument {
+                message: format!("Invalid trailer key '{}'", trailer.key),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Remove the trailer paragraph from a description, keeping the body
+fn strip_trailers(description: &str) -> &str {
+    let body = description.trim_end();
+    if parse_description_trailers(body).is_empty() {
+        return body;
+    }
+    match body.rfind("\n\n") {
+        Some(end) => body[..end].trim_end(),
+        None => "",
+    }
+}
+
+/// Append trailers to a description, starting a trailer paragraph if needed
+///
+/// Trailers already present in the description are skipped.
+fn append_trailers(description: &str, trailers: &[FfiTrailer]) -> String {
+    let existing: Vec<FfiTrailer> = parse_description_trailers(description)
+        .into_iter()
+        .map(FfiTrailer::from)
+        .collect();
+    let mut new_trailers: Vec<&FfiTrailer> = Vec::new();
+    for trailer in trailers {
+        if !existing.contains(trailer) && !new_trailers.contains(&trailer) {
+            new_trailers.push(trailer);
+        }
+    }
+    if new_trailers.is_empty() {
+        return description.to_string();
+    }
+
+    let mut description = description.trim_end().to_string();
+    if !description.is_empty() {
+        description.push('\n');
+    }
+    // A blank line separates the trailer paragraph from the subject and body.
+    // Trailers of an empty description start with one too, so that they are
+    // parsed as trailers.
+    if existing.is_empty() {
+        description.push('\n');
+    }
+    for trailer in new_trailers {
+        description.push_str(&format!("{}: {}\n", trailer.key, trailer.value));
+    }
+    description
+}
+
+/// Parse the trailers of a commit description
+#[uniffi::export]
+pub fn description_trailers(description: String) -> Vec<FfiTrailer> {
+    parse_description_trailers(&description)
+        .into_iter()
+        .map(FfiTrailer::from)
+        .collect()
+}
+
+/// Add trailers to a commit description
+///
+/// Trailers with the same key and value as an existing trailer are skipped,
+/// so adding e.g. a `Signed-off-by` trailer twice is harmless.
+#[uniffi::export]
+pub fn add_description_trailers(description: String, trailers: Vec<FfiTrailer>) -> Result<String> {
+    validate_trailers(&trailers)?;
+    Ok(append_trailers(&description, &trailers))
+}
+
+/// Replace the trailers of a commit description
+///
+/// Existing trailers are removed, so trailers can be modified or removed by
+/// editing the list returned by `description_trailers()`.
+#[uniffi::export]
+pub fn set_description_trailers(description: String, trailers: Vec<FfiTrailer>) -> Result<String> {
+    validate_trailers(&trailers)?;
+    let body = strip_trailers(&description);
+    let mut description = if body.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", body)
+    };
+    if !trailers.is_empty() {
+        description = append_trailers(&description, &trailers);
+    }
+    Ok(description)
+}
+
+/// Get the `templates.commit_trailers` template, if configured
+fn commit_trailers_template(settings: &UserSettings) -> Option<String> {
+    settings
+        .get_string("templates.commit_trailers")
+        .ok()
+        .filter(|template| !template.trim().is_empty())
+}
+
+/// Render the `templates.commit_trailers` template into trailers
+fn configured_trailers(
+    mut_repo: &MutableRepo,
+    commit: &Commit,
+    template: &str,
+) -> Result<Vec<FfiTrailer>> {
+    let context = RevsetContext::new(mut_repo)?;
+    let rendered = crate::template::render_template(mut_repo, &context, None, commit, template)?;
+    let trailers = parse_trailers(&rendered).map_err(|e| JjError::Template {
+        message: format!("Invalid trailers from templates.commit_trailers: {}", e),
+    })?;
+    Ok(trailers.into_iter().map(FfiTrailer::from).collect())
+}
+
+/// Write a commit, adding the trailers configured by
+/// `templates.commit_trailers` to its description
+///
+/// The template is rendered against the commit as it will be written, like
+/// in the `jj` CLI, and must produce a trailer paragraph. If it can't be
+/// rendered or doesn't produce trailers, the commit is written without them
+/// and a warning is logged. Empty descriptions are left unchanged.
+pub(crate) fn write_with_configured_trailers(
+    mut_repo: &mut MutableRepo,
+    mut builder: DetachedCommitBuilder,
+) -> Result<Commit> {
+    let template = commit_trailers_template(mut_repo.base_repo().settings());
+    if let Some(template) = template.filter(|_| !builder.description().is_empty()) {
+        let commit = builder.write_hidden()?;
+        match configured_trailers(mut_repo, &commit, &template) {
+            Ok(trailers) => {
+                let description = append_trailers(builder.description(), &trailers);
+                builder.set_description(description);
+            }
+            Err(e) => {
+                tracing::warn!("Skipping trailers from templates.commit_trailers: {}", e);
+            }
+        }
+    }
+    Ok(builder.write(mut_repo)?)
+}
@@ -1,17 +1,86 @@
 //! Transaction operations for FFI
 
+use std::collections::{HashMap, HashSet};
+use std::slice;
 use std::sync::{Arc, Mutex};
 
 use jj_lib::backend::{CommitId, Signature, Timestamp};
-use jj_lib::repo::Repo;
+use jj_lib::evolution::accumulate_predecessors;
+use jj_lib::repo::{ReadonlyRepo, Repo};
+use jj_lib::rewrite::merge_commit_trees;
 use jj_lib::transaction::Transaction;
 
+use crate::bookmarks::FfiBookmark;
+use crate::commit_builder::FfiCommitBuilder;
 use crate::error::{JjError, Result};
+use crate::operation::IDEMPOTENCY_KEY_TAG;
 use crate::repo::FfiReadonlyRepo;
-use crate::types::{FfiCommit, FfiCommitId, FfiNewCommit, FfiRewriteCommit};
+use crate::revset::{FfiRevsetOptions, RevsetContext, RevsetContextCache, RevsetWorkspace};
+use crate::rewrite::{
+    FfiAbsorbResult, FfiRebaseDestination, FfiRebaseStats, FfiRebaseTarget, FfiSplitResult,
+    FfiSquashOptions,
+};
+use crate::trailers::FfiTrailer;
+use crate::types::{FfiChangeId, FfiCommit, FfiCommitId, FfiNewCommit, FfiRewriteCommit};
+
+/// Result of committing a transaction
+#[derive(uniffi::Record)]
+pub struct FfiTransactionResult {
+    /// The repository after the transaction
+    pub repo: Arc<FfiReadonlyRepo>,
+    /// Commits rewritten in the transaction, mapped to their new commits
+    ///
+    /// A commit usually maps to a single new commit, or to several if it was
+    /// rewritten divergently (e.g. split). Abandoned commits are not included.
+    pub rewritten_commits: HashMap<FfiCommitId, Vec<FfiCommitId>>,
+}
+
+/// Map the commits rewritten by the repository's operation to their new commits
+fn rewritten_commits(
+    repo: &ReadonlyRepo,
+    base_repo: &ReadonlyRepo,
+) -> Result<HashMap<FfiCommitId, Vec<FfiCommitId>>> {
+    let Some(recorded) = &repo.operation().store_operation().commit_predecessors else {
+        return Ok(HashMap::new());
+    };
+    // Commits created and rewritten again within the transaction
+    let intermediate: HashSet<&CommitId> = recorded
+        .values()
+        .flatten()
+        .filter(|id| recorded.contains_key(*id))
+        .collect();
+    let predecessors = accumulate_predecessors(
+        slice::from_ref(repo.operation()),
+        slice::from_ref(base_repo.operation()),
+    )
+    .map_err(|e| JjError::Internal {
+        message: format!("Failed to resolve rewritten commits: {}", e),
+    })?;
+
+    let mut rewritten: HashMap<FfiCommitId, Vec<FfiCommitId>> = HashMap::new();
+    for (new_id, old_ids) in &predecessors {
+        if intermediate.contains(new_id) {
+            continue;
+        }
+        for old_id in old_ids {
+            rewritten
+                .entry(FfiCommitId::from(old_id))
+                .or_default()
+                .push(FfiCommitId::from(new_id));
+        }
+    }
+    Ok(rewritten)
+}
 
 /// A transaction for making changes to a repository
 ///
+/// # Snapshot isolation
+/// Changes made in a transaction are invisible outside of it until it is
+/// committed. The `FfiReadonlyRepo` the transaction was started from, and any
+/// other handle to the same operation, keeps answering queries from that
+/// operation while the transaction is in flight and after it is committed.
+/// Committing returns a new `FfiReadonlyRepo` for the new operation.
+///
 /// # Safety
 /// The Transaction type from jj-lib is not Send + Sync, but we wrap it in a Mutex
 /// to ensure synchronized access. All FFI operations acquire the lock before accessing
@@ -19,6 +88,9 @@ use crate::types::{FfiCommit, FfiCommitId, FfiNewCommit, FfiRewriteCommit};
 #[derive(uniffi::Object)]
 pub struct FfiTransaction {
     inner: Mutex<Option<Transaction>>,
+    base_repo: Arc<ReadonlyRepo>,
+    workspace: Option<RevsetWorkspace>,
+    revset_context: Arc<RevsetContextCache>,
 }
 
 // SAFETY: FfiTransaction is protected by a Mutex, ensuring synchronized access
@@ -27,12 +99,27 @@ unsafe impl Send for FfiTransaction {}
 unsafe impl Sync for FfiTransaction {}
 
 impl FfiTransaction {
-    pub(crate) fn new(transaction: Transaction) -> Self {
+    /// Wrap a transaction, sharing the revset context of the repository
+    /// handle it was started from
+    pub(crate) fn new(
+        transaction: Transaction,
+        workspace: Option<RevsetWorkspace>,
+        revset_context: Arc<RevsetContextCache>,
+    ) -> Self {
+        let base_repo = Arc::clone(transaction.base_repo());
         Self {
             inner: Mutex::new(Some(transaction)),
+            base_repo,
+            workspace,
+            revset_context,
         }
     }
 
+    /// Get the context for parsing revsets, preparing it on first use
+    fn revset_context(&self) -> Result<Arc<RevsetContext>> {
+        self.revset_context.get(self.base_repo.as_ref())
+    }
+
     fn with_transaction<T, F>(&self, f: F) -> Result<T>
     where
         F: FnOnce(&Transaction) -> Result<T>,
@@ -46,7 +133,7 @@ impl FfiTransaction {
         f(tx)
     }
 
-    fn with_transaction_mut<T, F>(&self, f: F) -> Result<T>
+    pub(crate) fn with_transaction_mut<T, F>(&self, f: F) -> Result<T>
     where
         F: FnOnce(&mut Transaction) -> Result<T>,
     {
@@ -71,6 +158,127 @@ impl FfiTransaction {
 
 #[uniffi::export]
 impl FfiTransaction {
+    /// Get the repository at the operation the transaction was started from
+    ///
+    /// Remains available after the transaction is committed or discarded.
+    pub fn base_repo(&self) -> Arc<FfiReadonlyRepo> {
+        Arc::new(FfiReadonlyRepo::with_workspace(
+            Arc::clone(&self.base_repo),
+            self.workspace.clone(),
+        ))
+    }
+
+    /// Get a commit by its commit ID, including commits created in this transaction
+    pub fn get_commit(&self, commit_id: &FfiCommitId) -> Result<FfiCommit> {
+        let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+            message: format!("Invalid commit ID: {}", e),
+        })?;
+        self.with_transaction(|tx| {
+            let commit = tx.repo().store().get_commit(&id)?;
+            Ok(FfiCommit::from(&commit))
+        })
+    }
+
+    /// Evaluate a revset expression against the in-progress state of the transaction
+    pub fn evaluate_revset(
+        &self,
+        revset_str: String,
+        user_email: String,
+    ) -> Result<Vec<FfiCommitId>> {
+        self.evaluate_revset_with_options(revset_str, user_email, FfiRevsetOptions::default())
+    }
+
+    /// Same as `evaluate_revset`, with options like a cancellation token
+    pub fn evaluate_revset_with_options(
+        &self,
+        revset_str: String,
+        user_email: String,
+        options: FfiRevsetOptions,
+    ) -> Result<Vec<FfiCommitId>> {
+        self.with_transaction(|tx| {
+            crate::revset::evaluate_revset(
+                tx.repo(),
+                self.revset_context()?.as_ref(),
+                &revset_str,
+                &user_email,
+                self.workspace.as_ref(),
+                &options,
+            )
+        })
+    }
+
+    /// Evaluate a revset expression against the in-progress state of the
+    /// transaction and return matching commits
+    pub fn evaluate_revset_to_commits(
+        &self,
+        revset_str: String,
+        user_email: String,
+    ) -> Result<Vec<FfiCommit>> {
+        self.evaluate_revset_to_commits_with_options(
+            revset_str,
+            user_email,
+            FfiRevsetOptions::default(),
+        )
+    }
+
+    /// Same as `evaluate_revset_to_commits`, with options like a cancellation token
+    pub fn evaluate_revset_to_commits_with_options(
+        &self,
+        revset_str: String,
+        user_email: String,
+        options: FfiRevsetOptions,
+    ) -> Result<Vec<FfiCommit>> {
+        self.with_transaction(|tx| {
+            crate::revset::evaluate_revset_to_commits(
+                tx.repo(),
+                self.revset_context()?.as_ref(),
+                &revset_str,
+                &user_email,
+                self.workspace.as_ref(),
+                &options,
+            )
+        })
+    }
+
+    /// Evaluate a revset expression against the in-progress state of the
+    /// transaction and return the change IDs of matching commits, without
+    /// duplicates, in revset order
+    pub fn evaluate_revset_to_change_ids(
+        &self,
+        revset_str: String,
+        user_email: String,
+    ) -> Result<Vec<FfiChangeId>> {
+        self.evaluate_revset_to_change_ids_with_options(
+            revset_str,
+            user_email,
+            FfiRevsetOptions::default(),
+        )
+    }
+
+    /// Same as `evaluate_revset_to_change_ids`, with options like a cancellation token
+    pub fn evaluate_revset_to_change_ids_with_options(
+        &self,
+        revset_str: String,
+        user_email: String,
+        options: FfiRevsetOptions,
+    ) -> Result<Vec<FfiChangeId>> {
+        self.with_transaction(|tx| {
+            crate::revset::evaluate_revset_to_change_ids(
+                tx.repo(),
+                self.revset_context()?.as_ref(),
+                &revset_str,
+                &user_email,
+                self.workspace.as_ref(),
+                &options,
+            )
+        })
+    }
+
+    /// List the local bookmarks in the in-progress state of the transaction
+    pub fn bookmarks(&self) -> Result<Vec<FfiBookmark>> {
+        self.with_transaction(|tx| Ok(crate::bookmarks::local_bookmarks(tx.repo())))
+    }
+
     /// Create a new commit with an empty tree (for creating empty commits)
     pub fn create_empty_commit(&self, new_commit: FfiNewCommit) -> Result<FfiCommit> {
         self.with_transaction_mut(|tx| {
@@ -80,7 +288,7 @@ impl FfiTransaction {
             let parent_ids: Vec<CommitId> = new_commit
                 .parent_ids
                 .iter()
-                .map(|id| CommitId::try_from(id))
+                .map(CommitId::try_from)
                 .collect::<std::result::Result<Vec<_>, _>>()
                 .map_err(|e| JjError::InvalidArgument {
                     message: format!("Invalid parent commit ID: {}", e),
@@ -121,6 +329,71 @@ impl FfiTransaction {
         })
     }
 
+    /// Start building a new commit on the given parents
+    ///
+    /// Unless a tree is set, the commit gets the merged tree of its parents,
+    /// like `jj new`.
+    pub fn new_commit_builder(
+        self: Arc<Self>,
+        parent_ids: Vec<FfiCommitId>,
+    ) -> Arc<FfiCommitBuilder> {
+        Arc::new(FfiCommitBuilder::new_commit(self, parent_ids))
+    }
+
+    /// Start building a rewrite of an existing commit
+    ///
+    /// The rewritten commit keeps the properties that aren't set on the
+    /// builder, except for the committer. The root commit can't be rewritten.
+    pub fn rewrite_commit_builder(
+        self: Arc<Self>,
+        commit_id: &FfiCommitId,
+    ) -> Result<Arc<FfiCommitBuilder>> {
+        let commit_id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+            message: format!("Invalid commit ID: {}", e),
+        })?;
+        if commit_id == *self.base_repo.store().root_commit_id() {
+            return Err(JjError::InvalidArgument {
+                message: "Cannot rewrite the root commit".to_string(),
+            });
+        }
+        Ok(Arc::new(FfiCommitBuilder::rewrite_commit(self, commit_id)))
+    }
+
+    /// Create a merge commit whose tree merges the parents' trees, like `jj new a b`
+    ///
+    /// Conflicting changes in the parents result in a conflicted tree.
+    pub fn merge(&self, parent_ids: Vec<FfiCommitId>, description: String) -> Result<FfiCommit> {
+        self.with_transaction_mut(|tx| {
+            let parent_ids: Vec<CommitId> = parent_ids
+                .iter()
+                .map(CommitId::try_from)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| JjError::InvalidArgument {
+                    message: format!("Invalid parent commit ID: {}", e),
+                })?;
+
+            if parent_ids.is_empty() {
+                return Err(JjError::InvalidArgument {
+                    message: "At least one parent commit ID is required".to_string(),
+                });
+            }
+
+            let parents = parent_ids
+                .iter()
+                .map(|id| tx.repo().store().get_commit(id))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let tree = pollster::block_on(merge_commit_trees(tx.repo(), &parents))?;
+
+            let commit = tx
+                .repo_mut()
+                .new_commit(parent_ids, tree)
+                .set_description(&description)
+                .write()?;
+
+            Ok(FfiCommit::from(&commit))
+        })
+    }
+
     /// Create a new commit with the same tree as a parent commit
     pub fn create_commit_from_parent(
         &self,
@@ -161,6 +434,9 @@ impl FfiTransaction {
     }
 
     /// Rewrite an existing commit with new properties
+    ///
+    /// Descendants are rebased onto the new commit by `rebase_descendants()`
+    /// or when the transaction is committed.
     pub fn rewrite_commit(&self, rewrite: FfiRewriteCommit) -> Result<FfiCommit> {
         self.with_transaction_mut(|tx| {
             // Convert commit ID
@@ -189,7 +465,7 @@ impl FfiTransaction {
             if let Some(new_parent_ids) = &rewrite.new_parent_ids {
                 let parent_ids: Vec<CommitId> = new_parent_ids
                     .iter()
-                    .map(|id| CommitId::try_from(id))
+                    .map(CommitId::try_from)
                     .collect::<std::result::Result<Vec<_>, _>>()
                     .map_err(|e| JjError::InvalidArgument {
                         message: format!("Invalid parent commit ID: {}", e),
@@ -204,9 +480,55 @@ impl FfiTransaction {
                 builder = builder.set_parents(parent_ids);
             }
 
-            let new_commit = builder.write().map_err(|e| JjError::Backend {
-                message: e.to_string(),
-            })?;
+            if let Some(committer) = &rewrite.new_committer {
+                builder = builder.set_committer(Signature::from(committer));
+            }
+
+            if rewrite.reset_author {
+                if rewrite.new_author.is_some() {
+                    return Err(JjError::InvalidArgument {
+                        message: "Cannot both set and reset the author".to_string(),
+                    });
+                }
+                let author = builder.committer().clone();
+                builder = builder.set_author(author);
+            } else if let Some(author) = &rewrite.new_author {
+                builder = builder.set_author(Signature::from(author));
+            }
+
+            if let Some(timestamp) = &rewrite.new_committer_timestamp {
+                let mut committer = builder.committer().clone();
+                committer.timestamp = Timestamp::from(timestamp);
+                builder = builder.set_committer(committer);
+            }
+
+            let author_timestamp = match (
+                &rewrite.new_author_timestamp,
+                rewrite.update_author_timestamp,
+            ) {
+                (Some(_), true) => {
+                    return Err(JjError::InvalidArgument {
+                        message: "Cannot both set and update the author timestamp".to_string(),
+                    });
+                }
+                (Some(timestamp), false) => Some(Timestamp::from(timestamp)),
+                (None, true) => Some(Timestamp::now()),
+                (None, false) => None,
+            };
+            if let Some(timestamp) = author_timestamp {
+                let mut author = builder.author().clone();
+                author.timestamp = timestamp;
+                builder = builder.set_author(author);
+            }
+
+            let new_commit = if rewrite.new_description.is_some() {
+                let builder = builder.detach();
+                crate::trailers::write_with_configured_trailers(tx.repo_mut(), builder)?
+            } else {
+                builder.write().map_err(|e| JjError::Backend {
+                    message: e.to_string(),
+                })?
+            };
 
             Ok(FfiCommit::from(&new_commit))
         })
@@ -222,11 +544,34 @@ impl FfiTransaction {
             commit_id: commit_id.clone(),
             new_description: Some(new_description),
             new_parent_ids: None,
+            new_author: None,
+            new_committer: None,
+            reset_author: false,
+            new_author_timestamp: None,
+            update_author_timestamp: false,
+            new_committer_timestamp: None,
         };
         self.rewrite_commit(rewrite)
     }
 
+    /// Add trailers to the description of an existing commit
+    ///
+    /// Trailers already present in the description are skipped. See
+    /// `add_description_trailers()`.
+    pub fn add_trailers(
+        &self,
+        commit_id: &FfiCommitId,
+        trailers: Vec<FfiTrailer>,
+    ) -> Result<FfiCommit> {
+        let description = self.get_commit(commit_id)?.description;
+        let description = crate::trailers::add_description_trailers(description, trailers)?;
+        self.update_description(commit_id, description)
+    }
+
     /// Abandon a commit (its children will be rebased to its parents)
+    ///
+    /// Descendants are rebased by `rebase_descendants()` or when the
+    /// transaction is committed.
     pub fn abandon_commit(&self, commit_id: &FfiCommitId) -> Result<()> {
         self.with_transaction_mut(|tx| {
             // Convert commit ID
@@ -250,15 +595,297 @@ impl FfiTransaction {
         })
     }
 
-    /// Commit the transaction and return the updated repository
-    pub fn commit(&self, description: String) -> Result<Arc<FfiReadonlyRepo>> {
-        let inner = self.take_transaction()?;
+    /// Resolve a conflicted file in a commit with the given content
+    ///
+    /// Rewrites the commit and rebases its descendants. Returns the new commit.
+    pub fn resolve_conflict(
+        &self,
+        commit_id: &FfiCommitId,
+        path: String,
+        content: Vec<u8>,
+    ) -> Result<FfiCommit> {
+        self.with_transaction_mut(|tx| {
+            let commit =
+                crate::conflicts::resolve_conflict(tx.repo_mut(), commit_id, &path, &content)?;
+            Ok(FfiCommit::from(&commit))
+        })
+    }
+
+    /// Resolve a conflicted file with the result of an external merge tool
+    ///
+    /// `output_path` is the result file, usually the `output` path passed to
+    /// `FfiReadonlyRepo::write_merge_tool_files()`. Conflict markers left in
+    /// the result keep those hunks conflicted. Returns the rewritten commit.
+    pub fn resolve_conflict_from_merge_tool(
+        &self,
+        commit_id: &FfiCommitId,
+        path: String,
+        output_path: String,
+    ) -> Result<FfiCommit> {
+        self.with_transaction_mut(|tx| {
+            let commit = crate::conflicts::resolve_conflict_from_merge_tool(
+                tx.repo_mut(),
+                commit_id,
+                &path,
+                &output_path,
+            )?;
+            Ok(FfiCommit::from(&commit))
+        })
+    }
+
+    /// Rebase commits onto new parents
+    ///
+    /// Each source commit is moved together with its descendants, like
+    /// `jj rebase -s`. Descendants of rewritten commits elsewhere in the graph
+    /// are rebased as well. See `rebase_commits()` for the other modes.
+    pub fn rebase(
+        &self,
+        source_commit_ids: Vec<FfiCommitId>,
+        new_parent_ids: Vec<FfiCommitId>,
+    ) -> Result<FfiRebaseStats> {
+        let target = FfiRebaseTarget::Source {
+            commit_ids: source_commit_ids,
+        };
+        let destination = FfiRebaseDestination::Onto {
+            parent_ids: new_parent_ids,
+        };
+        self.rebase_commits(target, destination)
+    }
+
+    /// Rebase commits, a subtree or a branch onto new parents or between
+    /// existing commits, like `jj rebase` with `-r`, `-s` or `-b` and `-d`,
+    /// `--insert-after` or `--insert-before`
+    ///
+    /// Descendants of rewritten commits elsewhere in the graph are rebased as
+    /// well.
+    pub fn rebase_commits(
+        &self,
+        target: FfiRebaseTarget,
+        destination: FfiRebaseDestination,
+    ) -> Result<FfiRebaseStats> {
+        self.with_transaction_mut(|tx| crate::rewrite::rebase(tx.repo_mut(), &target, &destination))
+    }
+
+    /// Commit the snapshotted working-copy changes, like `jj commit`
+    ///
+    /// The working-copy commit, with the tree recorded by the latest
+    /// `FfiWorkspace::snapshot()`, gets the description, and a new empty
+    /// working-copy commit is created on top of it. Returns the committed
+    /// commit. The files on disk are unchanged, so snapshot the workspace
+    /// before starting the transaction to include the latest edits.
+    ///
+    /// Only available in transactions started from a workspace's repository.
+    pub fn commit_working_copy(&self, description: String) -> Result<FfiCommit> {
+        let workspace = self.workspace.as_ref().ok_or_else(|| JjError::Workspace {
+            message: "Committing the working copy requires a repository loaded from a workspace"
+                .to_string(),
+        })?;
+        self.with_transaction_mut(|tx| {
+            let workspace_name = workspace.name().to_owned();
+            let wc_commit_id = tx
+                .repo()
+                .view()
+                .get_wc_commit_id(&workspace_name)
+                .cloned()
+                .ok_or_else(|| JjError::Workspace {
+                    message: format!(
+                        "No working-copy commit for workspace '{}'",
+                        workspace_name.as_symbol()
+                    ),
+                })?;
+            let wc_commit = tx.repo().store().get_commit(&wc_commit_id)?;
+            let builder = tx
+                .repo_mut()
+                .rewrite_commit(&wc_commit)
+                .set_description(&description)
+                .detach();
+            let commit = crate::trailers::write_with_configured_trailers(tx.repo_mut(), builder)?;
+            tx.repo_mut().rebase_descendants()?;
+            tx.repo_mut()
+                .check_out(workspace_name, &commit)
+                .map_err(|e| JjError::Transaction {
+                    message: e.to_string(),
+                })?;
+            Ok(FfiCommit::from(&commit))
+        })
+    }
+
+    /// Squash a commit into its parent, like `jj squash -r`
+    ///
+    /// Returns the rewritten parent.
+    pub fn squash(&self, commit_id: &FfiCommitId, options: FfiSquashOptions) -> Result<FfiCommit> {
+        self.with_transaction_mut(|tx| {
+            let commit = crate::rewrite::squash(tx.repo_mut(), commit_id, None, &options)?;
+            Ok(FfiCommit::from(&commit))
+        })
+    }
+
+    /// Squash a commit into another commit, like `jj squash --from --into`
+    ///
+    /// Returns the rewritten destination.
+    pub fn squash_into(
+        &self,
+        from: &FfiCommitId,
+        into: &FfiCommitId,
+        options: FfiSquashOptions,
+    ) -> Result<FfiCommit> {
+        self.with_transaction_mut(|tx| {
+            let commit = crate::rewrite::squash(tx.repo_mut(), from, Some(into), &options)?;
+            Ok(FfiCommit::from(&commit))
+        })
+    }
+
+    /// Split a commit by paths, like `jj split <paths>`
+    ///
+    /// The first commit contains the changes to the given paths (files or
+    /// directories) and the second one, on top of it, the remaining changes.
+    /// At least one path is required.
+    pub fn split(&self, commit_id: &FfiCommitId, paths: Vec<String>) -> Result<FfiSplitResult> {
+        self.with_transaction_mut(|tx| crate::rewrite::split(tx.repo_mut(), commit_id, &paths))
+    }
+
+    /// Absorb the changes of a commit into its mutable ancestors, like
+    /// `jj absorb`
+    ///
+    /// Each hunk moves into the mutable ancestor that last modified the
+    /// surrounding lines. Without `source_commit`, the working-copy commit is
+    /// used, which requires a transaction started from a workspace's
+    /// repository.
+    pub fn absorb(&self, source_commit: Option<FfiCommitId>) -> Result<FfiAbsorbResult> {
+        self.with_transaction_mut(|tx| {
+            let source_id = match &source_commit {
+                Some(id) => CommitId::try_from(id).map_err(|e| JjError::InvalidArgument {
+                    message: format!("Invalid commit ID: {}", e),
+                })?,
+                None => {
+                    let workspace = self.workspace.as_ref().ok_or_else(|| JjError::Workspace {
+                        message: "Absorbing the working copy requires a repository loaded from a workspace"
+                            .to_string(),
+                    })?;
+                    tx.repo()
+                        .view()
+                        .get_wc_commit_id(workspace.name())
+                        .cloned()
+                        .ok_or_else(|| JjError::Workspace {
+                            message: format!(
+                                "No working-copy commit for workspace '{}'",
+                                workspace.name().as_symbol()
+                            ),
+                        })?
+                }
+            };
+            let user_email = tx.repo().base_repo().settings().user_email().to_owned();
+            let destinations = crate::revset::resolve_revset(
+                tx.repo(),
+                self.revset_context()?.as_ref(),
+                "mutable()",
+                &user_email,
+                self.workspace.as_ref(),
+            )?;
+            crate::rewrite::absorb(tx.repo_mut(), &source_id, &destinations)
+        })
+    }
+
+    /// Make commits siblings of each other, like `jj parallelize`
+    ///
+    /// Typically the commits are a linear run, which becomes a set of
+    /// siblings on the run's parent. Children of the run are rebased onto all
+    /// of the siblings. Returns the rewritten commits, children first.
+    pub fn parallelize(&self, commit_ids: Vec<FfiCommitId>) -> Result<Vec<FfiCommit>> {
+        self.with_transaction_mut(|tx| {
+            let commits = crate::rewrite::parallelize(tx.repo_mut(), &commit_ids)?;
+            Ok(commits.iter().map(FfiCommit::from).collect())
+        })
+    }
+
+    /// Create commits from `git format-patch` style text
+    ///
+    /// Each patch in the text becomes a new commit, stacked in order on top of
+    /// `destination`. Renames and copies are applied, and commits keep the
+    /// change ID of the patch's `Change-Id` trailer, so importing a patch into
+    /// a repository that still has the change makes the change divergent.
+    /// Returns the created commits.
+    pub fn import_patch(&self, patch: Vec<u8>, destination: &FfiCommitId) -> Result<Vec<FfiCommit>> {
+        self.with_transaction_mut(|tx| {
+            let commits = crate::patch::import_patch(tx.repo_mut(), &patch, destination)?;
+            Ok(commits.iter().map(FfiCommit::from).collect())
+        })
+    }
+
+    /// Record a client-supplied idempotency key in the operation metadata
+    ///
+    /// Before retrying a transaction whose outcome is unknown, check
+    /// `FfiReadonlyRepo::find_operation_by_idempotency_key()` to avoid
+    /// applying the same changes twice.
+    pub fn set_idempotency_key(&self, key: String) -> Result<()> {
+        if key.is_empty() {
+            return Err(JjError::InvalidArgument {
+                message: "Idempotency key must not be empty".to_string(),
+            });
+        }
+        self.with_transaction_mut(|tx| {
+            tx.set_tag(IDEMPOTENCY_KEY_TAG.to_string(), key);
+            Ok(())
+        })
+    }
+
+    /// Set a tag in the operation metadata
+    ///
+    /// Tags are returned in `FfiOperation::tags` and can be used to tell
+    /// operations made by the app from those made by the `jj` CLI.
+    pub fn set_tag(&self, key: String, value: String) -> Result<()> {
+        if key.is_empty() {
+            return Err(JjError::InvalidArgument {
+                message: "Tag key must not be empty".to_string(),
+            });
+        }
+        self.with_transaction_mut(|tx| {
+            tx.set_tag(key, value);
+            Ok(())
+        })
+    }
+
+    /// Rebase the descendants of commits rewritten or abandoned in this transaction
+    ///
+    /// Returns the number of rebased commits, e.g. to report "3 descendant
+    /// commits rebased". Committing the transaction rebases any remaining
+    /// descendants, but calling this first also makes the rebased commits
+    /// visible to queries within the transaction.
+    pub fn rebase_descendants(&self) -> Result<u32> {
+        self.with_transaction_mut(|tx| Ok(tx.repo_mut().rebase_descendants()? as u32))
+    }
+
+    /// Commit the transaction and return the updated repository along with
+    /// the commits rewritten in it
+    ///
+    /// Descendants of rewritten or abandoned commits are rebased first. In a
+    /// colocated workspace, Git HEAD and refs are then updated like the `jj`
+    /// CLI does. Files on disk are left unchanged; use
+    /// `FfiWorkspace::finish_transaction()` to also update the working copy.
+    pub fn commit(&self, description: String) -> Result<FfiTransactionResult> {
+        let mut inner = self.take_transaction()?;
+        inner.repo_mut().rebase_descendants()?;
+        #[cfg(feature = "git")]
+        if let Some(workspace) = self.workspace.as_ref().filter(|w| w.is_colocated()) {
+            crate::git::export_to_colocated_git(
+                inner.repo_mut(),
+                &self.base_repo,
+                workspace.name(),
+            )?;
+        }
 
         let repo = inner.commit(&description).map_err(|e| JjError::Transaction {
             message: e.to_string(),
         })?;
-
-        Ok(Arc::new(FfiReadonlyRepo::new(repo)))
+        let rewritten_commits = rewritten_commits(&repo, &self.base_repo)?;
+
+        Ok(FfiTransactionResult {
+            repo: Arc::new(FfiReadonlyRepo::with_workspace(
+                repo,
+                self.workspace.clone(),
+            )),
+            rewritten_commits,
+        })
     }
 
     /// Discard the transaction without committing
@@ -0,0 +1,391 @@
+//! Tree operations for FFI
+//!
+//! This module exposes the contents of a commit's tree, allowing clients to
+//! browse and export files and directories without a working copy, and to
+//! build new trees for commits.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use jj_lib::backend::{CommitId, CopyId, TreeId, TreeValue};
+use jj_lib::conflicts::ConflictMarkerStyle;
+use jj_lib::merge::{Merge, MergedTreeVal, MergedTreeValue};
+use jj_lib::merged_tree::{MergedTree, MergedTreeBuilder, all_merged_tree_entries};
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::{ReadonlyRepo, Repo};
+use jj_lib::repo_path::{RepoPath, RepoPathBuf};
+use jj_lib::store::Store;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::error::{JjError, Result};
+use crate::files::{
+    conflict_marker_style, materialize_file_conflict, parse_repo_path, read_file_content,
+};
+use crate::types::FfiCommitId;
+
+/// Type of an entry in a tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiTreeEntryType {
+    /// A regular file
+    File,
+    /// A directory
+    Directory,
+    /// A symbolic link
+    Symlink,
+    /// A Git submodule
+    Submodule,
+    /// An unresolved conflict
+    Conflict,
+}
+
+/// An entry in a tree listing
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiTreeEntry {
+    /// Name of the entry (last path component)
+    pub name: String,
+    /// Repository-relative path of the entry
+    pub path: String,
+    /// Type of the entry
+    pub entry_type: FfiTreeEntryType,
+    /// Whether the entry is an executable file
+    pub executable: bool,
+}
+
+/// Destination format for exporting a revision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FfiExportFormat {
+    /// Write files into a directory
+    Directory,
+    /// Write files into a zip archive
+    Zip,
+}
+
+/// Classify a tree value as `(type, executable)`
+pub(crate) fn classify_value(value: &MergedTreeVal<'_>) -> (FfiTreeEntryType, bool) {
+    match value.as_resolved() {
+        Some(Some(TreeValue::File { executable, .. })) => (FfiTreeEntryType::File, *executable),
+        Some(Some(TreeValue::Symlink(_))) => (FfiTreeEntryType::Symlink, false),
+        Some(Some(TreeValue::Tree(_))) => (FfiTreeEntryType::Directory, false),
+        Some(Some(TreeValue::GitSubmodule(_))) => (FfiTreeEntryType::Submodule, false),
+        // Absent entries are filtered out by the caller
+        Some(None) => (FfiTreeEntryType::Conflict, false),
+        // A conflict between trees is merged on the fly, so it can be browsed
+        None if value.is_tree() => (FfiTreeEntryType::Directory, false),
+        None => (FfiTreeEntryType::Conflict, false),
+    }
+}
+
+/// Look up a tree by its hex IDs, as listed in `FfiCommit::tree_ids`
+pub(crate) fn merged_tree_from_ids(store: &Arc<Store>, tree_ids: &[String]) -> Result<MergedTree> {
+    if tree_ids.len().is_multiple_of(2) {
+        return Err(JjError::InvalidArgument {
+            message: format!("Expected an odd number of tree IDs, got {}", tree_ids.len()),
+        });
+    }
+    let mut ids = Vec::with_capacity(tree_ids.len());
+    for hex in tree_ids {
+        let id = TreeId::try_from_hex(hex).ok_or_else(|| JjError::InvalidArgument {
+            message: format!("Invalid tree ID: {}", hex),
+        })?;
+        store.get_tree(RepoPathBuf::root(), &id)?;
+        ids.push(id);
+    }
+    Ok(MergedTree::new(store.clone(), Merge::from_vec(ids)))
+}
+
+/// List the entries directly inside a directory of a commit's tree
+pub fn list_tree(
+    repo: &Arc<ReadonlyRepo>,
+    commit_id: &FfiCommitId,
+    path_prefix: &str,
+) -> Result<Vec<FfiTreeEntry>> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let dir = parse_repo_path(path_prefix.trim_end_matches('/'))?;
+
+    let commit = repo.store().get_commit(&id)?;
+    let trees = commit.tree().trees()?;
+    let trees = pollster::block_on(trees.sub_tree_recursive(&dir))?.ok_or_else(|| {
+        JjError::InvalidArgument {
+            message: format!("Not a directory: {}", path_prefix),
+        }
+    })?;
+
+    let entries = all_merged_tree_entries(&trees)
+        .filter_map(|(name, _)| {
+            let value = trees.value(name);
+            if value.is_absent() {
+                return None;
+            }
+            let (entry_type, executable) = classify_value(&value);
+            Some(FfiTreeEntry {
+                name: name.as_internal_str().to_string(),
+                path: dir.join(name).as_internal_file_string().to_string(),
+                entry_type,
+                executable,
+            })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// Content of a tree entry to be exported
+enum ExportContent {
+    File { content: Vec<u8>, executable: bool },
+    Symlink { target: String },
+}
+
+/// Read the exportable content of a tree value
+///
+/// Conflicted files are exported with conflict markers. Submodules and
+/// non-file conflicts are skipped.
+fn export_content(
+    store: &Store,
+    path: &RepoPath,
+    value: &MergedTreeValue,
+    marker_style: ConflictMarkerStyle,
+) -> Result<Option<ExportContent>> {
+    match value.as_resolved() {
+        Some(Some(TreeValue::File { id, executable, .. })) => Ok(Some(ExportContent::File {
+            content: read_file_content(store, path, id)?,
+            executable: *executable,
+        })),
+        Some(Some(TreeValue::Symlink(id))) => Ok(Some(ExportContent::Symlink {
+            target: pollster::block_on(store.read_symlink(path, id))?,
+        })),
+        Some(_) => Ok(None),
+        None => Ok(
+            materialize_file_conflict(store, path, value, marker_style)?.map(|content| {
+                ExportContent::File {
+                    content,
+                    executable: false,
+                }
+            }),
+        ),
+    }
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> JjError {
+    JjError::Internal {
+        message: format!("Failed to write '{}': {}", path.display(), err),
+    }
+}
+
+/// Write a single entry below a destination directory
+fn write_to_directory(dest: &Path, path: &RepoPath, content: ExportContent) -> Result<()> {
+    let fs_path = path
+        .to_fs_path(dest)
+        .map_err(|e| JjError::InvalidArgument {
+            message: format!("Invalid path '{}': {}", path.as_internal_file_string(), e),
+        })?;
+    if let Some(parent) = fs_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| io_error(parent, e))?;
+    }
+    match content {
+        ExportContent::File {
+            content,
+            executable,
+        } => {
+            let mut file = File::create(&fs_path).map_err(|e| io_error(&fs_path, e))?;
+            file.write_all(&content)
+                .map_err(|e| io_error(&fs_path, e))?;
+            #[cfg(unix)]
+            if executable {
+                use std::os::unix::fs::PermissionsExt as _;
+                file.set_permissions(fs::Permissions::from_mode(0o755))
+                    .map_err(|e| io_error(&fs_path, e))?;
+            }
+            #[cfg(not(unix))]
+            let _ = executable;
+        }
+        ExportContent::Symlink { target } => {
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(&target, &fs_path).map_err(|e| io_error(&fs_path, e))?;
+            #[cfg(not(unix))]
+            fs::write(&fs_path, target).map_err(|e| io_error(&fs_path, e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Write a single entry into a zip archive
+fn write_to_zip(
+    zip: &mut ZipWriter<File>,
+    dest: &Path,
+    path: &RepoPath,
+    content: ExportContent,
+) -> Result<()> {
+    let zip_error = |e: zip::result::ZipError| JjError::Internal {
+        message: format!("Failed to write '{}': {}", dest.display(), e),
+    };
+    let name = path.as_internal_file_string();
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    match content {
+        ExportContent::File {
+            content,
+            executable,
+        } => {
+            let mode = if executable { 0o755 } else { 0o644 };
+            zip.start_file(name, options.unix_permissions(mode))
+                .map_err(zip_error)?;
+            zip.write_all(&content).map_err(|e| io_error(dest, e))?;
+        }
+        ExportContent::Symlink { target } => {
+            zip.add_symlink(name, target, options).map_err(zip_error)?;
+        }
+    }
+    Ok(())
+}
+
+/// Export a commit's tree to a directory or zip archive
+///
+/// Returns the number of exported entries.
+pub fn export_revision(
+    repo: &Arc<ReadonlyRepo>,
+    commit_id: &FfiCommitId,
+    destination: &str,
+    format: FfiExportFormat,
+) -> Result<u64> {
+    let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid commit ID: {}", e),
+    })?;
+    let dest = PathBuf::from(destination);
+    if dest.exists() && !dest.read_dir().is_ok_and(|mut dir| dir.next().is_none()) {
+        return Err(JjError::InvalidArgument {
+            message: format!("Destination already exists: {}", destination),
+        });
+    }
+
+    let store = repo.store();
+    let commit = store.get_commit(&id)?;
+    let marker_style = conflict_marker_style(repo.settings());
+
+    let mut zip = match format {
+        FfiExportFormat::Directory => {
+            fs::create_dir_all(&dest).map_err(|e| io_error(&dest, e))?;
+            None
+        }
+        FfiExportFormat::Zip => {
+            let file = File::create(&dest).map_err(|e| io_error(&dest, e))?;
+            Some(ZipWriter::new(file))
+        }
+    };
+
+    let mut count = 0;
+    for (path, value) in commit.tree().entries() {
+        let Some(content) = export_content(store, &path, &value?, marker_style)? else {
+            continue;
+        };
+        match &mut zip {
+            Some(zip) => write_to_zip(zip, &dest, &path, content)?,
+            None => write_to_directory(&dest, &path, content)?,
+        }
+        count += 1;
+    }
+
+    if let Some(zip) = zip {
+        zip.finish().map_err(|e| JjError::Internal {
+            message: format!("Failed to write '{}': {}", dest.display(), e),
+        })?;
+    }
+
+    Ok(count)
+}
+
+/// Builds a tree from a base tree and changes to individual paths
+///
+/// Files are written to the store as they are added. The resulting tree can
+/// be used with `FfiCommitBuilder::set_tree()` to create commits without a
+/// working copy.
+#[derive(uniffi::Object)]
+pub struct FfiTreeBuilder {
+    store: Arc<Store>,
+    base_tree: MergedTree,
+    overrides: Mutex<BTreeMap<RepoPathBuf, MergedTreeValue>>,
+}
+
+impl FfiTreeBuilder {
+    pub(crate) fn new(store: &Arc<Store>, base_tree_ids: Option<&[String]>) -> Result<Self> {
+        let base_tree = match base_tree_ids {
+            Some(tree_ids) => merged_tree_from_ids(store, tree_ids)?,
+            None => store.empty_merged_tree(),
+        };
+        Ok(Self {
+            store: store.clone(),
+            base_tree,
+            overrides: Mutex::default(),
+        })
+    }
+
+    /// Parse a path of a file or symlink to set
+    fn parse_entry_path(path: &str) -> Result<RepoPathBuf> {
+        let repo_path = parse_repo_path(path)?;
+        if repo_path.is_root() {
+            return Err(JjError::InvalidArgument {
+                message: "Path must not be empty".to_string(),
+            });
+        }
+        Ok(repo_path)
+    }
+
+    fn set(&self, path: RepoPathBuf, value: Option<TreeValue>) {
+        self.overrides
+            .lock()
+            .unwrap()
+            .insert(path, Merge::resolved(value));
+    }
+}
+
+#[uniffi::export]
+impl FfiTreeBuilder {
+    /// Set the content of a file, replacing any existing entry at the path
+    pub fn write_file(&self, path: String, content: Vec<u8>, executable: bool) -> Result<()> {
+        let repo_path = Self::parse_entry_path(&path)?;
+        let id = pollster::block_on(self.store.write_file(&repo_path, &mut content.as_slice()))?;
+        let value = TreeValue::File {
+            id,
+            executable,
+            copy_id: CopyId::placeholder(),
+        };
+        self.set(repo_path, Some(value));
+        Ok(())
+    }
+
+    /// Set a symlink, replacing any existing entry at the path
+    pub fn set_symlink(&self, path: String, target: String) -> Result<()> {
+        let repo_path = Self::parse_entry_path(&path)?;
+        let id = pollster::block_on(self.store.write_symlink(&repo_path, &target))?;
+        self.set(repo_path, Some(TreeValue::Symlink(id)));
+        Ok(())
+    }
+
+    /// Remove the file, symlink or conflict at a path
+    ///
+    /// Directories are removed with their contents.
+    pub fn remove(&self, path: String) -> Result<()> {
+        let repo_path = Self::parse_entry_path(&path)?;
+        let mut overrides = self.overrides.lock().unwrap();
+        overrides.retain(|entry_path, _| !entry_path.starts_with(&repo_path));
+        overrides.insert(repo_path, Merge::absent());
+        Ok(())
+    }
+
+    /// Write the tree to the store and return its hex IDs
+    ///
+    /// The IDs are in the form of `FfiCommit::tree_ids`. The builder can keep
+    /// being used to write further trees.
+    pub fn write_tree(&self) -> Result<Vec<String>> {
+        let mut builder = MergedTreeBuilder::new(self.base_tree.clone());
+        for (path, value) in self.overrides.lock().unwrap().iter() {
+            builder.set_or_remove(path.clone(), value.clone());
+        }
+        let tree = builder.write_tree()?;
+        Ok(tree.tree_ids().iter().map(|id| id.hex()).collect())
+    }
+}
@@ -1,9 +1,12 @@
 //! Commit type for FFI
 
 use jj_lib::commit::Commit;
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::Repo;
 
 use super::ids::{FfiChangeId, FfiCommitId};
 use super::signature::{FfiSignature, FfiTimestamp};
+use crate::error::Result;
 
 /// A commit exposed via FFI
 #[derive(Debug, Clone, uniffi::Record)]
@@ -22,6 +25,18 @@ pub struct FfiCommit {
     pub parent_ids: Vec<FfiCommitId>,
     /// Whether this commit is signed
     pub is_signed: bool,
+    /// Whether this commit's tree has unresolved conflicts
+    pub has_conflict: bool,
+    /// Hex IDs of the commit's tree
+    ///
+    /// This is a single ID unless the tree has conflicts, in which case the
+    /// IDs of the conflict's terms are listed in order (alternating added and
+    /// removed trees). Commits with equal tree IDs have the same content.
+    pub tree_ids: Vec<String>,
+    /// Length of the shortest unique commit ID prefix, if requested
+    pub commit_id_prefix_len: Option<u32>,
+    /// Length of the shortest unique change ID prefix, if requested
+    pub change_id_prefix_len: Option<u32>,
 }
 
 impl From<&Commit> for FfiCommit {
@@ -34,10 +49,27 @@ impl From<&Commit> for FfiCommit {
             committer: FfiSignature::from(commit.committer()),
             parent_ids: commit.parent_ids().iter().map(FfiCommitId::from).collect(),
             is_signed: commit.is_signed(),
+            has_conflict: commit.has_conflict(),
+            tree_ids: commit.tree_ids().iter().map(|id| id.hex()).collect(),
+            commit_id_prefix_len: None,
+            change_id_prefix_len: None,
         }
     }
 }
 
+impl FfiCommit {
+    /// Convert a commit, including its shortest unique ID prefix lengths
+    pub(crate) fn with_id_prefixes(commit: &Commit, repo: &dyn Repo) -> Result<Self> {
+        let commit_id_prefix_len = crate::prefix::commit_id_prefix_len(repo, commit.id())?;
+        let change_id_prefix_len = crate::prefix::change_id_prefix_len(repo, commit.change_id())?;
+        Ok(Self {
+            commit_id_prefix_len: Some(commit_id_prefix_len as u32),
+            change_id_prefix_len: Some(change_id_prefix_len as u32),
+            ..Self::from(commit)
+        })
+    }
+}
+
 impl From<Commit> for FfiCommit {
     fn from(commit: Commit) -> Self {
         Self::from(&commit)
@@ -81,4 +113,17 @@ pub struct FfiRewriteCommit {
     pub new_description: Option<String>,
     /// New parent IDs (optional, keeps original if not provided)
     pub new_parent_ids: Option<Vec<FfiCommitId>>,
+    /// New author (optional, keeps original if not provided)
+    pub new_author: Option<FfiSignature>,
+    /// New committer (optional, uses settings and current time if not provided)
+    pub new_committer: Option<FfiSignature>,
+    /// Reset the author to the committer, like `jj describe --reset-author`
+    pub reset_author: bool,
+    /// New author timestamp, keeping the author's name and email (optional)
+    pub new_author_timestamp: Option<FfiTimestamp>,
+    /// Set the author timestamp to the current time, like
+    /// `jj metaedit --update-author-timestamp`
+    pub update_author_timestamp: bool,
+    /// New committer timestamp, instead of the current time (optional)
+    pub new_committer_timestamp: Option<FfiTimestamp>,
 }
@@ -1,19 +1,166 @@
 //! Workspace operations for FFI
 
-use std::path::Path;
+use std::env;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 
-use jj_lib::config::{ConfigLayer, ConfigSource, StackedConfig};
-use jj_lib::repo::{ReadonlyRepo, StoreFactories};
+use jj_lib::backend::CommitId;
+use jj_lib::commit::Commit;
+#[cfg(feature = "git")]
+use jj_lib::config::ConfigFile;
+use jj_lib::config::{
+    ConfigLayer, ConfigLoadError, ConfigResolutionContext, ConfigSource, StackedConfig,
+};
+use jj_lib::default_index::DefaultIndexStore;
+#[cfg(feature = "git")]
+use jj_lib::git;
+use jj_lib::matchers::PrefixMatcher;
+use jj_lib::merge::Merge;
+use jj_lib::merged_tree::MergedTreeBuilder;
+use jj_lib::object_id::ObjectId as _;
+#[cfg(feature = "git")]
+use jj_lib::op_heads_store::OpHeadsStoreError;
+#[cfg(feature = "git")]
+use jj_lib::op_store::RefTarget;
+#[cfg(feature = "git")]
+use jj_lib::ref_name::{RefName, RefNameBuf, RemoteName};
+use jj_lib::ref_name::{WorkspaceName, WorkspaceNameBuf};
+use jj_lib::repo::{MutableRepo, ReadonlyRepo, Repo, StoreFactories};
+#[cfg(feature = "git")]
+use jj_lib::revset;
+use jj_lib::rewrite::{merge_commit_trees, restore_tree};
 use jj_lib::settings::UserSettings;
-use jj_lib::workspace::{default_working_copy_factories, Workspace};
+#[cfg(feature = "git")]
+use jj_lib::str_util::StringExpression;
+use jj_lib::working_copy::{SnapshotStats, WorkingCopyFreshness};
+use jj_lib::workspace::{
+    DefaultWorkspaceLoaderFactory, Workspace, WorkspaceLoaderFactory as _,
+    default_working_copy_factories, default_working_copy_factory,
+};
 
+use crate::cancel::{FfiCancellationToken, catch_cancelled, check_cancelled};
+#[cfg(feature = "git")]
+use crate::credentials::FfiGitCredentialProvider;
 use crate::error::{JjError, Result};
+use crate::files::{FfiConflictMarkerStyle, conflict_marker_style, parse_repo_path};
+#[cfg(feature = "git")]
+use crate::git::{FfiFetchOptions, FfiGitHeadSyncReport};
+use crate::info::{FfiRepoFormat, check_workspace_format};
+use crate::operation::FfiOperationMergeReport;
+#[cfg(feature = "git")]
+use crate::progress::FfiGitProgress;
 use crate::repo::FfiReadonlyRepo;
+use crate::revset::RevsetWorkspace;
+use crate::snapshot::{
+    FfiFsmonitor, FfiSnapshotOptions, FfiSnapshotResult, FfiUntrackedPath, SnapshotSettings,
+    fsmonitor, untracked_paths,
+};
+use crate::transaction::{FfiTransaction, FfiTransactionResult};
+use crate::types::{FfiCommit, FfiCommitId, FfiTimestamp};
+
+fn workspace_error(err: impl ToString) -> JjError {
+    JjError::Workspace {
+        message: err.to_string(),
+    }
+}
+
+/// Get the working-copy commit of a workspace
+fn working_copy_commit(repo: &ReadonlyRepo, workspace_name: &WorkspaceName) -> Result<Commit> {
+    let id = repo
+        .view()
+        .get_wc_commit_id(workspace_name)
+        .ok_or_else(|| JjError::Workspace {
+            message: format!(
+                "No working-copy commit for workspace '{}'",
+                workspace_name.as_symbol()
+            ),
+        })?;
+    Ok(repo.store().get_commit(id)?)
+}
+
+/// Snapshot the files on disk into the working-copy commit
+///
+/// Records a new operation if files changed. Fails if the working copy is
+/// stale, i.e. the working-copy commit was rewritten after it was checked out.
+/// Cancellation is checked while scanning the files.
+fn snapshot_working_copy(
+    workspace: &mut Workspace,
+    repo: Arc<ReadonlyRepo>,
+    snapshot_settings: &SnapshotSettings,
+    op_description: &str,
+) -> Result<(Arc<ReadonlyRepo>, SnapshotStats)> {
+    let cancellation = snapshot_settings.cancellation();
+    check_cancelled(cancellation)?;
+    let wc_commit = working_copy_commit(&repo, workspace.workspace_name())?;
+    #[cfg(feature = "git")]
+    let colocated = crate::git::is_colocated(workspace);
+    let mut locked_ws = workspace
+        .start_working_copy_mutation()
+        .map_err(workspace_error)?;
+    if locked_ws.locked_wc().old_tree().tree_ids() != wc_commit.tree_ids() {
+        return Err(JjError::Workspace {
+            message: "The working copy is stale; the working-copy commit was rewritten after it \
+                      was checked out"
+                .to_string(),
+        });
+    }
+    let (tree, stats) = catch_cancelled(|| {
+        pollster::block_on(locked_ws.locked_wc().snapshot(&snapshot_settings.options()))
+    })?
+    .map_err(workspace_error)?;
+    snapshot_settings.finish_progress(&wc_commit.tree(), &tree);
+    // Dropping the lock without finishing leaves the working-copy state untouched
+    check_cancelled(cancellation)?;
+
+    let repo = if tree.tree_ids() == wc_commit.tree_ids() {
+        repo
+    } else {
+        let mut tx = repo.start_transaction();
+        tx.repo_mut()
+            .rewrite_commit(&wc_commit)
+            .set_tree(tree)
+            .write()?;
+        tx.repo_mut().rebase_descendants()?;
+        #[cfg(feature = "git")]
+        if colocated {
+            git::export_refs(tx.repo_mut()).map_err(|e| JjError::Git {
+                message: e.to_string(),
+            })?;
+        }
+        tx.commit(op_description)?
+    };
+    locked_ws
+        .finish(repo.op_id().clone())
+        .map_err(workspace_error)?;
+    Ok((repo, stats))
+}
+
+/// Wrap a repository for FFI, resolving `@` in revsets in the workspace
+fn repo_handle(workspace: &Workspace, repo: Arc<ReadonlyRepo>) -> Arc<FfiReadonlyRepo> {
+    let revset_workspace = RevsetWorkspace::new(workspace);
+    Arc::new(FfiReadonlyRepo::with_workspace(
+        repo,
+        Some(revset_workspace),
+    ))
+}
+
+/// The default config of the `jj` CLI
+///
+/// jj-lib reads settings like `signing.behavior` and `merge.hunk-level`
+/// without fallbacks, so its default config layer must be present for
+/// `UserSettings::from_config()` and merges to succeed. The CLI's defaults
+/// on top of it define the aliases and settings used by templates like
+/// `builtin_log_compact`.
+fn default_config() -> StackedConfig {
+    let mut config = StackedConfig::with_defaults();
+    config.extend_layers(jj_cli::config::default_config_layers());
+    config
+}
 
 /// Create default user settings for FFI operations
-fn create_user_settings(user_name: &str, user_email: &str) -> Result<UserSettings> {
-    let mut config = StackedConfig::empty();
+pub(crate) fn create_user_settings(user_name: &str, user_email: &str) -> Result<UserSettings> {
+    let mut config = default_config();
 
     // Create a config layer with user settings
     let toml_str = format!(
@@ -45,16 +192,340 @@ username = "ffi-user"
     })
 }
 
+/// Find the user's jj config files and directories, like the `jj` CLI
+///
+/// `$JJ_CONFIG` takes precedence over the default locations. Otherwise the
+/// first existing config file is used, plus the `conf.d` directory of the
+/// config directory.
+fn user_config_paths() -> Vec<PathBuf> {
+    if let Some(paths) = env::var_os("JJ_CONFIG") {
+        return env::split_paths(&paths).collect();
+    }
+    let home = env::var_os("HOME").map(PathBuf::from);
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")))
+        .map(|dir| dir.join("jj"));
+    let mut candidates = Vec::new();
+    candidates.extend(config_dir.as_ref().map(|dir| dir.join("config.toml")));
+    if let Some(home) = &home {
+        #[cfg(target_os = "macos")]
+        candidates.push(home.join("Library/Application Support/jj/config.toml"));
+        candidates.push(home.join(".jjconfig.toml"));
+    }
+    let mut paths: Vec<PathBuf> = candidates
+        .into_iter()
+        .find(|path| path.is_file())
+        .into_iter()
+        .collect();
+    paths.extend(
+        config_dir
+            .map(|dir| dir.join("conf.d"))
+            .filter(|dir| dir.is_dir()),
+    );
+    paths
+}
+
+/// Load the user and repo config files into `config`
+///
+/// Files that can't be read or parsed are skipped with a warning, so a
+/// malformed config doesn't prevent loading the repository.
+fn load_config_files(config: &mut StackedConfig, repo_path: &Path) {
+    let warn_skipped = |path: &Path, e: ConfigLoadError| {
+        tracing::warn!("Ignoring config {}: {}", path.display(), e);
+    };
+    for path in user_config_paths() {
+        if path.is_dir() {
+            if let Err(e) = config.load_dir(ConfigSource::User, &path) {
+                warn_skipped(&path, e);
+            }
+        } else if path.is_file() {
+            if let Err(e) = config.load_file(ConfigSource::User, &path) {
+                warn_skipped(&path, e);
+            }
+        }
+    }
+    let repo_config_path = repo_path.join("config.toml");
+    if repo_config_path.is_file() {
+        if let Err(e) = config.load_file(ConfigSource::Repo, &repo_config_path) {
+            warn_skipped(&repo_config_path, e);
+        }
+    }
+}
+
+/// Derive user settings that include the revset and template aliases of the
+/// user and repo config files
+///
+/// Only the `revset-aliases` and `template-aliases` tables are read from the
+/// files, so the rest of the user's configuration doesn't change the behavior
+/// of the library.
+pub(crate) fn with_config_aliases(
+    settings: &UserSettings,
+    repo_path: &Path,
+) -> Result<UserSettings> {
+    let mut files = StackedConfig::empty();
+    load_config_files(&mut files, repo_path);
+    let mut config = settings.config().clone();
+    for layer in files.layers() {
+        let mut data = toml_edit::DocumentMut::new();
+        for table_name in ["revset-aliases", "template-aliases"] {
+            if let Some(aliases) = layer.data.get(table_name) {
+                data.insert(table_name, aliases.clone());
+            }
+        }
+        if data.is_empty() {
+            continue;
+        }
+        config.add_layer(ConfigLayer {
+            source: layer.source,
+            path: layer.path.clone(),
+            data,
+        });
+    }
+
+    UserSettings::from_config(config).map_err(|e| JjError::Internal {
+        message: format!("Failed to create user settings: {}", e),
+    })
+}
+
+/// Create user settings from the user and repo config files, like the `jj` CLI
+///
+/// Conditional `--when` tables are resolved for the repo and workspace.
+pub(crate) fn load_user_settings(
+    repo_path: &Path,
+    workspace_root: Option<&Path>,
+) -> Result<UserSettings> {
+    let hostname = env::var("HOSTNAME").unwrap_or_else(|_| "ffi-client".to_string());
+    let username = env::var("USER").unwrap_or_else(|_| "ffi-user".to_string());
+    let mut env_layer = ConfigLayer::empty(ConfigSource::EnvBase);
+    env_layer
+        .set_value("operation.hostname", hostname.as_str())
+        .and_then(|_| env_layer.set_value("operation.username", username))
+        .map_err(|e| JjError::Internal {
+            message: format!("Failed to create config: {}", e),
+        })?;
+
+    let mut config = default_config();
+    config.add_layer(env_layer);
+    load_config_files(&mut config, repo_path);
+
+    let home_dir = env::var_os("HOME").map(PathBuf::from);
+    let context = ConfigResolutionContext {
+        home_dir: home_dir.as_deref(),
+        repo_path: Some(repo_path),
+        workspace_path: workspace_root,
+        command: None,
+        hostname: &hostname,
+    };
+    let config = jj_lib::config::resolve(&config, &context).map_err(|e| JjError::Internal {
+        message: format!("Failed to resolve config: {}", e),
+    })?;
+
+    UserSettings::from_config(config).map_err(|e| JjError::Internal {
+        message: format!("Failed to create user settings: {}", e),
+    })
+}
+
+/// Derive user settings with a config layer overriding the given values
+fn with_config_override(settings: &UserSettings, toml_str: &str) -> Result<UserSettings> {
+    let mut config = settings.config().clone();
+    let layer =
+        ConfigLayer::parse(ConfigSource::CommandArg, toml_str).map_err(|e| JjError::Internal {
+            message: format!("Failed to parse config: {}", e),
+        })?;
+    config.add_layer(layer);
+
+    UserSettings::from_config(config).map_err(|e| JjError::Internal {
+        message: format!("Failed to create user settings: {}", e),
+    })
+}
+
+/// Derive user settings that use the given conflict marker style
+fn with_conflict_marker_style(
+    settings: &UserSettings,
+    style: FfiConflictMarkerStyle,
+) -> Result<UserSettings> {
+    let toml_str = format!(
+        r#"
+[ui]
+conflict-marker-style = "{}"
+"#,
+        style.config_value()
+    );
+    with_config_override(settings, &toml_str)
+}
+
+/// Find the repository path of the workspace at `path`
+fn workspace_repo_path(path: &Path) -> Result<PathBuf> {
+    Ok(DefaultWorkspaceLoaderFactory
+        .create(path)?
+        .repo_path()
+        .to_owned())
+}
+
+/// Reload a workspace with the given settings, keeping the repository at the
+/// same operation
+///
+/// The working copy reads settings like the conflict marker style and the
+/// filesystem monitor when it is loaded.
+fn reload_with_settings(
+    workspace: &mut Workspace,
+    repo: &mut Arc<ReadonlyRepo>,
+    settings: &UserSettings,
+) -> Result<()> {
+    let new_workspace = Workspace::load(
+        settings,
+        workspace.workspace_root(),
+        &StoreFactories::default(),
+        &default_working_copy_factories(),
+    )?;
+    let new_repo = new_workspace.repo_loader().load_at(repo.operation())?;
+    *workspace = new_workspace;
+    *repo = new_repo;
+    Ok(())
+}
+
 /// A workspace exposed via FFI
 #[derive(uniffi::Object)]
 pub struct FfiWorkspace {
     inner: Mutex<Workspace>,
-    repo: Arc<ReadonlyRepo>,
+    repo: Mutex<Arc<ReadonlyRepo>>,
+    /// Divergent operations merged by the latest load of the head operation
+    operation_merge: Mutex<Option<FfiOperationMergeReport>>,
+    /// Git changes imported by the latest load, reload or snapshot
+    #[cfg(feature = "git")]
+    git_head_sync: Mutex<Option<FfiGitHeadSyncReport>>,
+}
+
+impl FfiWorkspace {
+    /// Load an existing workspace with the given settings
+    fn load_with_settings(path: &Path, settings: &UserSettings) -> Result<Arc<Self>> {
+        let store_factories = StoreFactories::default();
+        let working_copy_factories = default_working_copy_factories();
+
+        #[allow(unused_mut)]
+        let mut workspace =
+            Workspace::load(settings, path, &store_factories, &working_copy_factories)?;
+
+        let (repo, operation_merge) = crate::operation::load_at_head(workspace.repo_loader())?;
+        #[cfg(feature = "git")]
+        let (repo, git_head_sync) = crate::git::sync_colocated_git_head(&mut workspace, repo)?;
+
+        Ok(Arc::new(Self {
+            inner: Mutex::new(workspace),
+            repo: Mutex::new(repo),
+            operation_merge: Mutex::new(operation_merge),
+            #[cfg(feature = "git")]
+            git_head_sync: Mutex::new(git_head_sync),
+        }))
+    }
+
+    /// Import Git HEAD and refs changed outside of jj in a colocated
+    /// workspace, and record the result for `git_head_sync_report()`
+    #[cfg(feature = "git")]
+    fn sync_git_head(&self, workspace: &mut Workspace, repo: &mut Arc<ReadonlyRepo>) -> Result<()> {
+        let report;
+        (*repo, report) = crate::git::sync_colocated_git_head(workspace, Arc::clone(repo))?;
+        *self.git_head_sync.lock().unwrap() = report;
+        Ok(())
+    }
+
+    /// Snapshot the working copy, first importing Git changes made outside of
+    /// jj like the `jj` CLI does
+    fn import_and_snapshot(
+        &self,
+        workspace: &mut Workspace,
+        repo: &mut Arc<ReadonlyRepo>,
+        snapshot_settings: &SnapshotSettings,
+        op_description: &str,
+    ) -> Result<SnapshotStats> {
+        #[cfg(feature = "git")]
+        self.sync_git_head(workspace, repo)?;
+        let stats;
+        (*repo, stats) = snapshot_working_copy(
+            workspace,
+            Arc::clone(repo),
+            snapshot_settings,
+            op_description,
+        )?;
+        Ok(stats)
+    }
+
+    /// Snapshot the working copy, then make and check out a new working-copy commit
+    ///
+    /// `update` sets the new working-copy commit in the transaction and
+    /// returns it. It receives the given commit IDs with the working-copy
+    /// commit replaced by its snapshot. The snapshot and the update are
+    /// separate operations.
+    fn update_working_copy<F>(
+        &self,
+        op_description: &str,
+        commit_ids: &[CommitId],
+        update: F,
+    ) -> Result<Commit>
+    where
+        F: FnOnce(&mut MutableRepo, WorkspaceNameBuf, Vec<CommitId>) -> Result<Commit>,
+    {
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let unsnapshotted_wc_commit = working_copy_commit(&repo, workspace.workspace_name())?;
+        let snapshot_settings = SnapshotSettings::new(&workspace, &FfiSnapshotOptions::default())?;
+        self.import_and_snapshot(
+            &mut workspace,
+            &mut repo,
+            &snapshot_settings,
+            "snapshot working copy",
+        )?;
+        let old_wc_commit = working_copy_commit(&repo, workspace.workspace_name())?;
+        let commit_ids = commit_ids
+            .iter()
+            .map(|id| {
+                if id == unsnapshotted_wc_commit.id() {
+                    old_wc_commit.id().clone()
+                } else {
+                    id.clone()
+                }
+            })
+            .collect();
+
+        let mut tx = repo.start_transaction();
+        let new_wc_commit = update(
+            tx.repo_mut(),
+            workspace.workspace_name().to_owned(),
+            commit_ids,
+        )?;
+        tx.repo_mut().rebase_descendants()?;
+        #[cfg(feature = "git")]
+        if crate::git::is_colocated(&workspace) {
+            crate::git::export_to_colocated_git(tx.repo_mut(), &repo, workspace.workspace_name())?;
+        }
+        let new_repo = tx.commit(op_description)?;
+
+        workspace
+            .check_out(
+                new_repo.op_id().clone(),
+                Some(&old_wc_commit.tree()),
+                &new_wc_commit,
+            )
+            .map_err(workspace_error)?;
+        *repo = new_repo;
+        Ok(new_wc_commit)
+    }
 }
 
 #[uniffi::export]
 impl FfiWorkspace {
     /// Load an existing workspace from the given path
+    ///
+    /// Fails with `IncompatibleRepository` if the repository uses a format
+    /// this library doesn't support.
+    ///
+    /// Divergent operations, e.g. from the app and the `jj` CLI running
+    /// concurrently, are merged like the CLI does. See
+    /// `operation_merge_report()`.
+    ///
+    /// In a colocated workspace, Git HEAD moves made by external tools are
+    /// imported on load, reload and snapshot. See `git_head_sync_report()`.
     #[uniffi::constructor]
     pub fn load(
         workspace_path: String,
@@ -62,19 +533,26 @@ impl FfiWorkspace {
         user_email: String,
     ) -> Result<Arc<Self>> {
         let path = Path::new(&workspace_path);
+        check_workspace_format(path)?;
+        let repo_path = workspace_repo_path(path)?;
         let settings = create_user_settings(&user_name, &user_email)?;
-        let store_factories = StoreFactories::default();
-        let working_copy_factories = default_working_copy_factories();
-
-        let workspace =
-            Workspace::load(&settings, path, &store_factories, &working_copy_factories)?;
-
-        let repo = workspace.repo_loader().load_at_head()?;
+        let settings = with_config_aliases(&settings, &repo_path)?;
+        Self::load_with_settings(path, &settings)
+    }
 
-        Ok(Arc::new(Self {
-            inner: Mutex::new(workspace),
-            repo,
-        }))
+    /// Load an existing workspace with settings from the user's jj config
+    ///
+    /// The config files are found like the `jj` CLI does: `$JJ_CONFIG`, or
+    /// the user config file and `conf.d` directory, followed by the repo's
+    /// config. Unlike `load()`, this applies every setting, such as the
+    /// user identity, signing and Git settings.
+    #[uniffi::constructor]
+    pub fn load_with_user_config(workspace_path: String) -> Result<Arc<Self>> {
+        let path = Path::new(&workspace_path);
+        check_workspace_format(path)?;
+        let repo_path = workspace_repo_path(path)?;
+        let settings = load_user_settings(&repo_path, Some(path))?;
+        Self::load_with_settings(path, &settings)
     }
 
     /// Get the workspace root path
@@ -89,10 +567,891 @@ impl FfiWorkspace {
         workspace.repo_path().to_string_lossy().to_string()
     }
 
+    /// Get the store and working copy types used by the workspace
+    pub fn repo_format(&self) -> Result<FfiRepoFormat> {
+        let workspace = self.inner.lock().unwrap();
+        check_workspace_format(workspace.workspace_root())
+    }
+
     /// Get a readonly repository handle
+    ///
+    /// Revsets evaluated with the handle resolve `@` to this workspace's
+    /// working-copy commit.
     pub fn repo(&self) -> Arc<FfiReadonlyRepo> {
-        Arc::new(FfiReadonlyRepo::new(Arc::clone(&self.repo)))
+        let workspace = self.inner.lock().unwrap();
+        let repo = self.repo.lock().unwrap();
+        repo_handle(&workspace, Arc::clone(&repo))
+    }
+
+    /// Reload the repository at the latest operation
+    ///
+    /// Picks up operations made since the workspace was loaded, e.g. by the
+    /// `jj` CLI. Subsequent calls to `repo()` return the reloaded repository.
+    /// Divergent operations are merged and, in a colocated workspace, Git
+    /// changes are imported like on load.
+    pub fn reload(&self) -> Result<Arc<FfiReadonlyRepo>> {
+        // Only syncing with Git modifies the workspace and repository
+        #[cfg_attr(not(feature = "git"), allow(unused_mut))]
+        let mut workspace = self.inner.lock().unwrap();
+        #[cfg_attr(not(feature = "git"), allow(unused_mut))]
+        let (mut new_repo, operation_merge) =
+            crate::operation::load_at_head(workspace.repo_loader())?;
+        *self.operation_merge.lock().unwrap() = operation_merge;
+        #[cfg(feature = "git")]
+        self.sync_git_head(&mut workspace, &mut new_repo)?;
+        *self.repo.lock().unwrap() = Arc::clone(&new_repo);
+        Ok(repo_handle(&workspace, new_repo))
+    }
+
+    /// Snapshot the files on disk into the working-copy commit
+    ///
+    /// Records a new operation if files changed and returns the resulting
+    /// repository. Fails with `Cancelled` if the cancellation token is
+    /// cancelled before the snapshot is recorded.
+    pub fn snapshot(
+        &self,
+        cancellation: Option<Arc<FfiCancellationToken>>,
+    ) -> Result<Arc<FfiReadonlyRepo>> {
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let options = FfiSnapshotOptions {
+            cancellation,
+            ..FfiSnapshotOptions::default()
+        };
+        let snapshot_settings = SnapshotSettings::new(&workspace, &options)?;
+        self.import_and_snapshot(
+            &mut workspace,
+            &mut repo,
+            &snapshot_settings,
+            "snapshot working copy",
+        )?;
+        Ok(repo_handle(&workspace, Arc::clone(&repo)))
+    }
+
+    /// Snapshot the working copy with the given options
+    ///
+    /// Unlike `snapshot()`, new files left untracked because they are too
+    /// large or not matched by the auto-track fileset are reported in the
+    /// result.
+    pub fn snapshot_with_options(&self, options: FfiSnapshotOptions) -> Result<FfiSnapshotResult> {
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        if let Some(style) = options.conflict_marker_style {
+            let settings = with_conflict_marker_style(workspace.settings(), style)?;
+            reload_with_settings(&mut workspace, &mut repo, &settings)?;
+        }
+        let snapshot_settings = SnapshotSettings::new(&workspace, &options)?;
+        let stats = self.import_and_snapshot(
+            &mut workspace,
+            &mut repo,
+            &snapshot_settings,
+            "snapshot working copy",
+        )?;
+        Ok(FfiSnapshotResult {
+            repo: repo_handle(&workspace, Arc::clone(&repo)),
+            untracked_paths: untracked_paths(&stats),
+        })
+    }
+
+    /// List new files in the working copy that a snapshot leaves untracked
+    ///
+    /// These are files that are too large or don't match the auto-track
+    /// fileset. Ignored files are not listed. Nothing is recorded; the
+    /// working copy is scanned without finishing a snapshot.
+    pub fn untracked_files(&self) -> Result<Vec<FfiUntrackedPath>> {
+        let mut workspace = self.inner.lock().unwrap();
+        let snapshot_settings = SnapshotSettings::new(&workspace, &FfiSnapshotOptions::default())?;
+        let mut locked_ws = workspace
+            .start_working_copy_mutation()
+            .map_err(workspace_error)?;
+        let (_tree, stats) =
+            pollster::block_on(locked_ws.locked_wc().snapshot(&snapshot_settings.options()))
+                .map_err(workspace_error)?;
+        // Dropping the lock without finishing leaves the working-copy state untouched
+        Ok(untracked_paths(&stats))
+    }
+
+    /// Start tracking paths, like `jj file track`
+    ///
+    /// Paths are repository-relative files or directories in internal
+    /// ("/"-separated) form. Files under them are tracked even if they are
+    /// ignored, too large or not matched by the auto-track fileset.
+    pub fn track(&self, paths: Vec<String>) -> Result<Arc<FfiReadonlyRepo>> {
+        let repo_paths = paths
+            .iter()
+            .map(|path| parse_repo_path(path))
+            .collect::<Result<Vec<_>>>()?;
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let snapshot_settings = SnapshotSettings::new(&workspace, &FfiSnapshotOptions::default())?
+            .with_forced_paths(&repo_paths);
+        self.import_and_snapshot(&mut workspace, &mut repo, &snapshot_settings, "track paths")?;
+        Ok(repo_handle(&workspace, Arc::clone(&repo)))
+    }
+
+    /// Stop tracking paths, like `jj file untrack`
+    ///
+    /// Paths are repository-relative files or directories in internal
+    /// ("/"-separated) form. The files stay on disk and are removed from the
+    /// working-copy commit. Fails if a file would be tracked again by the next
+    /// snapshot, i.e. it is matched by the auto-track fileset and not ignored.
+    pub fn untrack(&self, paths: Vec<String>) -> Result<Arc<FfiReadonlyRepo>> {
+        let repo_paths = paths
+            .iter()
+            .map(|path| parse_repo_path(path))
+            .collect::<Result<Vec<_>>>()?;
+        let matcher = PrefixMatcher::new(&repo_paths);
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let snapshot_settings = SnapshotSettings::new(&workspace, &FfiSnapshotOptions::default())?;
+        self.import_and_snapshot(
+            &mut workspace,
+            &mut repo,
+            &snapshot_settings,
+            "snapshot working copy",
+        )?;
+
+        let wc_commit = working_copy_commit(&repo, workspace.workspace_name())?;
+        let wc_tree = wc_commit.tree();
+        let mut tree_builder = MergedTreeBuilder::new(wc_tree.clone());
+        for (path, _value) in wc_tree.entries_matching(&matcher) {
+            tree_builder.set_or_remove(path, Merge::absent());
+        }
+        let new_tree = tree_builder.write_tree()?;
+        let mut tx = repo.start_transaction();
+        let new_commit = tx
+            .repo_mut()
+            .rewrite_commit(&wc_commit)
+            .set_tree(new_tree)
+            .write()?;
+        tx.repo_mut().rebase_descendants()?;
+
+        let mut locked_ws = workspace
+            .start_working_copy_mutation()
+            .map_err(workspace_error)?;
+        pollster::block_on(locked_ws.locked_wc().reset(&new_commit)).map_err(workspace_error)?;
+        let (new_wc_tree, _stats) =
+            pollster::block_on(locked_ws.locked_wc().snapshot(&snapshot_settings.options()))
+                .map_err(workspace_error)?;
+        if new_wc_tree.tree_ids() != new_commit.tree_ids() {
+            // Dropping the lock without finishing leaves the working-copy state untouched
+            return Err(JjError::InvalidArgument {
+                message: "Some paths could not be untracked because they are not ignored"
+                    .to_string(),
+            });
+        }
+        let new_repo = tx.commit("untrack paths")?;
+        locked_ws
+            .finish(new_repo.op_id().clone())
+            .map_err(workspace_error)?;
+        *repo = new_repo;
+        Ok(repo_handle(&workspace, Arc::clone(&repo)))
+    }
+
+    /// Get the conflict marker style used for materialization and working-copy writes
+    pub fn conflict_marker_style(&self) -> FfiConflictMarkerStyle {
+        let workspace = self.inner.lock().unwrap();
+        conflict_marker_style(workspace.settings()).into()
+    }
+
+    /// Set the conflict marker style used for materialization and working-copy writes
+    ///
+    /// This should match the user's `ui.conflict-marker-style` so conflicted
+    /// files can be parsed back. Repositories obtained from `repo()` afterwards
+    /// use the new style.
+    pub fn set_conflict_marker_style(&self, style: FfiConflictMarkerStyle) -> Result<()> {
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let settings = with_conflict_marker_style(workspace.settings(), style)?;
+        reload_with_settings(&mut workspace, &mut repo, &settings)
+    }
+
+    /// Get the filesystem monitor used to find changed files when snapshotting
+    pub fn fsmonitor(&self) -> FfiFsmonitor {
+        let workspace = self.inner.lock().unwrap();
+        fsmonitor(workspace.settings())
+    }
+
+    /// Set the filesystem monitor used to find changed files when snapshotting
+    ///
+    /// Overrides the `fsmonitor.backend` setting. With Watchman, snapshots of
+    /// large working copies only check the files changed since the previous
+    /// snapshot. Fails if the library was built without the `watchman`
+    /// feature.
+    pub fn set_fsmonitor(&self, fsmonitor: FfiFsmonitor) -> Result<()> {
+        if cfg!(not(feature = "watchman")) && fsmonitor != FfiFsmonitor::None {
+            return Err(JjError::InvalidArgument {
+                message: "Watchman is not supported: built without the `watchman` feature"
+                    .to_string(),
+            });
+        }
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let settings = with_config_override(workspace.settings(), &fsmonitor.config_toml())?;
+        reload_with_settings(&mut workspace, &mut repo, &settings)
+    }
+
+    /// Create a new empty working-copy commit on top of the given parents, like `jj new`
+    ///
+    /// Pending changes on disk are snapshotted into the current working-copy
+    /// commit first. Files on disk are updated to the new commit's tree.
+    pub fn new_child(&self, parent_ids: Vec<FfiCommitId>) -> Result<FfiCommit> {
+        let parent_ids = parent_ids
+            .iter()
+            .map(CommitId::try_from)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| JjError::InvalidArgument {
+                message: format!("Invalid parent commit ID: {}", e),
+            })?;
+        if parent_ids.is_empty() {
+            return Err(JjError::InvalidArgument {
+                message: "At least one parent commit ID is required".to_string(),
+            });
+        }
+
+        let commit = self.update_working_copy(
+            "new empty commit",
+            &parent_ids,
+            |mut_repo, workspace_name, parent_ids| {
+                let parents = parent_ids
+                    .iter()
+                    .map(|id| mut_repo.store().get_commit(id))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                let tree = pollster::block_on(merge_commit_trees(mut_repo, &parents))?;
+                let commit = mut_repo.new_commit(parent_ids, tree).write()?;
+                mut_repo
+                    .edit(workspace_name, &commit)
+                    .map_err(|e| JjError::Transaction {
+                        message: e.to_string(),
+                    })?;
+                Ok(commit)
+            },
+        )?;
+        Ok(FfiCommit::from(&commit))
+    }
+
+    /// Make an existing commit the working-copy commit, like `jj edit`
+    ///
+    /// Pending changes on disk are snapshotted into the current working-copy
+    /// commit first. Files on disk are updated to the edited commit's tree.
+    pub fn edit(&self, commit_id: &FfiCommitId) -> Result<FfiCommit> {
+        let id = CommitId::try_from(commit_id).map_err(|e| JjError::InvalidArgument {
+            message: format!("Invalid commit ID: {}", e),
+        })?;
+
+        let root_commit_id = self.repo.lock().unwrap().store().root_commit_id().clone();
+        if id == root_commit_id {
+            return Err(JjError::InvalidArgument {
+                message: "Cannot edit the root commit".to_string(),
+            });
+        }
+
+        let op_description = format!("edit commit {}", id.hex());
+        let commit =
+            self.update_working_copy(&op_description, &[id], |mut_repo, workspace_name, ids| {
+                let commit = mut_repo.store().get_commit(&ids[0])?;
+                mut_repo
+                    .edit(workspace_name, &commit)
+                    .map_err(|e| JjError::Transaction {
+                        message: e.to_string(),
+                    })?;
+                Ok(commit)
+            })?;
+        Ok(FfiCommit::from(&commit))
+    }
+
+    /// Discard the changes to paths in the working copy, like `jj restore <paths>`
+    ///
+    /// Paths are repository-relative files or directories in internal
+    /// ("/"-separated) form. Pending changes on disk are snapshotted first,
+    /// then the paths are restored to their state in the parent commit(s) in
+    /// the working-copy commit and on disk. Returns the rewritten
+    /// working-copy commit.
+    pub fn restore_paths_from_parent(&self, paths: Vec<String>) -> Result<FfiCommit> {
+        if paths.is_empty() {
+            return Err(JjError::InvalidArgument {
+                message: "At least one path is required".to_string(),
+            });
+        }
+        let repo_paths = paths
+            .iter()
+            .map(|path| parse_repo_path(path))
+            .collect::<Result<Vec<_>>>()?;
+        let matcher = PrefixMatcher::new(&repo_paths);
+
+        let wc_commit_id = {
+            let workspace = self.inner.lock().unwrap();
+            let repo = self.repo.lock().unwrap();
+            working_copy_commit(&repo, workspace.workspace_name())?
+                .id()
+                .clone()
+        };
+        let commit =
+            self.update_working_copy("restore paths", &[wc_commit_id], |mut_repo, _, ids| {
+                let wc_commit = mut_repo.store().get_commit(&ids[0])?;
+                let parent_tree = wc_commit.parent_tree(mut_repo)?;
+                let new_tree =
+                    pollster::block_on(restore_tree(&parent_tree, &wc_commit.tree(), &matcher))?;
+                if new_tree.tree_ids() == wc_commit.tree_ids() {
+                    return Ok(wc_commit);
+                }
+                let commit = mut_repo
+                    .rewrite_commit(&wc_commit)
+                    .set_tree(new_tree)
+                    .write()?;
+                Ok(commit)
+            })?;
+        Ok(FfiCommit::from(&commit))
+    }
+
+    /// Commit a transaction and update the working copy to its result
+    ///
+    /// Like `FfiTransaction::commit()`, then files on disk are updated to the
+    /// workspace's new working-copy commit, and `repo()` returns the new
+    /// repository. The transaction must be started from a repository of this
+    /// workspace. Fails without committing if the working copy was updated
+    /// since the transaction was started, e.g. by a snapshot, or if files on
+    /// disk have changes that were not snapshotted, since checking out would
+    /// overwrite them.
+    pub fn finish_transaction(
+        &self,
+        tx: Arc<FfiTransaction>,
+        description: String,
+    ) -> Result<FfiTransactionResult> {
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let base_repo = tx.base_repo();
+        let base_repo = base_repo.inner();
+        if workspace
+            .repo_loader()
+            .load_operation(base_repo.op_id())
+            .is_err()
+        {
+            return Err(JjError::InvalidArgument {
+                message: "The transaction was not started from this workspace's repository"
+                    .to_string(),
+            });
+        }
+        let workspace_name = workspace.workspace_name().to_owned();
+        let old_wc_commit = base_repo
+            .view()
+            .get_wc_commit_id(&workspace_name)
+            .map(|id| base_repo.store().get_commit(id))
+            .transpose()?;
+
+        let snapshot_settings = SnapshotSettings::new(&workspace, &FfiSnapshotOptions::default())?;
+        let mut locked_ws = workspace
+            .start_working_copy_mutation()
+            .map_err(workspace_error)?;
+        if let Some(old_wc_commit) = &old_wc_commit {
+            if locked_ws.locked_wc().old_tree().tree_ids() != old_wc_commit.tree_ids() {
+                return Err(JjError::Workspace {
+                    message: "The working copy was updated after the transaction was started"
+                        .to_string(),
+                });
+            }
+            let (disk_tree, _stats) =
+                pollster::block_on(locked_ws.locked_wc().snapshot(&snapshot_settings.options()))
+                    .map_err(workspace_error)?;
+            if disk_tree.tree_ids() != old_wc_commit.tree_ids() {
+                // Dropping the lock without finishing leaves the working-copy state untouched
+                return Err(JjError::Workspace {
+                    message: "The working copy has changes that were not snapshotted; snapshot \
+                              and start the transaction again"
+                        .to_string(),
+                });
+            }
+        }
+        let result = tx.commit(description)?;
+        let new_repo = Arc::clone(result.repo.inner());
+        if let Some(new_wc_id) = new_repo.view().get_wc_commit_id(&workspace_name) {
+            let new_wc_commit = new_repo.store().get_commit(new_wc_id)?;
+            pollster::block_on(locked_ws.locked_wc().check_out(&new_wc_commit))
+                .map_err(workspace_error)?;
+        }
+        locked_ws
+            .finish(new_repo.op_id().clone())
+            .map_err(workspace_error)?;
+        *repo = new_repo;
+        Ok(FfiTransactionResult {
+            repo: repo_handle(&workspace, Arc::clone(&repo)),
+            rewritten_commits: result.rewritten_commits,
+        })
+    }
+
+    /// Update a stale working copy to the latest operation, like
+    /// `jj workspace update-stale`
+    ///
+    /// The working copy becomes stale when the working-copy commit is
+    /// rewritten without updating the files on disk, e.g. by
+    /// `FfiReadonlyRepo::undo_latest_operation()`,
+    /// `FfiReadonlyRepo::restore_to_operation()` or the `jj` CLI in another
+    /// workspace. Snapshots fail until the working copy is updated.
+    ///
+    /// Pending changes on disk are first snapshotted at the operation the
+    /// working copy was last updated at, and merged with the latest
+    /// operation like the CLI does, so they are not lost. Files on disk are
+    /// then updated to the latest working-copy commit. Does nothing but
+    /// reload if the working copy is not stale.
+    pub fn update_stale(&self) -> Result<Arc<FfiReadonlyRepo>> {
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let loader = workspace.repo_loader().clone();
+        let wc_op_id = workspace.working_copy().operation_id().clone();
+        let wc_op = loader
+            .load_operation(&wc_op_id)
+            .map_err(|e| JjError::Workspace {
+                message: format!(
+                    "Failed to load the working copy's operation {}: {}",
+                    wc_op_id.hex(),
+                    e
+                ),
+            })?;
+        let snapshot_settings = SnapshotSettings::new(&workspace, &FfiSnapshotOptions::default())?;
+        let (stale_repo, _stats) = snapshot_working_copy(
+            &mut workspace,
+            loader.load_at(&wc_op)?,
+            &snapshot_settings,
+            "snapshot working copy",
+        )?;
+        let stale_wc_commit = working_copy_commit(&stale_repo, workspace.workspace_name())?;
+
+        let (new_repo, operation_merge) = crate::operation::load_at_head(&loader)?;
+        *self.operation_merge.lock().unwrap() = operation_merge;
+        let new_wc_commit = working_copy_commit(&new_repo, workspace.workspace_name())?;
+        let mut locked_ws = workspace
+            .start_working_copy_mutation()
+            .map_err(workspace_error)?;
+        let freshness =
+            WorkingCopyFreshness::check_stale(locked_ws.locked_wc(), &new_wc_commit, &new_repo)
+                .map_err(|e| JjError::Repository {
+                    message: e.to_string(),
+                })?;
+        match freshness {
+            WorkingCopyFreshness::Fresh | WorkingCopyFreshness::Updated(_) => drop(locked_ws),
+            WorkingCopyFreshness::WorkingCopyStale | WorkingCopyFreshness::SiblingOperation => {
+                if locked_ws.locked_wc().old_tree().tree_ids() != stale_wc_commit.tree_ids() {
+                    return Err(JjError::Workspace {
+                        message: "The working copy was updated concurrently; try again".to_string(),
+                    });
+                }
+                pollster::block_on(locked_ws.locked_wc().check_out(&new_wc_commit))
+                    .map_err(workspace_error)?;
+                locked_ws
+                    .finish(new_repo.op_id().clone())
+                    .map_err(workspace_error)?;
+            }
+        }
+        *repo = new_repo;
+
+        // Import Git changes made while the working copy was stale
+        self.import_and_snapshot(
+            &mut workspace,
+            &mut repo,
+            &snapshot_settings,
+            "snapshot working copy",
+        )?;
+        Ok(repo_handle(&workspace, Arc::clone(&repo)))
+    }
+
+    /// Get a readonly repository handle at a past operation
+    ///
+    /// Accepts an operation ID (or unique prefix) or an operation expression
+    /// such as "@-".
+    pub fn repo_at_operation(&self, op_id: String) -> Result<Arc<FfiReadonlyRepo>> {
+        let workspace = self.inner.lock().unwrap();
+        let repo = crate::operation::load_at_operation(workspace.repo_loader(), &op_id)?;
+        Ok(repo_handle(&workspace, repo))
+    }
+
+    /// Abandon old operations and delete unreachable data, like `jj op abandon`
+    /// followed by `jj util gc`
+    ///
+    /// Operations that ended before `keep_ops_newer_than` are abandoned, and
+    /// the newer operations get new IDs. The latest operation is always kept.
+    /// Commits and operations that are no longer reachable and older than
+    /// `keep_ops_newer_than` are then deleted from the store. Returns the
+    /// repository at the new latest operation.
+    pub fn gc(&self, keep_ops_newer_than: FfiTimestamp) -> Result<Arc<FfiReadonlyRepo>> {
+        let keep_newer = SystemTime::UNIX_EPOCH
+            + Duration::from_millis(keep_ops_newer_than.millis_since_epoch.max(0) as u64);
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let loader = workspace.repo_loader().clone();
+        let (head_repo, head_merge) = crate::operation::load_at_head(&loader)?;
+        let new_head_id = crate::operation::abandon_operations_before(
+            &loader,
+            head_repo.operation(),
+            keep_newer,
+        )?;
+        if let Some(new_head_id) = new_head_id {
+            let mut locked_ws = workspace
+                .start_working_copy_mutation()
+                .map_err(workspace_error)?;
+            // The working copy's operation was rewritten if it was the head
+            if locked_ws.locked_wc().old_operation_id() == head_repo.op_id() {
+                locked_ws.finish(new_head_id).map_err(workspace_error)?;
+            }
+        }
+
+        let (new_repo, new_merge) = crate::operation::load_at_head(&loader)?;
+        *self.operation_merge.lock().unwrap() = new_merge.or(head_merge);
+        crate::operation::gc(&new_repo, keep_newer)?;
+        *repo = new_repo;
+        Ok(repo_handle(&workspace, Arc::clone(&repo)))
+    }
+
+    /// Rebuild the commit index from scratch, like `jj debug reindex`
+    ///
+    /// Commits imported from a shallow Git repository keep their truncated
+    /// history after the repository is deepened by a fetch with a larger
+    /// depth. Reindexing picks up the fetched history. Returns the repository
+    /// at the latest operation.
+    pub fn reindex(&self) -> Result<Arc<FfiReadonlyRepo>> {
+        let mut workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let index_store = workspace.repo_loader().index_store();
+        let Some(default_index_store) = index_store.downcast_ref::<DefaultIndexStore>() else {
+            return Err(JjError::Internal {
+                message: format!("Cannot reindex a '{}' index", index_store.name()),
+            });
+        };
+        default_index_store.reinit().map_err(|e| JjError::Internal {
+            message: format!("Index error: {}", e),
+        })?;
+
+        // Load the workspace again so commits cached with the old history are dropped
+        *workspace = Workspace::load(
+            workspace.settings(),
+            workspace.workspace_root(),
+            &StoreFactories::default(),
+            &default_working_copy_factories(),
+        )?;
+        let operation_merge;
+        (*repo, operation_merge) = crate::operation::load_at_head(workspace.repo_loader())?;
+        *self.operation_merge.lock().unwrap() = operation_merge;
+        Ok(repo_handle(&workspace, Arc::clone(&repo)))
+    }
+
+    /// Get the name of the workspace
+    pub fn workspace_name(&self) -> String {
+        let workspace = self.inner.lock().unwrap();
+        workspace.workspace_name().as_str().to_string()
+    }
+
+    /// Add a secondary workspace of the repository at the given path, like
+    /// `jj workspace add`
+    ///
+    /// The new workspace's working-copy commit is created on top of the
+    /// parents of this workspace's working-copy commit, and its files are
+    /// written to the path. The directory is created if it doesn't exist.
+    pub fn workspace_add(&self, path: String, name: String) -> Result<Arc<FfiWorkspace>> {
+        if name.is_empty() {
+            return Err(JjError::InvalidArgument {
+                message: "Workspace name must not be empty".to_string(),
+            });
+        }
+        let workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let name = WorkspaceNameBuf::from(name);
+        if repo.view().get_wc_commit_id(&name).is_some() {
+            return Err(JjError::InvalidArgument {
+                message: format!("Workspace '{}' already exists", name.as_symbol()),
+            });
+        }
+        let parent_ids = working_copy_commit(&repo, workspace.workspace_name())?
+            .parent_ids()
+            .to_vec();
+
+        let path = Path::new(&path);
+        std::fs::create_dir_all(path).map_err(workspace_error)?;
+        let (mut new_workspace, new_repo) = Workspace::init_workspace_with_existing_repo(
+            path,
+            workspace.repo_path(),
+            &repo,
+            &*default_working_copy_factory(),
+            name.clone(),
+        )?;
+
+        let mut tx = new_repo.start_transaction();
+        let parents = parent_ids
+            .iter()
+            .map(|id| tx.repo().store().get_commit(id))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let tree = pollster::block_on(merge_commit_trees(tx.repo(), &parents))?;
+        let wc_commit = tx.repo_mut().new_commit(parent_ids, tree).write()?;
+        tx.repo_mut()
+            .edit(name.clone(), &wc_commit)
+            .map_err(|e| JjError::Transaction {
+                message: e.to_string(),
+            })?;
+        tx.repo_mut().rebase_descendants()?;
+        let new_repo = tx.commit(format!(
+            "create initial working-copy commit in workspace {}",
+            name.as_symbol()
+        ))?;
+        new_workspace
+            .check_out(new_repo.op_id().clone(), None, &wc_commit)
+            .map_err(workspace_error)?;
+
+        *repo = Arc::clone(&new_repo);
+        Ok(Arc::new(FfiWorkspace {
+            inner: Mutex::new(new_workspace),
+            repo: Mutex::new(new_repo),
+            operation_merge: Mutex::new(None),
+            #[cfg(feature = "git")]
+            git_head_sync: Mutex::new(None),
+        }))
+    }
+
+    /// List the workspaces of the repository, like `jj workspace list`
+    pub fn workspace_list(&self) -> Vec<FfiWorkspaceInfo> {
+        let repo = self.repo.lock().unwrap();
+        repo.view()
+            .wc_commit_ids()
+            .iter()
+            .map(|(name, commit_id)| FfiWorkspaceInfo {
+                name: name.as_str().to_string(),
+                working_copy_commit_id: FfiCommitId::from(commit_id),
+            })
+            .collect()
+    }
+
+    /// Stop tracking a workspace's working-copy commit, like `jj workspace forget`
+    ///
+    /// The workspace's files on disk are left untouched. This workspace can't
+    /// forget itself; forget it from another workspace instead. Returns the
+    /// updated repository.
+    pub fn workspace_forget(&self, name: String) -> Result<Arc<FfiReadonlyRepo>> {
+        let workspace = self.inner.lock().unwrap();
+        let mut repo = self.repo.lock().unwrap();
+        let name = WorkspaceNameBuf::from(name);
+        if name == *workspace.workspace_name() {
+            return Err(JjError::InvalidArgument {
+                message: format!("Cannot forget the current workspace: {}", name.as_symbol()),
+            });
+        }
+        if repo.view().get_wc_commit_id(&name).is_none() {
+            return Err(JjError::InvalidArgument {
+                message: format!("No such workspace: {}", name.as_symbol()),
+            });
+        }
+
+        let mut tx = repo.start_transaction();
+        tx.repo_mut()
+            .remove_wc_commit(&name)
+            .map_err(|e| JjError::Transaction {
+                message: e.to_string(),
+            })?;
+        tx.repo_mut().rebase_descendants()?;
+        *repo = tx.commit(format!("forget workspace {}", name.as_symbol()))?;
+        Ok(repo_handle(&workspace, Arc::clone(&repo)))
+    }
+
+    /// Get the divergent operations merged by the latest `load()`,
+    /// `reload()`, `update_stale()`, `gc()` or `reindex()`
+    ///
+    /// Returns `None` if the repository had a single latest operation.
+    pub fn operation_merge_report(&self) -> Option<FfiOperationMergeReport> {
+        self.operation_merge.lock().unwrap().clone()
+    }
+}
+
+/// Methods backed by Git, which uniffi can only leave out of the bindings
+/// as a whole block
+#[cfg(feature = "git")]
+#[uniffi::export]
+impl FfiWorkspace {
+    /// Check whether the workspace shares its working directory with Git
+    ///
+    /// In a colocated workspace, Git HEAD and refs are imported on load and
+    /// exported when snapshots and transactions are committed, like the `jj`
+    /// CLI does, so jj and Git stay in sync.
+    pub fn is_colocated(&self) -> bool {
+        let workspace = self.inner.lock().unwrap();
+        crate::git::is_colocated(&workspace)
+    }
+
+    /// Get what was reconciled with Git by the latest load, reload or
+    /// snapshot
+    ///
+    /// Returns `None` unless the workspace is colocated and Git HEAD or refs
+    /// were changed outside of jj.
+    pub fn git_head_sync_report(&self) -> Option<FfiGitHeadSyncReport> {
+        self.git_head_sync.lock().unwrap().clone()
+    }
+}
+
+/// A workspace of a repository and its working-copy commit
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiWorkspaceInfo {
+    /// Name of the workspace
+    pub name: String,
+    /// The workspace's working-copy commit
+    pub working_copy_commit_id: FfiCommitId,
+}
+
+/// Options for creating an initial commit when initializing a workspace
+#[cfg(feature = "git")]
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiInitOptions {
+    /// Description of the initial commit (no initial commit is created if `None`)
+    pub initial_commit_description: Option<String>,
+    /// Whether to include the files already present in the workspace directory
+    pub include_existing_files: bool,
+    /// Name of a local bookmark to point at the initial commit
+    pub bookmark_name: Option<String>,
+}
+
+/// Create the initial commit and bookmark of a freshly initialized workspace
+///
+/// `Workspace::init_*` records an operation that checks out an empty
+/// working-copy commit. It is replaced by an operation that creates the
+/// initial commit and checks out a new working-copy commit on top of it, so
+/// initializing the workspace is still a single operation.
+#[cfg(feature = "git")]
+fn create_initial_commit(
+    workspace: &mut Workspace,
+    repo: Arc<ReadonlyRepo>,
+    options: &FfiInitOptions,
+    bookmark_name: Option<&RefName>,
+    colocated: bool,
+) -> Result<Arc<ReadonlyRepo>> {
+    let Some(description) = &options.initial_commit_description else {
+        return Ok(repo);
+    };
+
+    let snapshot_settings = if options.include_existing_files {
+        Some(SnapshotSettings::new(
+            workspace,
+            &FfiSnapshotOptions::default(),
+        )?)
+    } else {
+        None
+    };
+    let workspace_name = workspace.workspace_name().to_owned();
+    let mut locked_ws = workspace
+        .start_working_copy_mutation()
+        .map_err(workspace_error)?;
+
+    let tree = if let Some(snapshot_settings) = &snapshot_settings {
+        let (tree, _stats) =
+            pollster::block_on(locked_ws.locked_wc().snapshot(&snapshot_settings.options()))
+                .map_err(workspace_error)?;
+        tree
+    } else {
+        repo.store().empty_merged_tree()
+    };
+
+    let loader = repo.loader();
+    let root_repo = loader.load_at(&loader.root_operation())?;
+    let mut tx = root_repo.start_transaction();
+    let initial_commit = tx
+        .repo_mut()
+        .new_commit(vec![repo.store().root_commit_id().clone()], tree)
+        .set_description(description)
+        .write()?;
+    let new_wc_commit = tx
+        .repo_mut()
+        .check_out(workspace_name, &initial_commit)
+        .map_err(|e| JjError::Transaction {
+            message: e.to_string(),
+        })?;
+    if let Some(bookmark_name) = bookmark_name {
+        tx.repo_mut().set_local_bookmark_target(
+            bookmark_name,
+            RefTarget::normal(initial_commit.id().clone()),
+        );
     }
+    if colocated {
+        git::reset_head(tx.repo_mut(), &new_wc_commit).map_err(|e| JjError::Git {
+            message: e.to_string(),
+        })?;
+    }
+    git::export_refs(tx.repo_mut()).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    let operation = tx.write("initialize repository")?;
+    let op_heads_error = |e: OpHeadsStoreError| JjError::Repository {
+        message: e.to_string(),
+    };
+    {
+        let op_heads_store = loader.op_heads_store();
+        let _lock = pollster::block_on(op_heads_store.lock()).map_err(op_heads_error)?;
+        pollster::block_on(
+            op_heads_store.update_op_heads(&[repo.op_id().clone()], operation.operation().id()),
+        )
+        .map_err(op_heads_error)?;
+    }
+    let repo = operation.leave_unpublished();
+
+    pollster::block_on(locked_ws.locked_wc().reset(&new_wc_commit)).map_err(workspace_error)?;
+    locked_ws
+        .finish(repo.op_id().clone())
+        .map_err(workspace_error)?;
+    Ok(repo)
+}
+
+/// Check the init options, returning the bookmark name to create
+///
+/// Bookmark names are parsed like in `jj bookmark create`, so names that
+/// aren't valid revset symbols must be quoted.
+#[cfg(feature = "git")]
+fn parse_init_options(options: &FfiInitOptions) -> Result<Option<RefNameBuf>> {
+    if options.initial_commit_description.is_none()
+        && (options.include_existing_files || options.bookmark_name.is_some())
+    {
+        return Err(JjError::InvalidArgument {
+            message:
+                "An initial commit description is required to include files or create a bookmark"
+                    .to_string(),
+        });
+    }
+    let Some(name) = &options.bookmark_name else {
+        return Ok(None);
+    };
+    let name = revset::parse_symbol(name).map_err(|e| JjError::InvalidArgument {
+        message: format!("Invalid bookmark name '{}': {}", name, e.kind()),
+    })?;
+    Ok(Some(name.into()))
+}
+
+/// Initialize a new Git workspace with the given settings
+#[cfg(feature = "git")]
+fn init_git_workspace(
+    path: &Path,
+    settings: &UserSettings,
+    options: Option<FfiInitOptions>,
+    colocated: bool,
+) -> Result<Arc<FfiWorkspace>> {
+    // Checked before anything is written to the workspace directory
+    let bookmark_name = options
+        .as_ref()
+        .map(parse_init_options)
+        .transpose()?
+        .flatten();
+    let (mut workspace, repo) = if colocated {
+        Workspace::init_colocated_git(settings, path)?
+    } else {
+        Workspace::init_internal_git(settings, path)?
+    };
+    let repo = match &options {
+        Some(options) => create_initial_commit(
+            &mut workspace,
+            repo,
+            options,
+            bookmark_name.as_deref(),
+            colocated,
+        )?,
+        None => repo,
+    };
+
+    Ok(Arc::new(FfiWorkspace {
+        inner: Mutex::new(workspace),
+        repo: Mutex::new(repo),
+        operation_merge: Mutex::new(None),
+        git_head_sync: Mutex::new(None),
+    }))
 }
 
 /// Initialize a new Git workspace with internal Git backend
@@ -105,13 +1464,40 @@ pub fn init_internal_git_workspace(
 ) -> Result<Arc<FfiWorkspace>> {
     let path = Path::new(&workspace_path);
     let settings = create_user_settings(&user_name, &user_email)?;
+    let settings = with_config_aliases(&settings, &path.join(".jj").join("repo"))?;
+    init_git_workspace(path, &settings, None, false)
+}
 
-    let (workspace, repo) = Workspace::init_internal_git(&settings, path)?;
+/// Initialize a new Git workspace with internal Git backend and options
+///
+/// An initial commit and bookmark can be created in the same operation.
+#[cfg(feature = "git")]
+#[uniffi::export]
+pub fn init_internal_git_workspace_with_options(
+    workspace_path: String,
+    user_name: String,
+    user_email: String,
+    options: FfiInitOptions,
+) -> Result<Arc<FfiWorkspace>> {
+    let path = Path::new(&workspace_path);
+    let settings = create_user_settings(&user_name, &user_email)?;
+    let settings = with_config_aliases(&settings, &path.join(".jj").join("repo"))?;
+    init_git_workspace(path, &settings, Some(options), false)
+}
 
-    Ok(Arc::new(FfiWorkspace {
-        inner: Mutex::new(workspace),
-        repo,
-    }))
+/// Initialize a new Git workspace with internal Git backend, with settings
+/// from the user's jj config
+///
+/// See `FfiWorkspace::load_with_user_config()` for how the config is found.
+#[cfg(feature = "git")]
+#[uniffi::export]
+pub fn init_internal_git_workspace_with_user_config(
+    workspace_path: String,
+    options: Option<FfiInitOptions>,
+) -> Result<Arc<FfiWorkspace>> {
+    let path = Path::new(&workspace_path);
+    let settings = load_user_settings(&path.join(".jj").join("repo"), Some(path))?;
+    init_git_workspace(path, &settings, options, false)
 }
 
 /// Initialize a new Git workspace with colocated Git backend
@@ -124,11 +1510,358 @@ pub fn init_colocated_git_workspace(
 ) -> Result<Arc<FfiWorkspace>> {
     let path = Path::new(&workspace_path);
     let settings = create_user_settings(&user_name, &user_email)?;
+    let settings = with_config_aliases(&settings, &path.join(".jj").join("repo"))?;
+    init_git_workspace(path, &settings, None, true)
+}
 
-    let (workspace, repo) = Workspace::init_colocated_git(&settings, path)?;
+/// Initialize a new Git workspace with colocated Git backend and options
+///
+/// An initial commit and bookmark can be created in the same operation.
+/// Git HEAD is set to the initial commit.
+#[cfg(feature = "git")]
+#[uniffi::export]
+pub fn init_colocated_git_workspace_with_options(
+    workspace_path: String,
+    user_name: String,
+    user_email: String,
+    options: FfiInitOptions,
+) -> Result<Arc<FfiWorkspace>> {
+    let path = Path::new(&workspace_path);
+    let settings = create_user_settings(&user_name, &user_email)?;
+    let settings = with_config_aliases(&settings, &path.join(".jj").join("repo"))?;
+    init_git_workspace(path, &settings, Some(options), true)
+}
+
+/// Initialize a new Git workspace with colocated Git backend, with settings
+/// from the user's jj config
+///
+/// See `FfiWorkspace::load_with_user_config()` for how the config is found.
+#[cfg(feature = "git")]
+#[uniffi::export]
+pub fn init_colocated_git_workspace_with_user_config(
+    workspace_path: String,
+    options: Option<FfiInitOptions>,
+) -> Result<Arc<FfiWorkspace>> {
+    let path = Path::new(&workspace_path);
+    let settings = load_user_settings(&path.join(".jj").join("repo"), Some(path))?;
+    init_git_workspace(path, &settings, options, true)
+}
+
+/// Initialize a new workspace backed by an existing external Git repository
+///
+/// Git refs are imported and the working copy is checked out on top of Git
+/// HEAD (if any) in a single operation, like `jj git init --git-repo`.
+#[cfg(feature = "git")]
+fn init_external_git(
+    path: &Path,
+    git_repo_path: &Path,
+    settings: &UserSettings,
+) -> Result<Arc<FfiWorkspace>> {
+    // Accept the Git working tree as well as the `.git` directory, falling
+    // back to the given path for bare repositories
+    let mut git_store_path = git_repo_path.to_owned();
+    if !git_store_path.ends_with(".git") {
+        git_store_path.push(".git");
+        if !git_store_path.exists() {
+            git_store_path.pop();
+        }
+    }
+    let (mut workspace, repo) = Workspace::init_external_git(settings, path, &git_store_path)?;
+    let git_settings = git::GitSettings::from_settings(settings).map_err(|e| JjError::Git {
+        message: format!("Failed to load Git settings: {}", e),
+    })?;
+    let old_wc_commit = working_copy_commit(&repo, workspace.workspace_name())?;
+
+    let mut tx = repo.start_transaction();
+    git::import_refs(tx.repo_mut(), &git_settings).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    git::import_head(tx.repo_mut()).map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    let new_wc_commit = match tx.repo().view().git_head().as_normal().cloned() {
+        Some(head_id) => {
+            let head_commit = tx.repo().store().get_commit(&head_id)?;
+            let wc_commit = tx
+                .repo_mut()
+                .check_out(workspace.workspace_name().to_owned(), &head_commit)
+                .map_err(|e| JjError::Transaction {
+                    message: e.to_string(),
+                })?;
+            Some(wc_commit)
+        }
+        None => None,
+    };
+    tx.repo_mut().rebase_descendants()?;
+    let repo = if tx.repo().has_changes() {
+        tx.commit("import git refs")?
+    } else {
+        repo
+    };
+
+    if let Some(wc_commit) = &new_wc_commit {
+        workspace
+            .check_out(repo.op_id().clone(), Some(&old_wc_commit.tree()), wc_commit)
+            .map_err(workspace_error)?;
+    }
 
     Ok(Arc::new(FfiWorkspace {
         inner: Mutex::new(workspace),
-        repo,
+        repo: Mutex::new(repo),
+        operation_merge: Mutex::new(None),
+        git_head_sync: Mutex::new(None),
     }))
 }
+
+/// Initialize a new workspace backed by an existing Git repository at
+/// `git_repo_path`, like `jj git init --git-repo`
+///
+/// `git_repo_path` may be a Git working tree or a (bare) Git directory. The
+/// Git repository is not colocated: jj keeps its own working copy in
+/// `workspace_path`, which starts on top of the Git repository's HEAD.
+#[cfg(feature = "git")]
+#[uniffi::export]
+pub fn init_external_git_workspace(
+    workspace_path: String,
+    git_repo_path: String,
+    user_name: String,
+    user_email: String,
+) -> Result<Arc<FfiWorkspace>> {
+    let path = Path::new(&workspace_path);
+    let settings = create_user_settings(&user_name, &user_email)?;
+    let settings = with_config_aliases(&settings, &path.join(".jj").join("repo"))?;
+    init_external_git(path, Path::new(&git_repo_path), &settings)
+}
+
+/// Initialize a new workspace backed by an existing Git repository, with
+/// settings from the user's jj config
+///
+/// See `FfiWorkspace::load_with_user_config()` for how the config is found.
+#[cfg(feature = "git")]
+#[uniffi::export]
+pub fn init_external_git_workspace_with_user_config(
+    workspace_path: String,
+    git_repo_path: String,
+) -> Result<Arc<FfiWorkspace>> {
+    let path = Path::new(&workspace_path);
+    let settings = load_user_settings(&path.join(".jj").join("repo"), Some(path))?;
+    init_external_git(path, Path::new(&git_repo_path), &settings)
+}
+
+/// Options for cloning a Git repository
+#[cfg(feature = "git")]
+#[derive(Clone, uniffi::Record)]
+pub struct FfiCloneOptions {
+    /// Name of the remote created for the source, e.g. "origin"
+    pub remote_name: String,
+    /// Whether to colocate the Git repository with the workspace
+    pub colocated: bool,
+    /// Number of commits to fetch from the tips, like `jj git clone --depth`
+    /// (the full history is fetched if `None`)
+    ///
+    /// Fetch with a larger depth and call `FfiWorkspace::reindex()` to
+    /// deepen the clone later.
+    pub depth: Option<u32>,
+    /// Asked for the credentials of the source URL
+    pub credentials: Option<Arc<dyn FfiGitCredentialProvider>>,
+    /// Receives the transfer progress
+    pub progress: Option<Arc<dyn FfiGitProgress>>,
+    /// Cancels the clone, checked during the transfer
+    ///
+    /// The partially created workspace is removed when a clone is cancelled.
+    pub cancellation: Option<Arc<FfiCancellationToken>>,
+}
+
+/// Clones the full history into a non-colocated workspace, naming the remote
+/// "origin" like `jj git clone`
+#[cfg(feature = "git")]
+impl Default for FfiCloneOptions {
+    fn default() -> Self {
+        Self {
+            remote_name: "origin".to_string(),
+            colocated: false,
+            depth: None,
+            credentials: None,
+            progress: None,
+            cancellation: None,
+        }
+    }
+}
+
+/// Clone a Git repository into a new workspace, like `jj git clone`
+///
+/// `load_settings` is called with the repository path, once before the
+/// workspace is created and again when the repository config was updated.
+/// The partially created workspace is removed if cloning fails.
+#[cfg(feature = "git")]
+fn clone_git(
+    source: &str,
+    path: &Path,
+    options: &FfiCloneOptions,
+    load_settings: impl Fn(&Path) -> Result<UserSettings>,
+) -> Result<Arc<FfiWorkspace>> {
+    let path_existed = path.exists();
+    if path_existed
+        && path
+            .read_dir()
+            .map_or(true, |mut entries| entries.next().is_some())
+    {
+        return Err(JjError::InvalidArgument {
+            message: format!(
+                "Destination path {} exists and is not an empty directory",
+                path.display()
+            ),
+        });
+    }
+    std::fs::create_dir_all(path).map_err(|e| JjError::Workspace {
+        message: format!("Failed to create {}: {}", path.display(), e),
+    })?;
+
+    let result = clone_git_into(source, path, options, &load_settings);
+    if result.is_err() {
+        let _ = std::fs::remove_dir_all(path.join(".jj"));
+        if options.colocated {
+            let _ = std::fs::remove_dir_all(path.join(".git"));
+        }
+        if !path_existed {
+            let _ = std::fs::remove_dir(path);
+        }
+    }
+    result
+}
+
+/// Initialize the workspace of a clone, add the remote and fetch from it,
+/// then check out the remote's default branch
+#[cfg(feature = "git")]
+fn clone_git_into(
+    source: &str,
+    path: &Path,
+    options: &FfiCloneOptions,
+    load_settings: &dyn Fn(&Path) -> Result<UserSettings>,
+) -> Result<Arc<FfiWorkspace>> {
+    let repo_path = path.join(".jj").join("repo");
+    let settings = load_settings(&repo_path)?;
+    let remote = RemoteName::new(&options.remote_name);
+    let (_workspace, repo) = if options.colocated {
+        Workspace::init_colocated_git(&settings, path)?
+    } else {
+        Workspace::init_internal_git(&settings, path)?
+    };
+    let mut tx = repo.start_transaction();
+    git::add_remote(
+        tx.repo_mut(),
+        remote,
+        source,
+        gix::remote::fetch::Tags::Included,
+        &StringExpression::all(),
+    )
+    .map_err(|e| JjError::Git {
+        message: e.to_string(),
+    })?;
+    tx.commit(format!("add git remote {}", remote.as_symbol()))?;
+
+    // Load the workspace again so the Git repository sees the new remote
+    let workspace = FfiWorkspace::load_with_settings(path, &settings)?;
+    let git_tx = workspace.repo().start_git_transaction()?;
+    let fetch_options = FfiFetchOptions {
+        prune: true,
+        depth: options.depth,
+        credentials: options.credentials.clone(),
+        progress: options.progress.clone(),
+        cancellation: options.cancellation.clone(),
+    };
+    let (_stats, default_branch) =
+        git_tx.fetch_impl(&options.remote_name, &[], &fetch_options, true)?;
+    git_tx.commit("fetch from git remote into empty repo".to_string())?;
+    let repo = workspace.reload()?;
+
+    let Some(name) = default_branch else {
+        return Ok(workspace);
+    };
+    let symbol = name.to_remote_symbol(remote);
+    let remote_ref = repo.inner().view().get_remote_bookmark(symbol);
+    let Some(commit_id) = remote_ref.target.as_normal().cloned() else {
+        return Ok(workspace);
+    };
+    // Make the default branch the trunk like the `jj` CLI does
+    let mut config_file =
+        ConfigFile::load_or_empty(ConfigSource::Repo, repo_path.join("config.toml")).map_err(
+            |e| JjError::Repository {
+                message: e.to_string(),
+            },
+        )?;
+    config_file
+        .set_value(["revset-aliases", "trunk()"], symbol.to_string())
+        .map_err(|e| JjError::Repository {
+            message: e.to_string(),
+        })?;
+    config_file.save().map_err(|e| JjError::Repository {
+        message: e.to_string(),
+    })?;
+
+    let track_default = settings
+        .get_bool("git.track-default-bookmark-on-clone")
+        .unwrap_or(true);
+    workspace.update_working_copy(
+        &format!("check out git remote's branch: {}", name.as_symbol()),
+        &[commit_id],
+        |mut_repo, workspace_name, ids| {
+            if track_default {
+                mut_repo
+                    .track_remote_bookmark(symbol)
+                    .map_err(|e| JjError::Internal {
+                        message: format!("Index error: {}", e),
+                    })?;
+            }
+            let commit = mut_repo.store().get_commit(&ids[0])?;
+            mut_repo
+                .check_out(workspace_name, &commit)
+                .map_err(|e| JjError::Transaction {
+                    message: e.to_string(),
+                })
+        },
+    )?;
+    drop(workspace);
+
+    // Load the workspace again with the trunk alias
+    FfiWorkspace::load_with_settings(path, &load_settings(&repo_path)?)
+}
+
+/// Clone a Git repository into a new workspace, like `jj git clone`
+///
+/// `source` is the URL or path of the repository to clone. The remote's
+/// bookmarks are fetched, and the working copy is checked out on top of the
+/// remote's default branch, which becomes `trunk()` and is tracked by a
+/// local bookmark. `workspace_path` must not exist or be an empty directory.
+#[cfg(feature = "git")]
+#[uniffi::export]
+pub fn clone_git_workspace(
+    source: String,
+    workspace_path: String,
+    user_name: String,
+    user_email: String,
+    options: FfiCloneOptions,
+) -> Result<Arc<FfiWorkspace>> {
+    clone_git(&source, Path::new(&workspace_path), &options, |repo_path| {
+        let settings = create_user_settings(&user_name, &user_email)?;
+        with_config_aliases(&settings, repo_path)
+    })
+}
+
+/// Clone a Git repository into a new workspace, with settings from the
+/// user's jj config
+///
+/// See `clone_git_workspace()` for how the repository is cloned and
+/// `FfiWorkspace::load_with_user_config()` for how the config is found.
+#[cfg(feature = "git")]
+#[uniffi::export]
+pub fn clone_git_workspace_with_user_config(
+    source: String,
+    workspace_path: String,
+    options: FfiCloneOptions,
+) -> Result<Arc<FfiWorkspace>> {
+    let path = Path::new(&workspace_path);
+    clone_git(&source, path, &options, |repo_path| {
+        load_user_settings(repo_path, Some(path))
+    })
+}